@@ -0,0 +1,61 @@
+use std::collections::HashMap;
+
+use criterion::black_box;
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::Criterion;
+use indradb::Identifier;
+use serde_json::json;
+use uuid::Uuid;
+
+use inexor_rgf_core_model::ComponentTypeId;
+use inexor_rgf_core_model::EntityInstance;
+use inexor_rgf_core_model::EntityInstanceDao;
+use inexor_rgf_core_model::EntityTypeId;
+use inexor_rgf_core_model::PropertyInstanceGetter;
+use inexor_rgf_core_model::PropertyInstanceRefGetter;
+use inexor_rgf_core_model::RelationInstance;
+use inexor_rgf_core_model::TypeDefinitionGetter;
+
+fn create_entity_instance() -> EntityInstance {
+    let mut properties = HashMap::new();
+    for i in 0..16 {
+        properties.insert(format!("property_{}", i), json!(i));
+    }
+    properties.insert("label".to_string(), json!("/org/inexor/benchmark"));
+    EntityInstance::new_from_type("logical", "and", Uuid::new_v4(), properties)
+}
+
+fn identifier_benchmark(c: &mut Criterion) {
+    c.bench_function("component_type_id_new", |b| {
+        b.iter(|| ComponentTypeId::new_from_type(black_box("base"), black_box("labeled")))
+    });
+    let ty = EntityTypeId::new_from_type("logical", "and");
+    c.bench_function("entity_type_id_type_id", |b| b.iter(|| black_box(&ty).type_id()));
+    c.bench_function("entity_type_id_type_definition_identifier", |b| {
+        b.iter(|| Identifier::from(&black_box(&ty).type_definition()))
+    });
+    let relation_instance = RelationInstance::new_from_type_unique_id("connector", Uuid::new_v4(), "default_connector", Uuid::new_v4(), HashMap::new());
+    c.bench_function("relation_instance_get_key", |b| b.iter(|| black_box(&relation_instance).get_key()));
+}
+
+fn conversion_benchmark(c: &mut Criterion) {
+    let entity_instance = create_entity_instance();
+    c.bench_function("entity_instance_clone", |b| b.iter(|| black_box(&entity_instance).clone()));
+    c.bench_function("entity_instance_dao_round_trip", |b| {
+        b.iter(|| EntityInstance::from(EntityInstanceDao::from(black_box(&entity_instance).clone())))
+    });
+    let json = serde_json::to_string(&entity_instance).unwrap();
+    c.bench_function("entity_instance_from_json", |b| b.iter(|| serde_json::from_str::<EntityInstance>(black_box(&json)).unwrap()));
+}
+
+fn property_access_benchmark(c: &mut Criterion) {
+    let entity_instance = create_entity_instance();
+    c.bench_function("entity_instance_get", |b| b.iter(|| black_box(&entity_instance).get("label")));
+    c.bench_function("entity_instance_as_string", |b| b.iter(|| black_box(&entity_instance).as_string("label")));
+    c.bench_function("entity_instance_get_ref", |b| b.iter(|| black_box(&entity_instance).get_ref("label").cloned()));
+    c.bench_function("entity_instance_as_str", |b| b.iter(|| black_box(&entity_instance).as_str("label").map(str::len)));
+}
+
+criterion_group!(benches, identifier_benchmark, conversion_benchmark, property_access_benchmark);
+criterion_main!(benches);
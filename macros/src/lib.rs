@@ -0,0 +1,115 @@
+use proc_macro::Delimiter;
+use proc_macro::Span;
+use proc_macro::TokenStream;
+use proc_macro::TokenTree;
+
+/// The separator of the namespace and the type name. Mirrors the separator of the model.
+const TYPE_ID_TYPE_SEPARATOR: &str = "__";
+
+/// The maximum length of the namespace and of the type name. Mirrors the limit of the model.
+const MAX_IDENTIFIER_LENGTH: usize = 255;
+
+/// Validates the given type id of the form `namespace__type_name` at compile time and expands to
+/// a `StaticTypeId`, which can be used in constants.
+///
+/// ```ignore
+/// const LABELED: StaticTypeId = ty!("core__labeled");
+/// let component_ty: ComponentTypeId = LABELED.into();
+/// ```
+#[proc_macro]
+pub fn ty(input: TokenStream) -> TokenStream {
+    let (type_id, span) = match string_literal(input) {
+        Ok(literal) => literal,
+        Err((message, span)) => return compile_error(message, span),
+    };
+    match parse_type_id(&type_id) {
+        Ok((namespace, type_name)) => format!("::inexor_rgf_core_model::StaticTypeId::new({:?}, {:?})", namespace, type_name)
+            .parse()
+            .expect("Failed to expand the type id"),
+        Err(message) => compile_error(&format!("Invalid type id {:?}: {}", type_id, message), span),
+    }
+}
+
+/// Returns the content of the only string literal of the given tokens.
+fn string_literal(input: TokenStream) -> Result<(String, Span), (&'static str, Span)> {
+    const EXPECTED: &str = "Expected a string literal of the form \"namespace__type_name\"";
+    let mut tokens = input.into_iter();
+    match (tokens.next(), tokens.next()) {
+        // Literals which are passed through declarative macros are wrapped in an invisible group
+        (Some(TokenTree::Group(group)), None) if group.delimiter() == Delimiter::None => string_literal(group.stream()),
+        (Some(TokenTree::Literal(literal)), None) => {
+            let s = literal.to_string();
+            match s.strip_prefix('"').and_then(|s| s.strip_suffix('"')) {
+                Some(s) if !s.contains('\\') => Ok((s.to_string(), literal.span())),
+                _ => Err((EXPECTED, literal.span())),
+            }
+        }
+        (Some(token), _) => Err((EXPECTED, token.span())),
+        (None, _) => Err((EXPECTED, Span::call_site())),
+    }
+}
+
+/// Splits the given type id into the namespace and the type name, following the rules of the
+/// validation of namespaced types. Additionally rejects whitespace, which is most likely a typo.
+fn parse_type_id(type_id: &str) -> Result<(&str, &str), String> {
+    let mut parts = type_id.split(TYPE_ID_TYPE_SEPARATOR);
+    let (Some(namespace), Some(type_name), None) = (parts.next(), parts.next(), parts.next()) else {
+        return Err(format!("Expected exactly one separator {}", TYPE_ID_TYPE_SEPARATOR));
+    };
+    validate_identifier_part("namespace", namespace)?;
+    validate_identifier_part("type name", type_name)?;
+    Ok((namespace, type_name))
+}
+
+fn validate_identifier_part(part: &str, s: &str) -> Result<(), String> {
+    if s.is_empty() {
+        return Err(format!("The {} is empty", part));
+    }
+    if s.len() > MAX_IDENTIFIER_LENGTH {
+        return Err(format!("The {} is longer than {} characters", part, MAX_IDENTIFIER_LENGTH));
+    }
+    if s.chars().any(char::is_whitespace) {
+        return Err(format!("The {} contains whitespace", part));
+    }
+    Ok(())
+}
+
+fn compile_error(message: &str, span: Span) -> TokenStream {
+    format!("::core::compile_error!({:?})", message)
+        .parse::<TokenStream>()
+        .expect("Failed to expand the compile error")
+        .into_iter()
+        .map(|mut token| {
+            token.set_span(span);
+            token
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_type_id;
+    use super::MAX_IDENTIFIER_LENGTH;
+
+    #[test]
+    fn parse_type_id_test() {
+        assert_eq!(Ok(("core", "labeled")), parse_type_id("core__labeled"));
+        assert_eq!(Ok(("logical_gates", "and3")), parse_type_id("logical_gates__and3"));
+    }
+
+    #[test]
+    fn parse_invalid_type_id_test() {
+        assert_eq!(Err(String::from("Expected exactly one separator __")), parse_type_id("core"));
+        assert_eq!(Err(String::from("Expected exactly one separator __")), parse_type_id("a__b__c"));
+        assert_eq!(Err(String::from("The namespace is empty")), parse_type_id("__labeled"));
+        assert_eq!(Err(String::from("The type name is empty")), parse_type_id("core__"));
+        assert_eq!(Err(String::from("The namespace contains whitespace")), parse_type_id("co re__labeled"));
+        assert_eq!(Err(String::from("The type name contains whitespace")), parse_type_id("core__ label"));
+        let too_long = "a".repeat(MAX_IDENTIFIER_LENGTH + 1);
+        assert_eq!(
+            Err(format!("The type name is longer than {} characters", MAX_IDENTIFIER_LENGTH)),
+            parse_type_id(&format!("core__{}", too_long))
+        );
+        assert!(parse_type_id(&format!("core__{}", "a".repeat(MAX_IDENTIFIER_LENGTH))).is_ok());
+    }
+}
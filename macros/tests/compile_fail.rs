@@ -0,0 +1,6 @@
+/// Invalid type ids are rejected at compile time with a message which points at the type id.
+#[test]
+fn ty_compile_fail_test() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
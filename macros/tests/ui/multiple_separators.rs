@@ -0,0 +1,5 @@
+use inexor_rgf_core_model_macros::ty;
+
+fn main() {
+    let _ = ty!("a__b__c");
+}
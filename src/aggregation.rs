@@ -0,0 +1,191 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::json;
+use serde_json::Number;
+use serde_json::Value;
+
+use crate::compare_values;
+use crate::values_equal;
+
+/// The function which aggregates the property values of instances.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum AggregateFunction {
+    /// The number of instances or, if a property is given, the number of instances which have
+    /// the property.
+    Count,
+
+    /// The sum of the numeric property values, null if there are no numeric property values.
+    Sum,
+
+    /// The arithmetic mean of the numeric property values.
+    #[serde(alias = "avg")]
+    Average,
+
+    /// The smallest property value.
+    Min,
+
+    /// The greatest property value.
+    Max,
+}
+
+/// An aggregation of the property values of instances, which are optionally grouped by the value
+/// of another property.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Aggregation {
+    /// The aggregate function.
+    pub function: AggregateFunction,
+
+    /// The name of the aggregated property. Only counting doesn't require a property.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub property: Option<String>,
+
+    /// The name of the property whose values group the instances.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group_by: Option<String>,
+}
+
+/// The result of an aggregation of a group of instances.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct AggregationGroup {
+    /// The value of the property which groups the instances. Null if the instances aren't grouped
+    /// or don't have the property.
+    pub group: Value,
+
+    /// The aggregated value. Null if no instance of the group has a suitable property value.
+    pub value: Value,
+}
+
+impl Aggregation {
+    pub fn new(function: AggregateFunction, property: Option<String>) -> Aggregation {
+        Aggregation {
+            function,
+            property,
+            group_by: None,
+        }
+    }
+
+    /// Counts the instances.
+    pub fn count() -> Aggregation {
+        Aggregation::new(AggregateFunction::Count, None)
+    }
+
+    /// Sums up the values of the given property.
+    pub fn sum<S: Into<String>>(property: S) -> Aggregation {
+        Aggregation::new(AggregateFunction::Sum, Some(property.into()))
+    }
+
+    /// Averages the values of the given property.
+    pub fn average<S: Into<String>>(property: S) -> Aggregation {
+        Aggregation::new(AggregateFunction::Average, Some(property.into()))
+    }
+
+    /// Returns the smallest value of the given property.
+    pub fn min<S: Into<String>>(property: S) -> Aggregation {
+        Aggregation::new(AggregateFunction::Min, Some(property.into()))
+    }
+
+    /// Returns the greatest value of the given property.
+    pub fn max<S: Into<String>>(property: S) -> Aggregation {
+        Aggregation::new(AggregateFunction::Max, Some(property.into()))
+    }
+
+    /// Groups the instances by the values of the given property.
+    pub fn group_by<S: Into<String>>(mut self, property: S) -> Aggregation {
+        self.group_by = Some(property.into());
+        self
+    }
+
+    /// Aggregates the given properties of instances.
+    ///
+    /// The groups are ordered by the first instance of the group. Numbers are grouped by their
+    /// numeric value, so `1` and `1.0` are in the same group. Without grouping there is exactly
+    /// one group.
+    pub fn aggregate<'a, I: IntoIterator<Item = &'a HashMap<String, Value>>>(&self, instances: I) -> Vec<AggregationGroup> {
+        let mut groups: Vec<(Value, Vec<&'a HashMap<String, Value>>)> = Vec::new();
+        if self.group_by.is_none() {
+            groups.push((Value::Null, Vec::new()));
+        }
+        for properties in instances {
+            let group = match &self.group_by {
+                Some(group_by) => properties.get(group_by).cloned().unwrap_or(Value::Null),
+                None => Value::Null,
+            };
+            match groups.iter_mut().find(|(g, _)| values_equal(g, &group)) {
+                Some((_, instances)) => instances.push(properties),
+                None => groups.push((group, vec![properties])),
+            }
+        }
+        groups
+            .into_iter()
+            .map(|(group, instances)| AggregationGroup {
+                group,
+                value: self.aggregate_group(&instances),
+            })
+            .collect()
+    }
+
+    fn aggregate_group(&self, instances: &[&HashMap<String, Value>]) -> Value {
+        let Some(property) = &self.property else {
+            return match self.function {
+                AggregateFunction::Count => json!(instances.len()),
+                _ => Value::Null,
+            };
+        };
+        let values = instances.iter().filter_map(|properties| properties.get(property));
+        match self.function {
+            AggregateFunction::Count => json!(values.count()),
+            AggregateFunction::Sum => sum(values.filter_map(as_number).collect()),
+            AggregateFunction::Average => {
+                let numbers: Vec<f64> = values.filter_map(Value::as_f64).collect();
+                if numbers.is_empty() {
+                    Value::Null
+                } else {
+                    json!(numbers.iter().sum::<f64>() / numbers.len() as f64)
+                }
+            }
+            AggregateFunction::Min => extremum(values, Ordering::Less),
+            AggregateFunction::Max => extremum(values, Ordering::Greater),
+        }
+    }
+}
+
+fn as_number(value: &Value) -> Option<&Number> {
+    match value {
+        Value::Number(number) => Some(number),
+        _ => None,
+    }
+}
+
+/// Returns the sum of the numbers. The sum of integers is an integer unless it overflows.
+fn sum(numbers: Vec<&Number>) -> Value {
+    if numbers.is_empty() {
+        return Value::Null;
+    }
+    let integer_sum = numbers
+        .iter()
+        .try_fold(0i64, |sum, number| number.as_i64().and_then(|number| sum.checked_add(number)));
+    match integer_sum {
+        Some(sum) => json!(sum),
+        None => json!(numbers.iter().filter_map(|number| number.as_f64()).sum::<f64>()),
+    }
+}
+
+/// Returns the value which compares with the given ordering to all other values. Values which
+/// can't be compared with the current extremum are skipped.
+fn extremum<'a, I: Iterator<Item = &'a Value>>(values: I, ordering: Ordering) -> Value {
+    let mut extremum: Option<&Value> = None;
+    for value in values.filter(|value| value.is_number() || value.is_string()) {
+        match extremum {
+            Some(current) if compare_values(value, current) != Some(ordering) => {}
+            _ => extremum = Some(value),
+        }
+    }
+    extremum.cloned().unwrap_or(Value::Null)
+}
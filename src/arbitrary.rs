@@ -0,0 +1,216 @@
+use std::collections::HashMap;
+
+use proptest::collection::btree_map;
+use proptest::collection::hash_map;
+use proptest::collection::vec;
+use proptest::prelude::*;
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::identifier_string;
+use crate::BehaviourTypeId;
+use crate::Component;
+use crate::ComponentTypeId;
+use crate::DataType;
+use crate::EntityInstance;
+use crate::EntityTypeId;
+use crate::ExtensionTypeId;
+use crate::FlowTypeId;
+use crate::Mutability;
+use crate::NamespacedType;
+use crate::PropertyType;
+use crate::RelationInstance;
+use crate::RelationInstanceTypeId;
+use crate::RelationTypeId;
+use crate::SocketType;
+
+/// The pattern of generated namespaces, type names and property names. The pattern never contains
+/// the separator of type ids.
+const IDENTIFIER_PATTERN: &str = "[a-z][a-z0-9]{0,11}(_[a-z0-9]{1,8}){0,2}";
+
+/// The maximum number of generated properties of a type or an instance.
+const MAX_PROPERTIES: usize = 6;
+
+/// Returns a strategy of valid namespaces, type names and property names.
+pub fn arb_identifier() -> impl Strategy<Value = String> {
+    proptest::string::string_regex(IDENTIFIER_PATTERN).expect("Invalid identifier pattern")
+}
+
+/// Returns a strategy of arbitrary JSON values including nested arrays and objects.
+pub fn arb_value() -> impl Strategy<Value = Value> {
+    let leaf = prop_oneof![
+        Just(Value::Null),
+        any::<bool>().prop_map(Value::from),
+        any::<i64>().prop_map(Value::from),
+        (-1.0e9f64..1.0e9f64).prop_map(Value::from),
+        ".{0,16}".prop_map(Value::from),
+    ];
+    leaf.prop_recursive(3, 32, 4, |inner| {
+        prop_oneof![
+            vec(inner.clone(), 0..4).prop_map(Value::Array),
+            hash_map(arb_identifier(), inner, 0..4).prop_map(|object| Value::Object(object.into_iter().collect())),
+        ]
+    })
+}
+
+/// Returns a strategy of JSON values of the given data type.
+pub fn arb_value_of(data_type: DataType) -> BoxedStrategy<Value> {
+    match data_type {
+        DataType::Null => Just(Value::Null).boxed(),
+        DataType::Bool => any::<bool>().prop_map(Value::from).boxed(),
+        DataType::Number => any::<i64>().prop_map(Value::from).boxed(),
+        DataType::String => ".{0,16}".prop_map(Value::from).boxed(),
+        DataType::Array => vec(arb_value(), 0..4).prop_map(Value::Array).boxed(),
+        DataType::Object => hash_map(arb_identifier(), arb_value(), 0..4)
+            .prop_map(|object| Value::Object(object.into_iter().collect()))
+            .boxed(),
+        DataType::Any => arb_value().boxed(),
+    }
+}
+
+fn arb_uuid() -> impl Strategy<Value = Uuid> {
+    any::<[u8; 16]>().prop_map(Uuid::from_bytes)
+}
+
+fn arb_properties() -> impl Strategy<Value = HashMap<String, Value>> {
+    hash_map(arb_identifier(), arb_value(), 0..MAX_PROPERTIES)
+}
+
+impl Arbitrary for NamespacedType {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (arb_identifier(), arb_identifier())
+            .prop_map(|(namespace, type_name)| NamespacedType::new(namespace, type_name))
+            .boxed()
+    }
+}
+
+macro_rules! arbitrary_type_id {
+    ($type_id: ident) => {
+        impl Arbitrary for $type_id {
+            type Parameters = ();
+            type Strategy = BoxedStrategy<Self>;
+
+            fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+                any::<NamespacedType>().prop_map($type_id::from).boxed()
+            }
+        }
+    };
+}
+
+arbitrary_type_id!(BehaviourTypeId);
+arbitrary_type_id!(ComponentTypeId);
+arbitrary_type_id!(EntityTypeId);
+arbitrary_type_id!(ExtensionTypeId);
+arbitrary_type_id!(FlowTypeId);
+arbitrary_type_id!(RelationTypeId);
+
+impl Arbitrary for RelationInstanceTypeId {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (any::<RelationTypeId>(), proptest::option::of(arb_identifier()))
+            .prop_map(|(ty, instance_id)| match instance_id {
+                Some(instance_id) => RelationInstanceTypeId::new_unique_for_instance_id(ty, instance_id),
+                None => RelationInstanceTypeId::new_unique_id(ty),
+            })
+            .boxed()
+    }
+}
+
+impl Arbitrary for DataType {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            Just(DataType::Null),
+            Just(DataType::Bool),
+            Just(DataType::Number),
+            Just(DataType::String),
+            Just(DataType::Array),
+            Just(DataType::Object),
+            Just(DataType::Any),
+        ]
+        .boxed()
+    }
+}
+
+impl Arbitrary for SocketType {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        prop_oneof![Just(SocketType::None), Just(SocketType::Input), Just(SocketType::Output)].boxed()
+    }
+}
+
+impl Arbitrary for Mutability {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        prop_oneof![Just(Mutability::Mutable), Just(Mutability::Immutable)].boxed()
+    }
+}
+
+impl Arbitrary for PropertyType {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (arb_identifier(), ".{0,32}", any::<DataType>(), any::<SocketType>(), any::<Mutability>())
+            .prop_map(|(name, description, data_type, socket_type, mutability)| {
+                PropertyType::new_with_socket(name, data_type, socket_type)
+                    .with_description(description)
+                    .with_mutability(mutability)
+            })
+            .boxed()
+    }
+}
+
+/// The properties of generated components are unique by name.
+impl Arbitrary for Component {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (any::<ComponentTypeId>(), ".{0,32}", btree_map(arb_identifier(), any::<PropertyType>(), 0..MAX_PROPERTIES))
+            .prop_map(|(ty, description, properties)| {
+                let properties = properties
+                    .into_iter()
+                    .map(|(name, mut property_type)| {
+                        property_type.name = identifier_string(name);
+                        property_type
+                    })
+                    .collect();
+                Component::new_without_extensions(ty, description, properties)
+            })
+            .boxed()
+    }
+}
+
+impl Arbitrary for EntityInstance {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (any::<EntityTypeId>(), arb_uuid(), arb_properties())
+            .prop_map(|(ty, id, properties)| EntityInstance::new(ty, id, properties))
+            .boxed()
+    }
+}
+
+impl Arbitrary for RelationInstance {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (arb_uuid(), any::<RelationInstanceTypeId>(), arb_uuid(), arb_properties())
+            .prop_map(|(outbound_id, ty, inbound_id, properties)| RelationInstance::new(outbound_id, ty, inbound_id, properties))
+            .boxed()
+    }
+}
@@ -0,0 +1,116 @@
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+use serde::Deserialize;
+use serde::Serialize;
+use thiserror::Error;
+
+/// The lifecycle state of a behaviour.
+///
+/// A behaviour is created, validated against the reactive instance, initialized and finally
+/// connected to the property streams of the reactive instance:
+///
+/// `Created` -> `Valid` -> `Ready` <-> `Connected`
+///
+/// A ready behaviour can be shut down, which brings it back to the state `Valid`.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum BehaviourState {
+    /// The behaviour has been created.
+    #[default]
+    Created,
+
+    /// The reactive instance has been validated, for example that all properties exist.
+    Valid,
+
+    /// The behaviour has been initialized and is ready to be connected.
+    Ready,
+
+    /// The behaviour is connected to the property streams of the reactive instance.
+    Connected,
+}
+
+impl BehaviourState {
+    /// Returns true, if the behaviour can transition from this state to the given state.
+    pub fn can_transition_to(&self, target: BehaviourState) -> bool {
+        matches!(
+            (self, target),
+            (BehaviourState::Created, BehaviourState::Valid)
+                | (BehaviourState::Valid, BehaviourState::Ready)
+                | (BehaviourState::Ready, BehaviourState::Connected)
+                | (BehaviourState::Connected, BehaviourState::Ready)
+                | (BehaviourState::Ready, BehaviourState::Valid)
+        )
+    }
+
+    /// Returns the given state, if the behaviour can transition from this state to the given state.
+    pub fn transition(self, target: BehaviourState) -> Result<BehaviourState, BehaviourTransitionError> {
+        if self.can_transition_to(target) {
+            Ok(target)
+        } else {
+            Err(BehaviourTransitionError::InvalidTransition { from: self, to: target })
+        }
+    }
+
+    /// Returns true, if the behaviour is connected.
+    pub fn is_connected(&self) -> bool {
+        *self == BehaviourState::Connected
+    }
+}
+
+/// Error of parsing an unknown lifecycle state of a behaviour.
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+#[error("Unknown behaviour state {0}")]
+pub struct BehaviourStateParseError(pub String);
+
+impl FromStr for BehaviourState {
+    type Err = BehaviourStateParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "created" => Ok(Self::Created),
+            "valid" => Ok(Self::Valid),
+            "ready" => Ok(Self::Ready),
+            "connected" => Ok(Self::Connected),
+            _ => Err(BehaviourStateParseError(s.to_string())),
+        }
+    }
+}
+
+impl Display for BehaviourState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// Error of a transition of the lifecycle state of a behaviour.
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum BehaviourTransitionError {
+    /// The behaviour can't transition from the current state to the target state.
+    #[error("The behaviour can't transition from {from} to {to}")]
+    InvalidTransition { from: BehaviourState, to: BehaviourState },
+
+    /// The reactive instance is not valid for the behaviour.
+    #[error("The validation of the behaviour failed: {0}")]
+    ValidationFailed(String),
+
+    /// The initialization of the behaviour failed.
+    #[error("The initialization of the behaviour failed: {0}")]
+    InitializationFailed(String),
+
+    /// The behaviour couldn't be connected to the reactive instance.
+    #[error("The behaviour couldn't be connected: {0}")]
+    ConnectFailed(String),
+
+    /// The behaviour couldn't be disconnected from the reactive instance.
+    #[error("The behaviour couldn't be disconnected: {0}")]
+    DisconnectFailed(String),
+
+    /// The shutdown of the behaviour failed.
+    #[error("The shutdown of the behaviour failed: {0}")]
+    ShutdownFailed(String),
+}
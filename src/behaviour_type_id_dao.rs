@@ -0,0 +1,134 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::BehaviourTypeId;
+use crate::ComponentBehaviourTypeId;
+use crate::ComponentTypeId;
+use crate::EntityBehaviourTypeId;
+use crate::EntityTypeId;
+use crate::NamespacedTypeGetter;
+use crate::RelationBehaviourTypeId;
+use crate::RelationTypeId;
+
+/// Data access object of the binding of a behaviour to a component.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ComponentBehaviourTypeIdDao {
+    /// The namespace of the component.
+    #[serde(default = "String::new")]
+    pub namespace: String,
+
+    /// The name of the component.
+    #[serde(alias = "name")]
+    pub type_name: String,
+
+    /// The namespace of the behaviour.
+    #[serde(default = "String::new")]
+    pub behaviour_namespace: String,
+
+    /// The name of the behaviour.
+    #[serde(alias = "behaviour")]
+    pub behaviour_type_name: String,
+}
+
+impl From<ComponentBehaviourTypeId> for ComponentBehaviourTypeIdDao {
+    fn from(ty: ComponentBehaviourTypeId) -> Self {
+        ComponentBehaviourTypeIdDao {
+            namespace: ty.component_ty.namespace(),
+            type_name: ty.component_ty.type_name(),
+            behaviour_namespace: ty.behaviour_ty.namespace(),
+            behaviour_type_name: ty.behaviour_ty.type_name(),
+        }
+    }
+}
+
+impl From<ComponentBehaviourTypeIdDao> for ComponentBehaviourTypeId {
+    fn from(dao: ComponentBehaviourTypeIdDao) -> Self {
+        ComponentBehaviourTypeId::new(
+            ComponentTypeId::new_from_type(dao.namespace, dao.type_name),
+            BehaviourTypeId::new_from_type(dao.behaviour_namespace, dao.behaviour_type_name),
+        )
+    }
+}
+
+/// Data access object of the binding of a behaviour to an entity type.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct EntityBehaviourTypeIdDao {
+    /// The namespace of the entity type.
+    #[serde(default = "String::new")]
+    pub namespace: String,
+
+    /// The name of the entity type.
+    #[serde(alias = "name")]
+    pub type_name: String,
+
+    /// The namespace of the behaviour.
+    #[serde(default = "String::new")]
+    pub behaviour_namespace: String,
+
+    /// The name of the behaviour.
+    #[serde(alias = "behaviour")]
+    pub behaviour_type_name: String,
+}
+
+impl From<EntityBehaviourTypeId> for EntityBehaviourTypeIdDao {
+    fn from(ty: EntityBehaviourTypeId) -> Self {
+        EntityBehaviourTypeIdDao {
+            namespace: ty.entity_ty.namespace(),
+            type_name: ty.entity_ty.type_name(),
+            behaviour_namespace: ty.behaviour_ty.namespace(),
+            behaviour_type_name: ty.behaviour_ty.type_name(),
+        }
+    }
+}
+
+impl From<EntityBehaviourTypeIdDao> for EntityBehaviourTypeId {
+    fn from(dao: EntityBehaviourTypeIdDao) -> Self {
+        EntityBehaviourTypeId::new(
+            EntityTypeId::new_from_type(dao.namespace, dao.type_name),
+            BehaviourTypeId::new_from_type(dao.behaviour_namespace, dao.behaviour_type_name),
+        )
+    }
+}
+
+/// Data access object of the binding of a behaviour to a relation type.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct RelationBehaviourTypeIdDao {
+    /// The namespace of the relation type.
+    #[serde(default = "String::new")]
+    pub namespace: String,
+
+    /// The name of the relation type.
+    #[serde(alias = "name")]
+    pub type_name: String,
+
+    /// The namespace of the behaviour.
+    #[serde(default = "String::new")]
+    pub behaviour_namespace: String,
+
+    /// The name of the behaviour.
+    #[serde(alias = "behaviour")]
+    pub behaviour_type_name: String,
+}
+
+impl From<RelationBehaviourTypeId> for RelationBehaviourTypeIdDao {
+    fn from(ty: RelationBehaviourTypeId) -> Self {
+        RelationBehaviourTypeIdDao {
+            namespace: ty.relation_ty.namespace(),
+            type_name: ty.relation_ty.type_name(),
+            behaviour_namespace: ty.behaviour_ty.namespace(),
+            behaviour_type_name: ty.behaviour_ty.type_name(),
+        }
+    }
+}
+
+impl From<RelationBehaviourTypeIdDao> for RelationBehaviourTypeId {
+    fn from(dao: RelationBehaviourTypeIdDao) -> Self {
+        RelationBehaviourTypeId::new(
+            RelationTypeId::new_from_type(dao.namespace, dao.type_name),
+            BehaviourTypeId::new_from_type(dao.behaviour_namespace, dao.behaviour_type_name),
+        )
+    }
+}
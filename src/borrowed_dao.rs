@@ -0,0 +1,354 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use chrono::DateTime;
+use chrono::Utc;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde_json::value::RawValue;
+use serde_json::Map;
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::is_initial_revision;
+use crate::EntityInstance;
+use crate::EntityInstanceDao;
+use crate::EntityTypeId;
+use crate::Extension;
+use crate::FlowInstance;
+use crate::FlowInstanceDao;
+use crate::RelationInstance;
+use crate::RelationInstanceDao;
+use crate::RelationInstanceTypeId;
+use crate::RelationTypeId;
+use crate::SubFlowReference;
+
+/// Borrowing data access object of an entity instance.
+///
+/// The namespace, the type name, the description and the property names are borrowed from the
+/// input document if they don't contain escape sequences. The property values are borrowed as
+/// raw JSON and are parsed on conversion. Borrowing raw JSON requires deserializing with
+/// `serde_json` from a string; other formats and `serde_json::from_value` can't borrow raw JSON.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct EntityInstanceRefDao<'a> {
+    /// The namespace of the entity type.
+    #[serde(borrow, default)]
+    pub namespace: Cow<'a, str>,
+
+    /// The name of the entity type.
+    #[serde(borrow, alias = "name")]
+    pub type_name: Cow<'a, str>,
+
+    /// The unique identifier of the entity instance.
+    pub id: Uuid,
+
+    /// The description of the entity instance.
+    #[serde(borrow, default)]
+    pub description: Cow<'a, str>,
+
+    /// The properties of the entity instance.
+    #[serde(borrow, default = "HashMap::new", deserialize_with = "deserialize_borrowed_properties")]
+    #[cfg_attr(feature = "ordered_properties", serde(serialize_with = "crate::serialize_ordered_map"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "HashMap<String, Value>"))]
+    pub properties: HashMap<Cow<'a, str>, &'a RawValue>,
+
+    /// Entity instance specific extensions.
+    #[serde(default = "Vec::new")]
+    pub extensions: Vec<Extension>,
+
+    /// The point of time when the entity instance has been created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+
+    /// The point of time when the entity instance has been updated for the last time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+
+    /// The user or the service which has created the entity instance.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<String>,
+
+    /// The point of time when the entity instance has been deleted. Deleted entity instances are kept as
+    /// tombstones until they are purged, so that the deletion can be undone or synchronized.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<DateTime<Utc>>,
+
+    /// The revision of the entity instance, which is incremented by each modification. Concurrent editors
+    /// compare the revision in order to detect conflicting writes.
+    #[serde(default, skip_serializing_if = "is_initial_revision")]
+    pub revision: u64,
+
+    /// Unknown fields, for example written by a newer version, which are re-emitted on save.
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl TryFrom<EntityInstanceRefDao<'_>> for EntityInstance {
+    type Error = serde_json::Error;
+
+    fn try_from(dao: EntityInstanceRefDao<'_>) -> Result<Self, Self::Error> {
+        Ok(EntityInstance {
+            ty: EntityTypeId::new_from_type(dao.namespace, dao.type_name),
+            id: dao.id,
+            description: dao.description.into_owned(),
+            properties: parse_properties(dao.properties)?.into(),
+            extensions: dao.extensions,
+            created_at: dao.created_at,
+            updated_at: dao.updated_at,
+            created_by: dao.created_by,
+            deleted_at: dao.deleted_at,
+            revision: dao.revision,
+            extra: dao.extra,
+        })
+    }
+}
+
+impl TryFrom<EntityInstanceRefDao<'_>> for EntityInstanceDao {
+    type Error = serde_json::Error;
+
+    fn try_from(dao: EntityInstanceRefDao<'_>) -> Result<Self, Self::Error> {
+        Ok(EntityInstanceDao {
+            namespace: dao.namespace.into_owned(),
+            type_name: dao.type_name.into_owned(),
+            id: dao.id,
+            description: dao.description.into_owned(),
+            properties: parse_properties(dao.properties)?,
+            extensions: dao.extensions,
+            created_at: dao.created_at,
+            updated_at: dao.updated_at,
+            created_by: dao.created_by,
+            deleted_at: dao.deleted_at,
+            revision: dao.revision,
+            extra: dao.extra,
+        })
+    }
+}
+
+/// Borrowing data access object of a relation instance.
+///
+/// The namespace, the type name, the instance id, the description and the property names are
+/// borrowed from the input document if they don't contain escape sequences. The property values
+/// are borrowed as raw JSON and are parsed on conversion.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct RelationInstanceRefDao<'a> {
+    /// The id of the outbound entity instance.
+    #[serde(alias = "outbound")]
+    pub outbound_id: Uuid,
+
+    /// The namespace of the relation type.
+    #[serde(borrow, default)]
+    pub namespace: Cow<'a, str>,
+
+    /// The name of the relation type.
+    #[serde(borrow, alias = "name")]
+    pub type_name: Cow<'a, str>,
+
+    /// The instance id of the relation instance.
+    #[serde(borrow, default)]
+    pub instance_id: Cow<'a, str>,
+
+    /// The id of the inbound entity instance.
+    #[serde(alias = "inbound")]
+    pub inbound_id: Uuid,
+
+    /// The description of the relation instance.
+    #[serde(borrow, default)]
+    pub description: Cow<'a, str>,
+
+    /// The properties of the relation instance.
+    #[serde(borrow, default = "HashMap::new", deserialize_with = "deserialize_borrowed_properties")]
+    #[cfg_attr(feature = "ordered_properties", serde(serialize_with = "crate::serialize_ordered_map"))]
+    #[cfg_attr(feature = "schemars", schemars(with = "HashMap<String, Value>"))]
+    pub properties: HashMap<Cow<'a, str>, &'a RawValue>,
+
+    /// Relation instance specific extensions.
+    #[serde(default = "Vec::new")]
+    pub extensions: Vec<Extension>,
+
+    /// The point of time when the relation instance has been created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+
+    /// The point of time when the relation instance has been updated for the last time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+
+    /// The user or the service which has created the relation instance.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<String>,
+
+    /// The point of time when the relation instance has been deleted. Deleted relation instances are kept as
+    /// tombstones until they are purged, so that the deletion can be undone or synchronized.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<DateTime<Utc>>,
+
+    /// The revision of the relation instance, which is incremented by each modification. Concurrent editors
+    /// compare the revision in order to detect conflicting writes.
+    #[serde(default, skip_serializing_if = "is_initial_revision")]
+    pub revision: u64,
+
+    /// Unknown fields, for example written by a newer version, which are re-emitted on save.
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl TryFrom<RelationInstanceRefDao<'_>> for RelationInstance {
+    type Error = serde_json::Error;
+
+    fn try_from(dao: RelationInstanceRefDao<'_>) -> Result<Self, Self::Error> {
+        let ty = RelationInstanceTypeId::new_unique_for_instance_id(RelationTypeId::new_from_type(dao.namespace, dao.type_name), dao.instance_id);
+        Ok(RelationInstance {
+            outbound_id: dao.outbound_id,
+            ty,
+            inbound_id: dao.inbound_id,
+            description: dao.description.into_owned(),
+            properties: parse_properties(dao.properties)?.into(),
+            extensions: dao.extensions,
+            created_at: dao.created_at,
+            updated_at: dao.updated_at,
+            created_by: dao.created_by,
+            deleted_at: dao.deleted_at,
+            revision: dao.revision,
+            extra: dao.extra,
+        })
+    }
+}
+
+impl TryFrom<RelationInstanceRefDao<'_>> for RelationInstanceDao {
+    type Error = serde_json::Error;
+
+    fn try_from(dao: RelationInstanceRefDao<'_>) -> Result<Self, Self::Error> {
+        Ok(RelationInstanceDao {
+            outbound_id: dao.outbound_id,
+            namespace: dao.namespace.into_owned(),
+            type_name: dao.type_name.into_owned(),
+            instance_id: dao.instance_id.into_owned(),
+            inbound_id: dao.inbound_id,
+            description: dao.description.into_owned(),
+            properties: parse_properties(dao.properties)?,
+            extensions: dao.extensions,
+            created_at: dao.created_at,
+            updated_at: dao.updated_at,
+            created_by: dao.created_by,
+            deleted_at: dao.deleted_at,
+            revision: dao.revision,
+            extra: dao.extra,
+        })
+    }
+}
+
+/// Borrowing data access object of a flow instance.
+///
+/// Loading a flow file with the borrowing data access object doesn't duplicate the type names,
+/// the descriptions and the properties of the document until the flow instance is constructed.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct FlowInstanceRefDao<'a> {
+    /// The id of the flow instance which is also the id of the wrapper entity instance.
+    pub id: Uuid,
+
+    /// The namespace of the entity type of the wrapper entity instance.
+    #[serde(borrow, default)]
+    pub namespace: Cow<'a, str>,
+
+    /// The name of the entity type of the wrapper entity instance.
+    #[serde(borrow)]
+    pub type_name: Cow<'a, str>,
+
+    /// The name of the flow instance.
+    #[serde(borrow, default, alias = "flow_instance_name")]
+    pub name: Cow<'a, str>,
+
+    /// Textual description of the flow instance.
+    #[serde(borrow, default)]
+    pub description: Cow<'a, str>,
+
+    /// The entity instances which are contained in the flow instance.
+    #[serde(borrow, default = "Vec::new", alias = "entities")]
+    pub entity_instances: Vec<EntityInstanceRefDao<'a>>,
+
+    /// The relation instances which are contained in the flow instance.
+    #[serde(borrow, default = "Vec::new", alias = "relations")]
+    pub relation_instances: Vec<RelationInstanceRefDao<'a>>,
+
+    /// The ids of the external entity instances which are referenced by the relation instances.
+    #[serde(default = "Vec::new", alias = "externals")]
+    pub external_entity_ids: Vec<Uuid>,
+
+    /// The references to the embedded child flow instances.
+    #[serde(default = "Vec::new")]
+    pub sub_flows: Vec<SubFlowReference>,
+
+    /// Unknown fields, for example written by a newer version, which are re-emitted on save.
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl TryFrom<FlowInstanceRefDao<'_>> for FlowInstance {
+    type Error = serde_json::Error;
+
+    fn try_from(dao: FlowInstanceRefDao<'_>) -> Result<Self, Self::Error> {
+        Ok(FlowInstance {
+            id: dao.id,
+            ty: EntityTypeId::new_from_type(dao.namespace, dao.type_name),
+            name: dao.name.into_owned(),
+            description: dao.description.into_owned(),
+            entity_instances: dao.entity_instances.into_iter().map(EntityInstance::try_from).collect::<Result<Vec<_>, _>>()?,
+            relation_instances: dao
+                .relation_instances
+                .into_iter()
+                .map(RelationInstance::try_from)
+                .collect::<Result<Vec<_>, _>>()?,
+            external_entity_ids: dao.external_entity_ids,
+            sub_flows: dao.sub_flows,
+            extra: dao.extra,
+        })
+    }
+}
+
+impl TryFrom<FlowInstanceRefDao<'_>> for FlowInstanceDao {
+    type Error = serde_json::Error;
+
+    fn try_from(dao: FlowInstanceRefDao<'_>) -> Result<Self, Self::Error> {
+        Ok(FlowInstanceDao {
+            id: dao.id,
+            namespace: dao.namespace.into_owned(),
+            type_name: dao.type_name.into_owned(),
+            name: dao.name.into_owned(),
+            description: dao.description.into_owned(),
+            entity_instances: dao
+                .entity_instances
+                .into_iter()
+                .map(EntityInstanceDao::try_from)
+                .collect::<Result<Vec<_>, _>>()?,
+            relation_instances: dao
+                .relation_instances
+                .into_iter()
+                .map(RelationInstanceDao::try_from)
+                .collect::<Result<Vec<_>, _>>()?,
+            external_entity_ids: dao.external_entity_ids,
+            sub_flows: dao.sub_flows,
+            extra: dao.extra,
+        })
+    }
+}
+
+/// The name of a property, which is borrowed from the input document if it doesn't contain
+/// escape sequences.
+#[derive(Deserialize, PartialEq, Eq, Hash)]
+struct PropertyName<'a>(#[serde(borrow)] Cow<'a, str>);
+
+fn deserialize_borrowed_properties<'de: 'a, 'a, D: Deserializer<'de>>(deserializer: D) -> Result<HashMap<Cow<'a, str>, &'a RawValue>, D::Error> {
+    let properties: HashMap<PropertyName<'a>, &'a RawValue> = HashMap::deserialize(deserializer)?;
+    Ok(properties.into_iter().map(|(name, value)| (name.0, value)).collect())
+}
+
+fn parse_properties(properties: HashMap<Cow<'_, str>, &RawValue>) -> Result<HashMap<String, Value>, serde_json::Error> {
+    properties
+        .into_iter()
+        .map(|(name, value)| Ok((name.into_owned(), serde_json::from_str(value.get())?)))
+        .collect()
+}
@@ -0,0 +1,99 @@
+use std::collections::HashMap;
+
+use indradb::BulkInsertItem;
+use indradb::EdgeKey;
+use indradb::Identifier;
+use indradb::Vertex;
+
+use crate::property_identifier;
+use crate::EntityInstance;
+use crate::EntityTypeId;
+use crate::FlowInstance;
+use crate::RelationInstance;
+use crate::RelationInstanceTypeId;
+use crate::TypeDefinitionGetter;
+
+/// Returns the bulk insert items of the given entity instances.
+///
+/// Each vertex precedes its properties.
+pub fn entity_instances_to_bulk_insert_items(entity_instances: &[EntityInstance]) -> Vec<BulkInsertItem> {
+    to_bulk_insert_items(entity_instances, &[])
+}
+
+/// Returns the bulk insert items of the given relation instances.
+///
+/// Each edge precedes its properties. The outbound and inbound vertices have to exist already.
+pub fn relation_instances_to_bulk_insert_items(relation_instances: &[RelationInstance]) -> Vec<BulkInsertItem> {
+    to_bulk_insert_items(&[], relation_instances)
+}
+
+/// Returns the bulk insert items of the given entity instances and relation instances.
+///
+/// All vertices and their properties precede the edges and their properties, so that the edges
+/// can be inserted in a single bulk insert. The identifiers are constructed once per type and
+/// once per property name.
+pub fn to_bulk_insert_items(entity_instances: &[EntityInstance], relation_instances: &[RelationInstance]) -> Vec<BulkInsertItem> {
+    let capacity = entity_instances
+        .iter()
+        .map(|entity_instance| entity_instance.properties.len() + 1)
+        .sum::<usize>()
+        + relation_instances
+            .iter()
+            .map(|relation_instance| relation_instance.properties.len() + 1)
+            .sum::<usize>();
+    let mut items = Vec::with_capacity(capacity);
+    let mut identifiers = Identifiers::default();
+    for entity_instance in entity_instances {
+        let t = identifiers.entity_type_id(&entity_instance.ty);
+        items.push(BulkInsertItem::Vertex(Vertex::with_id(entity_instance.id, t)));
+        for (property_name, value) in entity_instance.properties.iter() {
+            items.push(BulkInsertItem::VertexProperty(entity_instance.id, identifiers.property_id(property_name), value.clone()));
+        }
+    }
+    for relation_instance in relation_instances {
+        let t = identifiers.relation_type_id(&relation_instance.ty);
+        let key = EdgeKey::new(relation_instance.outbound_id, t, relation_instance.inbound_id);
+        items.push(BulkInsertItem::Edge(key.clone()));
+        for (property_name, value) in relation_instance.properties.iter() {
+            items.push(BulkInsertItem::EdgeProperty(key.clone(), identifiers.property_id(property_name), value.clone()));
+        }
+    }
+    items
+}
+
+impl FlowInstance {
+    /// Returns the bulk insert items of the entity instances and the relation instances of the
+    /// flow instance, including the wrapper entity instance.
+    ///
+    /// External entity instances are not part of the flow instance and have to exist already.
+    pub fn to_bulk_insert_items(&self) -> Vec<BulkInsertItem> {
+        to_bulk_insert_items(&self.entity_instances, &self.relation_instances)
+    }
+}
+
+/// The identifiers which have been constructed so far.
+#[derive(Default)]
+struct Identifiers {
+    entity_type_ids: HashMap<EntityTypeId, Identifier>,
+    relation_type_ids: HashMap<RelationInstanceTypeId, Identifier>,
+    property_ids: HashMap<String, Identifier>,
+}
+
+impl Identifiers {
+    fn entity_type_id(&mut self, ty: &EntityTypeId) -> Identifier {
+        self.entity_type_ids.entry(ty.clone()).or_insert_with(|| ty.type_id()).clone()
+    }
+
+    fn relation_type_id(&mut self, ty: &RelationInstanceTypeId) -> Identifier {
+        self.relation_type_ids.entry(ty.clone()).or_insert_with(|| ty.type_id()).clone()
+    }
+
+    fn property_id(&mut self, property_name: &str) -> Identifier {
+        if let Some(property_id) = self.property_ids.get(property_name) {
+            return property_id.clone();
+        }
+        let property_id = property_identifier(property_name);
+        self.property_ids.insert(property_name.to_string(), property_id.clone());
+        property_id
+    }
+}
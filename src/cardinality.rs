@@ -0,0 +1,172 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use serde::Deserialize;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::RelationEndpoint;
+use crate::RelationInstance;
+use crate::RelationType;
+use crate::TypeSystem;
+use crate::ValidationError;
+
+/// How many entity instances can be related by relation instances of a relation type.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum Cardinality {
+    /// Each outbound entity instance is related to at most one inbound entity instance and each
+    /// inbound entity instance is related to at most one outbound entity instance.
+    OneToOne,
+
+    /// Each inbound entity instance is related to at most one outbound entity instance.
+    OneToMany,
+
+    /// Each outbound entity instance is related to at most one inbound entity instance.
+    ManyToOne,
+
+    /// The number of related entity instances is unrestricted.
+    #[default]
+    ManyToMany,
+}
+
+impl Cardinality {
+    /// Returns the maximum number of entity instances which can be related to an entity instance
+    /// at the given endpoint.
+    pub fn max(&self, endpoint: RelationEndpoint) -> Option<usize> {
+        match (self, endpoint) {
+            (Cardinality::OneToOne, _) => Some(1),
+            (Cardinality::OneToMany, RelationEndpoint::Inbound) => Some(1),
+            (Cardinality::ManyToOne, RelationEndpoint::Outbound) => Some(1),
+            _ => None,
+        }
+    }
+}
+
+/// The cardinality constraints of a relation type.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct RelationCardinality {
+    /// The cardinality of the relation type.
+    #[serde(default)]
+    pub cardinality: Cardinality,
+
+    /// The maximum number of inbound entity instances per outbound entity instance.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_outbound: Option<usize>,
+
+    /// The maximum number of outbound entity instances per inbound entity instance.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_inbound: Option<usize>,
+}
+
+impl RelationCardinality {
+    pub fn new(cardinality: Cardinality) -> RelationCardinality {
+        RelationCardinality {
+            cardinality,
+            max_outbound: None,
+            max_inbound: None,
+        }
+    }
+
+    pub fn one_to_one() -> RelationCardinality {
+        RelationCardinality::new(Cardinality::OneToOne)
+    }
+
+    pub fn one_to_many() -> RelationCardinality {
+        RelationCardinality::new(Cardinality::OneToMany)
+    }
+
+    pub fn many_to_one() -> RelationCardinality {
+        RelationCardinality::new(Cardinality::ManyToOne)
+    }
+
+    pub fn many_to_many() -> RelationCardinality {
+        RelationCardinality::new(Cardinality::ManyToMany)
+    }
+
+    /// Limits the number of inbound entity instances per outbound entity instance.
+    pub fn with_max_outbound(mut self, max_outbound: usize) -> RelationCardinality {
+        self.max_outbound = Some(max_outbound);
+        self
+    }
+
+    /// Limits the number of outbound entity instances per inbound entity instance.
+    pub fn with_max_inbound(mut self, max_inbound: usize) -> RelationCardinality {
+        self.max_inbound = Some(max_inbound);
+        self
+    }
+
+    /// Returns the maximum number of entity instances which can be related to an entity instance
+    /// at the given endpoint, which is the stricter limit of the cardinality and the maximum
+    /// count.
+    pub fn max(&self, endpoint: RelationEndpoint) -> Option<usize> {
+        let max_count = match endpoint {
+            RelationEndpoint::Outbound => self.max_outbound,
+            RelationEndpoint::Inbound => self.max_inbound,
+        };
+        match (self.cardinality.max(endpoint), max_count) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        }
+    }
+}
+
+impl RelationType {
+    /// Sets the cardinality constraints of the relation type.
+    pub fn with_cardinality(mut self, cardinality: RelationCardinality) -> RelationType {
+        self.cardinality = Some(cardinality);
+        self
+    }
+
+    /// Validates that the given relation instances of this relation type don't exceed the
+    /// cardinality of this relation type.
+    ///
+    /// The cardinality counts the distinct entity instances at the opposite endpoint, so multiple
+    /// relation instances between the same entity instances count once. Relation instances of
+    /// other relation types and deleted relation instances are ignored.
+    pub fn validate_cardinality(&self, relation_instances: &[RelationInstance]) -> Result<(), ValidationError> {
+        let Some(cardinality) = &self.cardinality else {
+            return Ok(());
+        };
+        let relation_instances: Vec<&RelationInstance> = relation_instances
+            .iter()
+            .filter(|relation_instance| !relation_instance.is_deleted() && self.is_known_as(&relation_instance.relation_type_id()))
+            .collect();
+        for endpoint in [RelationEndpoint::Outbound, RelationEndpoint::Inbound] {
+            let Some(max) = cardinality.max(endpoint) else {
+                continue;
+            };
+            let mut related: HashMap<Uuid, HashSet<Uuid>> = HashMap::new();
+            for relation_instance in relation_instances.iter() {
+                let (id, opposite_id) = match endpoint {
+                    RelationEndpoint::Outbound => (relation_instance.outbound_id, relation_instance.inbound_id),
+                    RelationEndpoint::Inbound => (relation_instance.inbound_id, relation_instance.outbound_id),
+                };
+                let related_ids = related.entry(id).or_default();
+                related_ids.insert(opposite_id);
+                if related_ids.len() > max {
+                    return Err(ValidationError::CardinalityExceeded {
+                        ty: self.ty.clone(),
+                        id,
+                        endpoint,
+                        max,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl TypeSystem {
+    /// Validates that the given relation instances don't exceed the cardinalities of their
+    /// relation types.
+    pub fn validate_cardinalities(&self, relation_instances: &[RelationInstance]) -> Result<(), ValidationError> {
+        for relation_type in self.relation_types.iter() {
+            relation_type.validate_cardinality(relation_instances)?;
+        }
+        Ok(())
+    }
+}
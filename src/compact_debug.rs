@@ -0,0 +1,150 @@
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::fmt;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::fmt::Write;
+
+use serde_json::Value;
+
+use crate::EntityInstance;
+use crate::FlowInstance;
+use crate::RelationInstance;
+
+/// The maximum length of a property value in the compact debug output. Longer values are
+/// truncated.
+pub const COMPACT_DEBUG_MAX_VALUE_LENGTH: usize = 64;
+
+/// Formats an instance without dumping the full property values.
+///
+/// Property values which are longer than `COMPACT_DEBUG_MAX_VALUE_LENGTH` are truncated and
+/// binary content (base64 data urls and arrays of bytes) is elided. The properties are ordered by
+/// name.
+pub struct CompactDebug<'a, T: ?Sized>(&'a T);
+
+impl<'a, T: ?Sized> CompactDebug<'a, T> {
+    /// Wraps the given instance.
+    pub fn new(inner: &'a T) -> CompactDebug<'a, T> {
+        CompactDebug(inner)
+    }
+}
+
+/// Returns the compact representation of the given property value.
+///
+/// Large values are not serialized completely, the serialization stops as soon as the maximum
+/// length is reached.
+pub fn compact_value(value: &Value) -> String {
+    match value {
+        Value::String(s) if is_data_url(s) => return format!("<binary {} bytes>", s.len()),
+        Value::Array(a) if a.len() > COMPACT_DEBUG_MAX_VALUE_LENGTH && a.iter().all(is_byte) => return format!("<binary {} bytes>", a.len()),
+        _ => {}
+    }
+    let mut writer = LimitedWriter::new(COMPACT_DEBUG_MAX_VALUE_LENGTH);
+    if write!(writer, "{}", value).is_ok() {
+        return writer.buffer;
+    }
+    match value {
+        Value::String(s) => format!("{}... ({} bytes)", writer.buffer, s.len()),
+        Value::Array(a) => format!("{}... ({} items)", writer.buffer, a.len()),
+        Value::Object(o) => format!("{}... ({} entries)", writer.buffer, o.len()),
+        _ => format!("{}...", writer.buffer),
+    }
+}
+
+fn is_data_url(s: &str) -> bool {
+    s.starts_with("data:") && s.contains(";base64,")
+}
+
+fn is_byte(value: &Value) -> bool {
+    value.as_u64().map(|v| v <= u8::MAX as u64).unwrap_or(false)
+}
+
+/// Writer which keeps at most the given number of bytes and fails as soon as the limit is
+/// exceeded, which aborts the formatting.
+struct LimitedWriter {
+    buffer: String,
+    limit: usize,
+}
+
+impl LimitedWriter {
+    fn new(limit: usize) -> LimitedWriter {
+        LimitedWriter { buffer: String::new(), limit }
+    }
+}
+
+impl Write for LimitedWriter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let remaining = self.limit - self.buffer.len();
+        if s.len() <= remaining {
+            self.buffer.push_str(s);
+            return Ok(());
+        }
+        let mut end = remaining;
+        while !s.is_char_boundary(end) {
+            end -= 1;
+        }
+        self.buffer.push_str(&s[..end]);
+        Err(fmt::Error)
+    }
+}
+
+/// Formats the property values in their compact representation.
+struct CompactProperties<'a>(&'a HashMap<String, Value>);
+
+impl Debug for CompactProperties<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(
+                self.0
+                    .iter()
+                    .collect::<BTreeMap<_, _>>()
+                    .into_iter()
+                    .map(|(name, value)| (name, CompactValue(value))),
+            )
+            .finish()
+    }
+}
+
+/// Formats a property value in its compact representation without quotes.
+struct CompactValue<'a>(&'a Value);
+
+impl Debug for CompactValue<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&compact_value(self.0))
+    }
+}
+
+impl Debug for CompactDebug<'_, EntityInstance> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EntityInstance")
+            .field("ty", &self.0.ty.to_string())
+            .field("id", &self.0.id)
+            .field("properties", &CompactProperties(&self.0.properties))
+            .field("extensions", &self.0.extensions.len())
+            .finish()
+    }
+}
+
+impl Debug for CompactDebug<'_, RelationInstance> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RelationInstance")
+            .field("outbound_id", &self.0.outbound_id)
+            .field("ty", &self.0.ty.to_string())
+            .field("inbound_id", &self.0.inbound_id)
+            .field("properties", &CompactProperties(&self.0.properties))
+            .field("extensions", &self.0.extensions.len())
+            .finish()
+    }
+}
+
+impl Debug for CompactDebug<'_, FlowInstance> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FlowInstance")
+            .field("id", &self.0.id)
+            .field("ty", &self.0.ty.to_string())
+            .field("name", &self.0.name)
+            .field("entity_instances", &self.0.entity_instances.iter().map(CompactDebug::new).collect::<Vec<_>>())
+            .field("relation_instances", &self.0.relation_instances.iter().map(CompactDebug::new).collect::<Vec<_>>())
+            .finish()
+    }
+}
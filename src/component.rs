@@ -1,11 +1,21 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::hash::Hasher;
+
 use serde::Deserialize;
 use serde::Serialize;
+use serde_json::Map;
+use serde_json::Value;
 
 use crate::ComponentTypeId;
+use crate::Deprecation;
+use crate::Documentation;
 use crate::Extension;
 use crate::ExtensionTypeId;
+use crate::LanguageTag;
 use crate::NamespacedTypeGetter;
 use crate::PropertyType;
+use crate::TagContainer;
 use crate::TypeDefinition;
 use crate::TypeDefinitionGetter;
 use crate::TypeIdType;
@@ -13,6 +23,8 @@ use crate::TypeIdType;
 /// A component defines a set of properties to be applied to entity
 /// types and relation types.
 #[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct Component {
     /// The type definition of the component.
     #[serde(flatten)]
@@ -22,6 +34,27 @@ pub struct Component {
     #[serde(default = "String::new")]
     pub description: String,
 
+    /// The descriptions in other languages, by language tag.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty", serialize_with = "crate::serialize_descriptions")]
+    pub descriptions: HashMap<LanguageTag, String>,
+
+    /// The structured documentation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub documentation: Option<Documentation>,
+
+    /// The tags of the component, for grouping types into categories.
+    #[serde(default = "Vec::new")]
+    pub tags: Vec<String>,
+
+    /// The previous type ids of the component. References to a previous type id are resolved
+    /// to the component.
+    #[serde(default = "Vec::new")]
+    pub aliases: Vec<ComponentTypeId>,
+
+    /// The deprecation of the component, if the component is deprecated.
+    #[serde(default)]
+    pub deprecation: Option<Deprecation>,
+
     /// The properties which are applied on entity or relation instances.
     #[serde(default = "Vec::new")]
     pub properties: Vec<PropertyType>,
@@ -29,6 +62,11 @@ pub struct Component {
     /// Component specific extensions
     #[serde(default = "Vec::new")]
     pub extensions: Vec<Extension>,
+
+    /// Unknown fields of the data access object, which are re-emitted when converting back into
+    /// a data access object.
+    #[serde(skip)]
+    pub extra: Map<String, Value>,
 }
 
 impl Component {
@@ -36,8 +74,14 @@ impl Component {
         Component {
             ty: ty.into(),
             description: description.into(),
+            descriptions: HashMap::new(),
+            documentation: None,
+            tags: Vec::new(),
+            aliases: Vec::new(),
+            deprecation: None,
             properties,
             extensions,
+            extra: Map::new(),
         }
     }
 
@@ -46,8 +90,14 @@ impl Component {
         Component {
             ty: ComponentTypeId::new_from_type(namespace, type_name),
             description: description.into(),
+            descriptions: HashMap::new(),
+            documentation: None,
+            tags: Vec::new(),
+            aliases: Vec::new(),
+            deprecation: None,
             properties,
             extensions,
+            extra: Map::new(),
         }
     }
 
@@ -56,8 +106,14 @@ impl Component {
         Component {
             ty: ty.into(),
             description: description.into(),
+            descriptions: HashMap::new(),
+            documentation: None,
+            tags: Vec::new(),
+            aliases: Vec::new(),
+            deprecation: None,
             properties,
             extensions: Vec::new(),
+            extra: Map::new(),
         }
     }
 
@@ -66,11 +122,45 @@ impl Component {
         Component {
             ty: ty.into(),
             description: description.into(),
+            descriptions: HashMap::new(),
+            documentation: None,
+            tags: Vec::new(),
+            aliases: Vec::new(),
+            deprecation: None,
             properties: Vec::new(),
             extensions,
+            extra: Map::new(),
         }
     }
 
+    /// Adds the given tag to the component.
+    pub fn with_tag<S: Into<String>>(mut self, tag: S) -> Component {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Adds the given previous type id to the component.
+    pub fn with_alias<A: Into<ComponentTypeId>>(mut self, alias: A) -> Component {
+        self.aliases.push(alias.into());
+        self
+    }
+
+    /// Returns true, if the given type id is the type id or a previous type id of the component.
+    pub fn is_known_as(&self, ty: &ComponentTypeId) -> bool {
+        &self.ty == ty || self.aliases.contains(ty)
+    }
+
+    /// Deprecates the component.
+    pub fn with_deprecation(mut self, deprecation: Deprecation) -> Component {
+        self.deprecation = Some(deprecation);
+        self
+    }
+
+    /// Returns true, if the component is deprecated.
+    pub fn is_deprecated(&self) -> bool {
+        self.deprecation.is_some()
+    }
+
     /// Returns true, if the component contains a property with the given name.
     pub fn has_property<S: Into<String>>(&self, property_name: S) -> bool {
         let property_name = property_name.into();
@@ -81,6 +171,26 @@ impl Component {
     pub fn has_extension(&self, ty: &ExtensionTypeId) -> bool {
         self.extensions.iter().any(|extension| &extension.ty == ty)
     }
+
+    /// Returns true, if both components have the same type and the same definition.
+    ///
+    /// In contrast, `==` compares only the type ids.
+    pub fn structurally_equals(&self, other: &Component) -> bool {
+        match (serde_json::to_value(self), serde_json::to_value(other)) {
+            (Ok(a), Ok(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl TagContainer for Component {
+    fn get_tags(&self) -> Vec<String> {
+        self.tags.clone()
+    }
+
+    fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t == tag)
+    }
 }
 
 impl NamespacedTypeGetter for Component {
@@ -99,6 +209,21 @@ impl TypeDefinitionGetter for Component {
     }
 }
 
+/// Two components are equal, if they have the same type id.
+impl PartialEq for Component {
+    fn eq(&self, other: &Self) -> bool {
+        self.ty == other.ty
+    }
+}
+
+impl Eq for Component {}
+
+impl Hash for Component {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.ty.hash(state);
+    }
+}
+
 impl From<Component> for TypeDefinition {
     fn from(component: Component) -> Self {
         TypeDefinition {
@@ -1,12 +1,15 @@
 use serde::Deserialize;
 use serde::Serialize;
+use std::fmt::Display;
+use std::fmt::Formatter;
 
 use crate::BehaviourTypeId;
 use crate::ComponentTypeId;
 use crate::NamespacedType;
 
 /// The behaviour of a component.
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct ComponentBehaviourTypeId {
     /// The component type.
     pub component_ty: ComponentTypeId,
@@ -24,6 +27,11 @@ impl ComponentBehaviourTypeId {
         let namespaced_type = NamespacedType::new(namespace, type_name);
         ComponentBehaviourTypeId::new(namespaced_type.clone().into(), namespaced_type.into())
     }
+
+    /// Returns true, if the behaviour applies to the given component.
+    pub fn applies_to(&self, ty: &ComponentTypeId) -> bool {
+        &self.component_ty == ty
+    }
 }
 
 impl From<NamespacedType> for ComponentBehaviourTypeId {
@@ -38,6 +46,12 @@ impl From<&BehaviourTypeId> for ComponentBehaviourTypeId {
     }
 }
 
+impl Display for ComponentBehaviourTypeId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}__{}", &self.component_ty, &self.behaviour_ty)
+    }
+}
+
 #[macro_export]
 macro_rules! component_behaviour_ty {
     (
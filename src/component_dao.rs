@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::Component;
+use crate::ComponentTypeId;
+use crate::Deprecation;
+use crate::Documentation;
+use crate::Extension;
+use crate::LanguageTag;
+use crate::NamespacedTypeGetter;
+use crate::PropertyType;
+
+/// Data access object of a component.
+///
+/// Defines the stable external representation of a component. The in-memory
+/// representation (`Component`) can evolve without breaking existing files.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct ComponentDao {
+    /// The namespace of the component.
+    #[serde(default = "String::new")]
+    pub namespace: String,
+
+    /// The name of the component.
+    #[serde(alias = "name")]
+    pub type_name: String,
+
+    /// Textual description of the component.
+    #[serde(default = "String::new")]
+    pub description: String,
+
+    /// The descriptions in other languages, by language tag.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty", serialize_with = "crate::serialize_descriptions")]
+    pub descriptions: HashMap<LanguageTag, String>,
+
+    /// The structured documentation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub documentation: Option<Documentation>,
+
+    /// The tags of the component, for grouping types into categories.
+    #[serde(default = "Vec::new")]
+    pub tags: Vec<String>,
+
+    /// The previous type ids of the component. References to a previous type id are resolved
+    /// to the component.
+    #[serde(default = "Vec::new")]
+    pub aliases: Vec<ComponentTypeId>,
+
+    /// The deprecation of the component, if the component is deprecated.
+    #[serde(default)]
+    pub deprecation: Option<Deprecation>,
+
+    /// The properties which are applied on entity or relation instances.
+    #[serde(default = "Vec::new")]
+    pub properties: Vec<PropertyType>,
+
+    /// Component specific extensions.
+    #[serde(default = "Vec::new")]
+    pub extensions: Vec<Extension>,
+
+    /// Unknown fields, for example written by a newer version, which are re-emitted on save.
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl From<Component> for ComponentDao {
+    fn from(component: Component) -> Self {
+        ComponentDao {
+            namespace: component.namespace(),
+            type_name: component.type_name(),
+            description: component.description,
+            descriptions: component.descriptions,
+            documentation: component.documentation,
+            tags: component.tags,
+            aliases: component.aliases,
+            deprecation: component.deprecation,
+            properties: component.properties,
+            extensions: component.extensions,
+            extra: component.extra,
+        }
+    }
+}
+
+impl From<ComponentDao> for Component {
+    fn from(dao: ComponentDao) -> Self {
+        Component {
+            ty: ComponentTypeId::new_from_type(dao.namespace, dao.type_name),
+            description: dao.description,
+            descriptions: dao.descriptions,
+            documentation: dao.documentation,
+            tags: dao.tags,
+            aliases: dao.aliases,
+            deprecation: dao.deprecation,
+            properties: dao.properties,
+            extensions: dao.extensions,
+            extra: dao.extra,
+        }
+    }
+}
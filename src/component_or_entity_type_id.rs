@@ -3,14 +3,22 @@ use serde::Serialize;
 use std::fmt::Display;
 use std::fmt::Formatter;
 
+use crate::glob_matches;
 use crate::ComponentTypeId;
+use crate::EntityType;
 use crate::EntityTypeId;
+use crate::ModelError;
 use crate::NamespacedType;
 use crate::NamespacedTypeGetter;
 use crate::TypeDefinition;
 use crate::TypeDefinitionGetter;
+use crate::TypeIdType;
+
+/// The wildcard which matches any namespace or any type name.
+pub const TYPE_WILDCARD: &str = "*";
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum ComponentOrEntityTypeId {
     #[serde(rename = "component")]
     Component(ComponentTypeId),
@@ -34,6 +42,29 @@ impl ComponentOrEntityTypeId {
             _ => false,
         }
     }
+
+    /// Matches every entity type.
+    pub fn any_entity_type() -> ComponentOrEntityTypeId {
+        ComponentOrEntityTypeId::EntityType(EntityTypeId::new_from_type(TYPE_WILDCARD, TYPE_WILDCARD))
+    }
+
+    /// Returns true, if the namespace or the type name contains a wildcard.
+    pub fn is_wildcard(&self) -> bool {
+        let is_pattern = |s: String| s.contains(['*', '?']);
+        is_pattern(self.namespace()) || is_pattern(self.type_name())
+    }
+
+    /// Returns true, if the given entity type matches this entity type or has a component which
+    /// matches this component.
+    ///
+    /// The namespace and the type name may contain the wildcards `*` and `?`. Previous type ids
+    /// of the entity type are matched as well. Inherited components are not considered.
+    pub fn accepts_entity_type(&self, entity_type: &EntityType) -> bool {
+        match self {
+            ComponentOrEntityTypeId::EntityType(ty) => type_matches(ty, &entity_type.ty) || entity_type.aliases.iter().any(|alias| type_matches(ty, alias)),
+            ComponentOrEntityTypeId::Component(ty) => entity_type.components.iter().any(|component_ty| type_matches(ty, component_ty)),
+        }
+    }
 }
 
 impl From<&ComponentOrEntityTypeId> for ComponentOrEntityTypeId {
@@ -49,12 +80,15 @@ impl From<ComponentTypeId> for ComponentOrEntityTypeId {
 }
 
 impl TryFrom<ComponentOrEntityTypeId> for ComponentTypeId {
-    type Error = ();
+    type Error = ModelError;
 
     fn try_from(ty: ComponentOrEntityTypeId) -> Result<Self, Self::Error> {
         match ty {
             ComponentOrEntityTypeId::Component(ty) => Ok(ty),
-            ComponentOrEntityTypeId::EntityType(_) => Err(()),
+            ComponentOrEntityTypeId::EntityType(_) => Err(ModelError::UnexpectedTypeIdType {
+                expected: TypeIdType::Component,
+                actual: TypeIdType::EntityType,
+            }),
         }
     }
 }
@@ -66,11 +100,14 @@ impl From<EntityTypeId> for ComponentOrEntityTypeId {
 }
 
 impl TryFrom<ComponentOrEntityTypeId> for EntityTypeId {
-    type Error = ();
+    type Error = ModelError;
 
     fn try_from(ty: ComponentOrEntityTypeId) -> Result<Self, Self::Error> {
         match ty {
-            ComponentOrEntityTypeId::Component(_) => Err(()),
+            ComponentOrEntityTypeId::Component(_) => Err(ModelError::UnexpectedTypeIdType {
+                expected: TypeIdType::EntityType,
+                actual: TypeIdType::Component,
+            }),
             ComponentOrEntityTypeId::EntityType(ty) => Ok(ty),
         }
     }
@@ -128,3 +165,9 @@ impl From<&ComponentOrEntityTypeId> for NamespacedType {
         }
     }
 }
+
+/// Returns true, if the namespace and the type name of the given type match the namespace and
+/// the type name of the given pattern.
+pub(crate) fn type_matches<P: NamespacedTypeGetter, T: NamespacedTypeGetter>(pattern: &P, ty: &T) -> bool {
+    glob_matches(&pattern.namespace(), &ty.namespace()) && glob_matches(&pattern.type_name(), &ty.type_name())
+}
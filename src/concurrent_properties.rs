@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+
+use dashmap::DashMap;
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::PropertyInstanceGetter;
+
+/// Property container which can be read and written concurrently.
+///
+/// In contrast to the property maps of the instances the properties can be written through a
+/// shared reference, so multi-threaded consumers don't have to wrap whole instances in a lock.
+#[derive(Clone, Debug, Default)]
+pub struct ConcurrentProperties(DashMap<String, Value>);
+
+impl ConcurrentProperties {
+    pub fn new() -> ConcurrentProperties {
+        ConcurrentProperties(DashMap::new())
+    }
+
+    /// Inserts the value of the given property. Returns the previous value.
+    pub fn insert<S: Into<String>>(&self, property_name: S, value: Value) -> Option<Value> {
+        self.0.insert(property_name.into(), value)
+    }
+
+    /// Sets the value of the given property by name if the property exists.
+    pub fn set<S: Into<String>>(&self, property_name: S, value: Value) {
+        if let Some(mut property_value) = self.0.get_mut(&property_name.into()) {
+            *property_value = value
+        }
+    }
+
+    /// Updates the value of the given property in place. Returns false if the property doesn't exist.
+    pub fn update<S: Into<String>, F: FnOnce(&mut Value)>(&self, property_name: S, f: F) -> bool {
+        match self.0.get_mut(&property_name.into()) {
+            Some(mut property_value) => {
+                f(&mut property_value);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Removes the given property. Returns the value of the removed property.
+    pub fn remove<S: Into<String>>(&self, property_name: S) -> Option<Value> {
+        self.0.remove(&property_name.into()).map(|(_, value)| value)
+    }
+
+    /// Returns true, if the given property exists.
+    pub fn contains<S: Into<String>>(&self, property_name: S) -> bool {
+        self.0.contains_key(&property_name.into())
+    }
+
+    /// Returns the names of the properties.
+    pub fn names(&self) -> Vec<String> {
+        self.0.iter().map(|property| property.key().clone()).collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns a snapshot of the properties.
+    pub fn to_map(&self) -> HashMap<String, Value> {
+        self.0.iter().map(|property| (property.key().clone(), property.value().clone())).collect()
+    }
+}
+
+impl PropertyInstanceGetter for ConcurrentProperties {
+    fn get<S: Into<String>>(&self, property_name: S) -> Option<Value> {
+        self.0.get(&property_name.into()).map(|p| p.value().clone())
+    }
+
+    fn as_bool<S: Into<String>>(&self, property_name: S) -> Option<bool> {
+        self.0.get(&property_name.into()).and_then(|p| p.as_bool())
+    }
+
+    fn as_u64<S: Into<String>>(&self, property_name: S) -> Option<u64> {
+        self.0.get(&property_name.into()).and_then(|p| p.as_u64())
+    }
+
+    fn as_i64<S: Into<String>>(&self, property_name: S) -> Option<i64> {
+        self.0.get(&property_name.into()).and_then(|p| p.as_i64())
+    }
+
+    fn as_f64<S: Into<String>>(&self, property_name: S) -> Option<f64> {
+        self.0.get(&property_name.into()).and_then(|p| p.as_f64())
+    }
+
+    fn as_string<S: Into<String>>(&self, property_name: S) -> Option<String> {
+        self.0.get(&property_name.into()).and_then(|p| p.as_str().map(|s| s.to_string()))
+    }
+
+    fn as_array<S: Into<String>>(&self, property_name: S) -> Option<Vec<Value>> {
+        self.0.get(&property_name.into()).and_then(|p| p.as_array().map(Vec::clone))
+    }
+
+    fn as_object<S: Into<String>>(&self, property_name: S) -> Option<Map<String, Value>> {
+        self.0.get(&property_name.into()).and_then(|p| p.as_object().map(Map::clone))
+    }
+}
+
+impl From<HashMap<String, Value>> for ConcurrentProperties {
+    fn from(properties: HashMap<String, Value>) -> Self {
+        ConcurrentProperties(properties.into_iter().collect())
+    }
+}
+
+impl From<&ConcurrentProperties> for HashMap<String, Value> {
+    fn from(properties: &ConcurrentProperties) -> Self {
+        properties.to_map()
+    }
+}
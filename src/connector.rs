@@ -0,0 +1,193 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::json;
+use serde_json::Value;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::FlowInstance;
+use crate::FlowType;
+use crate::NamespacedTypeGetter;
+use crate::PropertyInstanceGetter;
+use crate::RelationInstance;
+use crate::RelationInstanceTypeId;
+use crate::RelationTypeId;
+
+/// The namespace of the connector relation types.
+pub const CONNECTOR_NAMESPACE: &str = "connector";
+
+/// The type name of the default connector relation type.
+pub const DEFAULT_CONNECTOR_TYPE_NAME: &str = "default_connector";
+
+/// The name of the property of a connector relation instance which contains the name of the
+/// output property of the outbound entity instance.
+pub const CONNECTOR_OUTBOUND_PROPERTY_NAME: &str = "outbound_property_name";
+
+/// The name of the property of a connector relation instance which contains the name of the
+/// input property of the inbound entity instance.
+pub const CONNECTOR_INBOUND_PROPERTY_NAME: &str = "inbound_property_name";
+
+/// The separator of the outbound property name and the inbound property name in the instance id
+/// of a connector relation instance, for example `result__lhs`.
+pub const CONNECTOR_INSTANCE_ID_SEPARATOR: &str = "__";
+
+/// Error of the conversion of a relation instance into a connector.
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum ConnectorError {
+    /// The relation instance is not of a connector relation type.
+    #[error("The relation type {0} is not a connector")]
+    NotAConnector(RelationTypeId),
+
+    /// Neither the properties nor the instance id of the relation instance contain the property
+    /// names which are connected.
+    #[error("The connector {0} doesn't specify the connected properties")]
+    MissingPropertyNames(String),
+}
+
+/// Propagates the value of an output property of the outbound entity instance to an input
+/// property of the inbound entity instance.
+///
+/// Connectors are encoded as relation instances of a connector relation type. The connected
+/// property names are stored as properties and as the instance id of the relation instance.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Connector {
+    /// The connector relation type.
+    #[serde(flatten)]
+    pub ty: RelationTypeId,
+
+    /// The id of the outbound entity instance.
+    pub outbound_id: Uuid,
+
+    /// The name of the output property of the outbound entity instance.
+    pub outbound_property_name: String,
+
+    /// The id of the inbound entity instance.
+    pub inbound_id: Uuid,
+
+    /// The name of the input property of the inbound entity instance.
+    pub inbound_property_name: String,
+}
+
+/// Returns the type id of the default connector relation type.
+pub fn default_connector_type_id() -> RelationTypeId {
+    RelationTypeId::new_from_type(CONNECTOR_NAMESPACE, DEFAULT_CONNECTOR_TYPE_NAME)
+}
+
+impl Connector {
+    /// Constructs a default connector from the given output property of the outbound entity
+    /// instance to the given input property of the inbound entity instance.
+    pub fn new<S: Into<String>>(outbound_id: Uuid, outbound_property_name: S, inbound_id: Uuid, inbound_property_name: S) -> Connector {
+        Connector {
+            ty: default_connector_type_id(),
+            outbound_id,
+            outbound_property_name: outbound_property_name.into(),
+            inbound_id,
+            inbound_property_name: inbound_property_name.into(),
+        }
+    }
+
+    /// Sets the connector relation type.
+    pub fn with_type<T: Into<RelationTypeId>>(mut self, ty: T) -> Connector {
+        self.ty = ty.into();
+        self
+    }
+
+    /// Returns the instance id of the connector relation instance.
+    pub fn instance_id(&self) -> String {
+        format!("{}{}{}", self.outbound_property_name, CONNECTOR_INSTANCE_ID_SEPARATOR, self.inbound_property_name)
+    }
+
+    /// Returns the connector relation instance which encodes the connector.
+    pub fn to_relation_instance(&self) -> RelationInstance {
+        let mut properties: HashMap<String, Value> = HashMap::new();
+        properties.insert(CONNECTOR_OUTBOUND_PROPERTY_NAME.to_string(), json!(self.outbound_property_name));
+        properties.insert(CONNECTOR_INBOUND_PROPERTY_NAME.to_string(), json!(self.inbound_property_name));
+        let ty = RelationInstanceTypeId::new_unique_for_instance_id(self.ty.clone(), self.instance_id());
+        RelationInstance::new(self.outbound_id, ty, self.inbound_id, properties)
+    }
+}
+
+impl From<&Connector> for RelationInstance {
+    fn from(connector: &Connector) -> Self {
+        connector.to_relation_instance()
+    }
+}
+
+impl From<Connector> for RelationInstance {
+    fn from(connector: Connector) -> Self {
+        connector.to_relation_instance()
+    }
+}
+
+/// Decodes a connector relation instance.
+///
+/// The property names are read from the properties of the relation instance. If the properties
+/// are missing, the property names are parsed from the instance id.
+impl TryFrom<&RelationInstance> for Connector {
+    type Error = ConnectorError;
+
+    fn try_from(relation_instance: &RelationInstance) -> Result<Self, Self::Error> {
+        let ty = relation_instance.relation_type_id();
+        if ty.namespace() != CONNECTOR_NAMESPACE {
+            return Err(ConnectorError::NotAConnector(ty));
+        }
+        let instance_id = relation_instance.instance_id();
+        let property_names = match (
+            relation_instance.as_string(CONNECTOR_OUTBOUND_PROPERTY_NAME),
+            relation_instance.as_string(CONNECTOR_INBOUND_PROPERTY_NAME),
+        ) {
+            (Some(outbound_property_name), Some(inbound_property_name)) => Some((outbound_property_name, inbound_property_name)),
+            _ => instance_id
+                .split_once(CONNECTOR_INSTANCE_ID_SEPARATOR)
+                .map(|(outbound_property_name, inbound_property_name)| (outbound_property_name.to_string(), inbound_property_name.to_string())),
+        };
+        let (outbound_property_name, inbound_property_name) = property_names.ok_or(ConnectorError::MissingPropertyNames(instance_id))?;
+        Ok(Connector {
+            ty,
+            outbound_id: relation_instance.outbound_id,
+            outbound_property_name,
+            inbound_id: relation_instance.inbound_id,
+            inbound_property_name,
+        })
+    }
+}
+
+impl TryFrom<RelationInstance> for Connector {
+    type Error = ConnectorError;
+
+    fn try_from(relation_instance: RelationInstance) -> Result<Self, Self::Error> {
+        Connector::try_from(&relation_instance)
+    }
+}
+
+impl RelationInstance {
+    /// Returns true, if the relation instance is of a connector relation type.
+    pub fn is_connector(&self) -> bool {
+        self.namespace() == CONNECTOR_NAMESPACE
+    }
+}
+
+impl FlowInstance {
+    /// Returns the connectors of the flow instance. Relation instances which aren't connectors
+    /// are skipped.
+    pub fn connectors(&self) -> Vec<Connector> {
+        self.relation_instances
+            .iter()
+            .filter_map(|relation_instance| Connector::try_from(relation_instance).ok())
+            .collect()
+    }
+}
+
+impl FlowType {
+    /// Returns the connectors of the flow type. Relation instances which aren't connectors are
+    /// skipped.
+    pub fn connectors(&self) -> Vec<Connector> {
+        self.relation_instances
+            .iter()
+            .filter_map(|relation_instance| Connector::try_from(relation_instance).ok())
+            .collect()
+    }
+}
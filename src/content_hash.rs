@@ -0,0 +1,96 @@
+use std::fmt::Write;
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::Component;
+use crate::EntityInstance;
+use crate::EntityType;
+use crate::FlowInstance;
+use crate::FlowType;
+use crate::RelationInstance;
+use crate::RelationType;
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x00000100000001b3;
+
+/// Returns the canonical JSON representation of the given value.
+///
+/// The keys of objects are sorted and no whitespace is emitted, so that the representation
+/// doesn't depend on the order of the properties.
+pub fn canonical_json(value: &Value) -> String {
+    let mut json = String::new();
+    write_canonical_json(&mut json, value);
+    json
+}
+
+fn write_canonical_json(json: &mut String, value: &Value) {
+    match value {
+        Value::Array(values) => {
+            json.push('[');
+            for (i, value) in values.iter().enumerate() {
+                if i > 0 {
+                    json.push(',');
+                }
+                write_canonical_json(json, value);
+            }
+            json.push(']');
+        }
+        Value::Object(object) => {
+            let mut entries: Vec<(&String, &Value)> = object.iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+            json.push('{');
+            for (i, (key, value)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    json.push(',');
+                }
+                let _ = write!(json, "{}:", Value::String(key.clone()));
+                write_canonical_json(json, value);
+            }
+            json.push('}');
+        }
+        value => {
+            let _ = write!(json, "{}", value);
+        }
+    }
+}
+
+/// Returns the 64 bit FNV-1a hash of the given bytes.
+///
+/// The hash is stable across platforms and versions of the compiler, but it is not a
+/// cryptographic hash.
+pub fn fnv1a_64(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(FNV_OFFSET_BASIS, |hash, byte| (hash ^ *byte as u64).wrapping_mul(FNV_PRIME))
+}
+
+/// Returns the hash of the canonical JSON representation of the given value.
+///
+/// Fails if the value can't be represented as JSON, for example a map with non-string keys.
+pub fn content_hash<T: Serialize + ?Sized>(value: &T) -> Result<u64, serde_json::Error> {
+    let value = serde_json::to_value(value)?;
+    Ok(fnv1a_64(canonical_json(&value).as_bytes()))
+}
+
+/// Computes a hash of the content which is stable across property ordering.
+///
+/// Caches and sync protocols can compare the hashes in order to detect changes cheaply.
+pub trait ContentHash: Serialize {
+    /// Returns the hash of the canonical JSON representation.
+    fn content_hash(&self) -> Result<u64, serde_json::Error> {
+        content_hash(self)
+    }
+}
+
+impl ContentHash for Component {}
+
+impl ContentHash for EntityType {}
+
+impl ContentHash for RelationType {}
+
+impl ContentHash for FlowType {}
+
+impl ContentHash for EntityInstance {}
+
+impl ContentHash for RelationInstance {}
+
+impl ContentHash for FlowInstance {}
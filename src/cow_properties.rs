@@ -0,0 +1,143 @@
+use std::collections::hash_map::IntoIter;
+use std::collections::hash_map::Iter;
+use std::collections::HashMap;
+use std::fmt;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::ops::Deref;
+use std::ops::DerefMut;
+use std::sync::Arc;
+
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde::Serializer;
+use serde_json::Value;
+
+/// Copy-on-write property map of the instances.
+///
+/// Cloning the property map only clones a reference to the shared map. The map is copied on the
+/// first mutation of a clone, so snapshots of instances which are only read share their
+/// properties. Read access is provided through `Deref` to the inner `HashMap`.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct CowProperties(Arc<HashMap<String, Value>>);
+
+impl CowProperties {
+    pub fn new() -> CowProperties {
+        CowProperties::default()
+    }
+
+    /// Returns true, if both property maps share the same underlying map.
+    pub fn ptr_eq(&self, other: &CowProperties) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+
+    /// Returns true, if the underlying map is shared with another property map.
+    pub fn is_shared(&self) -> bool {
+        Arc::strong_count(&self.0) > 1
+    }
+
+    /// Returns the properties as map. Only copies the map if it is shared.
+    pub fn into_map(self) -> HashMap<String, Value> {
+        Arc::try_unwrap(self.0).unwrap_or_else(|map| (*map).clone())
+    }
+}
+
+impl Deref for CowProperties {
+    type Target = HashMap<String, Value>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Copies the underlying map, if it is shared.
+impl DerefMut for CowProperties {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        Arc::make_mut(&mut self.0)
+    }
+}
+
+impl From<HashMap<String, Value>> for CowProperties {
+    fn from(properties: HashMap<String, Value>) -> Self {
+        CowProperties(Arc::new(properties))
+    }
+}
+
+impl From<CowProperties> for HashMap<String, Value> {
+    fn from(properties: CowProperties) -> Self {
+        properties.into_map()
+    }
+}
+
+impl FromIterator<(String, Value)> for CowProperties {
+    fn from_iter<I: IntoIterator<Item = (String, Value)>>(iter: I) -> Self {
+        CowProperties::from(HashMap::from_iter(iter))
+    }
+}
+
+impl IntoIterator for CowProperties {
+    type Item = (String, Value);
+    type IntoIter = IntoIter<String, Value>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_map().into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a CowProperties {
+    type Item = (&'a String, &'a Value);
+    type IntoIter = Iter<'a, String, Value>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl PartialEq<HashMap<String, Value>> for CowProperties {
+    fn eq(&self, other: &HashMap<String, Value>) -> bool {
+        *self.0 == *other
+    }
+}
+
+impl PartialEq<CowProperties> for HashMap<String, Value> {
+    fn eq(&self, other: &CowProperties) -> bool {
+        *self == *other.0
+    }
+}
+
+impl Debug for CowProperties {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&*self.0, f)
+    }
+}
+
+/// With the feature `ordered_properties` the properties are serialized ordered by name.
+impl Serialize for CowProperties {
+    #[cfg(not(feature = "ordered_properties"))]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+
+    #[cfg(feature = "ordered_properties")]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::serialize_ordered_map(&self.0, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for CowProperties {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        HashMap::<String, Value>::deserialize(deserializer).map(CowProperties::from)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for CowProperties {
+    fn schema_name() -> String {
+        HashMap::<String, Value>::schema_name()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        HashMap::<String, Value>::json_schema(gen)
+    }
+}
@@ -0,0 +1,137 @@
+use serde::de::DeserializeOwned;
+
+use crate::ComponentDao;
+use crate::DaoParseError;
+use crate::EntityInstanceDao;
+use crate::EntityTypeDao;
+use crate::FlowInstanceDao;
+use crate::FlowTypeDao;
+use crate::RelationInstanceDao;
+use crate::RelationTypeDao;
+
+/// The limits of documents which are parsed from untrusted sources, for example type files of
+/// plugin repositories.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseLimits {
+    /// The maximum size of the document in bytes.
+    pub max_size: usize,
+
+    /// The maximum nesting depth of arrays and objects.
+    pub max_depth: usize,
+
+    /// The maximum length of a string or of a key in bytes.
+    pub max_string_length: usize,
+}
+
+impl ParseLimits {
+    /// Sets the maximum size of the document in bytes.
+    pub fn with_max_size(mut self, max_size: usize) -> ParseLimits {
+        self.max_size = max_size;
+        self
+    }
+
+    /// Sets the maximum nesting depth of arrays and objects.
+    pub fn with_max_depth(mut self, max_depth: usize) -> ParseLimits {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Sets the maximum length of a string or of a key in bytes.
+    pub fn with_max_string_length(mut self, max_string_length: usize) -> ParseLimits {
+        self.max_string_length = max_string_length;
+        self
+    }
+
+    /// Checks the given JSON document against the limits without parsing it.
+    ///
+    /// The check is a single pass over the bytes, so that pathological documents are rejected
+    /// before the parser allocates anything.
+    pub fn check(&self, bytes: &[u8]) -> Result<(), DaoParseError> {
+        if bytes.len() > self.max_size {
+            return Err(DaoParseError::TooLarge {
+                size: bytes.len(),
+                max_size: self.max_size,
+            });
+        }
+        let mut depth = 0;
+        // The length of the current string, if the scanner is inside of a string.
+        let mut string_length: Option<usize> = None;
+        let mut escaped = false;
+        for byte in bytes {
+            match string_length.as_mut() {
+                Some(length) => {
+                    if escaped {
+                        escaped = false;
+                    } else if *byte == b'\\' {
+                        escaped = true;
+                    } else if *byte == b'"' {
+                        string_length = None;
+                        continue;
+                    }
+                    *length += 1;
+                    if *length > self.max_string_length {
+                        return Err(DaoParseError::StringTooLong {
+                            max_string_length: self.max_string_length,
+                        });
+                    }
+                }
+                None => match byte {
+                    b'"' => string_length = Some(0),
+                    b'[' | b'{' => {
+                        depth += 1;
+                        if depth > self.max_depth {
+                            return Err(DaoParseError::TooDeeplyNested { max_depth: self.max_depth });
+                        }
+                    }
+                    b']' | b'}' => depth = depth.saturating_sub(1),
+                    _ => {}
+                },
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The default limits are generous for hand written type files and flow files, but reject
+/// documents which would exhaust the stack or the memory.
+impl Default for ParseLimits {
+    fn default() -> Self {
+        ParseLimits {
+            max_size: 16 * 1024 * 1024,
+            max_depth: 64,
+            max_string_length: 1024 * 1024,
+        }
+    }
+}
+
+/// Parses a DAO from the given untrusted JSON document within the given limits.
+pub fn from_slice_with_limits<T: DeserializeOwned>(bytes: &[u8], limits: &ParseLimits) -> Result<T, DaoParseError> {
+    limits.check(bytes)?;
+    Ok(serde_json::from_slice(bytes)?)
+}
+
+macro_rules! impl_from_slice {
+    ($dao: ident) => {
+        impl $dao {
+            /// Parses the DAO from the given untrusted JSON document within the default limits.
+            ///
+            /// The function never panics and is suitable as a fuzz target.
+            pub fn from_slice(bytes: &[u8]) -> Result<$dao, DaoParseError> {
+                from_slice_with_limits(bytes, &ParseLimits::default())
+            }
+
+            /// Parses the DAO from the given untrusted JSON document within the given limits.
+            pub fn from_slice_with_limits(bytes: &[u8], limits: &ParseLimits) -> Result<$dao, DaoParseError> {
+                from_slice_with_limits(bytes, limits)
+            }
+        }
+    };
+}
+
+impl_from_slice!(ComponentDao);
+impl_from_slice!(EntityTypeDao);
+impl_from_slice!(RelationTypeDao);
+impl_from_slice!(FlowTypeDao);
+impl_from_slice!(EntityInstanceDao);
+impl_from_slice!(RelationInstanceDao);
+impl_from_slice!(FlowInstanceDao);
@@ -0,0 +1,229 @@
+use serde::de::DeserializeOwned;
+use serde_ignored::Path;
+use serde_json::Map;
+use serde_json::Value;
+use thiserror::Error;
+
+use crate::ComponentDao;
+use crate::EntityInstanceDao;
+use crate::EntityTypeDao;
+use crate::FlowInstanceDao;
+use crate::FlowTypeDao;
+use crate::RelationInstanceDao;
+use crate::RelationTypeDao;
+
+/// The mode of parsing DAOs.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ParseMode {
+    /// Unknown fields are kept in `extra` and missing optional fields are defaulted. This keeps
+    /// documents of newer versions readable.
+    #[default]
+    Lenient,
+
+    /// Unknown fields are errors and the fields which are defaulted in lenient mode, like the
+    /// namespace, are required. Useful for validating type files in CI.
+    Strict,
+}
+
+/// Error of parsing a DAO.
+#[derive(Debug, Error)]
+pub enum DaoParseError {
+    /// The document is malformed or doesn't match the DAO.
+    #[error(transparent)]
+    Json(#[from] serde_json::Error),
+
+    /// The document contains unknown fields (strict mode only).
+    #[error("Unknown fields: {}", .0.join(", "))]
+    UnknownFields(Vec<String>),
+
+    /// The document misses fields which are required in strict mode.
+    #[error("Missing fields: {}", .0.join(", "))]
+    MissingFields(Vec<String>),
+
+    /// The document is larger than the limit.
+    #[error("The document has {size} bytes, but at most {max_size} bytes are allowed")]
+    TooLarge { size: usize, max_size: usize },
+
+    /// The document contains arrays or objects which are nested deeper than the limit.
+    #[error("The document is nested deeper than {max_depth} levels")]
+    TooDeeplyNested { max_depth: usize },
+
+    /// The document contains a string or a key which is longer than the limit.
+    #[error("The document contains a string longer than {max_string_length} bytes")]
+    StringTooLong { max_string_length: usize },
+}
+
+/// A DAO which can be parsed in strict mode.
+pub trait StrictDao: DeserializeOwned {
+    /// The fields which are defaulted in lenient mode but required in strict mode.
+    const STRICT_REQUIRED_FIELDS: &'static [&'static str] = &["namespace"];
+
+    /// Returns the paths of the unknown fields which are kept in `extra` of the DAO and of its
+    /// nested DAOs.
+    fn unknown_fields(&self) -> Vec<String>;
+
+    /// Returns the paths of the fields which are required in strict mode but are missing in the
+    /// given document or in its nested documents.
+    fn missing_fields(document: &Value) -> Vec<String> {
+        missing_fields(document, Self::STRICT_REQUIRED_FIELDS, "")
+    }
+}
+
+/// Parses a DAO from the given JSON document in the given mode.
+pub fn from_json_str_with_mode<T: StrictDao>(s: &str, mode: ParseMode) -> Result<T, DaoParseError> {
+    match mode {
+        ParseMode::Lenient => Ok(serde_json::from_str(s)?),
+        ParseMode::Strict => from_value_with_mode(serde_json::from_str(s)?, mode),
+    }
+}
+
+/// Parses a DAO from the given document in the given mode.
+///
+/// Documents of other formats can be checked strictly by parsing them into a `Value` first. In
+/// strict mode the unknown fields of nested objects which don't keep unknown fields, like
+/// property types and extensions, are reported as well.
+pub fn from_value_with_mode<T: StrictDao>(document: Value, mode: ParseMode) -> Result<T, DaoParseError> {
+    if mode == ParseMode::Lenient {
+        return Ok(serde_json::from_value(document)?);
+    }
+    let missing = T::missing_fields(&document);
+    if !missing.is_empty() {
+        return Err(DaoParseError::MissingFields(missing));
+    }
+    let mut ignored = Vec::new();
+    let result: Result<T, serde_json::Error> = serde_ignored::deserialize(document, |path| ignored.push(path_to_string(&path)));
+    let dao = match result {
+        Ok(dao) => dao,
+        // A misspelled field is reported instead of the missing field it causes.
+        Err(_) if !ignored.is_empty() => return Err(DaoParseError::UnknownFields(ignored)),
+        Err(e) => return Err(e.into()),
+    };
+    let mut unknown = dao.unknown_fields();
+    unknown.extend(ignored);
+    if !unknown.is_empty() {
+        return Err(DaoParseError::UnknownFields(unknown));
+    }
+    Ok(dao)
+}
+
+pub(crate) fn path_to_string(path: &Path) -> String {
+    match path {
+        Path::Root => String::new(),
+        Path::Seq { parent, index } => format!("{}[{index}]", path_to_string(parent)),
+        Path::Map { parent, key } => match path_to_string(parent) {
+            parent if parent.is_empty() => key.clone(),
+            parent => format!("{parent}.{key}"),
+        },
+        Path::Some { parent } | Path::NewtypeStruct { parent } | Path::NewtypeVariant { parent } => path_to_string(parent),
+    }
+}
+
+fn missing_fields(document: &Value, fields: &[&str], prefix: &str) -> Vec<String> {
+    match document.as_object() {
+        Some(object) => fields
+            .iter()
+            .filter(|field| !object.contains_key(**field))
+            .map(|field| format!("{prefix}{field}"))
+            .collect(),
+        None => Vec::new(),
+    }
+}
+
+fn nested_missing_fields<T: StrictDao>(document: &Value, names: &[&str]) -> Vec<String> {
+    let Some((name, nested)) = names.iter().find_map(|name| document.get(*name).map(|nested| (name, nested))) else {
+        return Vec::new();
+    };
+    match nested.as_array() {
+        Some(nested) => nested
+            .iter()
+            .enumerate()
+            .flat_map(|(i, nested)| missing_fields(nested, T::STRICT_REQUIRED_FIELDS, &format!("{name}[{i}].")))
+            .collect(),
+        None => missing_fields(nested, T::STRICT_REQUIRED_FIELDS, &format!("{name}.")),
+    }
+}
+
+fn extra_fields(extra: &Map<String, Value>, prefix: &str) -> Vec<String> {
+    let mut fields: Vec<String> = extra.keys().map(|field| format!("{prefix}{field}")).collect();
+    fields.sort();
+    fields
+}
+
+fn nested_extra_fields<T: StrictDao>(name: &str, nested: &[T]) -> Vec<String> {
+    nested
+        .iter()
+        .enumerate()
+        .flat_map(|(i, nested)| nested.unknown_fields().into_iter().map(move |field| format!("{name}[{i}].{field}")))
+        .collect()
+}
+
+impl StrictDao for ComponentDao {
+    fn unknown_fields(&self) -> Vec<String> {
+        extra_fields(&self.extra, "")
+    }
+}
+
+impl StrictDao for EntityTypeDao {
+    fn unknown_fields(&self) -> Vec<String> {
+        extra_fields(&self.extra, "")
+    }
+}
+
+impl StrictDao for RelationTypeDao {
+    fn unknown_fields(&self) -> Vec<String> {
+        extra_fields(&self.extra, "")
+    }
+}
+
+impl StrictDao for EntityInstanceDao {
+    fn unknown_fields(&self) -> Vec<String> {
+        extra_fields(&self.extra, "")
+    }
+}
+
+impl StrictDao for RelationInstanceDao {
+    const STRICT_REQUIRED_FIELDS: &'static [&'static str] = &["namespace", "instance_id"];
+
+    fn unknown_fields(&self) -> Vec<String> {
+        extra_fields(&self.extra, "")
+    }
+}
+
+impl StrictDao for FlowInstanceDao {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields = extra_fields(&self.extra, "");
+        fields.extend(nested_extra_fields("entity_instances", &self.entity_instances));
+        fields.extend(nested_extra_fields("relation_instances", &self.relation_instances));
+        fields
+    }
+
+    fn missing_fields(document: &Value) -> Vec<String> {
+        let mut fields = missing_fields(document, Self::STRICT_REQUIRED_FIELDS, "");
+        fields.extend(nested_missing_fields::<EntityInstanceDao>(document, &["entity_instances", "entities"]));
+        fields.extend(nested_missing_fields::<RelationInstanceDao>(document, &["relation_instances", "relations"]));
+        fields
+    }
+}
+
+impl StrictDao for FlowTypeDao {
+    fn unknown_fields(&self) -> Vec<String> {
+        let mut fields = extra_fields(&self.extra, "");
+        fields.extend(
+            self.wrapper_entity_instance
+                .unknown_fields()
+                .into_iter()
+                .map(|field| format!("wrapper_entity_instance.{field}")),
+        );
+        fields.extend(nested_extra_fields("entity_instances", &self.entity_instances));
+        fields.extend(nested_extra_fields("relation_instances", &self.relation_instances));
+        fields
+    }
+
+    fn missing_fields(document: &Value) -> Vec<String> {
+        let mut fields = missing_fields(document, Self::STRICT_REQUIRED_FIELDS, "");
+        fields.extend(nested_missing_fields::<EntityInstanceDao>(document, &["wrapper_entity_instance"]));
+        fields.extend(nested_missing_fields::<EntityInstanceDao>(document, &["entity_instances", "entities"]));
+        fields.extend(nested_missing_fields::<RelationInstanceDao>(document, &["relation_instances", "relations"]));
+        fields
+    }
+}
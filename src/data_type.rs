@@ -9,8 +9,10 @@ use serde_json::json;
 use serde_json::Value;
 
 /// Derived from serde_json::Value but without value payload.
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Copy, Eq)]
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Copy, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "lowercase")]
+#[non_exhaustive]
 pub enum DataType {
     /// Represents a JSON null value.
     Null,
@@ -22,6 +24,7 @@ pub enum DataType {
     Number,
 
     /// Represents a JSON string.
+    #[default]
     String,
 
     /// Represents a JSON array.
@@ -57,6 +60,25 @@ impl DataType {
             _ => json!(""),
         }
     }
+
+    /// Returns true, if the given value is of this data type. Any data type accepts every value.
+    pub fn accepts(&self, value: &Value) -> bool {
+        *self == DataType::Any || *self == DataType::from(value)
+    }
+}
+
+/// Returns the data type of the given value.
+impl From<&Value> for DataType {
+    fn from(value: &Value) -> Self {
+        match value {
+            Value::Null => DataType::Null,
+            Value::Bool(_) => DataType::Bool,
+            Value::Number(_) => DataType::Number,
+            Value::String(_) => DataType::String,
+            Value::Array(_) => DataType::Array,
+            Value::Object(_) => DataType::Object,
+        }
+    }
 }
 
 impl From<&str> for DataType {
@@ -0,0 +1,45 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::NamespacedType;
+
+/// The deprecation of a type. Deprecated types are about to be removed.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Deprecation {
+    /// The version since the type is deprecated.
+    #[serde(default)]
+    pub since: Option<String>,
+
+    /// The type which replaces the deprecated type. The replacement is of the same type of types
+    /// as the deprecated type.
+    #[serde(default)]
+    pub replacement: Option<NamespacedType>,
+
+    /// The message which explains the deprecation.
+    #[serde(default = "String::new")]
+    pub message: String,
+}
+
+impl Deprecation {
+    /// Constructs a deprecation with the given message.
+    pub fn new<S: Into<String>>(message: S) -> Deprecation {
+        Deprecation {
+            since: None,
+            replacement: None,
+            message: message.into(),
+        }
+    }
+
+    /// Sets the version since the type is deprecated.
+    pub fn since<S: Into<String>>(mut self, version: S) -> Deprecation {
+        self.since = Some(version.into());
+        self
+    }
+
+    /// Sets the type which replaces the deprecated type.
+    pub fn replaced_by<T: Into<NamespacedType>>(mut self, replacement: T) -> Deprecation {
+        self.replacement = Some(replacement.into());
+        self
+    }
+}
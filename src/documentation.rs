@@ -0,0 +1,86 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::Component;
+use crate::EntityType;
+use crate::FlowType;
+use crate::PropertyType;
+use crate::RelationType;
+
+/// The structured documentation of a type or of a property type.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Documentation {
+    /// The short summary in one sentence.
+    #[serde(default = "String::new")]
+    pub summary: String,
+
+    /// The long documentation in markdown.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+
+    /// The links to further documentation.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub links: Vec<DocumentationLink>,
+}
+
+/// A link to further documentation.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct DocumentationLink {
+    /// The title of the link.
+    pub title: String,
+
+    /// The url of the link.
+    pub url: String,
+}
+
+impl Documentation {
+    /// Constructs a documentation with the given summary.
+    pub fn new<S: Into<String>>(summary: S) -> Documentation {
+        Documentation {
+            summary: summary.into(),
+            body: None,
+            links: Vec::new(),
+        }
+    }
+
+    /// Sets the long documentation in markdown.
+    pub fn with_body<S: Into<String>>(mut self, body: S) -> Documentation {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// Adds a link to further documentation.
+    pub fn with_link<S: Into<String>>(mut self, title: S, url: S) -> Documentation {
+        self.links.push(DocumentationLink {
+            title: title.into(),
+            url: url.into(),
+        });
+        self
+    }
+}
+
+macro_rules! documentation {
+    ($($ty: ident),*) => {
+        $(
+            impl $ty {
+                /// Sets the structured documentation.
+                pub fn with_documentation(mut self, documentation: Documentation) -> $ty {
+                    self.documentation = Some(documentation);
+                    self
+                }
+
+                /// Returns the summary of the documentation or, if there is none, the description.
+                pub fn summary(&self) -> &str {
+                    match &self.documentation {
+                        Some(documentation) if !documentation.summary.is_empty() => &documentation.summary,
+                        _ => &self.description,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+documentation!(Component, EntityType, RelationType, FlowType, PropertyType);
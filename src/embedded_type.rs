@@ -0,0 +1,59 @@
+use serde::de::DeserializeOwned;
+
+/// Parses the given embedded JSON document of a type.
+///
+/// Panics with the name of the embedded file if the document is not valid, so that invalid
+/// embedded types surface in tests.
+pub fn parse_embedded_type<T: DeserializeOwned>(json: &str, file: &str) -> T {
+    match serde_json::from_str(json) {
+        Ok(ty) => ty,
+        Err(e) => panic!("The embedded type {} is invalid: {}", file, e),
+    }
+}
+
+/// Embeds the JSON document of a type in the binary and parses it lazily once.
+///
+/// The path is relative to the file which invokes the macro. Returns a static reference.
+///
+/// ```ignore
+/// let entity_type: &'static EntityType = embedded_type!(EntityType, "types/and.json");
+/// ```
+#[macro_export]
+macro_rules! embedded_type {
+    ($ty: ty, $path: expr) => {{
+        static EMBEDDED_TYPE: ::std::sync::OnceLock<$ty> = ::std::sync::OnceLock::new();
+        EMBEDDED_TYPE.get_or_init(|| $crate::parse_embedded_type::<$ty>(include_str!($path), $path))
+    }};
+}
+
+/// Embeds the JSON document of a component in the binary and parses it lazily once.
+#[macro_export]
+macro_rules! embedded_component {
+    ($path: expr) => {
+        $crate::embedded_type!($crate::Component, $path)
+    };
+}
+
+/// Embeds the JSON document of an entity type in the binary and parses it lazily once.
+#[macro_export]
+macro_rules! embedded_entity_type {
+    ($path: expr) => {
+        $crate::embedded_type!($crate::EntityType, $path)
+    };
+}
+
+/// Embeds the JSON document of a relation type in the binary and parses it lazily once.
+#[macro_export]
+macro_rules! embedded_relation_type {
+    ($path: expr) => {
+        $crate::embedded_type!($crate::RelationType, $path)
+    };
+}
+
+/// Embeds the JSON document of a flow type in the binary and parses it lazily once.
+#[macro_export]
+macro_rules! embedded_flow_type {
+    ($path: expr) => {
+        $crate::embedded_type!($crate::FlowType, $path)
+    };
+}
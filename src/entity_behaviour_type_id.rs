@@ -8,7 +8,8 @@ use crate::EntityTypeId;
 use crate::NamespacedType;
 
 /// The behaviour of an entity type.
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct EntityBehaviourTypeId {
     /// The entity type.
     pub entity_ty: EntityTypeId,
@@ -26,6 +27,11 @@ impl EntityBehaviourTypeId {
         let namespaced_type = NamespacedType::new(namespace, type_name);
         EntityBehaviourTypeId::new(namespaced_type.clone().into(), namespaced_type.into())
     }
+
+    /// Returns true, if the behaviour applies to the given entity type.
+    pub fn applies_to(&self, ty: &EntityTypeId) -> bool {
+        &self.entity_ty == ty
+    }
 }
 
 impl From<NamespacedType> for EntityBehaviourTypeId {
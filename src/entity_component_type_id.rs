@@ -6,6 +6,7 @@ use crate::EntityTypeId;
 
 /// Addresses the component of an entity type.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct EntityComponentTypeId {
     /// The entity type.
     pub entity_ty: EntityTypeId,
@@ -2,6 +2,10 @@ use std::collections::HashMap;
 use std::fmt::Display;
 use std::fmt::Formatter;
 
+use chrono::DateTime;
+use chrono::Utc;
+use indradb::BulkInsertItem;
+use indradb::Vertex;
 use indradb::VertexProperties;
 use serde::Deserialize;
 use serde::Serialize;
@@ -9,13 +13,21 @@ use serde_json::Map;
 use serde_json::Value;
 use uuid::Uuid;
 
+use crate::check_revision;
+use crate::is_initial_revision;
+use crate::property_identifier;
+use crate::CompactDebug;
+use crate::CowProperties;
 use crate::EntityTypeId;
 use crate::Extension;
 use crate::ExtensionContainer;
 use crate::ExtensionTypeId;
+use crate::ModelError;
 use crate::MutablePropertyInstanceSetter;
 use crate::NamespacedTypeGetter;
 use crate::PropertyInstanceGetter;
+use crate::PropertyInstanceRefGetter;
+use crate::RevisionError;
 use crate::TypeDefinition;
 use crate::TypeDefinitionGetter;
 
@@ -25,7 +37,11 @@ use crate::TypeDefinitionGetter;
 ///
 /// In contrast to the entity type the entity instance stores values in it's
 /// properties.
+///
+/// Entity instances can be built with struct update syntax based on
+/// `EntityInstance::default()`.
 #[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct EntityInstance {
     /// The type definition of the entity type.
     #[serde(flatten)]
@@ -44,12 +60,41 @@ pub struct EntityInstance {
     /// a representation of a JSON. Therefore the value can be boolean, number, string,
     /// array or an object. For more information about the data types please look at
     /// https://docs.serde.rs/serde_json/value/enum.Value.html
-    #[serde(default = "HashMap::new")]
-    pub properties: HashMap<String, Value>,
+    ///
+    /// Clones of the instance share the property map until one of them is mutated.
+    #[serde(default = "CowProperties::new")]
+    pub properties: CowProperties,
 
     /// Entity instance specific extensions.
     #[serde(default = "Vec::new")]
     pub extensions: Vec<Extension>,
+
+    /// The point of time when the entity instance has been created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+
+    /// The point of time when the entity instance has been updated for the last time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+
+    /// The user or the service which has created the entity instance.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<String>,
+
+    /// The point of time when the entity instance has been deleted. Deleted entity instances are kept as
+    /// tombstones until they are purged, so that the deletion can be undone or synchronized.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<DateTime<Utc>>,
+
+    /// The revision of the entity instance, which is incremented by each modification. Concurrent editors
+    /// compare the revision in order to detect conflicting writes.
+    #[serde(default, skip_serializing_if = "is_initial_revision")]
+    pub revision: u64,
+
+    /// Unknown fields of the data access object, which are re-emitted when converting back into
+    /// a data access object.
+    #[serde(skip)]
+    pub extra: Map<String, Value>,
 }
 
 impl EntityInstance {
@@ -59,8 +104,14 @@ impl EntityInstance {
             ty: ty.into(),
             id,
             description: String::new(),
-            properties,
+            properties: properties.into(),
             extensions: Vec::new(),
+            created_at: None,
+            updated_at: None,
+            created_by: None,
+            deleted_at: None,
+            revision: 0,
+            extra: Map::new(),
         }
     }
 
@@ -70,8 +121,14 @@ impl EntityInstance {
             ty: EntityTypeId::new_from_type(namespace, type_name),
             id,
             description: String::new(),
-            properties,
+            properties: properties.into(),
             extensions: Vec::new(),
+            created_at: None,
+            updated_at: None,
+            created_by: None,
+            deleted_at: None,
+            revision: 0,
+            extra: Map::new(),
         }
     }
 
@@ -81,14 +138,130 @@ impl EntityInstance {
             ty: ty.into(),
             id,
             description: String::new(),
-            properties: HashMap::new(),
+            properties: CowProperties::new(),
             extensions: Vec::new(),
+            created_at: None,
+            updated_at: None,
+            created_by: None,
+            deleted_at: None,
+            revision: 0,
+            extra: Map::new(),
+        }
+    }
+
+    /// Sets the description of the entity instance.
+    pub fn with_description<S: Into<String>>(mut self, description: S) -> EntityInstance {
+        self.description = description.into();
+        self
+    }
+
+    /// Adds the given extension to the entity instance.
+    pub fn with_extension(mut self, extension: Extension) -> EntityInstance {
+        self.extensions.push(extension);
+        self
+    }
+
+    /// Sets the user or the service which has created the entity instance and marks the entity instance as
+    /// created now.
+    pub fn with_created_by<S: Into<String>>(mut self, created_by: S) -> EntityInstance {
+        self.created_by = Some(created_by.into());
+        self.touch();
+        self
+    }
+
+    /// Marks the entity instance as updated now. If the entity instance has no creation time, it is marked
+    /// as created now, too.
+    pub fn touch(&mut self) {
+        self.touch_at(Utc::now());
+    }
+
+    /// Marks the entity instance as updated at the given point of time. If the entity instance has no
+    /// creation time, it is marked as created at the given point of time, too.
+    pub fn touch_at(&mut self, now: DateTime<Utc>) {
+        self.created_at.get_or_insert(now);
+        self.updated_at = Some(now);
+    }
+
+    /// Returns true, if the entity instance is marked as deleted.
+    pub fn is_deleted(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+
+    /// Marks the entity instance as deleted now without removing it.
+    pub fn mark_deleted(&mut self) {
+        self.mark_deleted_at(Utc::now());
+    }
+
+    /// Marks the entity instance as deleted at the given point of time without removing it. An
+    /// entity instance which is already marked as deleted keeps its point of time of deletion.
+    pub fn mark_deleted_at(&mut self, now: DateTime<Utc>) {
+        self.touch_at(now);
+        self.deleted_at = Some(now);
+        self.increment_revision();
+        if self.deleted_at.is_none() {
+            self.touch_at(now);
+            self.deleted_at = Some(now);
+        }
+    }
+
+    /// Undoes the deletion of the entity instance.
+    pub fn restore(&mut self) {
+        if self.deleted_at.take().is_some() {
+            self.touch();
+            self.increment_revision();
+        }
+    }
+
+    /// Increments the revision of the entity instance. The setters increment the revision themselves,
+    /// only direct modifications of the fields have to be followed by an increment.
+    pub fn increment_revision(&mut self) {
+        self.revision = self.revision.wrapping_add(1);
+    }
+
+    /// Sets the value of the given property, if the entity instance still has the expected revision.
+    ///
+    /// Returns the new revision. Fails without modification, if another write happened since the
+    /// expected revision or if the property doesn't exist.
+    pub fn check_and_set<S: Into<String>>(&mut self, expected_revision: u64, property_name: S, value: Value) -> Result<u64, RevisionError> {
+        check_revision(expected_revision, self.revision)?;
+        let property_name = property_name.into();
+        if !self.properties.contains_key(&property_name) {
+            return Err(RevisionError::MissingProperty(property_name));
         }
+        self.set(property_name, value);
+        Ok(self.revision)
+    }
+
+    /// Returns a debug formatter which truncates large property values and elides binary content.
+    pub fn debug_compact(&self) -> CompactDebug<'_, EntityInstance> {
+        CompactDebug::new(self)
+    }
+
+    /// Returns the bulk insert items of the vertex and of the properties of the entity instance.
+    ///
+    /// The vertex precedes the properties. This is the inverse of the conversion from
+    /// `VertexProperties`.
+    pub fn to_bulk_insert_items(&self) -> Vec<BulkInsertItem> {
+        let mut items = Vec::with_capacity(self.properties.len() + 1);
+        items.push(BulkInsertItem::Vertex(Vertex::with_id(self.id, self.type_id())));
+        items.extend(
+            self.properties
+                .iter()
+                .map(|(property_name, value)| BulkInsertItem::VertexProperty(self.id, property_identifier(property_name), value.clone())),
+        );
+        items
+    }
+}
+
+/// Constructs an entity instance of an empty type with a new random id and without properties.
+impl Default for EntityInstance {
+    fn default() -> Self {
+        EntityInstance::new_without_properties(EntityTypeId::default(), Uuid::new_v4())
     }
 }
 
 impl TryFrom<VertexProperties> for EntityInstance {
-    type Error = ();
+    type Error = ModelError;
 
     fn try_from(properties: VertexProperties) -> Result<Self, Self::Error> {
         let ty = EntityTypeId::try_from(&properties.vertex.t)?;
@@ -98,8 +271,14 @@ impl TryFrom<VertexProperties> for EntityInstance {
             ty,
             id,
             description: String::new(),
-            properties,
+            properties: properties.into(),
             extensions: Vec::new(),
+            created_at: None,
+            updated_at: None,
+            created_by: None,
+            deleted_at: None,
+            revision: 0,
+            extra: Map::new(),
         })
     }
 }
@@ -138,10 +317,17 @@ impl PropertyInstanceGetter for EntityInstance {
     }
 }
 
+impl PropertyInstanceRefGetter for EntityInstance {
+    fn get_ref(&self, property_name: &str) -> Option<&Value> {
+        self.properties.get(property_name)
+    }
+}
+
 impl MutablePropertyInstanceSetter for EntityInstance {
     fn set<S: Into<String>>(&mut self, property_name: S, value: Value) {
         if let Some(property_value) = self.properties.get_mut(&property_name.into()) {
-            *property_value = value
+            *property_value = value;
+            self.increment_revision();
         }
     }
 }
@@ -172,8 +358,14 @@ impl TypeDefinitionGetter for EntityInstance {
     }
 }
 
+/// Formats the type and the id of the entity instance. The alternate form `{:#}` appends the
+/// number of properties.
 impl Display for EntityInstance {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}__{}", &self.ty, self.id)
+        write!(f, "{}__{}", &self.ty, self.id)?;
+        if f.alternate() {
+            write!(f, " ({} properties)", self.properties.len())?;
+        }
+        Ok(())
     }
 }
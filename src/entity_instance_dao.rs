@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+
+use chrono::DateTime;
+use chrono::Utc;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Map;
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::is_initial_revision;
+use crate::EntityInstance;
+use crate::EntityTypeId;
+use crate::Extension;
+use crate::NamespacedTypeGetter;
+
+/// Data access object of an entity instance.
+///
+/// Defines the stable external representation of an entity instance. The in-memory
+/// representation (`EntityInstance`) can evolve without breaking existing files.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct EntityInstanceDao {
+    /// The namespace of the entity type.
+    #[serde(default = "String::new")]
+    pub namespace: String,
+
+    /// The name of the entity type.
+    #[serde(alias = "name")]
+    pub type_name: String,
+
+    /// The unique identifier of the entity instance.
+    pub id: Uuid,
+
+    /// The description of the entity instance.
+    #[serde(default = "String::new")]
+    pub description: String,
+
+    /// The properties of the entity instance.
+    #[serde(default = "HashMap::new")]
+    #[cfg_attr(feature = "ordered_properties", serde(serialize_with = "crate::serialize_ordered_map"))]
+    pub properties: HashMap<String, Value>,
+
+    /// Entity instance specific extensions.
+    #[serde(default = "Vec::new")]
+    pub extensions: Vec<Extension>,
+
+    /// The point of time when the entity instance has been created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+
+    /// The point of time when the entity instance has been updated for the last time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+
+    /// The user or the service which has created the entity instance.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<String>,
+
+    /// The point of time when the entity instance has been deleted. Deleted entity instances are kept as
+    /// tombstones until they are purged, so that the deletion can be undone or synchronized.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<DateTime<Utc>>,
+
+    /// The revision of the entity instance, which is incremented by each modification. Concurrent editors
+    /// compare the revision in order to detect conflicting writes.
+    #[serde(default, skip_serializing_if = "is_initial_revision")]
+    pub revision: u64,
+
+    /// Unknown fields, for example written by a newer version, which are re-emitted on save.
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl From<EntityInstance> for EntityInstanceDao {
+    fn from(entity_instance: EntityInstance) -> Self {
+        EntityInstanceDao {
+            namespace: entity_instance.namespace(),
+            type_name: entity_instance.type_name(),
+            id: entity_instance.id,
+            description: entity_instance.description,
+            properties: entity_instance.properties.into(),
+            extensions: entity_instance.extensions,
+            created_at: entity_instance.created_at,
+            updated_at: entity_instance.updated_at,
+            created_by: entity_instance.created_by,
+            deleted_at: entity_instance.deleted_at,
+            revision: entity_instance.revision,
+            extra: entity_instance.extra,
+        }
+    }
+}
+
+impl From<EntityInstanceDao> for EntityInstance {
+    fn from(dao: EntityInstanceDao) -> Self {
+        EntityInstance {
+            ty: EntityTypeId::new_from_type(dao.namespace, dao.type_name),
+            id: dao.id,
+            description: dao.description,
+            properties: dao.properties.into(),
+            extensions: dao.extensions,
+            created_at: dao.created_at,
+            updated_at: dao.updated_at,
+            created_by: dao.created_by,
+            deleted_at: dao.deleted_at,
+            revision: dao.revision,
+            extra: dao.extra,
+        }
+    }
+}
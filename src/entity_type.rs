@@ -1,20 +1,37 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::hash::Hasher;
+
 use serde::Deserialize;
 use serde::Serialize;
+use serde_json::Map;
+use serde_json::Value;
 
+use crate::entity_instance_json_schema;
 use crate::extension::Extension;
+use crate::validate_instance_properties;
+use crate::Component;
 use crate::ComponentTypeId;
+use crate::Deprecation;
+use crate::Documentation;
+use crate::EntityInstance;
 use crate::EntityTypeId;
 use crate::ExtensionContainer;
 use crate::ExtensionTypeId;
+use crate::LanguageTag;
 use crate::NamespacedTypeGetter;
 use crate::PropertyType;
+use crate::TagContainer;
 use crate::TypeContainer;
 use crate::TypeDefinition;
 use crate::TypeDefinitionGetter;
 use crate::TypeIdType;
+use crate::ValidationError;
 
 /// Entity types defines the type of an entity instance.
 #[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct EntityType {
     /// The type definition contains the namespace and the type name.
     #[serde(flatten)]
@@ -24,6 +41,48 @@ pub struct EntityType {
     #[serde(default = "String::new")]
     pub description: String,
 
+    /// The descriptions in other languages, by language tag.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty", serialize_with = "crate::serialize_descriptions")]
+    pub descriptions: HashMap<LanguageTag, String>,
+
+    /// The structured documentation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub documentation: Option<Documentation>,
+
+    /// The tags of the entity type, for grouping types into categories.
+    #[serde(default = "Vec::new")]
+    pub tags: Vec<String>,
+
+    /// The previous type ids of the entity type. References to a previous type id are resolved
+    /// to the entity type.
+    #[serde(default = "Vec::new")]
+    pub aliases: Vec<EntityTypeId>,
+
+    /// The deprecation of the entity type, if the entity type is deprecated.
+    #[serde(default)]
+    pub deprecation: Option<Deprecation>,
+
+    /// The parent entity type, whose components and properties are inherited by the entity type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extends: Option<EntityTypeId>,
+
+    /// If true, the entity type can't be instantiated directly but only be extended by other
+    /// entity types or be used as endpoint constraint.
+    #[serde(rename = "abstract", default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_abstract: bool,
+
+    /// If true, the entity type can't be extended by other entity types and the instances of the
+    /// entity type can't be composed with further components.
+    ///
+    /// Relation types and flow types, which can't be extended, have no marker.
+    #[serde(rename = "final", default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_final: bool,
+
+    /// If true, the properties of the components are scoped by the type names of the components,
+    /// for example `labeled.label`.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub scoped_properties: bool,
+
     /// The names of the components of the entity type.
     #[serde(default = "Vec::new")]
     pub components: Vec<ComponentTypeId>,
@@ -32,9 +91,18 @@ pub struct EntityType {
     #[serde(default = "Vec::new")]
     pub properties: Vec<PropertyType>,
 
+    /// Example instances of the entity type.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub examples: Vec<EntityInstance>,
+
     /// Entity type specific extensions.
     #[serde(default = "Vec::new")]
     pub extensions: Vec<Extension>,
+
+    /// Unknown fields of the data access object, which are re-emitted when converting back into
+    /// a data access object.
+    #[serde(skip)]
+    pub extra: Map<String, Value>,
 }
 
 impl EntityType {
@@ -49,9 +117,20 @@ impl EntityType {
         EntityType {
             ty: ty.into(),
             description: description.into(),
+            descriptions: HashMap::new(),
+            documentation: None,
+            tags: Vec::new(),
+            aliases: Vec::new(),
+            deprecation: None,
+            extends: None,
+            is_abstract: false,
+            is_final: false,
+            scoped_properties: false,
             components,
             properties,
+            examples: Vec::new(),
             extensions,
+            extra: Map::new(),
         }
     }
 
@@ -66,9 +145,104 @@ impl EntityType {
         EntityType {
             ty: EntityTypeId::new_from_type(namespace, type_name),
             description: description.into(),
+            descriptions: HashMap::new(),
+            documentation: None,
+            tags: Vec::new(),
+            aliases: Vec::new(),
+            deprecation: None,
+            extends: None,
+            is_abstract: false,
+            is_final: false,
+            scoped_properties: false,
             components,
             properties,
+            examples: Vec::new(),
             extensions,
+            extra: Map::new(),
+        }
+    }
+
+    /// Adds the given tag to the entity type.
+    pub fn with_tag<S: Into<String>>(mut self, tag: S) -> EntityType {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Adds the given previous type id to the entity type.
+    pub fn with_alias<A: Into<EntityTypeId>>(mut self, alias: A) -> EntityType {
+        self.aliases.push(alias.into());
+        self
+    }
+
+    /// Returns true, if the given type id is the type id or a previous type id of the entity type.
+    pub fn is_known_as(&self, ty: &EntityTypeId) -> bool {
+        &self.ty == ty || self.aliases.contains(ty)
+    }
+
+    /// Deprecates the entity type.
+    pub fn with_deprecation(mut self, deprecation: Deprecation) -> EntityType {
+        self.deprecation = Some(deprecation);
+        self
+    }
+
+    /// Sets the parent entity type, whose components and properties are inherited.
+    pub fn with_parent<T: Into<EntityTypeId>>(mut self, parent: T) -> EntityType {
+        self.extends = Some(parent.into());
+        self
+    }
+
+    /// Returns true, if the entity type is deprecated.
+    pub fn is_deprecated(&self) -> bool {
+        self.deprecation.is_some()
+    }
+
+    /// Declares the entity type as abstract, which means that it can't be instantiated directly.
+    pub fn with_abstract(mut self) -> EntityType {
+        self.is_abstract = true;
+        self
+    }
+
+    /// Declares the entity type as final, which means that it can't be extended by other entity
+    /// types.
+    pub fn with_final(mut self) -> EntityType {
+        self.is_final = true;
+        self
+    }
+
+    /// Returns the JSON Schema of entity instance documents of this entity type.
+    ///
+    /// The properties of the given components are included if the component is applied on this
+    /// entity type.
+    pub fn instance_json_schema(&self, components: &[Component]) -> Value {
+        entity_instance_json_schema(self, components)
+    }
+
+    /// Validates that the given entity instance is of this entity type or of one of its previous
+    /// type ids and that it contains the properties of this entity type and of its components with
+    /// values of the right data type.
+    ///
+    /// The given components must contain every component of this entity type. Entity instances of
+    /// abstract entity types are invalid.
+    pub fn validate_instance(&self, entity_instance: &EntityInstance, components: &[Component]) -> Result<(), ValidationError> {
+        if !self.is_known_as(&entity_instance.ty) {
+            return Err(ValidationError::UnexpectedType {
+                expected: self.ty.to_string(),
+                actual: entity_instance.ty.to_string(),
+            });
+        }
+        if self.is_abstract {
+            return Err(ValidationError::AbstractEntityType(self.ty.clone()));
+        }
+        validate_instance_properties(self.scoped_properties, &self.properties, &self.components, components, &entity_instance.properties)
+    }
+
+    /// Returns true, if both entity types have the same type and the same definition.
+    ///
+    /// In contrast, `==` compares only the type ids.
+    pub fn structurally_equals(&self, other: &EntityType) -> bool {
+        match (serde_json::to_value(self), serde_json::to_value(other)) {
+            (Ok(a), Ok(b)) => a == b,
+            _ => false,
         }
     }
 }
@@ -99,6 +273,16 @@ impl ExtensionContainer for EntityType {
     }
 }
 
+impl TagContainer for EntityType {
+    fn get_tags(&self) -> Vec<String> {
+        self.tags.clone()
+    }
+
+    fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t == tag)
+    }
+}
+
 impl NamespacedTypeGetter for EntityType {
     fn namespace(&self) -> String {
         self.ty.namespace()
@@ -115,6 +299,21 @@ impl TypeDefinitionGetter for EntityType {
     }
 }
 
+/// Two entity types are equal, if they have the same type id.
+impl PartialEq for EntityType {
+    fn eq(&self, other: &Self) -> bool {
+        self.ty == other.ty
+    }
+}
+
+impl Eq for EntityType {}
+
+impl Hash for EntityType {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.ty.hash(state);
+    }
+}
+
 impl From<&EntityType> for TypeDefinition {
     fn from(entity_type: &EntityType) -> Self {
         TypeDefinition {
@@ -0,0 +1,144 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::ComponentTypeId;
+use crate::Deprecation;
+use crate::Documentation;
+use crate::EntityInstance;
+use crate::EntityInstanceDao;
+use crate::EntityType;
+use crate::EntityTypeId;
+use crate::Extension;
+use crate::LanguageTag;
+use crate::NamespacedTypeGetter;
+use crate::PropertyType;
+
+/// Data access object of an entity type.
+///
+/// Defines the stable external representation of an entity type. The in-memory
+/// representation (`EntityType`) can evolve without breaking existing files.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct EntityTypeDao {
+    /// The namespace of the entity type.
+    #[serde(default = "String::new")]
+    pub namespace: String,
+
+    /// The name of the entity type.
+    #[serde(alias = "name")]
+    pub type_name: String,
+
+    /// Textual description of the entity type.
+    #[serde(default = "String::new")]
+    pub description: String,
+
+    /// The descriptions in other languages, by language tag.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty", serialize_with = "crate::serialize_descriptions")]
+    pub descriptions: HashMap<LanguageTag, String>,
+
+    /// The structured documentation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub documentation: Option<Documentation>,
+
+    /// The tags of the entity type, for grouping types into categories.
+    #[serde(default = "Vec::new")]
+    pub tags: Vec<String>,
+
+    /// The previous type ids of the entity type. References to a previous type id are resolved
+    /// to the entity type.
+    #[serde(default = "Vec::new")]
+    pub aliases: Vec<EntityTypeId>,
+
+    /// The deprecation of the entity type, if the entity type is deprecated.
+    #[serde(default)]
+    pub deprecation: Option<Deprecation>,
+
+    /// The parent entity type, whose components and properties are inherited by the entity type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extends: Option<EntityTypeId>,
+
+    /// If true, the entity type can't be instantiated directly but only be extended by other
+    /// entity types or be used as endpoint constraint.
+    #[serde(rename = "abstract", default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_abstract: bool,
+
+    /// If true, the entity type can't be extended by other entity types.
+    #[serde(rename = "final", default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_final: bool,
+
+    /// If true, the properties of the components are scoped by the type names of the components,
+    /// for example `labeled.label`.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub scoped_properties: bool,
+
+    /// The components of the entity type.
+    #[serde(default = "Vec::new")]
+    pub components: Vec<ComponentTypeId>,
+
+    /// The properties which are defined by the entity type.
+    #[serde(default = "Vec::new")]
+    pub properties: Vec<PropertyType>,
+
+    /// Example instances of the entity type.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub examples: Vec<EntityInstanceDao>,
+
+    /// Entity type specific extensions.
+    #[serde(default = "Vec::new")]
+    pub extensions: Vec<Extension>,
+
+    /// Unknown fields, for example written by a newer version, which are re-emitted on save.
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl From<EntityType> for EntityTypeDao {
+    fn from(entity_type: EntityType) -> Self {
+        EntityTypeDao {
+            namespace: entity_type.namespace(),
+            type_name: entity_type.type_name(),
+            description: entity_type.description,
+            descriptions: entity_type.descriptions,
+            documentation: entity_type.documentation,
+            tags: entity_type.tags,
+            aliases: entity_type.aliases,
+            deprecation: entity_type.deprecation,
+            extends: entity_type.extends,
+            is_abstract: entity_type.is_abstract,
+            is_final: entity_type.is_final,
+            scoped_properties: entity_type.scoped_properties,
+            components: entity_type.components,
+            properties: entity_type.properties,
+            examples: entity_type.examples.into_iter().map(EntityInstanceDao::from).collect(),
+            extensions: entity_type.extensions,
+            extra: entity_type.extra,
+        }
+    }
+}
+
+impl From<EntityTypeDao> for EntityType {
+    fn from(dao: EntityTypeDao) -> Self {
+        EntityType {
+            ty: EntityTypeId::new_from_type(dao.namespace, dao.type_name),
+            description: dao.description,
+            descriptions: dao.descriptions,
+            documentation: dao.documentation,
+            tags: dao.tags,
+            aliases: dao.aliases,
+            deprecation: dao.deprecation,
+            extends: dao.extends,
+            is_abstract: dao.is_abstract,
+            is_final: dao.is_final,
+            scoped_properties: dao.scoped_properties,
+            components: dao.components,
+            properties: dao.properties,
+            examples: dao.examples.into_iter().map(EntityInstance::from).collect(),
+            extensions: dao.extensions,
+            extra: dao.extra,
+        }
+    }
+}
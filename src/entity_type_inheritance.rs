@@ -0,0 +1,117 @@
+use crate::ComponentTypeId;
+use crate::EntityType;
+use crate::EntityTypeId;
+use crate::TypeSystem;
+use crate::ValidationError;
+
+impl EntityType {
+    /// Returns a copy of this entity type which additionally contains the components and the
+    /// properties of the given parent entity type.
+    ///
+    /// The components of the parent precede the own components. Own properties override
+    /// inherited properties with the same name. Fails if the parent entity type is final.
+    pub fn inherit(&self, parent: &EntityType) -> Result<EntityType, ValidationError> {
+        if parent.is_final {
+            return Err(ValidationError::FinalEntityTypeExtended {
+                ty: self.ty.clone(),
+                parent: parent.ty.clone(),
+            });
+        }
+        let mut entity_type = self.clone();
+        entity_type.components = parent.components.clone();
+        for component_ty in self.components.iter() {
+            if !entity_type.components.contains(component_ty) {
+                entity_type.components.push(component_ty.clone());
+            }
+        }
+        entity_type.properties = parent
+            .properties
+            .iter()
+            .filter(|property_type| !self.properties.iter().any(|own| own.name == property_type.name))
+            .chain(self.properties.iter())
+            .cloned()
+            .collect();
+        Ok(entity_type)
+    }
+}
+
+impl TypeSystem {
+    /// Returns the ancestors of the given entity type, starting with its parent.
+    ///
+    /// Fails if the entity type or one of its ancestors is unknown or if the ancestors form a
+    /// cycle.
+    pub fn entity_type_ancestors(&self, ty: &EntityTypeId) -> Result<Vec<&EntityType>, ValidationError> {
+        let entity_type = self.resolve_entity_type(ty).ok_or_else(|| ValidationError::UnknownEntityType(ty.clone()))?;
+        let mut ancestors: Vec<&EntityType> = Vec::new();
+        let mut current = entity_type;
+        while let Some(parent_ty) = &current.extends {
+            let parent = self
+                .resolve_entity_type(parent_ty)
+                .ok_or_else(|| ValidationError::UnknownEntityType(parent_ty.clone()))?;
+            if parent.ty == entity_type.ty || ancestors.iter().any(|ancestor| ancestor.ty == parent.ty) {
+                return Err(ValidationError::InheritanceCycle(parent.ty.clone()));
+            }
+            ancestors.push(parent);
+            current = parent;
+        }
+        Ok(ancestors)
+    }
+
+    /// Returns the given entity type including the components and properties which are inherited
+    /// from its ancestors.
+    ///
+    /// Fails if the entity type or one of its ancestors extends a final entity type.
+    pub fn resolve_effective_entity_type(&self, ty: &EntityTypeId) -> Result<EntityType, ValidationError> {
+        let entity_type = self.resolve_entity_type(ty).ok_or_else(|| ValidationError::UnknownEntityType(ty.clone()))?;
+        let ancestors = self.entity_type_ancestors(ty)?;
+        validate_final_ancestors(entity_type, &ancestors)?;
+        ancestors
+            .into_iter()
+            .try_fold(entity_type.clone(), |entity_type, parent| entity_type.inherit(parent))
+    }
+
+    /// Validates that the parents of all entity types are known, that no entity type inherits
+    /// from itself and that no entity type extends a final entity type.
+    ///
+    /// The type system doesn't validate the inheritance when it is constructed, so type loaders
+    /// should call this after loading the entity types. The validation of entity instances
+    /// rejects instances of entity types which extend final entity types, too.
+    pub fn validate_entity_type_inheritance(&self) -> Result<(), ValidationError> {
+        for entity_type in self.entity_types.iter() {
+            validate_final_ancestors(entity_type, &self.entity_type_ancestors(&entity_type.ty)?)?;
+        }
+        Ok(())
+    }
+
+    /// Validates that the given components can be added to the instances of the given entity
+    /// type.
+    ///
+    /// The instances of a final entity type can't be composed with components which the entity
+    /// type neither declares nor inherits.
+    pub fn validate_component_composition(&self, ty: &EntityTypeId, components: &[ComponentTypeId]) -> Result<(), ValidationError> {
+        let entity_type = self.resolve_effective_entity_type(ty)?;
+        if !entity_type.is_final {
+            return Ok(());
+        }
+        match components.iter().find(|component_ty| !entity_type.components.contains(component_ty)) {
+            Some(component_ty) => Err(ValidationError::FinalEntityTypeComposed {
+                ty: ty.clone(),
+                component: component_ty.clone(),
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Validates that neither the given entity type nor one of its ancestors extends a final
+/// entity type.
+fn validate_final_ancestors(entity_type: &EntityType, ancestors: &[&EntityType]) -> Result<(), ValidationError> {
+    let children = std::iter::once(entity_type).chain(ancestors.iter().copied());
+    match children.zip(ancestors.iter()).find(|(_, parent)| parent.is_final) {
+        Some((child, parent)) => Err(ValidationError::FinalEntityTypeExtended {
+            ty: child.ty.clone(),
+            parent: parent.ty.clone(),
+        }),
+        None => Ok(()),
+    }
+}
@@ -1,3 +1,4 @@
+use serde::de::DeserializeOwned;
 use serde::Deserialize;
 use serde::Serialize;
 use serde_json::Value;
@@ -10,6 +11,9 @@ use crate::TypeIdType;
 
 /// Extension on a type. The extension allows to extend information
 #[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(from = "ExtensionDefinition")]
+#[non_exhaustive]
 pub struct Extension {
     /// The type definition contains the namespace and the type name.
     #[serde(flatten)]
@@ -42,6 +46,35 @@ impl Extension {
     }
 }
 
+/// The deserialized representation of an extension.
+///
+/// Deserializing the namespace and the type name directly instead of flattening the type id
+/// passes unknown fields to the deserializer, so that they can be detected in strict mode.
+#[derive(Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+struct ExtensionDefinition {
+    #[serde(default)]
+    namespace: String,
+
+    #[serde(alias = "name")]
+    type_name: String,
+
+    #[serde(default = "String::new")]
+    description: String,
+
+    extension: Value,
+}
+
+impl From<ExtensionDefinition> for Extension {
+    fn from(extension: ExtensionDefinition) -> Self {
+        Extension {
+            ty: ExtensionTypeId::new_from_type(extension.namespace, extension.type_name),
+            description: extension.description,
+            extension: extension.extension,
+        }
+    }
+}
+
 impl NamespacedTypeGetter for Extension {
     fn namespace(&self) -> String {
         self.ty.namespace()
@@ -58,6 +91,48 @@ impl TypeDefinitionGetter for Extension {
     }
 }
 
+/// A typed extension with a well-known type id, which is serialized as the value of the
+/// extension.
+pub trait WellKnownExtension: Serialize + DeserializeOwned {
+    /// The namespace of the extension.
+    const NAMESPACE: &'static str;
+
+    /// The type name of the extension.
+    const TYPE_NAME: &'static str;
+
+    /// The description of the extension.
+    const DESCRIPTION: &'static str;
+
+    /// Returns the type id of the extension.
+    fn extension_type_id() -> ExtensionTypeId {
+        ExtensionTypeId::new_from_type(Self::NAMESPACE, Self::TYPE_NAME)
+    }
+}
+
+/// Replaces the well-known extension in the given extensions with an extension which contains
+/// the given value.
+///
+/// Fails if the value can't be represented as JSON.
+pub fn set_typed_extension<T: WellKnownExtension>(extensions: &mut Vec<Extension>, value: &T) -> Result<(), serde_json::Error> {
+    let extension = Extension::new(T::extension_type_id(), T::DESCRIPTION, serde_json::to_value(value)?);
+    extensions.retain(|other| other.ty != extension.ty);
+    extensions.push(extension);
+    Ok(())
+}
+
+/// Returns the value of the well-known extension in the given extensions or `None` if there is
+/// no such extension.
+///
+/// Fails if the extension doesn't contain a valid value.
+pub fn typed_extension<T: WellKnownExtension>(extensions: &[Extension]) -> Result<Option<T>, serde_json::Error> {
+    let ty = T::extension_type_id();
+    extensions
+        .iter()
+        .find(|extension| extension.ty == ty)
+        .map(|extension| T::deserialize(&extension.extension))
+        .transpose()
+}
+
 impl From<&Extension> for TypeDefinition {
     fn from(extension: &Extension) -> Self {
         TypeDefinition {
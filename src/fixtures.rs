@@ -0,0 +1,183 @@
+use std::collections::HashMap;
+
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::default_connector_type_id;
+use crate::Component;
+use crate::ComponentTypeId;
+use crate::Connector;
+use crate::DataType;
+use crate::EntityInstance;
+use crate::EntityType;
+use crate::EntityTypeId;
+use crate::FlowInstance;
+use crate::FlowType;
+use crate::FlowTypeId;
+use crate::FlowTypePort;
+use crate::PropertyType;
+use crate::RelationInstance;
+use crate::RelationType;
+use crate::RelationTypeId;
+use crate::TypeSystem;
+
+/// The id of the wrapper entity instance of the example flow.
+pub const EXAMPLE_FLOW_ID: Uuid = Uuid::from_u128(0x6ba7b810_9dad_11d1_80b4_00c04fd430c8);
+
+/// The id of the first logical and of the example flow.
+pub const EXAMPLE_FLOW_FIRST_AND_ID: Uuid = Uuid::from_u128(0x6ba7b811_9dad_11d1_80b4_00c04fd430c8);
+
+/// The id of the second logical and of the example flow.
+pub const EXAMPLE_FLOW_SECOND_AND_ID: Uuid = Uuid::from_u128(0x6ba7b812_9dad_11d1_80b4_00c04fd430c8);
+
+/// Returns the type id of the component `base::labeled`.
+pub fn labeled_component_ty() -> ComponentTypeId {
+    ComponentTypeId::new_from_type("base", "labeled")
+}
+
+/// Returns the component `base::labeled` which provides a label.
+pub fn labeled_component() -> Component {
+    Component::new_without_extensions(
+        labeled_component_ty(),
+        "The label is a hierarchical path with static segments, named parameters and catch-all parameters.",
+        vec![PropertyType::string("label")],
+    )
+}
+
+/// Returns the type id of the entity type `flow::generic_flow`.
+pub fn generic_flow_entity_type_ty() -> EntityTypeId {
+    EntityTypeId::new_from_type("flow", "generic_flow")
+}
+
+/// Returns the entity type `flow::generic_flow` of the wrapper entity instances of flows without
+/// a specific interface.
+pub fn generic_flow_entity_type() -> EntityType {
+    EntityType::new(
+        generic_flow_entity_type_ty(),
+        "Generic flow without inputs or outputs",
+        vec![labeled_component_ty()],
+        Vec::new(),
+        Vec::new(),
+    )
+}
+
+/// Returns the type id of the entity type `logical::and`.
+pub fn logical_and_entity_type_ty() -> EntityTypeId {
+    EntityTypeId::new_from_type("logical", "and")
+}
+
+/// Returns the entity type `logical::and` with the inputs `lhs` and `rhs` and the output `result`.
+pub fn logical_and_entity_type() -> EntityType {
+    EntityType::new(
+        logical_and_entity_type_ty(),
+        "Logical AND",
+        vec![labeled_component_ty()],
+        vec![
+            PropertyType::input("lhs", DataType::Bool),
+            PropertyType::input("rhs", DataType::Bool),
+            PropertyType::output("result", DataType::Bool),
+        ],
+        Vec::new(),
+    )
+}
+
+/// Returns the type id of the relation type `connector::default_connector`.
+pub fn default_connector_relation_type_ty() -> RelationTypeId {
+    default_connector_type_id()
+}
+
+/// Returns the relation type `connector::default_connector` which connects an output of a logical
+/// and with an input of another logical and.
+pub fn default_connector_relation_type() -> RelationType {
+    RelationType::new(
+        logical_and_entity_type_ty(),
+        default_connector_relation_type_ty(),
+        logical_and_entity_type_ty(),
+        "Propagates the value of the outbound property to the inbound property",
+        Vec::new(),
+        vec![PropertyType::string("outbound_property_name"), PropertyType::string("inbound_property_name")],
+        Vec::new(),
+    )
+}
+
+/// Returns a logical and entity instance with the given id and the given input values.
+pub fn logical_and_entity_instance(id: Uuid, lhs: bool, rhs: bool) -> EntityInstance {
+    let mut properties = HashMap::new();
+    properties.insert("label".to_string(), json!(format!("/logical/and/{id}")));
+    properties.insert("lhs".to_string(), json!(lhs));
+    properties.insert("rhs".to_string(), json!(rhs));
+    properties.insert("result".to_string(), json!(lhs && rhs));
+    EntityInstance::new(logical_and_entity_type_ty(), id, properties)
+}
+
+/// Returns a default connector from the given output of the outbound logical and to the given
+/// input of the inbound logical and.
+pub fn default_connector_relation_instance(outbound_id: Uuid, outbound_property_name: &str, inbound_id: Uuid, inbound_property_name: &str) -> RelationInstance {
+    Connector::new(outbound_id, outbound_property_name, inbound_id, inbound_property_name).to_relation_instance()
+}
+
+/// Returns the wrapper entity instance of the example flow.
+pub fn example_flow_wrapper_entity_instance() -> EntityInstance {
+    let mut properties = HashMap::new();
+    properties.insert("label".to_string(), json!("/flows/example"));
+    EntityInstance::new(generic_flow_entity_type_ty(), EXAMPLE_FLOW_ID, properties)
+}
+
+/// Returns the example flow of two logical ands, where the result of the first logical and is
+/// connected with the left hand side of the second logical and.
+pub fn example_flow_instance() -> FlowInstance {
+    let mut flow_instance = FlowInstance::from_instance_with_name(example_flow_wrapper_entity_instance(), "example");
+    flow_instance
+        .entity_instances
+        .push(logical_and_entity_instance(EXAMPLE_FLOW_FIRST_AND_ID, true, true));
+    flow_instance
+        .entity_instances
+        .push(logical_and_entity_instance(EXAMPLE_FLOW_SECOND_AND_ID, true, false));
+    flow_instance
+        .relation_instances
+        .push(default_connector_relation_instance(EXAMPLE_FLOW_FIRST_AND_ID, "result", EXAMPLE_FLOW_SECOND_AND_ID, "lhs"));
+    flow_instance
+}
+
+/// Returns the type id of the flow type `flows::example`.
+pub fn example_flow_type_ty() -> FlowTypeId {
+    FlowTypeId::new_from_type("flows", "example")
+}
+
+/// Returns the flow type of the example flow. The inputs of the first logical and and the result
+/// of the second logical and are exposed as ports.
+pub fn example_flow_type() -> FlowType {
+    FlowType::new(
+        example_flow_type_ty(),
+        "Example flow of two chained logical ands",
+        example_flow_wrapper_entity_instance(),
+        vec![
+            logical_and_entity_instance(EXAMPLE_FLOW_FIRST_AND_ID, true, true),
+            logical_and_entity_instance(EXAMPLE_FLOW_SECOND_AND_ID, true, false),
+        ],
+        vec![default_connector_relation_instance(
+            EXAMPLE_FLOW_FIRST_AND_ID,
+            "result",
+            EXAMPLE_FLOW_SECOND_AND_ID,
+            "lhs",
+        )],
+        Vec::new(),
+        Vec::new(),
+    )
+    .with_inputs(vec![
+        FlowTypePort::new("a", EXAMPLE_FLOW_FIRST_AND_ID, "lhs"),
+        FlowTypePort::new("b", EXAMPLE_FLOW_FIRST_AND_ID, "rhs"),
+        FlowTypePort::new("c", EXAMPLE_FLOW_SECOND_AND_ID, "rhs"),
+    ])
+    .with_outputs(vec![FlowTypePort::new("result", EXAMPLE_FLOW_SECOND_AND_ID, "result")])
+}
+
+/// Returns a type system of all fixture types.
+pub fn fixture_type_system() -> TypeSystem {
+    TypeSystem::new(
+        vec![labeled_component()],
+        vec![generic_flow_entity_type(), logical_and_entity_type()],
+        vec![default_connector_relation_type()],
+        vec![example_flow_type()],
+    )
+}
@@ -1,21 +1,71 @@
+use std::collections::HashMap;
 use std::convert::TryFrom;
+use std::fmt;
 use std::sync::Arc;
 
+use chrono::DateTime;
+use chrono::Utc;
+use indradb::EdgeKey;
 use serde::Deserialize;
 use serde::Serialize;
+use serde_json::Map;
+use serde_json::Value;
 use uuid::Uuid;
 
+use crate::flow_to_dot;
+use crate::CompactDebug;
 use crate::EntityInstance;
 use crate::EntityTypeId;
+use crate::FlowInstanceFlattenError;
+use crate::FlowInstanceMergeConflict;
+use crate::FlowInstanceMergeError;
+use crate::FlowInstanceMergeResult;
+use crate::FlowInstanceMerkleTree;
+use crate::FlowInstanceStatistics;
+use crate::MergeStrategy;
 use crate::NamespacedTypeGetter;
+use crate::PropertyInstanceGetter;
 use crate::ReactiveFlowInstance;
 use crate::RelationInstance;
+use crate::SubFlowReference;
 use crate::TypeDefinition;
 use crate::TypeDefinitionGetter;
 
+/// The names of the properties which are used to address entity instances symbolically, in order of precedence.
+pub const ENTITY_INSTANCE_NAME_PROPERTIES: [&str; 2] = ["name", "label"];
+
 #[derive(Debug)]
 pub struct FlowInstanceCreationError;
 
+#[derive(Debug, PartialEq, Eq)]
+pub enum FlowInstanceValidationError {
+    /// The wrapper entity instance is not contained in the flow instance.
+    MissingWrapperEntityInstance,
+
+    /// The relation instance references an entity instance which is neither contained in the flow
+    /// instance nor marked as external.
+    DanglingRelationInstance(EdgeKey, Uuid),
+}
+
+impl fmt::Display for FlowInstanceValidationError {
+    #[cfg(not(tarpaulin_include))]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FlowInstanceValidationError::MissingWrapperEntityInstance => {
+                write!(f, "Missing the wrapper entity instance. Check if an entity instance exists with the same id as the flow id")
+            }
+            FlowInstanceValidationError::DanglingRelationInstance(edge_key, id) => write!(
+                f,
+                "The relation instance {}--[{}]-->{} references the entity instance {} which is neither contained in the flow nor marked as external",
+                edge_key.outbound_id,
+                edge_key.t.as_str(),
+                edge_key.inbound_id,
+                id
+            ),
+        }
+    }
+}
+
 /// A flow instance is a container for entity instances and relation instances.
 ///
 /// A flow instance is strictly associated with a wrapper entity instance. The properties
@@ -30,7 +80,10 @@ pub struct FlowInstanceCreationError;
 /// It's even possible to connect entity instances from different flows with relation
 /// instances.
 ///
+/// Flow instances can be built with struct update syntax based on
+/// `FlowInstance::default()`.
 #[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct FlowInstance {
     /// The id of the flow corresponds to the id of the wrapper entity instance
     ///
@@ -64,6 +117,24 @@ pub struct FlowInstance {
     /// By default, no relation instances are contained in this flow instance.
     #[serde(default = "Vec::new", alias = "relations")]
     pub relation_instances: Vec<RelationInstance>,
+
+    /// The ids of the entity instances outside of the flow instance which are legitimately
+    /// referenced by the relation instances of this flow instance.
+    ///
+    /// By default, no external entity instances are referenced.
+    #[serde(default = "Vec::new", alias = "externals")]
+    pub external_entity_ids: Vec<Uuid>,
+
+    /// The references to the child flow instances which are embedded into this flow instance.
+    ///
+    /// By default, no child flow instances are embedded.
+    #[serde(default = "Vec::new")]
+    pub sub_flows: Vec<SubFlowReference>,
+
+    /// Unknown fields of the data access object, which are re-emitted when converting back into
+    /// a data access object.
+    #[serde(skip)]
+    pub extra: Map<String, Value>,
 }
 
 impl FlowInstance {
@@ -76,8 +147,392 @@ impl FlowInstance {
             description: String::new(),
             entity_instances: vec![wrapper_entity_instance],
             relation_instances: Vec::new(),
+            external_entity_ids: Vec::new(),
+            sub_flows: Vec::new(),
+            extra: Map::new(),
+        }
+    }
+
+    /// Returns true, if the flow instance contains an entity instance with the given id.
+    pub fn has_entity_instance(&self, id: Uuid) -> bool {
+        self.entity_instances.iter().any(|e| e.id == id)
+    }
+
+    /// Returns the entity instance with the given name.
+    ///
+    /// The name of an entity instance is the string value of the property `name` or, if not
+    /// present, of the property `label`.
+    pub fn entity_by_name<S: Into<String>>(&self, name: S) -> Option<&EntityInstance> {
+        let name = name.into();
+        self.entity_instances.iter().find(|e| entity_instance_name(e).as_deref() == Some(name.as_str()))
+    }
+
+    /// Returns an index which maps the names of the entity instances to their ids.
+    ///
+    /// Entity instances without name are not contained in the index. If multiple entity instances
+    /// have the same name, the first entity instance is indexed.
+    pub fn entity_name_index(&self) -> HashMap<String, Uuid> {
+        let mut index = HashMap::new();
+        for entity_instance in self.entity_instances.iter() {
+            if let Some(name) = entity_instance_name(entity_instance) {
+                index.entry(name).or_insert(entity_instance.id);
+            }
+        }
+        index
+    }
+
+    /// Marks the entity instance with the given id as external entity instance.
+    ///
+    /// Relation instances of the flow instance may reference external entity instances which
+    /// are not contained in the flow instance, for example shared global entity instances.
+    pub fn add_external_entity_id(&mut self, id: Uuid) {
+        if !self.external_entity_ids.contains(&id) {
+            self.external_entity_ids.push(id);
+        }
+    }
+
+    /// Removes the external mark of the entity instance with the given id.
+    pub fn remove_external_entity_id(&mut self, id: Uuid) {
+        self.external_entity_ids.retain(|external_id| *external_id != id);
+    }
+
+    /// Returns true, if the entity instance with the given id is marked as external.
+    pub fn is_external_entity_id(&self, id: Uuid) -> bool {
+        self.external_entity_ids.contains(&id)
+    }
+
+    /// Returns the relation instances which are connected with at least one external entity instance.
+    pub fn external_relation_instances(&self) -> Vec<RelationInstance> {
+        self.relation_instances
+            .iter()
+            .filter(|r| self.is_external_entity_id(r.outbound_id) || self.is_external_entity_id(r.inbound_id))
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the relation instances which are referencing at least one entity instance which is
+    /// neither contained in the flow instance nor marked as external.
+    pub fn dangling_relation_instances(&self) -> Vec<RelationInstance> {
+        self.relation_instances
+            .iter()
+            .filter(|r| self.dangling_endpoint(r).is_some())
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the id of the first endpoint of the given relation instance which is neither
+    /// contained in the flow instance nor marked as external.
+    fn dangling_endpoint(&self, relation_instance: &RelationInstance) -> Option<Uuid> {
+        [relation_instance.outbound_id, relation_instance.inbound_id]
+            .into_iter()
+            .find(|id| !self.has_entity_instance(*id) && !self.is_external_entity_id(*id))
+    }
+
+    /// Validates the flow instance.
+    ///
+    /// The wrapper entity instance has to be contained in the flow instance and the relation
+    /// instances must not reference entity instances which are neither contained in the flow
+    /// instance nor marked as external.
+    pub fn validate(&self) -> Result<(), FlowInstanceValidationError> {
+        if !self.has_entity_instance(self.id) {
+            return Err(FlowInstanceValidationError::MissingWrapperEntityInstance);
+        }
+        for relation_instance in self.relation_instances.iter() {
+            if let Some(id) = self.dangling_endpoint(relation_instance) {
+                return Err(FlowInstanceValidationError::DanglingRelationInstance(relation_instance.get_key(), id));
+            }
+        }
+        Ok(())
+    }
+
+    /// Marks the entity instance with the given id and the relation instances connected with it
+    /// as deleted. Returns false, if the flow instance doesn't contain the entity instance, if the
+    /// entity instance is already marked as deleted or if the entity instance is the wrapper
+    /// entity instance, which can't be deleted without the flow instance.
+    pub fn mark_entity_instance_deleted(&mut self, id: Uuid) -> bool {
+        if id == self.id {
+            return false;
+        }
+        let now = Utc::now();
+        let Some(entity_instance) = self.entity_instances.iter_mut().find(|entity_instance| entity_instance.id == id) else {
+            return false;
+        };
+        if entity_instance.is_deleted() {
+            return false;
+        }
+        entity_instance.mark_deleted_at(now);
+        for relation_instance in self.relation_instances.iter_mut() {
+            if (relation_instance.outbound_id == id || relation_instance.inbound_id == id) && !relation_instance.is_deleted() {
+                relation_instance.mark_deleted_at(now);
+            }
+        }
+        true
+    }
+
+    /// Undoes the deletion of the entity instance with the given id and of the relation
+    /// instances which have been deleted together with it. Relation instances whose other entity
+    /// instance is still marked as deleted stay deleted and are restored together with the other
+    /// entity instance instead. Returns false, if the flow instance
+    /// doesn't contain a deleted entity instance with the given id.
+    pub fn restore_entity_instance(&mut self, id: Uuid) -> bool {
+        let Some(entity_instance) = self.entity_instances.iter_mut().find(|entity_instance| entity_instance.id == id) else {
+            return false;
+        };
+        let Some(deleted_at) = entity_instance.deleted_at else {
+            return false;
+        };
+        entity_instance.restore();
+        let deleted: HashMap<Uuid, DateTime<Utc>> = self
+            .entity_instances
+            .iter()
+            .filter_map(|e| e.deleted_at.map(|deleted_at| (e.id, deleted_at)))
+            .collect();
+        for relation_instance in self.relation_instances.iter_mut() {
+            let other_id = if relation_instance.outbound_id == id {
+                relation_instance.inbound_id
+            } else if relation_instance.inbound_id == id {
+                relation_instance.outbound_id
+            } else {
+                continue;
+            };
+            if relation_instance.deleted_at != Some(deleted_at) {
+                continue;
+            }
+            match deleted.get(&other_id) {
+                Some(other_deleted_at) => relation_instance.deleted_at = Some(*other_deleted_at),
+                None => relation_instance.restore(),
+            }
+        }
+        true
+    }
+
+    /// Returns the entity instances which are marked as deleted.
+    pub fn deleted_entity_instances(&self) -> Vec<EntityInstance> {
+        self.entity_instances.iter().filter(|e| e.is_deleted()).cloned().collect()
+    }
+
+    /// Returns the relation instances which are marked as deleted.
+    pub fn deleted_relation_instances(&self) -> Vec<RelationInstance> {
+        self.relation_instances.iter().filter(|r| r.is_deleted()).cloned().collect()
+    }
+
+    /// Returns a copy of the flow instance without the entity instances and the relation
+    /// instances which are marked as deleted.
+    ///
+    /// Relation instances which are connected with a deleted entity instance are omitted too.
+    pub fn without_deleted(&self) -> FlowInstance {
+        let mut flow_instance = self.clone();
+        flow_instance.purge_deleted_before(None);
+        flow_instance
+    }
+
+    /// Removes the entity instances and the relation instances which are marked as deleted.
+    ///
+    /// Relation instances which are connected with a deleted entity instance are removed too.
+    pub fn purge_deleted(&mut self) {
+        self.purge_deleted_before(None);
+    }
+
+    /// Removes the entity instances and the relation instances which have been marked as
+    /// deleted before the given point of time or, if none, at any time.
+    ///
+    /// Relation instances which are connected with a removed entity instance are removed too.
+    pub fn purge_deleted_before(&mut self, before: Option<DateTime<Utc>>) {
+        let is_purged = |deleted_at: Option<DateTime<Utc>>| match (deleted_at, before) {
+            (Some(deleted_at), Some(before)) => deleted_at < before,
+            (Some(_), None) => true,
+            (None, _) => false,
+        };
+        let purged_ids: Vec<Uuid> = self.entity_instances.iter().filter(|e| is_purged(e.deleted_at)).map(|e| e.id).collect();
+        self.entity_instances.retain(|e| !purged_ids.contains(&e.id));
+        self.relation_instances
+            .retain(|r| !is_purged(r.deleted_at) && !purged_ids.contains(&r.outbound_id) && !purged_ids.contains(&r.inbound_id));
+    }
+
+    /// Merges the entity instances and the relation instances of the other flow instance into
+    /// this flow instance.
+    ///
+    /// Entity instances with the same id and relation instances with the same edge key are
+    /// conflicts. The merge strategy defines if conflicting instances are skipped, replaced or
+    /// if the whole merge is aborted. In any case the detected conflicts are reported.
+    pub fn merge(&mut self, other: FlowInstance, strategy: MergeStrategy) -> Result<FlowInstanceMergeResult, FlowInstanceMergeError> {
+        let mut conflicts = Vec::new();
+        for entity_instance in other.entity_instances.iter() {
+            if self.entity_instances.iter().any(|e| e.id == entity_instance.id) {
+                conflicts.push(FlowInstanceMergeConflict::EntityInstance(entity_instance.id));
+            }
+        }
+        for relation_instance in other.relation_instances.iter() {
+            let edge_key = relation_instance.get_key();
+            if self.relation_instances.iter().any(|r| r.get_key() == edge_key) {
+                conflicts.push(FlowInstanceMergeConflict::RelationInstance(edge_key));
+            }
+        }
+        if strategy == MergeStrategy::Abort && !conflicts.is_empty() {
+            return Err(FlowInstanceMergeError::Conflicts(conflicts));
+        }
+        let mut result = FlowInstanceMergeResult {
+            conflicts,
+            ..Default::default()
+        };
+        for entity_instance in other.entity_instances {
+            match self.entity_instances.iter_mut().find(|e| e.id == entity_instance.id) {
+                Some(existing) => {
+                    if strategy == MergeStrategy::Replace {
+                        *existing = entity_instance;
+                    }
+                }
+                None => {
+                    result.entity_instances_added.push(entity_instance.id);
+                    self.entity_instances.push(entity_instance);
+                }
+            }
+        }
+        for relation_instance in other.relation_instances {
+            let edge_key = relation_instance.get_key();
+            match self.relation_instances.iter_mut().find(|r| r.get_key() == edge_key) {
+                Some(existing) => {
+                    if strategy == MergeStrategy::Replace {
+                        *existing = relation_instance;
+                    }
+                }
+                None => {
+                    result.relation_instances_added.push(edge_key);
+                    self.relation_instances.push(relation_instance);
+                }
+            }
+        }
+        for external_entity_id in other.external_entity_ids {
+            self.add_external_entity_id(external_entity_id);
+        }
+        for sub_flow in other.sub_flows {
+            if !self.sub_flows.contains(&sub_flow) {
+                self.sub_flows.push(sub_flow);
+            }
+        }
+        Ok(result)
+    }
+
+    /// Returns a deep copy of the flow instance with newly generated ids for all entity instances.
+    ///
+    /// The outbound and inbound ids of the relation instances are updated accordingly. Relation
+    /// instances which are connected with entity instances outside of the flow keeps the id of
+    /// the external entity instance.
+    pub fn deep_clone_with_new_ids(&self) -> FlowInstance {
+        self.deep_clone_with_new_ids_mapping().0
+    }
+
+    /// Returns a deep copy of the flow instance with newly generated ids for all entity instances
+    /// together with the mapping of the old ids to the new ids.
+    pub fn deep_clone_with_new_ids_mapping(&self) -> (FlowInstance, HashMap<Uuid, Uuid>) {
+        let ids: HashMap<Uuid, Uuid> = self.entity_instances.iter().map(|e| (e.id, Uuid::new_v4())).collect();
+        let new_id = |id: Uuid| ids.get(&id).copied().unwrap_or(id);
+        let mut flow_instance = self.clone();
+        flow_instance.id = new_id(self.id);
+        for entity_instance in flow_instance.entity_instances.iter_mut() {
+            entity_instance.id = new_id(entity_instance.id);
+        }
+        for relation_instance in flow_instance.relation_instances.iter_mut() {
+            relation_instance.outbound_id = new_id(relation_instance.outbound_id);
+            relation_instance.inbound_id = new_id(relation_instance.inbound_id);
+        }
+        for sub_flow in flow_instance.sub_flows.iter_mut() {
+            for binding in sub_flow.bindings.iter_mut() {
+                binding.outer_entity_id = new_id(binding.outer_entity_id);
+            }
         }
+        (flow_instance, ids)
+    }
+
+    /// Embeds the child flow instance referenced by the given sub flow reference.
+    pub fn add_sub_flow(&mut self, sub_flow: SubFlowReference) {
+        self.sub_flows.push(sub_flow);
     }
+
+    /// Removes the references to the child flow instance with the given id.
+    pub fn remove_sub_flow(&mut self, flow_id: Uuid) {
+        self.sub_flows.retain(|sub_flow| sub_flow.flow_id != flow_id);
+    }
+
+    /// Flattens the hierarchy of flow instances into a single flow instance.
+    ///
+    /// The resolver returns the child flow instance with the given id. The entity instances and
+    /// the relation instances of the child flow instances are added recursively and the port
+    /// bindings are converted into connector relation instances.
+    pub fn flatten<F>(&self, resolver: F) -> Result<FlowInstance, FlowInstanceFlattenError>
+    where
+        F: Fn(Uuid) -> Option<FlowInstance>,
+    {
+        let mut path = vec![self.id];
+        self.flatten_recursive(&resolver, &mut path)
+    }
+
+    fn flatten_recursive(&self, resolver: &dyn Fn(Uuid) -> Option<FlowInstance>, path: &mut Vec<Uuid>) -> Result<FlowInstance, FlowInstanceFlattenError> {
+        let mut flattened = self.clone();
+        flattened.sub_flows.clear();
+        for sub_flow in self.sub_flows.iter() {
+            if path.contains(&sub_flow.flow_id) {
+                return Err(FlowInstanceFlattenError::CyclicSubFlow(sub_flow.flow_id));
+            }
+            let child = resolver(sub_flow.flow_id).ok_or(FlowInstanceFlattenError::MissingSubFlow(sub_flow.flow_id))?;
+            path.push(sub_flow.flow_id);
+            let child = child.flatten_recursive(resolver, path)?;
+            path.pop();
+            for entity_instance in child.entity_instances {
+                if !flattened.has_entity_instance(entity_instance.id) {
+                    flattened.entity_instances.push(entity_instance);
+                }
+            }
+            let connectors = sub_flow.bindings.iter().map(|binding| binding.to_relation_instance());
+            for relation_instance in child.relation_instances.into_iter().chain(connectors) {
+                let edge_key = relation_instance.get_key();
+                if !flattened.relation_instances.iter().any(|r| r.get_key() == edge_key) {
+                    flattened.relation_instances.push(relation_instance);
+                }
+            }
+            for external_entity_id in child.external_entity_ids {
+                flattened.add_external_entity_id(external_entity_id);
+            }
+        }
+        let entity_ids: Vec<Uuid> = flattened.entity_instances.iter().map(|e| e.id).collect();
+        flattened.external_entity_ids.retain(|id| !entity_ids.contains(id));
+        Ok(flattened)
+    }
+
+    /// Returns statistics and complexity metrics of the flow instance.
+    pub fn stats(&self) -> FlowInstanceStatistics {
+        FlowInstanceStatistics::from(self)
+    }
+
+    /// Returns the merkle tree of the content hashes of the flow instance.
+    ///
+    /// Fails if an instance can't be represented as JSON.
+    pub fn merkle_tree(&self) -> Result<FlowInstanceMerkleTree, serde_json::Error> {
+        FlowInstanceMerkleTree::try_from(self)
+    }
+
+    /// Returns the root hash of the merkle tree of the flow instance.
+    pub fn merkle_root(&self) -> Result<u64, serde_json::Error> {
+        Ok(self.merkle_tree()?.root)
+    }
+
+    /// Returns the Graphviz DOT representation of the flow instance.
+    pub fn to_dot(&self) -> String {
+        let name = if self.name.is_empty() { self.id.to_string() } else { self.name.clone() };
+        flow_to_dot(name, self.id, &self.entity_instances, &self.relation_instances)
+    }
+
+    /// Returns a debug formatter which truncates large property values and elides binary content.
+    pub fn debug_compact(&self) -> CompactDebug<'_, FlowInstance> {
+        CompactDebug::new(self)
+    }
+}
+
+/// Returns the name of the given entity instance.
+fn entity_instance_name(entity_instance: &EntityInstance) -> Option<String> {
+    ENTITY_INSTANCE_NAME_PROPERTIES
+        .iter()
+        .find_map(|property_name| entity_instance.as_string(*property_name))
 }
 
 impl From<EntityInstance> for FlowInstance {
@@ -89,10 +544,20 @@ impl From<EntityInstance> for FlowInstance {
             description: String::new(),
             entity_instances: vec![wrapper_entity_instance],
             relation_instances: Vec::new(),
+            external_entity_ids: Vec::new(),
+            sub_flows: Vec::new(),
+            extra: Map::new(),
         }
     }
 }
 
+/// Constructs a flow instance with a default wrapper entity instance.
+impl Default for FlowInstance {
+    fn default() -> Self {
+        FlowInstance::from(EntityInstance::default())
+    }
+}
+
 impl TryFrom<ReactiveFlowInstance> for FlowInstance {
     type Error = FlowInstanceCreationError;
 
@@ -156,3 +621,15 @@ impl TypeDefinitionGetter for FlowInstance {
         self.ty.type_definition()
     }
 }
+
+/// Formats the type and the id of the flow instance. The alternate form `{:#}` appends the
+/// number of entity instances and relation instances.
+impl fmt::Display for FlowInstance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}__{}", &self.ty, self.id)?;
+        if f.alternate() {
+            write!(f, " ({} entity instances, {} relation instances)", self.entity_instances.len(), self.relation_instances.len())?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,94 @@
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Map;
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::EntityInstance;
+use crate::EntityInstanceDao;
+use crate::EntityTypeId;
+use crate::FlowInstance;
+use crate::NamespacedTypeGetter;
+use crate::RelationInstance;
+use crate::RelationInstanceDao;
+use crate::SubFlowReference;
+
+/// Data access object of a flow instance.
+///
+/// Defines the stable external representation of a flow instance, for example in saved flow
+/// files. The in-memory representation (`FlowInstance`) can evolve without breaking existing
+/// files.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct FlowInstanceDao {
+    /// The id of the flow instance which is also the id of the wrapper entity instance.
+    pub id: Uuid,
+
+    /// The namespace of the entity type of the wrapper entity instance.
+    #[serde(default = "String::new")]
+    pub namespace: String,
+
+    /// The name of the entity type of the wrapper entity instance.
+    pub type_name: String,
+
+    /// The name of the flow instance.
+    #[serde(default = "String::new", alias = "flow_instance_name")]
+    pub name: String,
+
+    /// Textual description of the flow instance.
+    #[serde(default = "String::new")]
+    pub description: String,
+
+    /// The entity instances which are contained in the flow instance.
+    #[serde(default = "Vec::new", alias = "entities")]
+    pub entity_instances: Vec<EntityInstanceDao>,
+
+    /// The relation instances which are contained in the flow instance.
+    #[serde(default = "Vec::new", alias = "relations")]
+    pub relation_instances: Vec<RelationInstanceDao>,
+
+    /// The ids of the external entity instances which are referenced by the relation instances.
+    #[serde(default = "Vec::new", alias = "externals")]
+    pub external_entity_ids: Vec<Uuid>,
+
+    /// The references to the embedded child flow instances.
+    #[serde(default = "Vec::new")]
+    pub sub_flows: Vec<SubFlowReference>,
+
+    /// Unknown fields, for example written by a newer version, which are re-emitted on save.
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl From<FlowInstance> for FlowInstanceDao {
+    fn from(flow_instance: FlowInstance) -> Self {
+        FlowInstanceDao {
+            id: flow_instance.id,
+            namespace: flow_instance.namespace(),
+            type_name: flow_instance.type_name(),
+            name: flow_instance.name,
+            description: flow_instance.description,
+            entity_instances: flow_instance.entity_instances.into_iter().map(EntityInstanceDao::from).collect(),
+            relation_instances: flow_instance.relation_instances.into_iter().map(RelationInstanceDao::from).collect(),
+            external_entity_ids: flow_instance.external_entity_ids,
+            sub_flows: flow_instance.sub_flows,
+            extra: flow_instance.extra,
+        }
+    }
+}
+
+impl From<FlowInstanceDao> for FlowInstance {
+    fn from(dao: FlowInstanceDao) -> Self {
+        FlowInstance {
+            id: dao.id,
+            ty: EntityTypeId::new_from_type(dao.namespace, dao.type_name),
+            name: dao.name,
+            description: dao.description,
+            entity_instances: dao.entity_instances.into_iter().map(EntityInstance::from).collect(),
+            relation_instances: dao.relation_instances.into_iter().map(RelationInstance::from).collect(),
+            external_entity_ids: dao.external_entity_ids,
+            sub_flows: dao.sub_flows,
+            extra: dao.extra,
+        }
+    }
+}
@@ -0,0 +1,207 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::canonical_json;
+use crate::FlowInstance;
+use crate::RelationInstance;
+
+/// The metadata of the instances, which doesn't belong to the structure of a flow instance.
+const METADATA_FIELDS: [&str; 3] = ["created_at", "updated_at", "created_by"];
+
+/// The number of relation instances by outbound id, inbound id and signature.
+type RelationCounts = HashMap<(Uuid, Uuid, String), usize>;
+
+impl FlowInstance {
+    /// Returns true, if both flow instances are the same up to a consistent renaming of the ids of
+    /// the entity instances.
+    ///
+    /// Entity instances are compared by their type, description, properties and extensions and
+    /// relation instances additionally by the entity instances they connect. The name and the
+    /// description of the flow instance itself and the metadata of the instances (timestamps and
+    /// creator) are ignored. The ids of external entity instances are not renamed.
+    pub fn structurally_equals(&self, other: &FlowInstance) -> bool {
+        self.isomorphism(other).is_some()
+    }
+
+    /// Returns the mapping of the ids of the entity instances of this flow instance to the ids of
+    /// the entity instances of the other flow instance, if both flow instances are structurally
+    /// equal.
+    pub fn isomorphism(&self, other: &FlowInstance) -> Option<HashMap<Uuid, Uuid>> {
+        if self.ty != other.ty
+            || self.entity_instances.len() != other.entity_instances.len()
+            || self.relation_instances.len() != other.relation_instances.len()
+            || self.sub_flows.len() != other.sub_flows.len()
+            || self.external_entity_ids.iter().collect::<HashSet<_>>() != other.external_entity_ids.iter().collect::<HashSet<_>>()
+        {
+            return None;
+        }
+        let a = FlowGraph::new(self);
+        let b = FlowGraph::new(other);
+        let mut a_colors: Vec<&String> = a.colors.values().collect();
+        let mut b_colors: Vec<&String> = b.colors.values().collect();
+        a_colors.sort();
+        b_colors.sort();
+        if a_colors != b_colors {
+            return None;
+        }
+        // The wrapper entity instances have to be mapped onto each other.
+        let mut order: Vec<Uuid> = self.entity_instances.iter().map(|entity_instance| entity_instance.id).collect();
+        order.sort_by_key(|id| (*id != self.id, b.candidates(a.colors.get(id)).len()));
+        let mut mapping = HashMap::new();
+        let mut used = HashSet::new();
+        if !search(&a, &b, self.id, other.id, &order, 0, &mut mapping, &mut used) {
+            return None;
+        }
+        let map = |id: &Uuid| mapping.get(id).copied().unwrap_or(*id);
+        let renamed_sub_flows: Vec<_> = self
+            .sub_flows
+            .iter()
+            .map(|sub_flow| {
+                let mut sub_flow = sub_flow.clone();
+                for binding in sub_flow.bindings.iter_mut() {
+                    binding.outer_entity_id = map(&binding.outer_entity_id);
+                }
+                sub_flow
+            })
+            .collect();
+        if renamed_sub_flows.iter().any(|sub_flow| !other.sub_flows.contains(sub_flow)) {
+            return None;
+        }
+        Some(mapping)
+    }
+}
+
+/// The entity instances and relation instances of a flow instance reduced to their signatures.
+struct FlowGraph {
+    /// The signatures of the entity instances refined by the signatures of their relation instances.
+    colors: HashMap<Uuid, String>,
+
+    /// The relation instances by outbound id, inbound id and signature.
+    relations: RelationCounts,
+
+    /// The outbound ids and inbound ids of the relation instances by entity instance.
+    neighbours: HashMap<Uuid, Vec<(Uuid, Uuid, String)>>,
+}
+
+impl FlowGraph {
+    fn new(flow_instance: &FlowInstance) -> FlowGraph {
+        let signatures: HashMap<Uuid, String> = flow_instance
+            .entity_instances
+            .iter()
+            .map(|entity_instance| (entity_instance.id, signature(entity_instance, &["id"])))
+            .collect();
+        let mut relations = RelationCounts::new();
+        let mut neighbours: HashMap<Uuid, Vec<(Uuid, Uuid, String)>> = HashMap::new();
+        let mut refinements: HashMap<Uuid, Vec<String>> = HashMap::new();
+        for relation_instance in flow_instance.relation_instances.iter() {
+            let relation_signature = relation_signature(relation_instance);
+            let (outbound_id, inbound_id) = (relation_instance.outbound_id, relation_instance.inbound_id);
+            *relations.entry((outbound_id, inbound_id, relation_signature.clone())).or_insert(0) += 1;
+            let outbound_signature = signatures.get(&outbound_id).cloned().unwrap_or_else(|| outbound_id.to_string());
+            let inbound_signature = signatures.get(&inbound_id).cloned().unwrap_or_else(|| inbound_id.to_string());
+            refinements
+                .entry(outbound_id)
+                .or_default()
+                .push(format!("out:{relation_signature}:{inbound_signature}"));
+            refinements
+                .entry(inbound_id)
+                .or_default()
+                .push(format!("in:{relation_signature}:{outbound_signature}"));
+            for id in [outbound_id, inbound_id] {
+                neighbours.entry(id).or_default().push((outbound_id, inbound_id, relation_signature.clone()));
+            }
+        }
+        let colors = signatures
+            .into_iter()
+            .map(|(id, signature)| {
+                let mut refinement = refinements.remove(&id).unwrap_or_default();
+                refinement.sort();
+                (id, format!("{signature}|{}", refinement.join("|")))
+            })
+            .collect();
+        FlowGraph { colors, relations, neighbours }
+    }
+
+    fn candidates(&self, color: Option<&String>) -> Vec<Uuid> {
+        let mut candidates: Vec<Uuid> = self.colors.iter().filter(|(_, c)| Some(*c) == color).map(|(id, _)| *id).collect();
+        candidates.sort();
+        candidates
+    }
+}
+
+/// Maps the entity instance `order[index]` and recursively all following entity instances.
+#[allow(clippy::too_many_arguments)]
+fn search(
+    a: &FlowGraph,
+    b: &FlowGraph,
+    a_wrapper_id: Uuid,
+    b_wrapper_id: Uuid,
+    order: &[Uuid],
+    index: usize,
+    mapping: &mut HashMap<Uuid, Uuid>,
+    used: &mut HashSet<Uuid>,
+) -> bool {
+    let Some(id) = order.get(index) else {
+        return true;
+    };
+    let candidates = if *id == a_wrapper_id {
+        vec![b_wrapper_id]
+    } else {
+        b.candidates(a.colors.get(id))
+    };
+    for candidate in candidates {
+        if used.contains(&candidate) || a.colors.get(id) != b.colors.get(&candidate) {
+            continue;
+        }
+        mapping.insert(*id, candidate);
+        used.insert(candidate);
+        if is_consistent(a, b, *id, mapping) && search(a, b, a_wrapper_id, b_wrapper_id, order, index + 1, mapping, used) {
+            return true;
+        }
+        mapping.remove(id);
+        used.remove(&candidate);
+    }
+    false
+}
+
+/// Returns true, if the relation instances of the given entity instance, whose other entity
+/// instance is mapped too or is external, have a counterpart.
+fn is_consistent(a: &FlowGraph, b: &FlowGraph, id: Uuid, mapping: &HashMap<Uuid, Uuid>) -> bool {
+    let map = |id: &Uuid| match mapping.get(id) {
+        Some(mapped) => Some(*mapped),
+        None if a.colors.contains_key(id) => None,
+        // External entity instances keep their ids
+        None => Some(*id),
+    };
+    a.neighbours.get(&id).into_iter().flatten().all(|(outbound_id, inbound_id, signature)| {
+        let (Some(mapped_outbound_id), Some(mapped_inbound_id)) = (map(outbound_id), map(inbound_id)) else {
+            return true;
+        };
+        let count = a.relations.get(&(*outbound_id, *inbound_id, signature.clone())).copied().unwrap_or(0);
+        let mapped_count = b
+            .relations
+            .get(&(mapped_outbound_id, mapped_inbound_id, signature.clone()))
+            .copied()
+            .unwrap_or(0);
+        count == mapped_count
+    })
+}
+
+fn relation_signature(relation_instance: &RelationInstance) -> String {
+    signature(relation_instance, &["outbound_id", "inbound_id"])
+}
+
+/// Returns the canonical JSON representation of the given instance without the given fields and
+/// without the metadata.
+fn signature<T: Serialize>(instance: &T, ignored_fields: &[&str]) -> String {
+    let mut value = serde_json::to_value(instance).unwrap_or_default();
+    if let Some(object) = value.as_object_mut() {
+        for field in ignored_fields.iter().chain(METADATA_FIELDS.iter()) {
+            object.remove(*field);
+        }
+    }
+    canonical_json(&value)
+}
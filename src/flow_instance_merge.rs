@@ -0,0 +1,83 @@
+use std::fmt;
+
+use indradb::EdgeKey;
+use serde::Deserialize;
+use serde::Serialize;
+use uuid::Uuid;
+
+/// Defines how conflicts are resolved when merging a flow instance into another.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Copy, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum MergeStrategy {
+    /// Keeps the existing instance and skips the conflicting instance of the other flow.
+    Skip,
+
+    /// Replaces the existing instance with the conflicting instance of the other flow.
+    Replace,
+
+    /// Aborts the merge if at least one conflict exists. The flow instance remains unchanged.
+    Abort,
+}
+
+/// A conflict which has been detected during merging two flow instances.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FlowInstanceMergeConflict {
+    /// Both flows contains an entity instance with the given id.
+    EntityInstance(Uuid),
+
+    /// Both flows contains a relation instance with the given edge key.
+    RelationInstance(EdgeKey),
+}
+
+impl fmt::Display for FlowInstanceMergeConflict {
+    #[cfg(not(tarpaulin_include))]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FlowInstanceMergeConflict::EntityInstance(id) => write!(f, "The entity instance {} exists in both flows", id),
+            FlowInstanceMergeConflict::RelationInstance(edge_key) => write!(
+                f,
+                "The relation instance {}--[{}]-->{} exists in both flows",
+                edge_key.outbound_id,
+                edge_key.t.as_str(),
+                edge_key.inbound_id
+            ),
+        }
+    }
+}
+
+/// The result of a successful merge.
+#[derive(Clone, Debug, Default)]
+pub struct FlowInstanceMergeResult {
+    /// The ids of the entity instances which have been added.
+    pub entity_instances_added: Vec<Uuid>,
+
+    /// The edge keys of the relation instances which have been added.
+    pub relation_instances_added: Vec<EdgeKey>,
+
+    /// The conflicts which have been detected. Depending on the merge strategy the
+    /// conflicting instances have been skipped or replaced.
+    pub conflicts: Vec<FlowInstanceMergeConflict>,
+}
+
+impl FlowInstanceMergeResult {
+    /// Returns true, if no conflicts have been detected.
+    pub fn is_conflict_free(&self) -> bool {
+        self.conflicts.is_empty()
+    }
+}
+
+#[derive(Debug)]
+pub enum FlowInstanceMergeError {
+    /// The merge has been aborted because of the given conflicts.
+    Conflicts(Vec<FlowInstanceMergeConflict>),
+}
+
+impl fmt::Display for FlowInstanceMergeError {
+    #[cfg(not(tarpaulin_include))]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FlowInstanceMergeError::Conflicts(conflicts) => write!(f, "The merge has been aborted because of {} conflicts", conflicts.len()),
+        }
+    }
+}
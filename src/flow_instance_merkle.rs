@@ -0,0 +1,141 @@
+use std::collections::BTreeMap;
+
+use indradb::EdgeKey;
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::content_hash;
+use crate::fnv1a_64;
+use crate::ContentHash;
+use crate::FlowInstance;
+use crate::NamespacedTypeGetter;
+
+/// Merkle-style hash tree of a flow instance.
+///
+/// The leaves are the content hashes of the entity instances and the relation instances. Two
+/// flow instances can be compared by exchanging the root hash only. If the root hashes differ,
+/// the differing instances can be determined by exchanging the leaves.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FlowInstanceMerkleTree {
+    /// The root hash combines the header hash, the entity instances hash and the relation instances hash.
+    pub root: u64,
+
+    /// The hash of the flow instance itself without the contained instances.
+    pub header: u64,
+
+    /// The combined hash of all entity instances.
+    pub entity_instances_hash: u64,
+
+    /// The combined hash of all relation instances.
+    pub relation_instances_hash: u64,
+
+    /// The content hashes of the entity instances by id.
+    pub entity_instances: BTreeMap<Uuid, u64>,
+
+    /// The content hashes of the relation instances ordered by the edge key.
+    pub relation_instances: Vec<(EdgeKey, u64)>,
+}
+
+impl FlowInstanceMerkleTree {
+    /// Returns the differences to the given merkle tree.
+    pub fn diff(&self, other: &FlowInstanceMerkleTree) -> FlowInstanceMerkleDiff {
+        let mut diff = FlowInstanceMerkleDiff {
+            header_changed: self.header != other.header,
+            ..Default::default()
+        };
+        if self.entity_instances_hash != other.entity_instances_hash {
+            for (id, hash) in self.entity_instances.iter() {
+                if other.entity_instances.get(id) != Some(hash) {
+                    diff.entity_instances.push(*id);
+                }
+            }
+            for id in other.entity_instances.keys() {
+                if !self.entity_instances.contains_key(id) {
+                    diff.entity_instances.push(*id);
+                }
+            }
+        }
+        if self.relation_instances_hash != other.relation_instances_hash {
+            for (edge_key, hash) in self.relation_instances.iter() {
+                if !other
+                    .relation_instances
+                    .iter()
+                    .any(|(other_edge_key, other_hash)| other_edge_key == edge_key && other_hash == hash)
+                {
+                    diff.relation_instances.push(edge_key.clone());
+                }
+            }
+            for (edge_key, _) in other.relation_instances.iter() {
+                if !self.relation_instances.iter().any(|(own_edge_key, _)| own_edge_key == edge_key) {
+                    diff.relation_instances.push(edge_key.clone());
+                }
+            }
+        }
+        diff
+    }
+}
+
+impl TryFrom<&FlowInstance> for FlowInstanceMerkleTree {
+    type Error = serde_json::Error;
+
+    fn try_from(flow_instance: &FlowInstance) -> Result<Self, Self::Error> {
+        let mut external_entity_ids = flow_instance.external_entity_ids.clone();
+        external_entity_ids.sort();
+        let header = content_hash(&json!({
+            "id": flow_instance.id,
+            "namespace": flow_instance.namespace(),
+            "type_name": flow_instance.type_name(),
+            "name": flow_instance.name,
+            "description": flow_instance.description,
+            "external_entity_ids": external_entity_ids,
+            "sub_flows": flow_instance.sub_flows,
+        }))?;
+        let entity_instances = flow_instance
+            .entity_instances
+            .iter()
+            .map(|e| Ok((e.id, e.content_hash()?)))
+            .collect::<Result<BTreeMap<Uuid, u64>, serde_json::Error>>()?;
+        let mut relation_instances = flow_instance
+            .relation_instances
+            .iter()
+            .map(|r| Ok((r.get_key(), r.content_hash()?)))
+            .collect::<Result<Vec<(EdgeKey, u64)>, serde_json::Error>>()?;
+        relation_instances.sort_by(|(a, _), (b, _)| (a.outbound_id, a.t.as_str(), a.inbound_id).cmp(&(b.outbound_id, b.t.as_str(), b.inbound_id)));
+        let entity_instances_hash = combine_hashes(entity_instances.values().copied());
+        let relation_instances_hash = combine_hashes(relation_instances.iter().map(|(_, hash)| *hash));
+        Ok(FlowInstanceMerkleTree {
+            root: combine_hashes([header, entity_instances_hash, relation_instances_hash].into_iter()),
+            header,
+            entity_instances_hash,
+            relation_instances_hash,
+            entity_instances,
+            relation_instances,
+        })
+    }
+}
+
+/// The differences between two merkle trees of flow instances.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FlowInstanceMerkleDiff {
+    /// True, if the flow instance itself has been changed.
+    pub header_changed: bool,
+
+    /// The ids of the entity instances which have been changed, added or removed.
+    pub entity_instances: Vec<Uuid>,
+
+    /// The edge keys of the relation instances which have been changed, added or removed.
+    pub relation_instances: Vec<EdgeKey>,
+}
+
+impl FlowInstanceMerkleDiff {
+    /// Returns true, if no differences have been detected.
+    pub fn is_empty(&self) -> bool {
+        !self.header_changed && self.entity_instances.is_empty() && self.relation_instances.is_empty()
+    }
+}
+
+/// Combines the given hashes into a single hash.
+pub fn combine_hashes<I: Iterator<Item = u64>>(hashes: I) -> u64 {
+    let bytes: Vec<u8> = hashes.flat_map(|hash| hash.to_le_bytes()).collect();
+    fnv1a_64(&bytes)
+}
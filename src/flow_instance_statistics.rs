@@ -0,0 +1,149 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use uuid::Uuid;
+
+use crate::EntityTypeId;
+use crate::FlowInstance;
+use crate::RelationTypeId;
+
+/// Statistics and complexity metrics of a flow instance.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FlowInstanceStatistics {
+    /// The number of entity instances (including the wrapper entity instance).
+    pub entity_instance_count: usize,
+
+    /// The number of relation instances.
+    pub relation_instance_count: usize,
+
+    /// The number of entity instances per entity type.
+    pub entity_instances_per_type: HashMap<EntityTypeId, usize>,
+
+    /// The number of relation instances per relation type.
+    pub relation_instances_per_type: HashMap<RelationTypeId, usize>,
+
+    /// The total number of properties of all entity instances and relation instances.
+    pub property_count: usize,
+
+    /// The length of the longest path of relation instances. The entity instances of a cycle
+    /// count as a single entity instance, so the depth doesn't depend on where a cycle is entered.
+    pub depth: usize,
+
+    /// The maximum number of outbound relation instances of a single entity instance.
+    pub max_fan_out: usize,
+}
+
+impl From<&FlowInstance> for FlowInstanceStatistics {
+    fn from(flow_instance: &FlowInstance) -> Self {
+        let mut entity_instances_per_type = HashMap::new();
+        for entity_instance in flow_instance.entity_instances.iter() {
+            *entity_instances_per_type.entry(entity_instance.ty.clone()).or_insert(0) += 1;
+        }
+        let mut relation_instances_per_type = HashMap::new();
+        let mut outbound: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+        for relation_instance in flow_instance.relation_instances.iter() {
+            *relation_instances_per_type.entry(relation_instance.relation_type_id()).or_insert(0) += 1;
+            outbound.entry(relation_instance.outbound_id).or_default().push(relation_instance.inbound_id);
+        }
+        let property_count = flow_instance.entity_instances.iter().map(|e| e.properties.len()).sum::<usize>()
+            + flow_instance.relation_instances.iter().map(|r| r.properties.len()).sum::<usize>();
+        let depth = condensed_depth(&outbound);
+        let max_fan_out = outbound.values().map(|inbound_ids| inbound_ids.len()).max().unwrap_or(0);
+        FlowInstanceStatistics {
+            entity_instance_count: flow_instance.entity_instances.len(),
+            relation_instance_count: flow_instance.relation_instances.len(),
+            entity_instances_per_type,
+            relation_instances_per_type,
+            property_count,
+            depth,
+            max_fan_out,
+        }
+    }
+}
+
+/// Returns the length of the longest path of the graph in which each strongly connected
+/// component is condensed into a single node.
+fn condensed_depth(outbound: &HashMap<Uuid, Vec<Uuid>>) -> usize {
+    let mut tarjan = Tarjan::default();
+    for id in outbound.keys() {
+        if !tarjan.indices.contains_key(id) {
+            tarjan.visit(*id, outbound);
+        }
+    }
+    let mut condensed: HashMap<usize, HashSet<usize>> = HashMap::new();
+    for (outbound_id, inbound_ids) in outbound.iter() {
+        let outbound_component = tarjan.components[outbound_id];
+        for inbound_id in inbound_ids {
+            let inbound_component = tarjan.components[inbound_id];
+            if outbound_component != inbound_component {
+                condensed.entry(outbound_component).or_default().insert(inbound_component);
+            }
+        }
+    }
+    let mut depths = HashMap::new();
+    (0..tarjan.count)
+        .map(|component| longest_path(component, &condensed, &mut depths))
+        .max()
+        .unwrap_or(0)
+}
+
+/// Returns the length of the longest path of the acyclic condensed graph starting at the given
+/// component.
+fn longest_path(component: usize, condensed: &HashMap<usize, HashSet<usize>>, depths: &mut HashMap<usize, usize>) -> usize {
+    if let Some(depth) = depths.get(&component) {
+        return *depth;
+    }
+    let depth = condensed
+        .get(&component)
+        .map(|successors| {
+            successors
+                .iter()
+                .map(|successor| 1 + longest_path(*successor, condensed, depths))
+                .max()
+                .unwrap_or(0)
+        })
+        .unwrap_or(0);
+    depths.insert(component, depth);
+    depth
+}
+
+/// Tarjan's algorithm, which assigns each entity instance to its strongly connected component.
+#[derive(Default)]
+struct Tarjan {
+    indices: HashMap<Uuid, usize>,
+    low_links: HashMap<Uuid, usize>,
+    stack: Vec<Uuid>,
+    on_stack: HashSet<Uuid>,
+    components: HashMap<Uuid, usize>,
+    count: usize,
+}
+
+impl Tarjan {
+    fn visit(&mut self, id: Uuid, outbound: &HashMap<Uuid, Vec<Uuid>>) {
+        let index = self.indices.len();
+        self.indices.insert(id, index);
+        self.low_links.insert(id, index);
+        self.stack.push(id);
+        self.on_stack.insert(id);
+        for inbound_id in outbound.get(&id).into_iter().flatten() {
+            if !self.indices.contains_key(inbound_id) {
+                self.visit(*inbound_id, outbound);
+                let low_link = self.low_links[&id].min(self.low_links[inbound_id]);
+                self.low_links.insert(id, low_link);
+            } else if self.on_stack.contains(inbound_id) {
+                let low_link = self.low_links[&id].min(self.indices[inbound_id]);
+                self.low_links.insert(id, low_link);
+            }
+        }
+        if self.low_links[&id] == index {
+            while let Some(member) = self.stack.pop() {
+                self.on_stack.remove(&member);
+                self.components.insert(member, self.count);
+                if member == id {
+                    break;
+                }
+            }
+            self.count += 1;
+        }
+    }
+}
@@ -1,24 +1,46 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::hash::Hasher;
+
 use serde::Deserialize;
 use serde::Serialize;
+use serde_json::Map;
+use serde_json::Value;
 use uuid::Uuid;
 
+use crate::deserialize_entity_instance_with_local_id;
+use crate::deserialize_entity_instances_with_local_ids;
+use crate::deserialize_relation_instances_with_local_ids;
+use crate::flow_to_dot;
+use crate::Deprecation;
+use crate::Documentation;
 use crate::EntityInstance;
 use crate::EntityTypeId;
 use crate::Extension;
 use crate::ExtensionTypeId;
+use crate::FlowInstance;
 use crate::FlowTypeId;
+use crate::FlowTypeInstantiation;
+use crate::FlowTypeParameter;
+use crate::FlowTypePort;
+use crate::LanguageTag;
 use crate::NamespacedTypeGetter;
 use crate::PropertyType;
 use crate::RelationInstance;
 use crate::RelationTypeId;
+use crate::SubFlowTypeReference;
+use crate::TagContainer;
 use crate::TypeDefinition;
 use crate::TypeDefinitionGetter;
 use crate::TypeIdType;
+use crate::TYPE_PARAMETER_NAMESPACE;
 
 #[derive(Debug)]
 pub struct FlowTypeCreationError;
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct FlowType {
     /// The type definition of the entity type.
     #[serde(flatten)]
@@ -28,19 +50,50 @@ pub struct FlowType {
     #[serde(default = "String::new")]
     pub description: String,
 
+    /// The descriptions in other languages, by language tag.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty", serialize_with = "crate::serialize_descriptions")]
+    pub descriptions: HashMap<LanguageTag, String>,
+
+    /// The structured documentation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub documentation: Option<Documentation>,
+
+    /// The tags of the flow type, for grouping types into categories.
+    #[serde(default = "Vec::new")]
+    pub tags: Vec<String>,
+
+    /// The previous type ids of the flow type. References to a previous type id are resolved
+    /// to the flow type.
+    #[serde(default = "Vec::new")]
+    pub aliases: Vec<FlowTypeId>,
+
+    /// The deprecation of the flow type, if the flow type is deprecated.
+    #[serde(default)]
+    pub deprecation: Option<Deprecation>,
+
     /// The wrapper entity instance.
+    ///
+    /// The id of the wrapper entity instance may be a symbolic local id.
+    #[serde(deserialize_with = "deserialize_entity_instance_with_local_id")]
+    #[cfg_attr(feature = "schemars", schemars(with = "EntityInstance"))]
     pub wrapper_entity_instance: EntityInstance,
 
     /// The entity instances which are contained in this flow.
     ///
+    /// The ids of the entity instances may be symbolic local ids.
+    ///
     /// By default, no relation instances are contained in this flow type.
-    #[serde(default = "Vec::new", alias = "entities")]
+    #[serde(default = "Vec::new", alias = "entities", deserialize_with = "deserialize_entity_instances_with_local_ids")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Vec<EntityInstance>"))]
     pub entity_instances: Vec<EntityInstance>,
 
     /// The relation instances which are contained in this flow.
     ///
+    /// The outbound ids and the inbound ids of the relation instances may be symbolic local ids.
+    ///
     /// By default, no relation instances are contained in this flow type.
-    #[serde(default = "Vec::new", alias = "relations")]
+    #[serde(default = "Vec::new", alias = "relations", deserialize_with = "deserialize_relation_instances_with_local_ids")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Vec<RelationInstance>"))]
     pub relation_instances: Vec<RelationInstance>,
 
     /// The variables. Variables will be replaced by instantiation of a flow instance.
@@ -49,9 +102,44 @@ pub struct FlowType {
     #[serde(default = "Vec::new")]
     pub variables: Vec<PropertyType>,
 
+    /// The input ports of the flow type.
+    ///
+    /// By default, the flow type has no input ports.
+    #[serde(default = "Vec::new")]
+    pub inputs: Vec<FlowTypePort>,
+
+    /// The output ports of the flow type.
+    ///
+    /// By default, the flow type has no output ports.
+    #[serde(default = "Vec::new")]
+    pub outputs: Vec<FlowTypePort>,
+
+    /// The type parameters, which are substituted with concrete entity types at instantiation.
+    ///
+    /// By default, the flow type has no type parameters.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub type_parameters: Vec<FlowTypeParameter>,
+
+    /// The flow types which are included into this flow type.
+    ///
+    /// By default, the flow type includes no other flow types.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sub_flows: Vec<SubFlowTypeReference>,
+
+    /// Example flow instances of the flow type.
+    ///
+    /// By default, the flow type has no examples.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub examples: Vec<FlowInstance>,
+
     /// Flow type specific extensions.
     #[serde(default = "Vec::new")]
     pub extensions: Vec<Extension>,
+
+    /// Unknown fields of the data access object, which are re-emitted when converting back into
+    /// a data access object.
+    #[serde(skip)]
+    pub extra: Map<String, Value>,
 }
 
 impl FlowType {
@@ -67,11 +155,22 @@ impl FlowType {
         FlowType {
             ty: ty.into(),
             description: description.into(),
+            descriptions: HashMap::new(),
+            documentation: None,
+            tags: Vec::new(),
+            aliases: Vec::new(),
+            deprecation: None,
             wrapper_entity_instance,
             entity_instances,
             relation_instances,
             variables,
+            inputs: Vec::new(),
+            outputs: Vec::new(),
+            type_parameters: Vec::new(),
+            sub_flows: Vec::new(),
+            examples: Vec::new(),
             extensions,
+            extra: Map::new(),
         }
     }
 
@@ -89,14 +188,65 @@ impl FlowType {
         FlowType {
             ty: FlowTypeId::new_from_type(namespace, type_name),
             description: description.into(),
+            descriptions: HashMap::new(),
+            documentation: None,
+            tags: Vec::new(),
+            aliases: Vec::new(),
+            deprecation: None,
             wrapper_entity_instance,
             entity_instances,
             relation_instances,
             variables,
+            inputs: Vec::new(),
+            outputs: Vec::new(),
+            type_parameters: Vec::new(),
+            sub_flows: Vec::new(),
+            examples: Vec::new(),
             extensions,
+            extra: Map::new(),
         }
     }
 
+    /// Sets the input ports of the flow type.
+    pub fn with_inputs(mut self, inputs: Vec<FlowTypePort>) -> FlowType {
+        self.inputs = inputs;
+        self
+    }
+
+    /// Sets the output ports of the flow type.
+    pub fn with_outputs(mut self, outputs: Vec<FlowTypePort>) -> FlowType {
+        self.outputs = outputs;
+        self
+    }
+
+    /// Adds the given tag to the flow type.
+    pub fn with_tag<S: Into<String>>(mut self, tag: S) -> FlowType {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Adds the given previous type id to the flow type.
+    pub fn with_alias<A: Into<FlowTypeId>>(mut self, alias: A) -> FlowType {
+        self.aliases.push(alias.into());
+        self
+    }
+
+    /// Returns true, if the given type id is the type id or a previous type id of the flow type.
+    pub fn is_known_as(&self, ty: &FlowTypeId) -> bool {
+        &self.ty == ty || self.aliases.contains(ty)
+    }
+
+    /// Deprecates the flow type.
+    pub fn with_deprecation(mut self, deprecation: Deprecation) -> FlowType {
+        self.deprecation = Some(deprecation);
+        self
+    }
+
+    /// Returns true, if the flow type is deprecated.
+    pub fn is_deprecated(&self) -> bool {
+        self.deprecation.is_some()
+    }
+
     pub fn id(&self) -> Uuid {
         self.wrapper_entity_instance.id
     }
@@ -107,10 +257,15 @@ impl FlowType {
     }
 
     /// Returns the entity types which are used by the flow type
+    ///
+    /// The placeholder entity types of the type parameters are not included.
     pub fn uses_entity_types(&self) -> Vec<EntityTypeId> {
-        let mut entity_types: Vec<EntityTypeId> = self.entity_instances.iter().map(|e| e.ty.clone()).collect();
-        entity_types.push(self.wrapper_type());
-        entity_types.dedup();
+        let mut entity_types: Vec<EntityTypeId> = Vec::new();
+        for e in self.entity_instances.iter().chain(std::iter::once(&self.wrapper_entity_instance)) {
+            if e.ty.namespace() != TYPE_PARAMETER_NAMESPACE && !entity_types.contains(&e.ty) {
+                entity_types.push(e.ty.clone());
+            }
+        }
         entity_types
     }
 
@@ -169,6 +324,66 @@ impl FlowType {
         self.variables.retain(|v| v.name != variable_name)
     }
 
+    /// Returns true, if the flow type has an input port with the given name.
+    pub fn has_input<S: Into<String>>(&self, port_name: S) -> bool {
+        let port_name = port_name.into();
+        self.inputs.iter().any(|p| p.name == port_name)
+    }
+
+    /// Returns the input port with the given name.
+    pub fn get_input<S: Into<String>>(&self, port_name: S) -> Option<FlowTypePort> {
+        let port_name = port_name.into();
+        self.inputs.iter().find(|p| p.name == port_name).cloned()
+    }
+
+    /// Adds the given input port.
+    pub fn add_input(&mut self, port: FlowTypePort) {
+        self.inputs.push(port)
+    }
+
+    /// Removes the input port with the given name.
+    pub fn remove_input(&mut self, port_name: &str) {
+        self.inputs.retain(|p| p.name != port_name)
+    }
+
+    /// Returns true, if the flow type has an output port with the given name.
+    pub fn has_output<S: Into<String>>(&self, port_name: S) -> bool {
+        let port_name = port_name.into();
+        self.outputs.iter().any(|p| p.name == port_name)
+    }
+
+    /// Returns the output port with the given name.
+    pub fn get_output<S: Into<String>>(&self, port_name: S) -> Option<FlowTypePort> {
+        let port_name = port_name.into();
+        self.outputs.iter().find(|p| p.name == port_name).cloned()
+    }
+
+    /// Adds the given output port.
+    pub fn add_output(&mut self, port: FlowTypePort) {
+        self.outputs.push(port)
+    }
+
+    /// Removes the output port with the given name.
+    pub fn remove_output(&mut self, port_name: &str) {
+        self.outputs.retain(|p| p.name != port_name)
+    }
+
+    /// Returns the input ports and the output ports which are referencing an entity instance
+    /// which doesn't exist in the flow type or a property which doesn't exist on the entity instance.
+    pub fn invalid_ports(&self) -> Vec<FlowTypePort> {
+        let entity_instances = self.entity_instances();
+        self.inputs
+            .iter()
+            .chain(self.outputs.iter())
+            .filter(|port| {
+                !entity_instances
+                    .iter()
+                    .any(|e| e.id == port.entity_id && e.properties.contains_key(&port.property_name))
+            })
+            .cloned()
+            .collect()
+    }
+
     /// Returns true, if the flow type contains an extension with the given type.
     pub fn has_extension(&self, extension_ty: &ExtensionTypeId) -> bool {
         self.extensions.iter().any(|extension| &extension.ty == extension_ty)
@@ -183,6 +398,45 @@ impl FlowType {
     pub fn remove_extension(&mut self, extension_ty: &ExtensionTypeId) {
         self.extensions.retain(|extension| &extension.ty != extension_ty)
     }
+
+    /// Creates a new flow instance from the flow type template.
+    ///
+    /// The template ids (concrete or derived from symbolic local ids) of the entity instances are
+    /// replaced by newly generated ids. The returned instantiation allows to resolve the template
+    /// ids to the ids of the created flow instance.
+    pub fn instantiate(&self) -> FlowTypeInstantiation {
+        let mut template = FlowInstance::from(self.wrapper_entity_instance.clone());
+        template.description = self.description.clone();
+        template.entity_instances.extend(self.entity_instances.iter().cloned());
+        template.relation_instances = self.relation_instances.to_vec();
+        let (flow_instance, ids) = template.deep_clone_with_new_ids_mapping();
+        FlowTypeInstantiation { flow_instance, ids }
+    }
+
+    /// Returns the Graphviz DOT representation of the flow type.
+    pub fn to_dot(&self) -> String {
+        flow_to_dot(self.ty.to_string(), self.id(), &self.entity_instances(), &self.relation_instances)
+    }
+
+    /// Returns true, if both flow types have the same type and the same definition.
+    ///
+    /// In contrast, `==` compares only the type ids.
+    pub fn structurally_equals(&self, other: &FlowType) -> bool {
+        match (serde_json::to_value(self), serde_json::to_value(other)) {
+            (Ok(a), Ok(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl TagContainer for FlowType {
+    fn get_tags(&self) -> Vec<String> {
+        self.tags.clone()
+    }
+
+    fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t == tag)
+    }
 }
 
 impl NamespacedTypeGetter for FlowType {
@@ -201,6 +455,21 @@ impl TypeDefinitionGetter for FlowType {
     }
 }
 
+/// Two flow types are equal, if they have the same type id.
+impl PartialEq for FlowType {
+    fn eq(&self, other: &Self) -> bool {
+        self.ty == other.ty
+    }
+}
+
+impl Eq for FlowType {}
+
+impl Hash for FlowType {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.ty.hash(state);
+    }
+}
+
 impl From<&FlowType> for TypeDefinition {
     fn from(flow_type: &FlowType) -> Self {
         TypeDefinition {
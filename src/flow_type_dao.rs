@@ -0,0 +1,168 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::deserialize_entity_instance_with_local_id;
+use crate::deserialize_entity_instances_with_local_ids;
+use crate::deserialize_relation_instances_with_local_ids;
+use crate::Deprecation;
+use crate::Documentation;
+use crate::EntityInstance;
+use crate::EntityInstanceDao;
+use crate::Extension;
+use crate::FlowInstance;
+use crate::FlowInstanceDao;
+use crate::FlowType;
+use crate::FlowTypeId;
+use crate::FlowTypeParameter;
+use crate::FlowTypePort;
+use crate::LanguageTag;
+use crate::NamespacedTypeGetter;
+use crate::PropertyType;
+use crate::RelationInstance;
+use crate::RelationInstanceDao;
+use crate::SubFlowTypeReference;
+
+/// Data access object of a flow type.
+///
+/// Defines the stable external representation of a flow type. The in-memory
+/// representation (`FlowType`) can evolve without breaking existing files.
+///
+/// Like in flow types, the ids of the entity instances may be symbolic local ids.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct FlowTypeDao {
+    /// The namespace of the flow type.
+    #[serde(default = "String::new")]
+    pub namespace: String,
+
+    /// The name of the flow type.
+    #[serde(alias = "name")]
+    pub type_name: String,
+
+    /// Textual description of the flow type.
+    #[serde(default = "String::new")]
+    pub description: String,
+
+    /// The descriptions in other languages, by language tag.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty", serialize_with = "crate::serialize_descriptions")]
+    pub descriptions: HashMap<LanguageTag, String>,
+
+    /// The structured documentation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub documentation: Option<Documentation>,
+
+    /// The tags of the flow type, for grouping types into categories.
+    #[serde(default = "Vec::new")]
+    pub tags: Vec<String>,
+
+    /// The previous type ids of the flow type. References to a previous type id are resolved
+    /// to the flow type.
+    #[serde(default = "Vec::new")]
+    pub aliases: Vec<FlowTypeId>,
+
+    /// The deprecation of the flow type, if the flow type is deprecated.
+    #[serde(default)]
+    pub deprecation: Option<Deprecation>,
+
+    /// The wrapper entity instance.
+    #[serde(deserialize_with = "deserialize_entity_instance_with_local_id")]
+    #[cfg_attr(feature = "schemars", schemars(with = "EntityInstanceDao"))]
+    pub wrapper_entity_instance: EntityInstanceDao,
+
+    /// The entity instances which are contained in the flow type.
+    #[serde(default = "Vec::new", alias = "entities", deserialize_with = "deserialize_entity_instances_with_local_ids")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Vec<EntityInstanceDao>"))]
+    pub entity_instances: Vec<EntityInstanceDao>,
+
+    /// The relation instances which are contained in the flow type.
+    #[serde(default = "Vec::new", alias = "relations", deserialize_with = "deserialize_relation_instances_with_local_ids")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Vec<RelationInstanceDao>"))]
+    pub relation_instances: Vec<RelationInstanceDao>,
+
+    /// The variables which will be replaced by instantiation of a flow instance.
+    #[serde(default = "Vec::new")]
+    pub variables: Vec<PropertyType>,
+
+    /// The input ports of the flow type.
+    #[serde(default = "Vec::new")]
+    pub inputs: Vec<FlowTypePort>,
+
+    /// The output ports of the flow type.
+    #[serde(default = "Vec::new")]
+    pub outputs: Vec<FlowTypePort>,
+
+    /// The type parameters of the flow type.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub type_parameters: Vec<FlowTypeParameter>,
+
+    /// The flow types which are included into the flow type.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sub_flows: Vec<SubFlowTypeReference>,
+
+    /// Example flow instances of the flow type.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub examples: Vec<FlowInstanceDao>,
+
+    /// Flow type specific extensions.
+    #[serde(default = "Vec::new")]
+    pub extensions: Vec<Extension>,
+
+    /// Unknown fields, for example written by a newer version, which are re-emitted on save.
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl From<FlowType> for FlowTypeDao {
+    fn from(flow_type: FlowType) -> Self {
+        FlowTypeDao {
+            namespace: flow_type.namespace(),
+            type_name: flow_type.type_name(),
+            description: flow_type.description,
+            descriptions: flow_type.descriptions,
+            documentation: flow_type.documentation,
+            tags: flow_type.tags,
+            aliases: flow_type.aliases,
+            deprecation: flow_type.deprecation,
+            wrapper_entity_instance: flow_type.wrapper_entity_instance.into(),
+            entity_instances: flow_type.entity_instances.into_iter().map(EntityInstanceDao::from).collect(),
+            relation_instances: flow_type.relation_instances.into_iter().map(RelationInstanceDao::from).collect(),
+            variables: flow_type.variables,
+            inputs: flow_type.inputs,
+            outputs: flow_type.outputs,
+            type_parameters: flow_type.type_parameters,
+            sub_flows: flow_type.sub_flows,
+            examples: flow_type.examples.into_iter().map(FlowInstanceDao::from).collect(),
+            extensions: flow_type.extensions,
+            extra: flow_type.extra,
+        }
+    }
+}
+
+impl From<FlowTypeDao> for FlowType {
+    fn from(dao: FlowTypeDao) -> Self {
+        FlowType {
+            ty: FlowTypeId::new_from_type(dao.namespace, dao.type_name),
+            description: dao.description,
+            descriptions: dao.descriptions,
+            documentation: dao.documentation,
+            tags: dao.tags,
+            aliases: dao.aliases,
+            deprecation: dao.deprecation,
+            wrapper_entity_instance: dao.wrapper_entity_instance.into(),
+            entity_instances: dao.entity_instances.into_iter().map(EntityInstance::from).collect(),
+            relation_instances: dao.relation_instances.into_iter().map(RelationInstance::from).collect(),
+            variables: dao.variables,
+            inputs: dao.inputs,
+            outputs: dao.outputs,
+            type_parameters: dao.type_parameters,
+            sub_flows: dao.sub_flows,
+            examples: dao.examples.into_iter().map(FlowInstance::from).collect(),
+            extensions: dao.extensions,
+            extra: dao.extra,
+        }
+    }
+}
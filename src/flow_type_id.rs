@@ -1,10 +1,14 @@
 use std::fmt::Display;
 use std::fmt::Formatter;
+use std::str::FromStr;
+use std::sync::Arc;
 
 use indradb::Identifier;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::CachedNamespacedType;
+use crate::ModelError;
 use crate::NamespacedType;
 use crate::NamespacedTypeGetter;
 use crate::TypeDefinition;
@@ -12,26 +16,32 @@ use crate::TypeDefinitionGetter;
 use crate::TypeIdType;
 use crate::TYPE_ID_TYPE_SEPARATOR;
 
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
-pub struct FlowTypeId(NamespacedType);
+/// The type id of a flow type.
+///
+/// The namespaced type is shared, so cloning a type id doesn't copy the namespace and the type name.
+/// The identifier of the type id is computed once. Type ids are ordered by namespace, then by
+/// type name.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct FlowTypeId(Arc<CachedNamespacedType>);
 
 impl FlowTypeId {
     pub fn new(nt: NamespacedType) -> FlowTypeId {
-        FlowTypeId(nt)
+        FlowTypeId(Arc::new(nt.into()))
     }
 
     pub fn new_from_type<S: Into<String>>(namespace: S, type_name: S) -> FlowTypeId {
-        FlowTypeId(NamespacedType::new(namespace, type_name))
+        FlowTypeId(Arc::new(NamespacedType::new(namespace, type_name).into()))
     }
 }
 
 impl NamespacedTypeGetter for FlowTypeId {
     fn namespace(&self) -> String {
-        self.0.namespace.clone()
+        self.0.namespace.to_string()
     }
 
     fn type_name(&self) -> String {
-        self.0.type_name.clone()
+        self.0.type_name.to_string()
     }
 }
 
@@ -39,6 +49,10 @@ impl TypeDefinitionGetter for FlowTypeId {
     fn type_definition(&self) -> TypeDefinition {
         self.into()
     }
+
+    fn type_id(&self) -> Identifier {
+        self.0.identifier(|| (&self.type_definition()).into())
+    }
 }
 
 impl From<&FlowTypeId> for FlowTypeId {
@@ -49,79 +63,72 @@ impl From<&FlowTypeId> for FlowTypeId {
 
 impl From<&FlowTypeId> for TypeDefinition {
     fn from(ty: &FlowTypeId) -> Self {
-        TypeDefinition::new(TypeIdType::FlowType, ty.0.clone())
+        TypeDefinition::new(TypeIdType::FlowType, NamespacedType::clone(&ty.0))
     }
 }
 
 impl From<&FlowTypeId> for NamespacedType {
     fn from(ty: &FlowTypeId) -> Self {
-        ty.0.clone()
+        NamespacedType::clone(&ty.0)
     }
 }
 
 impl From<NamespacedType> for FlowTypeId {
     fn from(nt: NamespacedType) -> Self {
-        FlowTypeId(nt)
+        FlowTypeId(Arc::new(nt.into()))
     }
 }
 
 impl TryFrom<&TypeDefinition> for FlowTypeId {
-    type Error = ();
+    type Error = ModelError;
 
     fn try_from(type_definition: &TypeDefinition) -> Result<Self, Self::Error> {
         match type_definition.type_id_type {
             TypeIdType::FlowType => Ok(FlowTypeId::new_from_type(type_definition.namespace.clone(), type_definition.type_name.clone())),
-            _ => Err(()),
+            _ => Err(ModelError::UnexpectedTypeIdType {
+                expected: TypeIdType::FlowType,
+                actual: type_definition.type_id_type,
+            }),
         }
     }
 }
 
 impl TryFrom<&Identifier> for FlowTypeId {
-    type Error = ();
+    type Error = ModelError;
 
     fn try_from(t: &Identifier) -> Result<Self, Self::Error> {
-        let s = t.to_string();
-        let mut s = s.split(&TYPE_ID_TYPE_SEPARATOR);
-        let type_type = s.next().ok_or(())?.try_into()?;
-        if TypeIdType::FlowType == type_type {
-            let namespace = s.next().ok_or(())?;
-            if namespace.is_empty() {
-                return Err(());
-            }
-            let type_name = s.next().ok_or(())?;
-            if type_name.is_empty() {
-                return Err(());
-            }
-            if s.next().is_some() {
-                return Err(());
-            }
-            return Ok(FlowTypeId(NamespacedType::new(namespace, type_name)));
-        }
-        Err(())
+        FlowTypeId::try_from(&t.to_string())
     }
 }
 
 impl TryFrom<&String> for FlowTypeId {
-    type Error = ();
+    type Error = ModelError;
 
     fn try_from(s: &String) -> Result<Self, Self::Error> {
-        let mut s = s.split(&TYPE_ID_TYPE_SEPARATOR);
-        let type_type = s.next().ok_or(())?.try_into()?;
-        if TypeIdType::FlowType == type_type {
-            let namespace = s.next().ok_or(())?;
-            if namespace.is_empty() {
-                return Err(());
-            }
-            let type_name = s.next().ok_or(())?;
-            if type_name.is_empty() {
-                return Err(());
-            }
-            if s.next().is_some() {
-                return Err(());
-            }
-            return Ok(FlowTypeId(NamespacedType::new(namespace, type_name)));
+        let invalid = || ModelError::InvalidTypeId(s.clone());
+        let mut parts = s.split(&TYPE_ID_TYPE_SEPARATOR);
+        let type_id_type = TypeIdType::try_from(parts.next().ok_or_else(invalid)?)?;
+        if TypeIdType::FlowType != type_id_type {
+            return Err(ModelError::UnexpectedTypeIdType {
+                expected: TypeIdType::FlowType,
+                actual: type_id_type,
+            });
+        }
+        let namespace = parts.next().filter(|namespace| !namespace.is_empty()).ok_or_else(invalid)?;
+        let type_name = parts.next().filter(|type_name| !type_name.is_empty()).ok_or_else(invalid)?;
+        if parts.next().is_some() {
+            return Err(invalid());
         }
-        Err(())
+        Ok(FlowTypeId(Arc::new(NamespacedType::new(namespace, type_name).into())))
+    }
+}
+
+/// Parses the fully qualified type id.
+impl FromStr for FlowTypeId {
+    type Err = ModelError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        FlowTypeId::try_from(&s.to_string())
     }
 }
 
@@ -0,0 +1,93 @@
+use std::collections::HashMap;
+
+use serde::de::DeserializeOwned;
+use serde::de::Error;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::FlowInstance;
+
+pub static NAMESPACE_FLOW_TYPE_LOCAL_ID: Uuid = Uuid::from_u128(0x3ab7c8109dcd11c180b4f0d02fd5a0c7);
+
+/// Returns the template id of the entity instance with the given symbolic local id.
+///
+/// Inside of flow type templates entity instances can be addressed by a symbolic name instead
+/// of a concrete UUID. The template id is stable for the name.
+pub fn flow_type_local_id<S: Into<String>>(local_id: S) -> Uuid {
+    Uuid::new_v5(&NAMESPACE_FLOW_TYPE_LOCAL_ID, local_id.into().as_bytes())
+}
+
+/// Replaces the symbolic local ids of the given fields with the corresponding template ids.
+fn resolve_local_ids(value: &mut Value, fields: &[&str]) {
+    if let Value::Object(object) = value {
+        for field in fields {
+            if let Some(Value::String(id)) = object.get_mut(*field) {
+                if Uuid::parse_str(id).is_err() {
+                    *id = flow_type_local_id(id.as_str()).to_string();
+                }
+            }
+        }
+    }
+}
+
+/// Deserializes an entity instance id which may be a symbolic local id.
+pub fn deserialize_local_id<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Uuid, D::Error> {
+    let id = String::deserialize(deserializer)?;
+    Ok(Uuid::parse_str(&id).unwrap_or_else(|_| flow_type_local_id(id)))
+}
+
+/// Deserializes an entity instance (or the DAO of an entity instance) whose id may be a symbolic local id.
+pub fn deserialize_entity_instance_with_local_id<'de, D: Deserializer<'de>, T: DeserializeOwned>(deserializer: D) -> Result<T, D::Error> {
+    let mut value = Value::deserialize(deserializer)?;
+    resolve_local_ids(&mut value, &["id"]);
+    serde_json::from_value(value).map_err(D::Error::custom)
+}
+
+/// Deserializes entity instances (or the DAOs of entity instances) whose ids may be symbolic local ids.
+pub fn deserialize_entity_instances_with_local_ids<'de, D: Deserializer<'de>, T: DeserializeOwned>(deserializer: D) -> Result<Vec<T>, D::Error> {
+    let mut values = Vec::<Value>::deserialize(deserializer)?;
+    values.iter_mut().for_each(|value| resolve_local_ids(value, &["id"]));
+    values
+        .into_iter()
+        .map(|value| serde_json::from_value(value).map_err(D::Error::custom))
+        .collect()
+}
+
+/// Deserializes relation instances (or the DAOs of relation instances) whose outbound and inbound ids may be
+/// symbolic local ids.
+pub fn deserialize_relation_instances_with_local_ids<'de, D: Deserializer<'de>, T: DeserializeOwned>(deserializer: D) -> Result<Vec<T>, D::Error> {
+    let mut values = Vec::<Value>::deserialize(deserializer)?;
+    values
+        .iter_mut()
+        .for_each(|value| resolve_local_ids(value, &["outbound_id", "outbound", "inbound_id", "inbound"]));
+    values
+        .into_iter()
+        .map(|value| serde_json::from_value(value).map_err(D::Error::custom))
+        .collect()
+}
+
+/// The result of the instantiation of a flow type.
+#[derive(Clone, Debug)]
+pub struct FlowTypeInstantiation {
+    /// The newly created flow instance.
+    pub flow_instance: FlowInstance,
+
+    /// Maps the template ids of the flow type to the ids of the flow instance.
+    pub ids: HashMap<Uuid, Uuid>,
+}
+
+impl FlowTypeInstantiation {
+    /// Returns the id of the entity instance which has been created from the template entity
+    /// instance with the given template id.
+    pub fn resolve(&self, template_id: Uuid) -> Option<Uuid> {
+        self.ids.get(&template_id).copied()
+    }
+
+    /// Returns the id of the entity instance which has been created from the template entity
+    /// instance with the given symbolic local id.
+    pub fn resolve_local_id<S: Into<String>>(&self, local_id: S) -> Option<Uuid> {
+        self.resolve(flow_type_local_id(local_id))
+    }
+}
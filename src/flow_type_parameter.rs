@@ -0,0 +1,179 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::ComponentOrEntityTypeId;
+use crate::EntityTypeId;
+use crate::FlowType;
+use crate::FlowTypeInstantiation;
+use crate::NamespacedTypeGetter;
+use crate::TypeSystem;
+use crate::ValidationError;
+
+/// The namespace of the placeholder entity types which reference the type parameters of a flow
+/// type, for example `type_parameter::T`.
+pub const TYPE_PARAMETER_NAMESPACE: &str = "type_parameter";
+
+/// Returns the placeholder entity type which references the type parameter with the given name.
+pub fn type_parameter_placeholder<S: Into<String>>(name: S) -> EntityTypeId {
+    EntityTypeId::new_from_type(TYPE_PARAMETER_NAMESPACE.to_string(), name.into())
+}
+
+/// A type variable of a flow type, which is substituted with a concrete entity type at
+/// instantiation.
+///
+/// Entity instances of the flow type whose type is the placeholder of the type parameter, see
+/// [`type_parameter_placeholder`], become entity instances of the entity type argument.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct FlowTypeParameter {
+    /// The name of the type parameter.
+    pub name: String,
+
+    /// Textual description of the type parameter.
+    #[serde(default = "String::new")]
+    pub description: String,
+
+    /// The constraint which the entity type argument has to satisfy, for example a component.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub constraint: Option<ComponentOrEntityTypeId>,
+
+    /// The entity type which is substituted if no entity type argument is given.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<EntityTypeId>,
+}
+
+impl FlowTypeParameter {
+    pub fn new<S: Into<String>>(name: S) -> FlowTypeParameter {
+        FlowTypeParameter {
+            name: name.into(),
+            description: String::new(),
+            constraint: None,
+            default: None,
+        }
+    }
+
+    /// Sets the description of the type parameter.
+    pub fn with_description<S: Into<String>>(mut self, description: S) -> FlowTypeParameter {
+        self.description = description.into();
+        self
+    }
+
+    /// Sets the constraint which the entity type argument has to satisfy.
+    pub fn with_constraint<T: Into<ComponentOrEntityTypeId>>(mut self, constraint: T) -> FlowTypeParameter {
+        self.constraint = Some(constraint.into());
+        self
+    }
+
+    /// Sets the entity type which is substituted if no entity type argument is given.
+    pub fn with_default<T: Into<EntityTypeId>>(mut self, default: T) -> FlowTypeParameter {
+        self.default = Some(default.into());
+        self
+    }
+
+    /// Returns the placeholder entity type which references the type parameter.
+    pub fn placeholder(&self) -> EntityTypeId {
+        type_parameter_placeholder(self.name.clone())
+    }
+}
+
+impl FlowType {
+    /// Adds the given type parameter.
+    pub fn with_type_parameter(mut self, type_parameter: FlowTypeParameter) -> FlowType {
+        self.type_parameters.push(type_parameter);
+        self
+    }
+
+    /// Returns the type parameter with the given name.
+    pub fn get_type_parameter(&self, name: &str) -> Option<&FlowTypeParameter> {
+        self.type_parameters.iter().find(|type_parameter| type_parameter.name == name)
+    }
+
+    /// Returns true, if the flow type has type parameters.
+    pub fn is_generic(&self) -> bool {
+        !self.type_parameters.is_empty()
+    }
+
+    /// Returns the entity type arguments of all type parameters, falling back to the defaults of
+    /// the type parameters.
+    ///
+    /// Fails if an argument is given for an unknown type parameter or if neither an argument nor a
+    /// default is given for a type parameter.
+    pub fn resolve_type_arguments(&self, arguments: &HashMap<String, EntityTypeId>) -> Result<HashMap<String, EntityTypeId>, ValidationError> {
+        if let Some(name) = arguments.keys().find(|name| self.get_type_parameter(name).is_none()) {
+            return Err(ValidationError::UnknownTypeParameter {
+                ty: self.ty.clone(),
+                parameter: name.clone(),
+            });
+        }
+        self.type_parameters
+            .iter()
+            .map(|type_parameter| {
+                arguments
+                    .get(&type_parameter.name)
+                    .or(type_parameter.default.as_ref())
+                    .map(|argument| (type_parameter.name.clone(), argument.clone()))
+                    .ok_or_else(|| ValidationError::MissingTypeArgument {
+                        ty: self.ty.clone(),
+                        parameter: type_parameter.name.clone(),
+                    })
+            })
+            .collect()
+    }
+
+    /// Returns a copy of this flow type without type parameters, whose placeholder entity types
+    /// are substituted with the given entity type arguments.
+    ///
+    /// The constraints of the type parameters are only validated by
+    /// [`TypeSystem::substitute_flow_type_parameters`].
+    pub fn substitute_type_parameters(&self, arguments: &HashMap<String, EntityTypeId>) -> Result<FlowType, ValidationError> {
+        let arguments = self.resolve_type_arguments(arguments)?;
+        let mut flow_type = self.clone();
+        flow_type.type_parameters.clear();
+        for entity_instance in std::iter::once(&mut flow_type.wrapper_entity_instance).chain(flow_type.entity_instances.iter_mut()) {
+            if entity_instance.ty.namespace() != TYPE_PARAMETER_NAMESPACE {
+                continue;
+            }
+            let name = entity_instance.ty.type_name();
+            entity_instance.ty = arguments.get(&name).cloned().ok_or_else(|| ValidationError::UnknownTypeParameter {
+                ty: self.ty.clone(),
+                parameter: name.clone(),
+            })?;
+        }
+        Ok(flow_type)
+    }
+
+    /// Substitutes the type parameters with the given entity type arguments and creates a new flow
+    /// instance from the flow type template.
+    pub fn instantiate_with_type_arguments(&self, arguments: &HashMap<String, EntityTypeId>) -> Result<FlowTypeInstantiation, ValidationError> {
+        Ok(self.substitute_type_parameters(arguments)?.instantiate())
+    }
+}
+
+impl TypeSystem {
+    /// Substitutes the type parameters of the given flow type with the given entity type
+    /// arguments and validates that the entity type arguments satisfy the constraints of the type
+    /// parameters.
+    pub fn substitute_flow_type_parameters(&self, flow_type: &FlowType, arguments: &HashMap<String, EntityTypeId>) -> Result<FlowType, ValidationError> {
+        let arguments = flow_type.resolve_type_arguments(arguments)?;
+        for type_parameter in flow_type.type_parameters.iter() {
+            let Some(constraint) = &type_parameter.constraint else {
+                continue;
+            };
+            let argument = &arguments[&type_parameter.name];
+            let entity_type = self
+                .resolve_entity_type(argument)
+                .ok_or_else(|| ValidationError::UnknownEntityType(argument.clone()))?;
+            if !self.satisfies_endpoint(entity_type, constraint) {
+                return Err(ValidationError::UnsatisfiedTypeParameterConstraint {
+                    ty: flow_type.ty.clone(),
+                    parameter: type_parameter.name.clone(),
+                    constraint: constraint.clone(),
+                    argument: argument.clone(),
+                });
+            }
+        }
+        flow_type.substitute_type_parameters(&arguments)
+    }
+}
@@ -0,0 +1,40 @@
+use serde::Deserialize;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::deserialize_local_id;
+
+/// A port of a flow type maps a name to a property of an entity instance inside the flow type.
+///
+/// Ports allows to wire flows as black boxes without inspecting the internals of the flow.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct FlowTypePort {
+    /// The name of the port.
+    pub name: String,
+
+    /// Textual description of the port.
+    #[serde(default = "String::new")]
+    pub description: String,
+
+    /// The id of the entity instance inside the flow type. May be a symbolic local id.
+    #[serde(deserialize_with = "deserialize_local_id")]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
+    pub entity_id: Uuid,
+
+    /// The name of the property of the entity instance.
+    #[serde(alias = "property")]
+    pub property_name: String,
+}
+
+impl FlowTypePort {
+    /// Constructs a new port with the given name which maps to the property of the entity instance with the given id.
+    pub fn new<S: Into<String>>(name: S, entity_id: Uuid, property_name: S) -> FlowTypePort {
+        FlowTypePort {
+            name: name.into(),
+            description: String::new(),
+            entity_id,
+            property_name: property_name.into(),
+        }
+    }
+}
@@ -0,0 +1,280 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::path_to_string;
+use crate::ComponentDao;
+use crate::DaoParseError;
+use crate::EntityInstanceDao;
+use crate::EntityTypeDao;
+use crate::FlowInstanceDao;
+use crate::FlowTypeDao;
+use crate::RelationInstanceDao;
+use crate::RelationTypeDao;
+use crate::StrictDao;
+
+/// A DAO whose fields have been renamed in previous format versions.
+pub trait RenamedFields {
+    /// Returns the fields which were renamed in previous format versions, as pairs of the path of
+    /// the previous name and the current name. The previous names are still accepted as aliases.
+    ///
+    /// The indices of arrays are written as `[]`, for example `extensions[].name`.
+    fn renamed_fields() -> Vec<(String, &'static str)>;
+}
+
+fn renamed(path: &str, name: &'static str) -> (String, &'static str) {
+    (path.to_string(), name)
+}
+
+fn nested_renamed_fields<T: RenamedFields>(prefix: &str) -> Vec<(String, &'static str)> {
+    T::renamed_fields().into_iter().map(|(path, name)| (format!("{prefix}.{path}"), name)).collect()
+}
+
+/// Returns the renamed fields of a type with the given property types.
+fn type_renamed_fields(property_types: &str) -> Vec<(String, &'static str)> {
+    vec![
+        renamed("name", "type_name"),
+        renamed("aliases[].name", "type_name"),
+        renamed("deprecation.replacement.name", "type_name"),
+        renamed("extensions[].name", "type_name"),
+        (format!("{property_types}[].extensions[].name"), "type_name"),
+    ]
+}
+
+impl RenamedFields for ComponentDao {
+    fn renamed_fields() -> Vec<(String, &'static str)> {
+        type_renamed_fields("properties")
+    }
+}
+
+impl RenamedFields for EntityTypeDao {
+    fn renamed_fields() -> Vec<(String, &'static str)> {
+        let mut fields = type_renamed_fields("properties");
+        fields.push(renamed("components[].name", "type_name"));
+        fields
+    }
+}
+
+impl RenamedFields for RelationTypeDao {
+    fn renamed_fields() -> Vec<(String, &'static str)> {
+        let mut fields = type_renamed_fields("properties");
+        fields.push(renamed("components[].name", "type_name"));
+        fields.push(renamed("outbound_type", "outbound"));
+        fields.push(renamed("inbound_type", "inbound"));
+        fields
+    }
+}
+
+impl RenamedFields for EntityInstanceDao {
+    fn renamed_fields() -> Vec<(String, &'static str)> {
+        vec![renamed("name", "type_name"), renamed("extensions[].name", "type_name")]
+    }
+}
+
+impl RenamedFields for RelationInstanceDao {
+    fn renamed_fields() -> Vec<(String, &'static str)> {
+        vec![
+            renamed("name", "type_name"),
+            renamed("outbound", "outbound_id"),
+            renamed("inbound", "inbound_id"),
+            renamed("extensions[].name", "type_name"),
+        ]
+    }
+}
+
+impl RenamedFields for FlowInstanceDao {
+    fn renamed_fields() -> Vec<(String, &'static str)> {
+        let mut fields = vec![
+            renamed("flow_instance_name", "name"),
+            renamed("entities", "entity_instances"),
+            renamed("relations", "relation_instances"),
+            renamed("externals", "external_entity_ids"),
+        ];
+        fields.extend(nested_renamed_fields::<EntityInstanceDao>("entity_instances[]"));
+        fields.extend(nested_renamed_fields::<RelationInstanceDao>("relation_instances[]"));
+        fields
+    }
+}
+
+impl RenamedFields for FlowTypeDao {
+    fn renamed_fields() -> Vec<(String, &'static str)> {
+        let mut fields = type_renamed_fields("variables");
+        fields.push(renamed("entities", "entity_instances"));
+        fields.push(renamed("relations", "relation_instances"));
+        fields.push(renamed("inputs[].property", "property_name"));
+        fields.push(renamed("outputs[].property", "property_name"));
+        fields.extend(nested_renamed_fields::<EntityInstanceDao>("wrapper_entity_instance"));
+        fields.extend(nested_renamed_fields::<EntityInstanceDao>("entity_instances[]"));
+        fields.extend(nested_renamed_fields::<RelationInstanceDao>("relation_instances[]"));
+        fields
+    }
+}
+
+/// The differences between a document written by an older format version and the current format
+/// of the document.
+///
+/// The fields are identified by their paths, for example `properties[0].mutability`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FormatCompatibilityReport {
+    /// The fields which are missing in the document and have been defaulted.
+    pub defaulted: Vec<String>,
+
+    /// The fields which are present under a previous name, as pairs of the previous path and the
+    /// current path.
+    pub renamed: Vec<(String, String)>,
+
+    /// The fields of the document which are not part of the current format and are lost on save.
+    pub dropped: Vec<String>,
+
+    /// The fields of the document which are not part of the current format but are kept and
+    /// re-emitted on save.
+    pub unknown: Vec<String>,
+}
+
+impl FormatCompatibilityReport {
+    /// Returns true, if the document is in the current format.
+    pub fn is_current(&self) -> bool {
+        self.defaulted.is_empty() && self.renamed.is_empty() && self.dropped.is_empty() && self.unknown.is_empty()
+    }
+
+    /// Returns true, if no information of the document is lost on save.
+    pub fn is_lossless(&self) -> bool {
+        self.dropped.is_empty()
+    }
+}
+
+/// Formats the report as migration instructions, one line per field.
+impl Display for FormatCompatibilityReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for field in self.defaulted.iter() {
+            writeln!(f, "defaulted: {field}")?;
+        }
+        for (previous, current) in self.renamed.iter() {
+            writeln!(f, "renamed: {previous} -> {current}")?;
+        }
+        for field in self.dropped.iter() {
+            writeln!(f, "dropped: {field}")?;
+        }
+        for field in self.unknown.iter() {
+            writeln!(f, "unknown: {field}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses a DAO from the given document, which may be written by an older format version, and
+/// reports which fields have been defaulted, renamed or dropped.
+///
+/// The dropped fields are the fields which are ignored by the deserialization. The defaulted and
+/// the renamed fields are computed by comparing the document with the DAO serialized in the
+/// current format. Fields which are not serialized because they have their default value, are
+/// neither dropped nor renamed. Documents of other formats can be checked by parsing them into a
+/// `Value` first.
+pub fn check_format_compatibility<T: StrictDao + RenamedFields + Serialize>(document: &Value) -> Result<(T, FormatCompatibilityReport), DaoParseError> {
+    let mut ignored = Vec::new();
+    let dao: T = serde_ignored::deserialize(document.clone(), |path| ignored.push(path_to_string(&path)))?;
+    let current = serde_json::to_value(&dao)?;
+    let mut report = FormatCompatibilityReport::default();
+    let comparison = Comparison {
+        renamed_fields: T::renamed_fields(),
+        ignored,
+    };
+    comparison.compare(document, &current, "", "", &mut report);
+    report.defaulted.sort();
+    report.dropped.sort();
+    report.unknown = dao.unknown_fields();
+    Ok((dao, report))
+}
+
+/// Parses a DAO from the given JSON document, which may be written by an older format version, and
+/// reports which fields have been defaulted, renamed or dropped.
+pub fn check_json_format_compatibility<T: StrictDao + RenamedFields + Serialize>(s: &str) -> Result<(T, FormatCompatibilityReport), DaoParseError> {
+    check_format_compatibility(&serde_json::from_str(s)?)
+}
+
+struct Comparison {
+    /// The renamed fields of the DAO.
+    renamed_fields: Vec<(String, &'static str)>,
+
+    /// The paths of the fields which have been ignored by the deserialization.
+    ignored: Vec<String>,
+}
+
+impl Comparison {
+    /// Compares the document with the current format. The path in the document differs from the
+    /// current path, if a parent field has been renamed.
+    fn compare(&self, document: &Value, current: &Value, path: &str, document_path: &str, report: &mut FormatCompatibilityReport) {
+        match (document, current) {
+            (Value::Object(document), Value::Object(current)) => {
+                let mut renamed_to = Vec::new();
+                let mut fields: Vec<&String> = document.keys().collect();
+                fields.sort();
+                for field in fields {
+                    let value = &document[field];
+                    let previous_path = field_path(path, field);
+                    let field_document_path = field_path(document_path, field);
+                    if let Some(current_value) = current.get(field) {
+                        self.compare(value, current_value, &previous_path, &field_document_path, report);
+                        continue;
+                    }
+                    if self.ignored.contains(&field_document_path) {
+                        report.dropped.push(field_document_path);
+                        continue;
+                    }
+                    let pattern = path_pattern(&previous_path);
+                    let renamed = self
+                        .renamed_fields
+                        .iter()
+                        .filter(|(previous, _)| *previous == pattern)
+                        .find(|(_, name)| current.contains_key(*name) && !document.contains_key(*name));
+                    // Otherwise the field has been deserialized but isn't serialized, because it has its default value
+                    if let Some((_, name)) = renamed {
+                        report.renamed.push((previous_path, field_path(path, name)));
+                        renamed_to.push(*name);
+                        self.compare(value, &current[*name], &field_path(path, name), &field_document_path, report);
+                    }
+                }
+                let mut fields: Vec<&String> = current
+                    .keys()
+                    .filter(|field| !document.contains_key(*field) && !renamed_to.contains(&field.as_str()))
+                    .collect();
+                fields.sort();
+                report.defaulted.extend(fields.into_iter().map(|field| field_path(path, field)));
+            }
+            (Value::Array(document), Value::Array(current)) => {
+                for (i, (value, current_value)) in document.iter().zip(current.iter()).enumerate() {
+                    self.compare(value, current_value, &format!("{path}[{i}]"), &format!("{document_path}[{i}]"), report);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Returns the given path with the indices of arrays replaced by `[]`.
+fn path_pattern(path: &str) -> String {
+    let mut pattern = String::with_capacity(path.len());
+    let mut in_index = false;
+    for c in path.chars() {
+        match c {
+            '[' => {
+                in_index = true;
+                pattern.push_str("[]");
+            }
+            ']' => in_index = false,
+            _ if in_index => {}
+            _ => pattern.push(c),
+        }
+    }
+    pattern
+}
+
+fn field_path(path: &str, field: &str) -> String {
+    if path.is_empty() {
+        field.to_string()
+    } else {
+        format!("{path}.{field}")
+    }
+}
@@ -0,0 +1,55 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::EntityInstance;
+use crate::FlowInstance;
+use crate::RelationInstance;
+
+/// Deserializes a value from the given MessagePack encoded bytes.
+pub fn from_msgpack_slice<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, rmp_serde::decode::Error> {
+    rmp_serde::from_slice(bytes)
+}
+
+/// Serializes the given value as MessagePack.
+///
+/// Structs are encoded as maps with named fields, which is required by flattened fields and
+/// keeps the encoding compatible with added optional fields.
+pub fn to_msgpack_vec<T: Serialize>(value: &T) -> Result<Vec<u8>, rmp_serde::encode::Error> {
+    rmp_serde::to_vec_named(value)
+}
+
+impl EntityInstance {
+    /// Returns the MessagePack encoding of the entity instance.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, rmp_serde::encode::Error> {
+        to_msgpack_vec(self)
+    }
+
+    /// Decodes an entity instance from the given MessagePack encoded bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<EntityInstance, rmp_serde::decode::Error> {
+        from_msgpack_slice(bytes)
+    }
+}
+
+impl RelationInstance {
+    /// Returns the MessagePack encoding of the relation instance.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, rmp_serde::encode::Error> {
+        to_msgpack_vec(self)
+    }
+
+    /// Decodes a relation instance from the given MessagePack encoded bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<RelationInstance, rmp_serde::decode::Error> {
+        from_msgpack_slice(bytes)
+    }
+}
+
+impl FlowInstance {
+    /// Returns the MessagePack encoding of the flow instance.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, rmp_serde::encode::Error> {
+        to_msgpack_vec(self)
+    }
+
+    /// Decodes a flow instance from the given MessagePack encoded bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<FlowInstance, rmp_serde::decode::Error> {
+        from_msgpack_slice(bytes)
+    }
+}
@@ -0,0 +1,13 @@
+use ron::ser::PrettyConfig;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Deserializes a type definition or a flow instance from the given RON document.
+pub fn from_ron_str<T: DeserializeOwned>(s: &str) -> Result<T, ron::error::SpannedError> {
+    ron::from_str(s)
+}
+
+/// Serializes the given type definition or flow instance as pretty printed RON document.
+pub fn to_ron_string<T: Serialize>(value: &T) -> Result<String, ron::Error> {
+    ron::ser::to_string_pretty(value, PrettyConfig::default())
+}
@@ -0,0 +1,15 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Deserializes a DAO from the given TOML document.
+pub fn from_toml_str<T: DeserializeOwned>(s: &str) -> Result<T, toml::de::Error> {
+    toml::from_str(s)
+}
+
+/// Serializes the given DAO as TOML document.
+///
+/// TOML has no representation of null values. Properties or extensions with a null value
+/// cannot be serialized.
+pub fn to_toml_string<T: Serialize>(value: &T) -> Result<String, toml::ser::Error> {
+    toml::to_string(value)
+}
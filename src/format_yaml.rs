@@ -0,0 +1,12 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Deserializes a DAO from the given YAML document.
+pub fn from_yaml_str<T: DeserializeOwned>(s: &str) -> Result<T, serde_yaml::Error> {
+    serde_yaml::from_str(s)
+}
+
+/// Serializes the given DAO as YAML document.
+pub fn to_yaml_string<T: Serialize>(value: &T) -> Result<String, serde_yaml::Error> {
+    serde_yaml::to_string(value)
+}
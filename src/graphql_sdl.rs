@@ -0,0 +1,166 @@
+use std::fmt::Write;
+
+use crate::component_property_key;
+use crate::Component;
+use crate::ComponentOrEntityTypeId;
+use crate::DataType;
+use crate::EntityType;
+use crate::NamespacedTypeGetter;
+use crate::PropertyType;
+use crate::RelationEndpoint;
+use crate::RelationType;
+use crate::TypeSystem;
+
+/// The name of the custom scalar which represents arbitrary JSON values.
+pub const GRAPHQL_JSON_SCALAR: &str = "JSON";
+
+/// Returns the given name with all characters which are not allowed in GraphQL names replaced
+/// by underscores.
+pub fn graphql_name<S: Into<String>>(name: S) -> String {
+    let name: String = name
+        .into()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '_' { c } else { '_' })
+        .collect();
+    match name.chars().next() {
+        Some(c) if c.is_ascii_digit() => format!("_{}", name),
+        Some(_) => name,
+        None => String::from("_"),
+    }
+}
+
+/// Returns the GraphQL type name of the given namespaced type.
+///
+/// The namespace and the type name are converted to pascal case, for example `logical` and
+/// `and_gate` are converted to `LogicalAndGate`.
+pub fn graphql_type_name<T: NamespacedTypeGetter>(ty: &T) -> String {
+    let name = format!("{}_{}", ty.namespace(), ty.type_name());
+    let mut pascal_case = String::new();
+    for part in graphql_name(name).split('_').filter(|part| !part.is_empty()) {
+        let mut chars = part.chars();
+        if let Some(first) = chars.next() {
+            pascal_case.push(first.to_ascii_uppercase());
+            pascal_case.push_str(chars.as_str());
+        }
+    }
+    graphql_name(pascal_case)
+}
+
+/// Returns the GraphQL type of the given data type.
+pub fn data_type_graphql_type(data_type: &DataType) -> String {
+    match data_type {
+        DataType::Bool => String::from("Boolean"),
+        DataType::Number => String::from("Float"),
+        DataType::String => String::from("String"),
+        DataType::Array => format!("[{}]", GRAPHQL_JSON_SCALAR),
+        DataType::Null | DataType::Object | DataType::Any => String::from(GRAPHQL_JSON_SCALAR),
+    }
+}
+
+/// Renders the given type system as GraphQL SDL.
+///
+/// Components are rendered as interfaces and entity types are rendered as object types which
+/// implement the interfaces of their components. Relation types are rendered as object types
+/// with an outbound and an inbound field. Flow types are not rendered.
+///
+/// The fields of the properties of components are named like the keys of the properties in the
+/// instances. Entity types with scoped properties don't implement the interfaces of their
+/// components, because their qualified fields don't match the fields of the interfaces.
+pub fn type_system_to_graphql_sdl(type_system: &TypeSystem) -> String {
+    let mut sdl = String::new();
+    let _ = writeln!(sdl, "scalar {}", GRAPHQL_JSON_SCALAR);
+    for component in type_system.components.iter() {
+        sdl.push('\n');
+        write_component(&mut sdl, component);
+    }
+    for entity_type in type_system.entity_types.iter() {
+        sdl.push('\n');
+        write_entity_type(&mut sdl, type_system, entity_type);
+    }
+    for relation_type in type_system.relation_types.iter() {
+        sdl.push('\n');
+        write_relation_type(&mut sdl, type_system, relation_type);
+    }
+    sdl
+}
+
+fn write_component(sdl: &mut String, component: &Component) {
+    write_description(sdl, &component.description, "");
+    let _ = writeln!(sdl, "interface {} {{", graphql_type_name(&component.ty));
+    sdl.push_str("  id: ID!\n");
+    write_fields(sdl, component.properties.iter().map(|property| (property.name.to_string(), property)));
+    sdl.push_str("}\n");
+}
+
+fn write_entity_type(sdl: &mut String, type_system: &TypeSystem, entity_type: &EntityType) {
+    let components: Vec<&Component> = entity_type.components.iter().filter_map(|ty| type_system.get_component(ty)).collect();
+    write_description(sdl, &entity_type.description, "");
+    let _ = write!(sdl, "type {}", graphql_type_name(&entity_type.ty));
+    if !components.is_empty() && !entity_type.scoped_properties {
+        let interfaces: Vec<String> = components.iter().map(|component| graphql_type_name(&component.ty)).collect();
+        let _ = write!(sdl, " implements {}", interfaces.join(" & "));
+    }
+    sdl.push_str(" {\n");
+    sdl.push_str("  id: ID!\n");
+    // The object type has to contain the fields of all interfaces
+    write_fields(sdl, instance_fields(entity_type.scoped_properties, &entity_type.properties, &components).into_iter());
+    sdl.push_str("}\n");
+}
+
+fn write_relation_type(sdl: &mut String, type_system: &TypeSystem, relation_type: &RelationType) {
+    write_description(sdl, &relation_type.description, "");
+    let _ = writeln!(sdl, "type {} {{", graphql_type_name(&relation_type.ty));
+    let _ = writeln!(
+        sdl,
+        "  outbound: {}!",
+        endpoint_graphql_type(type_system, &relation_type.endpoint_types(RelationEndpoint::Outbound))
+    );
+    let _ = writeln!(
+        sdl,
+        "  inbound: {}!",
+        endpoint_graphql_type(type_system, &relation_type.endpoint_types(RelationEndpoint::Inbound))
+    );
+    let components: Vec<&Component> = relation_type.components.iter().filter_map(|ty| type_system.get_component(ty)).collect();
+    write_fields(sdl, instance_fields(relation_type.scoped_properties, &relation_type.properties, &components).into_iter());
+    sdl.push_str("}\n");
+}
+
+/// Returns the GraphQL type of the outbound or inbound entity instance of a relation type.
+///
+/// Falls back to the JSON scalar if the component or entity type is not part of the type system,
+/// if the type is a wildcard or if multiple types are accepted.
+fn endpoint_graphql_type(type_system: &TypeSystem, tys: &[&ComponentOrEntityTypeId]) -> String {
+    match tys {
+        [ComponentOrEntityTypeId::Component(ty)] if type_system.get_component(ty).is_some() => graphql_type_name(ty),
+        [ComponentOrEntityTypeId::EntityType(ty)] if type_system.get_entity_type(ty).is_some() => graphql_type_name(ty),
+        _ => String::from(GRAPHQL_JSON_SCALAR),
+    }
+}
+
+/// Returns the own properties and the properties of the given components by their keys in the
+/// properties of instances.
+fn instance_fields<'a>(scoped: bool, property_types: &'a [PropertyType], components: &[&'a Component]) -> Vec<(String, &'a PropertyType)> {
+    let mut fields: Vec<(String, &PropertyType)> = property_types.iter().map(|property| (property.name.to_string(), property)).collect();
+    for component in components {
+        for property in component.properties.iter() {
+            let key = component_property_key(scoped, &component.ty, &property.name);
+            if !fields.iter().any(|(field, _)| *field == key) {
+                fields.push((key, property));
+            }
+        }
+    }
+    fields
+}
+
+fn write_fields<'a, I: Iterator<Item = (String, &'a PropertyType)>>(sdl: &mut String, fields: I) {
+    for (name, property) in fields {
+        write_description(sdl, &property.description, "  ");
+        let _ = writeln!(sdl, "  {}: {}", graphql_name(name), data_type_graphql_type(&property.data_type));
+    }
+}
+
+fn write_description(sdl: &mut String, description: &str, indent: &str) {
+    if !description.is_empty() {
+        let _ = writeln!(sdl, "{}\"\"\"{}\"\"\"", indent, description.replace("\"\"\"", "\\\"\"\""));
+    }
+}
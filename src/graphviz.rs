@@ -0,0 +1,38 @@
+use std::fmt::Write;
+
+use uuid::Uuid;
+
+use crate::EntityInstance;
+use crate::RelationInstance;
+
+/// Escapes the given string in order to use it as quoted identifier or label in the DOT language.
+pub fn escape_dot_string<S: Into<String>>(s: S) -> String {
+    s.into().replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Renders the given entity instances and relation instances as Graphviz directed graph.
+///
+/// The nodes are labeled with the entity type and the edges are labeled with the relation type.
+/// The node of the wrapper entity instance is drawn with a double border.
+pub fn flow_to_dot<S: Into<String>>(name: S, wrapper_id: Uuid, entity_instances: &[EntityInstance], relation_instances: &[RelationInstance]) -> String {
+    let mut dot = String::new();
+    let _ = writeln!(dot, "digraph \"{}\" {{", escape_dot_string(name));
+    for entity_instance in entity_instances {
+        let _ = write!(dot, "  \"{}\" [label=\"{}\"", entity_instance.id, escape_dot_string(entity_instance.ty.to_string()));
+        if entity_instance.id == wrapper_id {
+            dot.push_str(", peripheries=2");
+        }
+        dot.push_str("];\n");
+    }
+    for relation_instance in relation_instances {
+        let _ = writeln!(
+            dot,
+            "  \"{}\" -> \"{}\" [label=\"{}\"];",
+            relation_instance.outbound_id,
+            relation_instance.inbound_id,
+            escape_dot_string(relation_instance.relation_type_id().to_string())
+        );
+    }
+    dot.push_str("}\n");
+    dot
+}
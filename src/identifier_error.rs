@@ -0,0 +1,20 @@
+use thiserror::Error;
+
+/// The maximum length in bytes of an identifier.
+pub const MAX_IDENTIFIER_LENGTH: usize = 255;
+
+/// Error of the construction of an identifier, a namespace or a type name.
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum IdentifierError {
+    /// The identifier is empty.
+    #[error("The identifier is empty")]
+    Empty,
+
+    /// The identifier is longer than the maximum length of an identifier.
+    #[error("The identifier is {0} bytes long but at most 255 bytes are allowed")]
+    TooLong(usize),
+
+    /// The namespace or the type name contains the separator of the type id.
+    #[error("{0} must not contain the separator __")]
+    ContainsSeparator(String),
+}
@@ -0,0 +1,169 @@
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::ComponentTypeId;
+use crate::EntityInstance;
+use crate::EntityTypeId;
+use crate::RelationInstance;
+use crate::RelationInstanceTypeId;
+
+/// Identifies an entity instance by its id or a relation instance by its edge key.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum InstanceId {
+    /// The id of an entity instance.
+    Entity(Uuid),
+
+    /// The edge key of a relation instance.
+    Relation {
+        outbound_id: Uuid,
+        ty: RelationInstanceTypeId,
+        inbound_id: Uuid,
+    },
+}
+
+impl From<&EntityInstance> for InstanceId {
+    fn from(entity_instance: &EntityInstance) -> Self {
+        InstanceId::Entity(entity_instance.id)
+    }
+}
+
+impl From<&RelationInstance> for InstanceId {
+    fn from(relation_instance: &RelationInstance) -> Self {
+        InstanceId::Relation {
+            outbound_id: relation_instance.outbound_id,
+            ty: relation_instance.ty.clone(),
+            inbound_id: relation_instance.inbound_id,
+        }
+    }
+}
+
+/// An event of a change of an entity instance or a relation instance.
+///
+/// The events are exchanged by the event buses of the runtime and of the plugins.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum InstanceEvent {
+    /// The entity instance has been created.
+    EntityInstanceCreated { id: Uuid, ty: EntityTypeId },
+
+    /// The entity instance has been deleted.
+    EntityInstanceDeleted { id: Uuid, ty: EntityTypeId },
+
+    /// The relation instance has been created.
+    RelationInstanceCreated {
+        outbound_id: Uuid,
+        ty: RelationInstanceTypeId,
+        inbound_id: Uuid,
+    },
+
+    /// The relation instance has been deleted.
+    RelationInstanceDeleted {
+        outbound_id: Uuid,
+        ty: RelationInstanceTypeId,
+        inbound_id: Uuid,
+    },
+
+    /// The component has been added to the instance.
+    ComponentAdded { instance: InstanceId, component: ComponentTypeId },
+
+    /// The component has been removed from the instance.
+    ComponentRemoved { instance: InstanceId, component: ComponentTypeId },
+
+    /// The value of the property has been changed. The old value is none if the property has been
+    /// added and the new value is none if the property has been removed. A null value is kept
+    /// apart from an absent value.
+    PropertyChanged {
+        instance: InstanceId,
+        property_name: String,
+        #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "deserialize_present_value")]
+        old_value: Option<Value>,
+        #[serde(default, skip_serializing_if = "Option::is_none", deserialize_with = "deserialize_present_value")]
+        new_value: Option<Value>,
+    },
+}
+
+impl InstanceEvent {
+    /// Returns the event of the creation of the given entity instance.
+    pub fn entity_instance_created(entity_instance: &EntityInstance) -> InstanceEvent {
+        InstanceEvent::EntityInstanceCreated {
+            id: entity_instance.id,
+            ty: entity_instance.ty.clone(),
+        }
+    }
+
+    /// Returns the event of the deletion of the given entity instance.
+    pub fn entity_instance_deleted(entity_instance: &EntityInstance) -> InstanceEvent {
+        InstanceEvent::EntityInstanceDeleted {
+            id: entity_instance.id,
+            ty: entity_instance.ty.clone(),
+        }
+    }
+
+    /// Returns the event of the creation of the given relation instance.
+    pub fn relation_instance_created(relation_instance: &RelationInstance) -> InstanceEvent {
+        InstanceEvent::RelationInstanceCreated {
+            outbound_id: relation_instance.outbound_id,
+            ty: relation_instance.ty.clone(),
+            inbound_id: relation_instance.inbound_id,
+        }
+    }
+
+    /// Returns the event of the deletion of the given relation instance.
+    pub fn relation_instance_deleted(relation_instance: &RelationInstance) -> InstanceEvent {
+        InstanceEvent::RelationInstanceDeleted {
+            outbound_id: relation_instance.outbound_id,
+            ty: relation_instance.ty.clone(),
+            inbound_id: relation_instance.inbound_id,
+        }
+    }
+
+    /// Returns the events of the changes from the old properties to the new properties of the
+    /// given instance, ordered by property name.
+    pub fn property_changes(instance: InstanceId, old: &HashMap<String, Value>, new: &HashMap<String, Value>) -> Vec<InstanceEvent> {
+        let property_names: BTreeSet<&String> = old.keys().chain(new.keys()).collect();
+        property_names
+            .into_iter()
+            .filter(|property_name| old.get(*property_name) != new.get(*property_name))
+            .map(|property_name| InstanceEvent::PropertyChanged {
+                instance: instance.clone(),
+                property_name: property_name.clone(),
+                old_value: old.get(property_name).cloned(),
+                new_value: new.get(property_name).cloned(),
+            })
+            .collect()
+    }
+
+    /// Returns the changed instance.
+    pub fn instance(&self) -> InstanceId {
+        match self {
+            InstanceEvent::EntityInstanceCreated { id, .. } | InstanceEvent::EntityInstanceDeleted { id, .. } => InstanceId::Entity(*id),
+            InstanceEvent::RelationInstanceCreated { outbound_id, ty, inbound_id } | InstanceEvent::RelationInstanceDeleted { outbound_id, ty, inbound_id } => {
+                InstanceId::Relation {
+                    outbound_id: *outbound_id,
+                    ty: ty.clone(),
+                    inbound_id: *inbound_id,
+                }
+            }
+            InstanceEvent::ComponentAdded { instance, .. }
+            | InstanceEvent::ComponentRemoved { instance, .. }
+            | InstanceEvent::PropertyChanged { instance, .. } => instance.clone(),
+        }
+    }
+}
+
+/// Deserializes a present value, including null, as some value. Absent values default to none.
+fn deserialize_present_value<'de, D>(deserializer: D) -> Result<Option<Value>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Value::deserialize(deserializer).map(Some)
+}
@@ -0,0 +1,213 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::EntityInstance;
+use crate::NamespacedType;
+use crate::NamespacedTypeGetter;
+use crate::RelationInstance;
+
+/// The operator of a comparison of a property value with a given value.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ComparisonOperator {
+    /// The property value equals the given value.
+    Equals,
+
+    /// The property value doesn't equal the given value.
+    NotEquals,
+
+    /// The property value is less than the given value.
+    LessThan,
+
+    /// The property value is less than or equal to the given value.
+    LessThanOrEqual,
+
+    /// The property value is greater than the given value.
+    GreaterThan,
+
+    /// The property value is greater than or equal to the given value.
+    GreaterThanOrEqual,
+
+    /// The string property value contains the given string or the array property value contains
+    /// the given value.
+    Contains,
+
+    /// The string property value starts with the given string.
+    StartsWith,
+
+    /// The string property value ends with the given string.
+    EndsWith,
+}
+
+impl ComparisonOperator {
+    /// Compares the given property value with the given value.
+    ///
+    /// Numbers are compared numerically, so `1` equals `1.0`, and strings lexicographically. Other
+    /// values can only be compared for equality.
+    pub fn compare(&self, property_value: &Value, value: &Value) -> bool {
+        match self {
+            ComparisonOperator::Equals => values_equal(property_value, value),
+            ComparisonOperator::NotEquals => !values_equal(property_value, value),
+            ComparisonOperator::LessThan => compare_values(property_value, value) == Some(Ordering::Less),
+            ComparisonOperator::LessThanOrEqual => matches!(compare_values(property_value, value), Some(Ordering::Less | Ordering::Equal)),
+            ComparisonOperator::GreaterThan => compare_values(property_value, value) == Some(Ordering::Greater),
+            ComparisonOperator::GreaterThanOrEqual => matches!(compare_values(property_value, value), Some(Ordering::Greater | Ordering::Equal)),
+            ComparisonOperator::Contains => match (property_value, value) {
+                (Value::String(property_value), Value::String(value)) => property_value.contains(value.as_str()),
+                (Value::Array(property_values), value) => property_values.contains(value),
+                _ => false,
+            },
+            ComparisonOperator::StartsWith => match (property_value, value) {
+                (Value::String(property_value), Value::String(value)) => property_value.starts_with(value.as_str()),
+                _ => false,
+            },
+            ComparisonOperator::EndsWith => match (property_value, value) {
+                (Value::String(property_value), Value::String(value)) => property_value.ends_with(value.as_str()),
+                _ => false,
+            },
+        }
+    }
+}
+
+/// A filter of entity instances or relation instances.
+///
+/// The filter is shared by the GraphQL API, the REST API and the datastore, which translates it
+/// into native queries. Relation instances match the type of their relation type regardless of
+/// their instance id.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum InstanceFilter {
+    /// Matches instances of the given type.
+    Type(NamespacedType),
+
+    /// Matches instances which have the given property.
+    HasProperty(String),
+
+    /// Matches instances whose property value compares to the given value. Instances without the
+    /// property don't match.
+    Property {
+        /// The name of the property.
+        name: String,
+
+        /// The comparison operator.
+        operator: ComparisonOperator,
+
+        /// The value to compare the property value with.
+        value: Value,
+    },
+
+    /// Matches instances which match all of the given filters.
+    And(Vec<InstanceFilter>),
+
+    /// Matches instances which match any of the given filters.
+    Or(Vec<InstanceFilter>),
+
+    /// Matches instances which don't match the given filter.
+    Not(Box<InstanceFilter>),
+}
+
+impl InstanceFilter {
+    /// Matches instances of the given type.
+    pub fn type_equals<T: NamespacedTypeGetter>(ty: &T) -> InstanceFilter {
+        InstanceFilter::Type(NamespacedType::new(ty.namespace(), ty.type_name()))
+    }
+
+    /// Matches instances which have the given property.
+    pub fn has_property<S: Into<String>>(name: S) -> InstanceFilter {
+        InstanceFilter::HasProperty(name.into())
+    }
+
+    /// Matches instances whose property value compares to the given value.
+    pub fn property<S: Into<String>>(name: S, operator: ComparisonOperator, value: Value) -> InstanceFilter {
+        InstanceFilter::Property {
+            name: name.into(),
+            operator,
+            value,
+        }
+    }
+
+    /// Matches instances whose property value equals the given value.
+    pub fn property_equals<S: Into<String>>(name: S, value: Value) -> InstanceFilter {
+        InstanceFilter::property(name, ComparisonOperator::Equals, value)
+    }
+
+    /// Matches instances which match this filter and the other filter.
+    pub fn and(self, other: InstanceFilter) -> InstanceFilter {
+        match self {
+            InstanceFilter::And(mut filters) => {
+                filters.push(other);
+                InstanceFilter::And(filters)
+            }
+            filter => InstanceFilter::And(vec![filter, other]),
+        }
+    }
+
+    /// Matches instances which match this filter or the other filter.
+    pub fn or(self, other: InstanceFilter) -> InstanceFilter {
+        match self {
+            InstanceFilter::Or(mut filters) => {
+                filters.push(other);
+                InstanceFilter::Or(filters)
+            }
+            filter => InstanceFilter::Or(vec![filter, other]),
+        }
+    }
+
+    /// Matches instances which don't match this filter.
+    pub fn negate(self) -> InstanceFilter {
+        match self {
+            InstanceFilter::Not(filter) => *filter,
+            filter => InstanceFilter::Not(Box::new(filter)),
+        }
+    }
+
+    /// Returns true, if the given entity instance matches the filter.
+    pub fn matches_entity_instance(&self, entity_instance: &EntityInstance) -> bool {
+        self.matches(&entity_instance.namespace(), &entity_instance.type_name(), &entity_instance.properties)
+    }
+
+    /// Returns true, if the given relation instance matches the filter.
+    pub fn matches_relation_instance(&self, relation_instance: &RelationInstance) -> bool {
+        let ty = relation_instance.relation_type_id();
+        self.matches(&ty.namespace(), &ty.type_name(), &relation_instance.properties)
+    }
+
+    /// Returns true, if an instance of the given type with the given properties matches the
+    /// filter.
+    pub fn matches(&self, namespace: &str, type_name: &str, properties: &HashMap<String, Value>) -> bool {
+        match self {
+            InstanceFilter::Type(ty) => ty.namespace() == namespace && ty.type_name() == type_name,
+            InstanceFilter::HasProperty(name) => properties.contains_key(name),
+            InstanceFilter::Property { name, operator, value } => properties.get(name).is_some_and(|property_value| operator.compare(property_value, value)),
+            InstanceFilter::And(filters) => filters.iter().all(|filter| filter.matches(namespace, type_name, properties)),
+            InstanceFilter::Or(filters) => filters.iter().any(|filter| filter.matches(namespace, type_name, properties)),
+            InstanceFilter::Not(filter) => !filter.matches(namespace, type_name, properties),
+        }
+    }
+}
+
+/// Compares numbers numerically and other values by equality.
+pub(crate) fn values_equal(property_value: &Value, value: &Value) -> bool {
+    match (property_value, value) {
+        (Value::Number(_), Value::Number(_)) => compare_values(property_value, value) == Some(Ordering::Equal),
+        _ => property_value == value,
+    }
+}
+
+/// Compares numbers numerically and strings lexicographically. Other values aren't ordered.
+pub(crate) fn compare_values(property_value: &Value, value: &Value) -> Option<Ordering> {
+    match (property_value, value) {
+        (Value::Number(property_value), Value::Number(value)) => match (property_value.as_i64(), value.as_i64()) {
+            (Some(property_value), Some(value)) => Some(property_value.cmp(&value)),
+            _ => property_value.as_f64()?.partial_cmp(&value.as_f64()?),
+        },
+        (Value::String(property_value), Value::String(value)) => Some(property_value.cmp(value)),
+        _ => None,
+    }
+}
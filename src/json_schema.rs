@@ -0,0 +1,120 @@
+use serde_json::json;
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::identifier_string;
+use crate::Component;
+use crate::DataType;
+use crate::EntityType;
+use crate::NamespacedTypeGetter;
+use crate::PropertyType;
+
+/// The JSON Schema dialect of the generated schemas.
+pub const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Returns the JSON Schema of a value of the given data type.
+pub fn data_type_json_schema(data_type: &DataType) -> Value {
+    match data_type {
+        DataType::Null => json!({ "type": "null" }),
+        DataType::Bool => json!({ "type": "boolean" }),
+        DataType::Number => json!({ "type": "number" }),
+        DataType::String => json!({ "type": "string" }),
+        DataType::Array => json!({ "type": "array" }),
+        DataType::Object => json!({ "type": "object" }),
+        DataType::Any => json!({}),
+    }
+}
+
+/// Returns the JSON Schema of a value of the given property type.
+pub fn property_type_json_schema(property_type: &PropertyType) -> Value {
+    let mut schema = data_type_json_schema(&property_type.data_type);
+    if let Some(schema) = schema.as_object_mut() {
+        if !property_type.description.is_empty() {
+            schema.insert("description".to_string(), json!(property_type.description));
+        }
+    }
+    schema
+}
+
+/// Returns the JSON Schema of the properties of an instance with the given property types.
+///
+/// Each property type is required.
+pub fn properties_json_schema(property_types: &[PropertyType]) -> Value {
+    let mut properties = Map::new();
+    let mut required = Vec::new();
+    for property_type in property_types {
+        properties.insert(property_type.name.to_string(), property_type_json_schema(property_type));
+        required.push(json!(property_type.name));
+    }
+    json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+    })
+}
+
+/// Returns the JSON Schema of entity instance documents of the given entity type.
+///
+/// The properties of the entity instance are the properties of entity type and the properties
+/// of the components of the entity type. Components which are not contained in the given list of
+/// components are ignored. Properties of the entity type takes precedence over properties of the
+/// components with the same name.
+pub fn entity_instance_json_schema(entity_type: &EntityType, components: &[Component]) -> Value {
+    let mut property_types: Vec<PropertyType> = entity_type.properties.to_vec();
+    for component in components.iter().filter(|component| entity_type.components.contains(&component.ty)) {
+        for property_type in component.properties.iter() {
+            let mut property_type = property_type.clone();
+            property_type.name = identifier_string(entity_type.component_property_key(&component.ty, &property_type.name));
+            if !property_types.iter().any(|p| p.name == property_type.name) {
+                property_types.push(property_type);
+            }
+        }
+    }
+    json!({
+        "$schema": JSON_SCHEMA_DIALECT,
+        "title": entity_type.ty.to_string(),
+        "description": entity_type.description,
+        "type": "object",
+        "properties": {
+            "namespace": {
+                "type": "string",
+                "const": entity_type.namespace(),
+            },
+            "type_name": {
+                "type": "string",
+                "const": entity_type.type_name(),
+            },
+            "id": {
+                "type": "string",
+                "format": "uuid",
+            },
+            "description": {
+                "type": "string",
+            },
+            "properties": properties_json_schema(&property_types),
+            "extensions": {
+                "type": "array",
+            },
+            "created_at": {
+                "type": "string",
+                "format": "date-time",
+            },
+            "updated_at": {
+                "type": "string",
+                "format": "date-time",
+            },
+            "created_by": {
+                "type": "string",
+            },
+            "deleted_at": {
+                "type": "string",
+                "format": "date-time",
+            },
+            "revision": {
+                "type": "integer",
+                "minimum": 0,
+            },
+        },
+        "required": ["namespace", "type_name", "id"],
+    })
+}
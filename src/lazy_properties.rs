@@ -0,0 +1,419 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::OnceLock;
+
+use chrono::DateTime;
+use chrono::Utc;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde::Serializer;
+use serde_json::value::RawValue;
+use serde_json::Map;
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::is_initial_revision;
+use crate::EntityInstance;
+use crate::EntityTypeId;
+use crate::Extension;
+use crate::FlowInstance;
+use crate::PropertyInstanceGetter;
+use crate::PropertyInstanceRefGetter;
+use crate::RelationInstance;
+use crate::RelationInstanceTypeId;
+use crate::RelationTypeId;
+use crate::SubFlowReference;
+
+/// Property value which is kept as unparsed JSON until it is accessed the first time.
+///
+/// The unparsed JSON is captured with `serde_json::value::RawValue`, so a lazy value can only be
+/// deserialized by `serde_json` from a string, a slice or a reader. Deserializing from a
+/// `serde_json::Value`, from other formats or inside of a `#[serde(flatten)]` or untagged
+/// container fails.
+pub struct LazyValue {
+    raw: Box<RawValue>,
+    value: OnceLock<Value>,
+}
+
+impl LazyValue {
+    /// Returns the unparsed JSON of the property value.
+    pub fn raw(&self) -> &str {
+        self.raw.get()
+    }
+
+    /// Returns true, if the property value has been parsed already.
+    pub fn is_parsed(&self) -> bool {
+        self.value.get().is_some()
+    }
+
+    /// Returns the property value. Parses the JSON on first access.
+    ///
+    /// Fails if the unparsed JSON is not a valid json value, in which case nothing is cached.
+    pub fn value(&self) -> serde_json::Result<&Value> {
+        if let Some(value) = self.value.get() {
+            return Ok(value);
+        }
+        let value = serde_json::from_str(self.raw.get())?;
+        Ok(self.value.get_or_init(|| value))
+    }
+
+    /// Deserializes the property value into the given type without constructing a json value.
+    pub fn parse<T: DeserializeOwned>(&self) -> serde_json::Result<T> {
+        serde_json::from_str(self.raw.get())
+    }
+
+    /// Returns the property value.
+    pub fn into_value(self) -> serde_json::Result<Value> {
+        match self.value.into_inner() {
+            Some(value) => Ok(value),
+            None => serde_json::from_str(self.raw.get()),
+        }
+    }
+}
+
+impl Clone for LazyValue {
+    fn clone(&self) -> Self {
+        LazyValue {
+            raw: self.raw.clone(),
+            value: self.value.clone(),
+        }
+    }
+}
+
+impl fmt::Debug for LazyValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.raw.get())
+    }
+}
+
+impl From<Box<RawValue>> for LazyValue {
+    fn from(raw: Box<RawValue>) -> Self {
+        LazyValue { raw, value: OnceLock::new() }
+    }
+}
+
+impl Serialize for LazyValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.raw.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for LazyValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Box::<RawValue>::deserialize(deserializer).map(LazyValue::from)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for LazyValue {
+    fn schema_name() -> String {
+        Value::schema_name()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        Value::json_schema(gen)
+    }
+}
+
+/// Properties whose values are parsed on first access.
+///
+/// The property getters treat a value which can't be parsed as missing.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(transparent)]
+pub struct LazyProperties(HashMap<String, LazyValue>);
+
+impl LazyProperties {
+    /// Returns the unparsed JSON of the given property.
+    pub fn get_raw(&self, property_name: &str) -> Option<&str> {
+        self.0.get(property_name).map(LazyValue::raw)
+    }
+
+    /// Deserializes the value of the given property into the given type.
+    pub fn parse<T: DeserializeOwned>(&self, property_name: &str) -> Option<serde_json::Result<T>> {
+        self.0.get(property_name).map(LazyValue::parse)
+    }
+
+    /// Returns true, if the given property exists.
+    pub fn contains(&self, property_name: &str) -> bool {
+        self.0.contains_key(property_name)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl PropertyInstanceGetter for LazyProperties {
+    fn get<S: Into<String>>(&self, property_name: S) -> Option<Value> {
+        self.get_ref(&property_name.into()).cloned()
+    }
+
+    fn as_bool<S: Into<String>>(&self, property_name: S) -> Option<bool> {
+        self.get_ref(&property_name.into()).and_then(|p| p.as_bool())
+    }
+
+    fn as_u64<S: Into<String>>(&self, property_name: S) -> Option<u64> {
+        self.get_ref(&property_name.into()).and_then(|p| p.as_u64())
+    }
+
+    fn as_i64<S: Into<String>>(&self, property_name: S) -> Option<i64> {
+        self.get_ref(&property_name.into()).and_then(|p| p.as_i64())
+    }
+
+    fn as_f64<S: Into<String>>(&self, property_name: S) -> Option<f64> {
+        self.get_ref(&property_name.into()).and_then(|p| p.as_f64())
+    }
+
+    fn as_string<S: Into<String>>(&self, property_name: S) -> Option<String> {
+        self.get_ref(&property_name.into()).and_then(|p| p.as_str().map(|s| s.to_string()))
+    }
+
+    fn as_array<S: Into<String>>(&self, property_name: S) -> Option<Vec<Value>> {
+        self.get_ref(&property_name.into()).and_then(|p| p.as_array().map(Vec::clone))
+    }
+
+    fn as_object<S: Into<String>>(&self, property_name: S) -> Option<Map<String, Value>> {
+        self.get_ref(&property_name.into()).and_then(|p| p.as_object().map(Map::clone))
+    }
+}
+
+impl PropertyInstanceRefGetter for LazyProperties {
+    fn get_ref(&self, property_name: &str) -> Option<&Value> {
+        self.0.get(property_name).and_then(|value| value.value().ok())
+    }
+}
+
+impl TryFrom<LazyProperties> for HashMap<String, Value> {
+    type Error = serde_json::Error;
+
+    fn try_from(properties: LazyProperties) -> Result<Self, Self::Error> {
+        properties.0.into_iter().map(|(name, value)| Ok((name, value.into_value()?))).collect()
+    }
+}
+
+/// Entity instance whose property values are parsed on first access.
+///
+/// Has the same JSON representation as an entity instance.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct LazyEntityInstance {
+    /// The namespace of the entity type.
+    #[serde(default = "String::new")]
+    pub namespace: String,
+
+    /// The name of the entity type.
+    #[serde(alias = "name")]
+    pub type_name: String,
+
+    /// The unique identifier of the entity instance.
+    pub id: Uuid,
+
+    /// The description of the entity instance.
+    #[serde(default = "String::new")]
+    pub description: String,
+
+    /// The properties of the entity instance.
+    #[serde(default)]
+    pub properties: LazyProperties,
+
+    /// Entity instance specific extensions.
+    #[serde(default = "Vec::new")]
+    pub extensions: Vec<Extension>,
+
+    /// The point of time when the entity instance has been created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+
+    /// The point of time when the entity instance has been updated for the last time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+
+    /// The user or the service which has created the entity instance.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<String>,
+
+    /// The point of time when the entity instance has been deleted. Deleted entity instances are kept as
+    /// tombstones until they are purged, so that the deletion can be undone or synchronized.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<DateTime<Utc>>,
+
+    /// The revision of the entity instance, which is incremented by each modification. Concurrent editors
+    /// compare the revision in order to detect conflicting writes.
+    #[serde(default, skip_serializing_if = "is_initial_revision")]
+    pub revision: u64,
+}
+
+impl TryFrom<LazyEntityInstance> for EntityInstance {
+    type Error = serde_json::Error;
+
+    fn try_from(entity_instance: LazyEntityInstance) -> Result<Self, Self::Error> {
+        Ok(EntityInstance {
+            ty: EntityTypeId::new_from_type(entity_instance.namespace, entity_instance.type_name),
+            id: entity_instance.id,
+            description: entity_instance.description,
+            properties: HashMap::try_from(entity_instance.properties)?.into(),
+            extensions: entity_instance.extensions,
+            created_at: entity_instance.created_at,
+            updated_at: entity_instance.updated_at,
+            created_by: entity_instance.created_by,
+            deleted_at: entity_instance.deleted_at,
+            revision: entity_instance.revision,
+            extra: Map::new(),
+        })
+    }
+}
+
+/// Relation instance whose property values are parsed on first access.
+///
+/// Has the same JSON representation as a relation instance.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct LazyRelationInstance {
+    /// The id of the outbound entity instance.
+    pub outbound_id: Uuid,
+
+    /// The namespace of the relation type.
+    #[serde(default = "String::new")]
+    pub namespace: String,
+
+    /// The name of the relation type.
+    #[serde(alias = "name")]
+    pub type_name: String,
+
+    /// The instance id of the relation instance.
+    #[serde(default = "String::new")]
+    pub instance_id: String,
+
+    /// The id of the inbound entity instance.
+    pub inbound_id: Uuid,
+
+    /// The description of the relation instance.
+    #[serde(default = "String::new")]
+    pub description: String,
+
+    /// The properties of the relation instance.
+    #[serde(default)]
+    pub properties: LazyProperties,
+
+    /// Relation instance specific extensions.
+    #[serde(default = "Vec::new")]
+    pub extensions: Vec<Extension>,
+
+    /// The point of time when the relation instance has been created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+
+    /// The point of time when the relation instance has been updated for the last time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+
+    /// The user or the service which has created the relation instance.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<String>,
+
+    /// The point of time when the relation instance has been deleted. Deleted relation instances are kept as
+    /// tombstones until they are purged, so that the deletion can be undone or synchronized.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<DateTime<Utc>>,
+
+    /// The revision of the relation instance, which is incremented by each modification. Concurrent editors
+    /// compare the revision in order to detect conflicting writes.
+    #[serde(default, skip_serializing_if = "is_initial_revision")]
+    pub revision: u64,
+}
+
+impl TryFrom<LazyRelationInstance> for RelationInstance {
+    type Error = serde_json::Error;
+
+    fn try_from(relation_instance: LazyRelationInstance) -> Result<Self, Self::Error> {
+        let ty = RelationTypeId::new_from_type(relation_instance.namespace, relation_instance.type_name);
+        Ok(RelationInstance {
+            outbound_id: relation_instance.outbound_id,
+            ty: RelationInstanceTypeId::new_unique_for_instance_id(ty, relation_instance.instance_id),
+            inbound_id: relation_instance.inbound_id,
+            description: relation_instance.description,
+            properties: HashMap::try_from(relation_instance.properties)?.into(),
+            extensions: relation_instance.extensions,
+            created_at: relation_instance.created_at,
+            updated_at: relation_instance.updated_at,
+            created_by: relation_instance.created_by,
+            deleted_at: relation_instance.deleted_at,
+            revision: relation_instance.revision,
+            extra: Map::new(),
+        })
+    }
+}
+
+/// Flow instance whose property values are parsed on first access.
+///
+/// Has the same JSON representation as a flow instance.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct LazyFlowInstance {
+    /// The id of the flow instance which is also the id of the wrapper entity instance.
+    pub id: Uuid,
+
+    /// The namespace of the entity type of the wrapper entity instance.
+    #[serde(default = "String::new")]
+    pub namespace: String,
+
+    /// The name of the entity type of the wrapper entity instance.
+    pub type_name: String,
+
+    /// The name of the flow instance.
+    #[serde(default = "String::new")]
+    pub name: String,
+
+    /// Textual description of the flow instance.
+    #[serde(default = "String::new")]
+    pub description: String,
+
+    /// The entity instances which are contained in the flow instance.
+    #[serde(default = "Vec::new", alias = "entities")]
+    pub entity_instances: Vec<LazyEntityInstance>,
+
+    /// The relation instances which are contained in the flow instance.
+    #[serde(default = "Vec::new", alias = "relations")]
+    pub relation_instances: Vec<LazyRelationInstance>,
+
+    /// The ids of the external entity instances which are referenced by the relation instances.
+    #[serde(default = "Vec::new", alias = "externals")]
+    pub external_entity_ids: Vec<Uuid>,
+
+    /// The references to the embedded child flow instances.
+    #[serde(default = "Vec::new")]
+    pub sub_flows: Vec<SubFlowReference>,
+}
+
+impl TryFrom<LazyFlowInstance> for FlowInstance {
+    type Error = serde_json::Error;
+
+    fn try_from(flow_instance: LazyFlowInstance) -> Result<Self, Self::Error> {
+        Ok(FlowInstance {
+            id: flow_instance.id,
+            ty: EntityTypeId::new_from_type(flow_instance.namespace, flow_instance.type_name),
+            name: flow_instance.name,
+            description: flow_instance.description,
+            entity_instances: flow_instance
+                .entity_instances
+                .into_iter()
+                .map(EntityInstance::try_from)
+                .collect::<Result<Vec<_>, _>>()?,
+            relation_instances: flow_instance
+                .relation_instances
+                .into_iter()
+                .map(RelationInstance::try_from)
+                .collect::<Result<Vec<_>, _>>()?,
+            external_entity_ids: flow_instance.external_entity_ids,
+            sub_flows: flow_instance.sub_flows,
+            extra: Map::new(),
+        })
+    }
+}
@@ -2,28 +2,98 @@
 #![feature(test)]
 #![register_tool(tarpaulin)]
 
+// Allows the macros to refer to the types of this crate by the name of this crate
+extern crate self as inexor_rgf_core_model;
+
+#[cfg(feature = "inventory")]
+pub use inventory;
+
+pub use inexor_rgf_core_model_macros::ty;
+
+pub use aggregation::*;
+#[cfg(feature = "proptest")]
+pub use arbitrary::*;
+pub use behaviour_state::*;
 pub use behaviour_type_id::*;
+pub use behaviour_type_id_dao::*;
+pub use borrowed_dao::*;
+pub use bulk_insert::*;
+pub use cardinality::*;
+pub use compact_debug::*;
 pub use component::*;
 pub use component_behaviour_type_id::*;
 pub use component_container::*;
+pub use component_dao::*;
 pub use component_or_entity_type_id::*;
 pub use component_type_id::*;
+pub use concurrent_properties::*;
+pub use connector::*;
+pub use content_hash::*;
+pub use cow_properties::*;
+pub use dao_parse_limits::*;
+pub use dao_parse_mode::*;
 pub use data_type::*;
+pub use deprecation::*;
+pub use documentation::*;
+pub use embedded_type::*;
 pub use entity_behaviour_type_id::*;
 pub use entity_component_type_id::*;
 pub use entity_instance::*;
+pub use entity_instance_dao::*;
 pub use entity_type::*;
+pub use entity_type_dao::*;
 pub use entity_type_id::*;
+pub use entity_type_inheritance::*;
 pub use extension::*;
 pub use extension_container::*;
 pub use extension_type_id::*;
+#[cfg(feature = "fixtures")]
+pub use fixtures::*;
 pub use flow_instance::*;
+pub use flow_instance_dao::*;
+pub use flow_instance_isomorphism::*;
+pub use flow_instance_merge::*;
+pub use flow_instance_merkle::*;
+pub use flow_instance_statistics::*;
 pub use flow_type::*;
+pub use flow_type_dao::*;
 pub use flow_type_id::*;
+pub use flow_type_local_id::*;
+pub use flow_type_parameter::*;
+pub use flow_type_port::*;
+pub use format_compatibility::*;
+#[cfg(feature = "rmp-serde")]
+pub use format_msgpack::*;
+#[cfg(feature = "ron")]
+pub use format_ron::*;
+#[cfg(feature = "toml")]
+pub use format_toml::*;
+#[cfg(feature = "serde_yaml")]
+pub use format_yaml::*;
+pub use graphql_sdl::*;
+pub use graphviz::*;
+pub use identifier_error::*;
+pub use instance_event::*;
+pub use instance_filter::*;
+pub use json_schema::*;
+#[cfg(feature = "raw_value")]
+pub use lazy_properties::*;
+pub use localized_description::*;
+pub use model_error::*;
 pub use mutability::*;
+pub use namespace::*;
+pub use node_geometry::*;
+pub use openapi::*;
+pub use ordered_properties::*;
+pub use pagination::*;
 pub use property::*;
+pub use property_conflict::*;
+pub use property_error::*;
 pub use property_identifier::*;
 pub use property_instance_accessor::*;
+pub use property_layout::*;
+pub use property_projection::*;
+pub use property_provenance::*;
 pub use property_type::*;
 pub use reactive_behaviour_container::*;
 pub use reactive_entity_instance::*;
@@ -34,58 +104,181 @@ pub use reactive_property_instance::*;
 pub use reactive_relation_instance::*;
 pub use relation_behaviour_type_id::*;
 pub use relation_component_type_id::*;
+pub use relation_endpoint::*;
 pub use relation_instance::*;
+pub use relation_instance_dao::*;
 pub use relation_instance_type_id::*;
 pub use relation_type::*;
+pub use relation_type_dao::*;
 pub use relation_type_id::*;
+pub use reserved_namespaces::*;
+pub use revision::*;
+pub use sanitize::*;
+pub use scoped_property::*;
+pub use snapshot::*;
 pub use socket_type::*;
+pub use static_type_id::*;
+pub use sub_flow::*;
+pub use sub_flow_type::*;
+pub use tag_container::*;
+#[cfg(feature = "testing")]
+pub use testing::*;
+pub use type_appearance::*;
 pub use type_container::*;
 pub use type_definition::*;
 pub use type_definition_component::*;
 pub use type_definition_extension::*;
 pub use type_definition_property::*;
+pub use type_example::*;
+pub use type_id_serde::*;
 pub use type_id_type::*;
 pub use type_namespaced_type::*;
+pub use type_package::*;
+pub use type_provider::*;
+pub use type_search::*;
+pub use type_system::*;
+pub use type_system_bundle::*;
+pub use type_system_event::*;
+pub use type_system_import::*;
+pub use unit::*;
+pub use unit_error::*;
+pub use validation_error::*;
+#[cfg(feature = "prost")]
+pub use wire::*;
 
+pub mod aggregation;
+#[cfg(feature = "proptest")]
+pub mod arbitrary;
+pub mod behaviour_state;
 pub mod behaviour_type_id;
+pub mod behaviour_type_id_dao;
+pub mod borrowed_dao;
+pub mod bulk_insert;
+pub mod cardinality;
+pub mod compact_debug;
 pub mod component;
 pub mod component_behaviour_type_id;
 pub mod component_container;
+pub mod component_dao;
 pub mod component_or_entity_type_id;
 pub mod component_type_id;
+pub mod concurrent_properties;
+pub mod connector;
+pub mod content_hash;
+pub mod cow_properties;
+pub mod dao_parse_limits;
+pub mod dao_parse_mode;
 pub mod data_type;
+pub mod deprecation;
+pub mod documentation;
+pub mod embedded_type;
 pub mod entity_behaviour_type_id;
 pub mod entity_component_type_id;
 pub mod entity_type;
+pub mod entity_type_dao;
 pub mod entity_type_id;
+pub mod entity_type_inheritance;
 pub mod extension;
 pub mod extension_container;
 pub mod extension_type_id;
+#[cfg(feature = "fixtures")]
+pub mod fixtures;
+pub mod flow_instance_isomorphism;
 pub mod flow_type;
+pub mod flow_type_dao;
 pub mod flow_type_id;
+pub mod flow_type_local_id;
+pub mod flow_type_parameter;
+pub mod flow_type_port;
+pub mod format_compatibility;
+#[cfg(feature = "rmp-serde")]
+pub mod format_msgpack;
+#[cfg(feature = "ron")]
+pub mod format_ron;
+#[cfg(feature = "toml")]
+pub mod format_toml;
+#[cfg(feature = "serde_yaml")]
+pub mod format_yaml;
+pub mod graphql_sdl;
+pub mod graphviz;
+pub mod identifier_error;
+pub mod instance_event;
+pub mod instance_filter;
+pub mod json_schema;
+#[cfg(feature = "raw_value")]
+pub mod lazy_properties;
+pub mod localized_description;
+pub mod model_error;
 pub mod mutability;
+pub mod namespace;
+pub mod node_geometry;
+pub mod openapi;
+pub mod ordered_properties;
+pub mod pagination;
 pub mod property;
+pub mod property_conflict;
+pub mod property_error;
 pub mod property_identifier;
 pub mod property_instance_accessor;
+pub mod property_layout;
+pub mod property_projection;
+pub mod property_provenance;
 pub mod property_type;
 pub mod reactive_behaviour_container;
 pub mod reactive_property_container;
 pub mod relation_behaviour_type_id;
 pub mod relation_component_type_id;
+pub mod relation_endpoint;
 pub mod relation_type;
+pub mod relation_type_dao;
 pub mod relation_type_id;
+pub mod reserved_namespaces;
+pub mod revision;
+pub mod sanitize;
+pub mod scoped_property;
+pub mod snapshot;
 pub mod socket_type;
+pub mod static_type_id;
+pub mod sub_flow;
+pub mod sub_flow_type;
+pub mod tag_container;
+#[cfg(any(test, feature = "test-utils"))]
+#[tarpaulin::skip]
+pub mod test_utils;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod type_appearance;
 pub mod type_container;
 pub mod type_definition;
 pub mod type_definition_component;
 pub mod type_definition_extension;
 pub mod type_definition_property;
+pub mod type_example;
+pub mod type_id_serde;
 pub mod type_id_type;
 pub mod type_namespaced_type;
+pub mod type_package;
+pub mod type_provider;
+pub mod type_search;
+pub mod type_system;
+pub mod type_system_bundle;
+pub mod type_system_event;
+pub mod type_system_import;
+pub mod unit;
+pub mod unit_error;
+pub mod validation_error;
+#[cfg(feature = "prost")]
+pub mod wire;
 
 pub mod entity_instance;
+pub mod entity_instance_dao;
 pub mod flow_instance;
+pub mod flow_instance_dao;
+pub mod flow_instance_merge;
+pub mod flow_instance_merkle;
+pub mod flow_instance_statistics;
 pub mod relation_instance;
+pub mod relation_instance_dao;
 pub mod relation_instance_type_id;
 
 pub mod reactive_entity_instance;
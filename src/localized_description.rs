@@ -0,0 +1,120 @@
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use serde::Deserialize;
+use serde::Serialize;
+use serde::Serializer;
+
+use crate::Component;
+use crate::EntityType;
+use crate::FlowType;
+use crate::PropertyType;
+use crate::RelationType;
+
+/// The separator of the subtags of a language tag.
+pub const LANGUAGE_TAG_SEPARATOR: char = '-';
+
+/// A language tag like `de` or `de-AT`, which identifies the language of a description.
+///
+/// The subtags are normalized to the conventional case: the language in lowercase, the script in
+/// titlecase and the region in uppercase.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(from = "String", into = "String")]
+pub struct LanguageTag(String);
+
+impl LanguageTag {
+    pub fn new<S: Into<String>>(tag: S) -> LanguageTag {
+        let tag: String = tag.into();
+        let subtags: Vec<String> = tag
+            .split(['-', '_'])
+            .enumerate()
+            .map(|(i, subtag)| match (i, subtag.len()) {
+                (0, _) => subtag.to_ascii_lowercase(),
+                (_, 2) => subtag.to_ascii_uppercase(),
+                (_, 4) if subtag.is_ascii() => subtag[..1].to_ascii_uppercase() + &subtag[1..].to_ascii_lowercase(),
+                _ => subtag.to_ascii_lowercase(),
+            })
+            .collect();
+        LanguageTag(subtags.join(&LANGUAGE_TAG_SEPARATOR.to_string()))
+    }
+
+    /// Returns the language tag as string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns the language tag without its last subtag, for example `de` for `de-AT`.
+    pub fn parent(&self) -> Option<LanguageTag> {
+        self.0.rsplit_once(LANGUAGE_TAG_SEPARATOR).map(|(parent, _)| LanguageTag(parent.to_string()))
+    }
+}
+
+impl From<String> for LanguageTag {
+    fn from(tag: String) -> Self {
+        LanguageTag::new(tag)
+    }
+}
+
+impl From<&str> for LanguageTag {
+    fn from(tag: &str) -> Self {
+        LanguageTag::new(tag)
+    }
+}
+
+impl From<LanguageTag> for String {
+    fn from(tag: LanguageTag) -> Self {
+        tag.0
+    }
+}
+
+impl Display for LanguageTag {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Serializes the given descriptions ordered by the language tags, so that the serialized types
+/// don't depend on the order of the hash map.
+pub fn serialize_descriptions<S: Serializer>(descriptions: &HashMap<LanguageTag, String>, serializer: S) -> Result<S::Ok, S::Error> {
+    descriptions.iter().collect::<BTreeMap<&LanguageTag, &String>>().serialize(serializer)
+}
+
+/// Returns the description in the given language.
+///
+/// Falls back to the less specific languages, for example from `de-AT` to `de`, and at last to
+/// the default description.
+pub fn localized_description<'a>(description: &'a str, descriptions: &'a HashMap<LanguageTag, String>, language: &LanguageTag) -> &'a str {
+    let mut language = Some(language.clone());
+    while let Some(tag) = language {
+        if let Some(description) = descriptions.get(&tag) {
+            return description;
+        }
+        language = tag.parent();
+    }
+    description
+}
+
+macro_rules! localized_descriptions {
+    ($($ty: ident),*) => {
+        $(
+            impl $ty {
+                /// Adds the description in the given language.
+                pub fn with_localized_description<L: Into<LanguageTag>, S: Into<String>>(mut self, language: L, description: S) -> $ty {
+                    self.descriptions.insert(language.into(), description.into());
+                    self
+                }
+
+                /// Returns the description in the given language, with fallback to the less
+                /// specific languages and to the default description.
+                pub fn localized_description(&self, language: &LanguageTag) -> &str {
+                    localized_description(&self.description, &self.descriptions, language)
+                }
+            }
+        )*
+    };
+}
+
+localized_descriptions!(Component, EntityType, RelationType, FlowType, PropertyType);
@@ -0,0 +1,34 @@
+use thiserror::Error;
+
+use crate::IdentifierError;
+use crate::PropertyError;
+use crate::TypeIdType;
+use crate::ValidationError;
+
+/// Error of the model, for example of a conversion from malformed identifiers.
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum ModelError {
+    /// The string is not a valid type of types.
+    #[error("{0} is not a valid type of types")]
+    InvalidTypeIdType(String),
+
+    /// The string or the identifier is not a valid type id.
+    #[error("{0} is not a valid type id")]
+    InvalidTypeId(String),
+
+    /// The type id has another type of types than expected.
+    #[error("Expected a type id of {expected:?} but got a type id of {actual:?}")]
+    UnexpectedTypeIdType { expected: TypeIdType, actual: TypeIdType },
+
+    /// The identifier is invalid.
+    #[error(transparent)]
+    Identifier(#[from] IdentifierError),
+
+    /// The property access failed.
+    #[error(transparent)]
+    Property(#[from] PropertyError),
+
+    /// The validation failed.
+    #[error(transparent)]
+    Validation(#[from] ValidationError),
+}
@@ -6,10 +6,13 @@ use serde::Deserialize;
 use serde::Serialize;
 
 /// The mutability of a property.
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Copy, Eq)]
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Copy, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "lowercase")]
+#[non_exhaustive]
 pub enum Mutability {
     /// The property is mutable.
+    #[default]
     Mutable,
 
     /// The property is immutable.
@@ -0,0 +1,137 @@
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde::Serializer;
+
+/// The separator of the segments of a hierarchical namespace.
+pub const NAMESPACE_SEPARATOR: &str = "::";
+
+/// A hierarchical namespace, for example `core::math::arithmetic`.
+///
+/// Namespaces are stored in the flat form in which the segments are joined by `::`. A flat
+/// namespace without separator is a namespace with a single segment. The namespace without
+/// segments is the root namespace.
+#[derive(Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Namespace(Vec<String>);
+
+impl Namespace {
+    /// Constructs a namespace from the given segments. Empty segments are skipped.
+    pub fn new<I: IntoIterator<Item = S>, S: Into<String>>(segments: I) -> Namespace {
+        Namespace(segments.into_iter().map(Into::into).filter(|segment: &String| !segment.is_empty()).collect())
+    }
+
+    /// Returns the root namespace.
+    pub fn root() -> Namespace {
+        Namespace::default()
+    }
+
+    /// Returns the segments of the namespace.
+    pub fn segments(&self) -> &[String] {
+        &self.0
+    }
+
+    /// Returns the number of segments of the namespace.
+    pub fn depth(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns true, if the namespace is the root namespace.
+    pub fn is_root(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the last segment of the namespace.
+    pub fn last_segment(&self) -> Option<&str> {
+        self.0.last().map(String::as_str)
+    }
+
+    /// Returns the parent namespace. The root namespace has no parent.
+    pub fn parent(&self) -> Option<Namespace> {
+        self.0.split_last().map(|(_, parent)| Namespace(parent.to_vec()))
+    }
+
+    /// Returns the child namespace with the given segment.
+    pub fn child<S: Into<String>>(&self, segment: S) -> Namespace {
+        let mut segments = self.0.clone();
+        segments.push(segment.into());
+        Namespace::new(segments)
+    }
+
+    /// Returns true, if the given namespace is a prefix of this namespace. Every namespace starts
+    /// with itself and with the root namespace.
+    pub fn starts_with(&self, prefix: &Namespace) -> bool {
+        self.0.starts_with(&prefix.0)
+    }
+
+    /// Returns true, if this namespace is a parent or an ancestor of the given namespace.
+    pub fn is_ancestor_of(&self, other: &Namespace) -> bool {
+        other.depth() > self.depth() && other.starts_with(self)
+    }
+
+    /// Returns the flat form of the namespace in which the segments are joined by `::`.
+    pub fn to_flat(&self) -> String {
+        self.0.join(NAMESPACE_SEPARATOR)
+    }
+}
+
+impl From<&str> for Namespace {
+    fn from(namespace: &str) -> Self {
+        Namespace::new(namespace.split(NAMESPACE_SEPARATOR))
+    }
+}
+
+impl From<String> for Namespace {
+    fn from(namespace: String) -> Self {
+        Namespace::from(namespace.as_str())
+    }
+}
+
+impl From<&Namespace> for String {
+    fn from(namespace: &Namespace) -> Self {
+        namespace.to_flat()
+    }
+}
+
+/// Parses the flat form of the namespace.
+impl FromStr for Namespace {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Namespace::from(s))
+    }
+}
+
+impl Display for Namespace {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_flat())
+    }
+}
+
+/// Namespaces are serialized in the flat form.
+impl Serialize for Namespace {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_flat())
+    }
+}
+
+impl<'de> Deserialize<'de> for Namespace {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Namespace::from)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for Namespace {
+    fn schema_name() -> String {
+        "Namespace".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        String::json_schema(gen)
+    }
+}
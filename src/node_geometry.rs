@@ -0,0 +1,111 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::set_typed_extension;
+use crate::typed_extension;
+use crate::EntityInstance;
+use crate::ExtensionTypeId;
+use crate::FlowInstance;
+use crate::WellKnownExtension;
+
+/// The namespace of the extension which contains the geometry of an entity instance in a flow
+/// editor.
+pub const NODE_GEOMETRY_EXTENSION_NAMESPACE: &str = "core";
+
+/// The type name of the extension which contains the geometry of an entity instance in a flow
+/// editor.
+pub const NODE_GEOMETRY_EXTENSION_TYPE_NAME: &str = "node_geometry";
+
+/// The position and the size of the node of an entity instance in a flow editor.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct NodeGeometry {
+    /// The horizontal position of the node.
+    pub x: f64,
+
+    /// The vertical position of the node.
+    pub y: f64,
+
+    /// The width of the node. Without width the flow editor chooses the width.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub width: Option<f64>,
+
+    /// The height of the node. Without height the flow editor chooses the height.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub height: Option<f64>,
+}
+
+impl NodeGeometry {
+    pub fn new(x: f64, y: f64) -> NodeGeometry {
+        NodeGeometry {
+            x,
+            y,
+            width: None,
+            height: None,
+        }
+    }
+
+    /// Sets the size of the node.
+    pub fn with_size(mut self, width: f64, height: f64) -> NodeGeometry {
+        self.width = Some(width);
+        self.height = Some(height);
+        self
+    }
+
+    /// Returns the node moved by the given offset.
+    pub fn translate(mut self, dx: f64, dy: f64) -> NodeGeometry {
+        self.x += dx;
+        self.y += dy;
+        self
+    }
+}
+
+impl WellKnownExtension for NodeGeometry {
+    const NAMESPACE: &'static str = NODE_GEOMETRY_EXTENSION_NAMESPACE;
+    const TYPE_NAME: &'static str = NODE_GEOMETRY_EXTENSION_TYPE_NAME;
+    const DESCRIPTION: &'static str = "The geometry of the node in a flow editor";
+}
+
+/// Returns the type id of the extension which contains the geometry of an entity instance in a
+/// flow editor.
+pub fn node_geometry_extension_type_id() -> ExtensionTypeId {
+    NodeGeometry::extension_type_id()
+}
+
+impl EntityInstance {
+    /// Sets the geometry of the node of the entity instance. Replaces the previous geometry.
+    pub fn with_node_geometry(mut self, geometry: NodeGeometry) -> Result<EntityInstance, serde_json::Error> {
+        self.set_node_geometry(geometry)?;
+        Ok(self)
+    }
+
+    /// Sets the geometry of the node of the entity instance. Replaces the previous geometry.
+    pub fn set_node_geometry(&mut self, geometry: NodeGeometry) -> Result<(), serde_json::Error> {
+        set_typed_extension(&mut self.extensions, &geometry)
+    }
+
+    /// Returns the geometry of the node of the entity instance.
+    ///
+    /// Fails if the extension doesn't contain a valid geometry, for example because a coordinate
+    /// which isn't finite has been written as null.
+    pub fn node_geometry(&self) -> Result<Option<NodeGeometry>, serde_json::Error> {
+        typed_extension(&self.extensions)
+    }
+}
+
+impl FlowInstance {
+    /// Returns the geometries of the nodes of the entity instances of the flow, by the ids of the
+    /// entity instances. Entity instances without geometry are omitted.
+    pub fn node_geometries(&self) -> Result<HashMap<Uuid, NodeGeometry>, serde_json::Error> {
+        let mut node_geometries = HashMap::new();
+        for entity_instance in self.entity_instances.iter() {
+            if let Some(geometry) = entity_instance.node_geometry()? {
+                node_geometries.insert(entity_instance.id, geometry);
+            }
+        }
+        Ok(node_geometries)
+    }
+}
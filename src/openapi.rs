@@ -0,0 +1,44 @@
+use serde_json::json;
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::entity_instance_json_schema;
+use crate::Component;
+use crate::EntityType;
+use crate::EntityTypeId;
+use crate::TypeSystem;
+
+/// The version of the OpenAPI specification of the generated schemas.
+///
+/// Since OpenAPI 3.1 the schema objects are fully compatible with JSON Schema 2020-12.
+pub const OPENAPI_VERSION: &str = "3.1.0";
+
+/// Returns the name of the schema of entity instance documents of the given entity type.
+pub fn entity_instance_openapi_schema_name(ty: &EntityTypeId) -> String {
+    ty.to_string()
+}
+
+/// Returns the OpenAPI schema object of entity instance documents of the given entity type.
+pub fn entity_instance_openapi_schema(entity_type: &EntityType, components: &[Component]) -> Value {
+    let mut schema = entity_instance_json_schema(entity_type, components);
+    if let Some(schema) = schema.as_object_mut() {
+        // The dialect is defined by the OpenAPI document
+        schema.remove("$schema");
+    }
+    schema
+}
+
+/// Returns the OpenAPI `components` object which contains the `schemas` of entity instance
+/// documents of the selected entity types.
+///
+/// Entity types which are not contained in the type system are ignored.
+pub fn openapi_components(type_system: &TypeSystem, entity_types: &[EntityTypeId]) -> Value {
+    let mut schemas = Map::new();
+    for entity_type in entity_types.iter().filter_map(|ty| type_system.get_entity_type(ty)) {
+        schemas.insert(
+            entity_instance_openapi_schema_name(&entity_type.ty),
+            entity_instance_openapi_schema(entity_type, &type_system.components),
+        );
+    }
+    json!({ "schemas": schemas })
+}
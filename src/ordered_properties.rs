@@ -0,0 +1,14 @@
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use serde::Serialize;
+use serde::Serializer;
+
+/// Serializes the given map ordered by the keys.
+///
+/// Property maps are hash maps, so the order of the serialized properties changes from run to
+/// run. With the feature `ordered_properties` the properties of instances and DAOs are
+/// serialized using this function, which makes exported files byte-stable.
+pub fn serialize_ordered_map<S: Serializer, K: Ord + Serialize, V: Serialize>(map: &HashMap<K, V>, serializer: S) -> Result<S::Ok, S::Error> {
+    map.iter().collect::<BTreeMap<&K, &V>>().serialize(serializer)
+}
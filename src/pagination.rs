@@ -0,0 +1,179 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::compare_values;
+use crate::EntityInstance;
+use crate::RelationInstance;
+
+/// The direction of sorting.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum SortDirection {
+    /// The smallest value first.
+    #[default]
+    #[serde(alias = "asc")]
+    Ascending,
+
+    /// The greatest value first.
+    #[serde(alias = "desc")]
+    Descending,
+}
+
+/// Orders instances by the value of a property.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct OrderBy {
+    /// The name of the property.
+    pub property: String,
+
+    /// The direction of sorting.
+    #[serde(default)]
+    pub direction: SortDirection,
+}
+
+impl OrderBy {
+    pub fn new<S: Into<String>>(property: S, direction: SortDirection) -> OrderBy {
+        OrderBy {
+            property: property.into(),
+            direction,
+        }
+    }
+
+    /// Orders by the given property with the smallest value first.
+    pub fn ascending<S: Into<String>>(property: S) -> OrderBy {
+        OrderBy::new(property, SortDirection::Ascending)
+    }
+
+    /// Orders by the given property with the greatest value first.
+    pub fn descending<S: Into<String>>(property: S) -> OrderBy {
+        OrderBy::new(property, SortDirection::Descending)
+    }
+
+    /// Compares the properties of two instances.
+    ///
+    /// Values of different JSON types are ordered by their type: null, booleans, numbers, strings,
+    /// arrays and objects. Numbers are compared numerically, strings lexicographically and arrays
+    /// and objects element by element. Instances without the property are ordered last regardless
+    /// of the direction.
+    pub fn compare(&self, a: &HashMap<String, Value>, b: &HashMap<String, Value>) -> Ordering {
+        match (a.get(&self.property), b.get(&self.property)) {
+            (Some(a), Some(b)) => {
+                let ordering = total_compare(a, b);
+                match self.direction {
+                    SortDirection::Ascending => ordering,
+                    SortDirection::Descending => ordering.reverse(),
+                }
+            }
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        }
+    }
+}
+
+/// The rank of the JSON type of a value. Values of different types are ordered by their rank.
+fn type_rank(value: &Value) -> u8 {
+    match value {
+        Value::Null => 0,
+        Value::Bool(_) => 1,
+        Value::Number(_) => 2,
+        Value::String(_) => 3,
+        Value::Array(_) => 4,
+        Value::Object(_) => 5,
+    }
+}
+
+/// Orders values by their JSON type first and then by their value.
+fn total_compare(a: &Value, b: &Value) -> Ordering {
+    match (a, b) {
+        (Value::Bool(a), Value::Bool(b)) => a.cmp(b),
+        // JSON numbers are finite, so numbers and strings are always ordered
+        (Value::Number(_), Value::Number(_)) | (Value::String(_), Value::String(_)) => compare_values(a, b).unwrap_or(Ordering::Equal),
+        (Value::Array(a), Value::Array(b)) => a
+            .iter()
+            .zip(b)
+            .map(|(a, b)| total_compare(a, b))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or_else(|| a.len().cmp(&b.len())),
+        (Value::Object(a), Value::Object(b)) => a
+            .iter()
+            .zip(b)
+            .map(|((a_key, a), (b_key, b))| a_key.cmp(b_key).then_with(|| total_compare(a, b)))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or_else(|| a.len().cmp(&b.len())),
+        _ => type_rank(a).cmp(&type_rank(b)),
+    }
+}
+
+/// Compares the properties of two instances by the given orders. Later orders decide if the
+/// earlier orders consider the instances equal.
+pub fn compare_properties(order_by: &[OrderBy], a: &HashMap<String, Value>, b: &HashMap<String, Value>) -> Ordering {
+    order_by
+        .iter()
+        .map(|order_by| order_by.compare(a, b))
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or(Ordering::Equal)
+}
+
+/// Sorts the given entity instances by the given orders. The sort is stable.
+pub fn sort_entity_instances(entity_instances: &mut [EntityInstance], order_by: &[OrderBy]) {
+    entity_instances.sort_by(|a, b| compare_properties(order_by, &a.properties, &b.properties));
+}
+
+/// Sorts the given relation instances by the given orders. The sort is stable.
+pub fn sort_relation_instances(relation_instances: &mut [RelationInstance], order_by: &[OrderBy]) {
+    relation_instances.sort_by(|a, b| compare_properties(order_by, &a.properties, &b.properties));
+}
+
+/// Selects a page of the results of a search.
+///
+/// The default pagination selects all results.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Pagination {
+    /// The number of results to skip.
+    #[serde(default)]
+    pub offset: usize,
+
+    /// The maximum number of results. If none, all remaining results are selected.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
+
+impl Pagination {
+    pub fn new(offset: usize, limit: Option<usize>) -> Pagination {
+        Pagination { offset, limit }
+    }
+
+    /// Selects the page with the given zero based index of pages of the given size.
+    pub fn page(index: usize, size: usize) -> Pagination {
+        Pagination::new(index.saturating_mul(size), Some(size))
+    }
+
+    /// Skips the given number of results.
+    pub fn with_offset(mut self, offset: usize) -> Pagination {
+        self.offset = offset;
+        self
+    }
+
+    /// Selects at most the given number of results.
+    pub fn with_limit(mut self, limit: usize) -> Pagination {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Returns the selected page of the given results.
+    pub fn apply<'a, T>(&self, results: &'a [T]) -> &'a [T] {
+        let start = self.offset.min(results.len());
+        let end = match self.limit {
+            Some(limit) => start.saturating_add(limit).min(results.len()),
+            None => results.len(),
+        };
+        &results[start..end]
+    }
+}
@@ -0,0 +1,81 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::ComponentTypeId;
+use crate::DataType;
+use crate::EntityType;
+use crate::SocketType;
+use crate::TypeSystem;
+
+/// The declaration of a property by a component.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PropertyDeclaration {
+    /// The component which declares the property.
+    pub component: ComponentTypeId,
+
+    /// The data type of the declared property.
+    pub data_type: DataType,
+
+    /// The socket type of the declared property.
+    pub socket_type: SocketType,
+}
+
+/// A property which is declared by multiple components of the same type with differing data types
+/// or socket types.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PropertyConflict {
+    /// The name of the property.
+    pub property_name: String,
+
+    /// The declarations of the property in the order of the components of the type.
+    pub declarations: Vec<PropertyDeclaration>,
+}
+
+impl PropertyConflict {
+    /// Returns true, if the declarations differ in their data types.
+    pub fn has_data_type_conflict(&self) -> bool {
+        self.declarations.windows(2).any(|d| d[0].data_type != d[1].data_type)
+    }
+
+    /// Returns true, if the declarations differ in their socket types.
+    pub fn has_socket_type_conflict(&self) -> bool {
+        self.declarations.windows(2).any(|d| d[0].socket_type != d[1].socket_type)
+    }
+}
+
+impl TypeSystem {
+    /// Detects the properties which are declared by multiple components of the given entity type
+    /// with differing data types or socket types.
+    ///
+    /// Declarations with equal data types and socket types are not reported. Components which
+    /// aren't part of the type system are skipped. The conflicts are ordered by the first
+    /// declaration of the property. The properties of entity types with scoped properties are
+    /// declared by their qualified names, which are scoped by the type names of the components.
+    /// Hence only components with the same type name in different namespaces can conflict.
+    pub fn detect_property_conflicts(&self, entity_type: &EntityType) -> Vec<PropertyConflict> {
+        let mut property_declarations: Vec<PropertyConflict> = Vec::new();
+        for component in entity_type.components.iter().filter_map(|ty| self.resolve_component(ty)) {
+            for property_type in component.properties.iter() {
+                let declaration = PropertyDeclaration {
+                    component: component.ty.clone(),
+                    data_type: property_type.data_type,
+                    socket_type: property_type.socket_type,
+                };
+                let property_name = entity_type.component_property_key(&component.ty, &property_type.name);
+                match property_declarations.iter_mut().find(|p| p.property_name == property_name) {
+                    Some(property_conflict) => property_conflict.declarations.push(declaration),
+                    None => property_declarations.push(PropertyConflict {
+                        property_name,
+                        declarations: vec![declaration],
+                    }),
+                }
+            }
+        }
+        property_declarations
+            .into_iter()
+            .filter(|p| p.has_data_type_conflict() || p.has_socket_type_conflict())
+            .collect()
+    }
+}
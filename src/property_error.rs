@@ -0,0 +1,19 @@
+use thiserror::Error;
+
+use crate::DataType;
+
+/// Error of an access to a property of an instance.
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum PropertyError {
+    /// The property doesn't exist.
+    #[error("The property {0} doesn't exist")]
+    MissingProperty(String),
+
+    /// The property is immutable.
+    #[error("The property {0} is immutable")]
+    ImmutableProperty(String),
+
+    /// The value of the property doesn't match the data type of the property.
+    #[error("The property {name} must be of data type {expected} but is of data type {actual}")]
+    InvalidDataType { name: String, expected: DataType, actual: DataType },
+}
@@ -1,3 +1,5 @@
+use crate::IdentifierError;
+use crate::MAX_IDENTIFIER_LENGTH;
 use crate::NAMESPACE_PROPERTY_TYPE;
 use indradb::Identifier;
 use uuid::Uuid;
@@ -10,3 +12,17 @@ pub fn property_identifier<S: Into<String>>(property_name: S) -> Identifier {
     let property_name = property_name.into();
     Identifier::new(&property_name).unwrap_or_else(|_| Identifier::new(Uuid::new_v5(&NAMESPACE_PROPERTY_TYPE, property_name.as_bytes()).to_string()).unwrap())
 }
+
+/// Constructs a property identifier without fallback.
+///
+/// Returns an error if the property name is empty or too long to be an identifier.
+pub fn try_property_identifier<S: Into<String>>(property_name: S) -> Result<Identifier, IdentifierError> {
+    let property_name = property_name.into();
+    if property_name.is_empty() {
+        return Err(IdentifierError::Empty);
+    }
+    if property_name.len() > MAX_IDENTIFIER_LENGTH {
+        return Err(IdentifierError::TooLong(property_name.len()));
+    }
+    Identifier::new(&property_name).map_err(|_| IdentifierError::TooLong(property_name.len()))
+}
@@ -1,7 +1,12 @@
+use std::collections::HashMap;
+
 use serde_json::Map;
 use serde_json::Value;
 
 use crate::Mutability;
+use crate::PropertyError;
+use crate::PropertyType;
+use crate::UnitError;
 
 pub trait PropertyInstanceGetter {
     /// Returns the json value of the given property by name
@@ -36,10 +41,58 @@ pub trait PropertyInstanceGetter {
     // as_uuid (string uuid -> Uuid)
 }
 
+/// Borrowing getters which look up properties by `&str` without allocating and without cloning
+/// the values.
+pub trait PropertyInstanceRefGetter {
+    /// Returns a reference to the json value of the given property by name
+    fn get_ref(&self, property_name: &str) -> Option<&Value>;
+
+    /// Returns a reference to the string value of the given property by name
+    fn as_str(&self, property_name: &str) -> Option<&str> {
+        self.get_ref(property_name).and_then(|p| p.as_str())
+    }
+
+    /// Returns a reference to the array value of the given property by name
+    fn as_array_ref(&self, property_name: &str) -> Option<&Vec<Value>> {
+        self.get_ref(property_name).and_then(|p| p.as_array())
+    }
+
+    /// Returns a reference to the object value of the given property by name
+    fn as_object_ref(&self, property_name: &str) -> Option<&Map<String, Value>> {
+        self.get_ref(property_name).and_then(|p| p.as_object())
+    }
+
+    /// Returns the f64 value of the given property converted from the unit of the property type
+    /// into the given unit. Returns `Ok(None)` if the property doesn't exist or isn't a number.
+    fn as_f64_in(&self, property_type: &PropertyType, unit: &str) -> Result<Option<f64>, UnitError> {
+        self.get_ref(&property_type.name)
+            .and_then(|p| p.as_f64())
+            .map(|value| property_type.convert_to(value, unit))
+            .transpose()
+    }
+}
+
+impl PropertyInstanceRefGetter for HashMap<String, Value> {
+    fn get_ref(&self, property_name: &str) -> Option<&Value> {
+        self.get(property_name)
+    }
+}
+
 pub trait MutablePropertyInstanceSetter: PropertyInstanceGetter {
     /// Sets the value of the given property by name
     fn set<S: Into<String>>(&mut self, property_name: S, value: Value);
 
+    /// Sets the value of the given property by name. Returns an error if the property doesn't
+    /// exist instead of ignoring the value.
+    fn try_set<S: Into<String>>(&mut self, property_name: S, value: Value) -> Result<(), PropertyError> {
+        let property_name = property_name.into();
+        if self.get(property_name.clone()).is_none() {
+            return Err(PropertyError::MissingProperty(property_name));
+        }
+        self.set(property_name, value);
+        Ok(())
+    }
+
     // TODO: Typed setters
     // set_bool(property_name, value)
     // set_number(property_name, value)
@@ -54,6 +107,20 @@ pub trait PropertyInstanceSetter: PropertyInstanceGetter {
     /// Sets the value of the given property by name if the property is mutable.
     fn set_checked<S: Into<String>>(&self, property_name: S, value: Value);
 
+    /// Sets the value of the given property by name if the property is mutable. Returns an error
+    /// if the property doesn't exist or is immutable instead of ignoring the value.
+    fn try_set_checked<S: Into<String>>(&self, property_name: S, value: Value) -> Result<(), PropertyError> {
+        let property_name = property_name.into();
+        match self.mutability(property_name.clone()) {
+            None => Err(PropertyError::MissingProperty(property_name)),
+            Some(Mutability::Immutable) => Err(PropertyError::ImmutableProperty(property_name)),
+            Some(Mutability::Mutable) => {
+                self.set(property_name, value);
+                Ok(())
+            }
+        }
+    }
+
     /// Sets the value of the given property by name
     fn set<S: Into<String>>(&self, property_name: S, value: Value);
 
@@ -0,0 +1,126 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::set_typed_extension;
+use crate::typed_extension;
+use crate::ExtensionTypeId;
+use crate::PropertyType;
+use crate::WellKnownExtension;
+
+/// The namespace of the extension which contains the layout of a property type.
+pub const PROPERTY_LAYOUT_EXTENSION_NAMESPACE: &str = "core";
+
+/// The type name of the extension which contains the layout of a property type.
+pub const PROPERTY_LAYOUT_EXTENSION_TYPE_NAME: &str = "property_layout";
+
+/// Where editors render a property, as section and position within the section.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PropertyLayout {
+    /// The name of the group of properties, which is rendered as section.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
+
+    /// The position of the property. Properties with lower order are rendered first.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub order: Option<i32>,
+}
+
+impl PropertyLayout {
+    pub fn new<S: Into<String>>(group: S, order: i32) -> PropertyLayout {
+        PropertyLayout {
+            group: Some(group.into()),
+            order: Some(order),
+        }
+    }
+}
+
+impl WellKnownExtension for PropertyLayout {
+    const NAMESPACE: &'static str = PROPERTY_LAYOUT_EXTENSION_NAMESPACE;
+    const TYPE_NAME: &'static str = PROPERTY_LAYOUT_EXTENSION_TYPE_NAME;
+    const DESCRIPTION: &'static str = "The layout of the property";
+}
+
+/// Returns the type id of the extension which contains the layout of a property type.
+pub fn property_layout_extension_type_id() -> ExtensionTypeId {
+    PropertyLayout::extension_type_id()
+}
+
+/// A group of properties, which is rendered as section.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PropertyGroup<'a> {
+    /// The name of the group or `None` for the properties without group.
+    pub name: Option<String>,
+
+    /// The properties of the group, in the order they are rendered.
+    pub properties: Vec<&'a PropertyType>,
+}
+
+impl PropertyType {
+    /// Sets the layout of the property type. Replaces the previous layout.
+    pub fn with_layout(mut self, layout: PropertyLayout) -> Result<PropertyType, serde_json::Error> {
+        set_typed_extension(&mut self.extensions, &layout)?;
+        Ok(self)
+    }
+
+    /// Sets the group of the property type and keeps the order.
+    pub fn with_group<S: Into<String>>(self, group: S) -> Result<PropertyType, serde_json::Error> {
+        let layout = PropertyLayout {
+            group: Some(group.into()),
+            ..self.layout()?.unwrap_or_default()
+        };
+        self.with_layout(layout)
+    }
+
+    /// Sets the order of the property type and keeps the group.
+    pub fn with_order(self, order: i32) -> Result<PropertyType, serde_json::Error> {
+        let layout = PropertyLayout {
+            order: Some(order),
+            ..self.layout()?.unwrap_or_default()
+        };
+        self.with_layout(layout)
+    }
+
+    /// Returns the layout of the property type.
+    ///
+    /// Fails if the extension doesn't contain a valid layout.
+    pub fn layout(&self) -> Result<Option<PropertyLayout>, serde_json::Error> {
+        typed_extension(&self.extensions)
+    }
+
+    /// Returns the name of the group of the property type.
+    pub fn group(&self) -> Result<Option<String>, serde_json::Error> {
+        Ok(self.layout()?.and_then(|layout| layout.group))
+    }
+
+    /// Returns the order of the property type.
+    pub fn order(&self) -> Result<Option<i32>, serde_json::Error> {
+        Ok(self.layout()?.and_then(|layout| layout.order))
+    }
+}
+
+/// Groups the given property types by their group and sorts them by their order.
+///
+/// The groups are ordered by their first property. Properties without order follow the ordered
+/// properties in the order of declaration. The properties without group form the last group.
+///
+/// Fails if the layout of a property type is invalid.
+pub fn group_property_types(property_types: &[PropertyType]) -> Result<Vec<PropertyGroup<'_>>, serde_json::Error> {
+    let mut layouts: Vec<(&PropertyType, PropertyLayout)> = property_types
+        .iter()
+        .map(|property_type| Ok((property_type, property_type.layout()?.unwrap_or_default())))
+        .collect::<Result<_, serde_json::Error>>()?;
+    layouts.sort_by_key(|(_, layout)| (layout.order.is_none(), layout.order));
+    let mut groups: Vec<PropertyGroup> = Vec::new();
+    for (property_type, layout) in layouts {
+        match groups.iter_mut().find(|group| group.name == layout.group) {
+            Some(group) => group.properties.push(property_type),
+            None => groups.push(PropertyGroup {
+                name: layout.group,
+                properties: vec![property_type],
+            }),
+        }
+    }
+    groups.sort_by_key(|group| group.name.is_none());
+    Ok(groups)
+}
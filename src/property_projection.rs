@@ -0,0 +1,78 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::glob_matches;
+use crate::CowProperties;
+use crate::EntityInstance;
+use crate::RelationInstance;
+
+/// The properties of an instance which are served by an API.
+///
+/// The property names may contain the wildcards `*` and `?`.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum PropertyProjection {
+    /// All properties.
+    #[default]
+    All,
+
+    /// Only the properties which match any of the given property names.
+    Include(Vec<String>),
+
+    /// All properties except the properties which match any of the given property names.
+    Exclude(Vec<String>),
+}
+
+impl PropertyProjection {
+    /// Only the properties which match any of the given property names.
+    pub fn include<I: IntoIterator<Item = S>, S: Into<String>>(property_names: I) -> PropertyProjection {
+        PropertyProjection::Include(property_names.into_iter().map(Into::into).collect())
+    }
+
+    /// All properties except the properties which match any of the given property names.
+    pub fn exclude<I: IntoIterator<Item = S>, S: Into<String>>(property_names: I) -> PropertyProjection {
+        PropertyProjection::Exclude(property_names.into_iter().map(Into::into).collect())
+    }
+
+    /// Returns true, if the projection contains the property with the given name.
+    pub fn contains(&self, property_name: &str) -> bool {
+        match self {
+            PropertyProjection::All => true,
+            PropertyProjection::Include(patterns) => patterns.iter().any(|pattern| glob_matches(pattern, property_name)),
+            PropertyProjection::Exclude(patterns) => !patterns.iter().any(|pattern| glob_matches(pattern, property_name)),
+        }
+    }
+
+    fn project(&self, properties: &CowProperties) -> CowProperties {
+        match self {
+            // The properties are shared with the original instance
+            PropertyProjection::All => properties.clone(),
+            _ => properties
+                .iter()
+                .filter(|(property_name, _)| self.contains(property_name))
+                .map(|(property_name, value)| (property_name.clone(), value.clone()))
+                .collect(),
+        }
+    }
+}
+
+impl EntityInstance {
+    /// Returns a copy of the entity instance which only contains the properties of the given
+    /// projection.
+    pub fn project(&self, projection: &PropertyProjection) -> EntityInstance {
+        let mut entity_instance = self.clone();
+        entity_instance.properties = projection.project(&self.properties);
+        entity_instance
+    }
+}
+
+impl RelationInstance {
+    /// Returns a copy of the relation instance which only contains the properties of the given
+    /// projection.
+    pub fn project(&self, projection: &PropertyProjection) -> RelationInstance {
+        let mut relation_instance = self.clone();
+        relation_instance.properties = projection.project(&self.properties);
+        relation_instance
+    }
+}
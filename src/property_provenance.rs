@@ -0,0 +1,142 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::component_property_key;
+use crate::set_typed_extension;
+use crate::typed_extension;
+use crate::Component;
+use crate::ComponentTypeId;
+use crate::EntityInstance;
+use crate::EntityType;
+use crate::Extension;
+use crate::ExtensionTypeId;
+use crate::PropertyType;
+use crate::RelationInstance;
+use crate::RelationType;
+use crate::WellKnownExtension;
+
+/// The namespace of the extension which records the property provenance on instances.
+pub const PROPERTY_PROVENANCE_EXTENSION_NAMESPACE: &str = "core";
+
+/// The type name of the extension which records the property provenance on instances.
+pub const PROPERTY_PROVENANCE_EXTENSION_TYPE_NAME: &str = "property_provenance";
+
+/// The type which declares a property of an entity type or of a relation type.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum PropertyProvenance {
+    /// The property is declared by the entity type or the relation type itself.
+    OwnType,
+
+    /// The property is declared by the given component.
+    Component(ComponentTypeId),
+}
+
+/// The provenance of the properties of an instance is recorded as a map of the property names to
+/// the types which declare them.
+impl WellKnownExtension for HashMap<String, PropertyProvenance> {
+    const NAMESPACE: &'static str = PROPERTY_PROVENANCE_EXTENSION_NAMESPACE;
+    const TYPE_NAME: &'static str = PROPERTY_PROVENANCE_EXTENSION_TYPE_NAME;
+    const DESCRIPTION: &'static str = "The types which declare the properties";
+}
+
+/// Returns the type id of the extension which records the property provenance on instances.
+pub fn property_provenance_extension_type_id() -> ExtensionTypeId {
+    HashMap::<String, PropertyProvenance>::extension_type_id()
+}
+
+/// Maps each effective property name to the type which declares it.
+///
+/// Own properties take precedence over the properties of components and earlier components take
+/// precedence over later components, in the same way as the validation of instances resolves
+/// properties. If the properties of the components are scoped, they are mapped by their
+/// qualified names. Components which are missing in the given components are skipped.
+pub fn property_provenance(
+    scoped: bool,
+    property_types: &[PropertyType],
+    component_tys: &[ComponentTypeId],
+    components: &[Component],
+) -> HashMap<String, PropertyProvenance> {
+    let mut provenance: HashMap<String, PropertyProvenance> = property_types
+        .iter()
+        .map(|property_type| (property_type.name.to_string(), PropertyProvenance::OwnType))
+        .collect();
+    for component_ty in component_tys.iter() {
+        let Some(component) = components.iter().find(|component| component.is_known_as(component_ty)) else {
+            continue;
+        };
+        for property_type in component.properties.iter() {
+            provenance
+                .entry(component_property_key(scoped, component_ty, &property_type.name))
+                .or_insert_with(|| PropertyProvenance::Component(component.ty.clone()));
+        }
+    }
+    provenance
+}
+
+impl EntityType {
+    /// Maps each property name of this entity type and of its components to the type which
+    /// declares it.
+    pub fn property_provenance(&self, components: &[Component]) -> HashMap<String, PropertyProvenance> {
+        property_provenance(self.scoped_properties, &self.properties, &self.components, components)
+    }
+}
+
+impl RelationType {
+    /// Maps each property name of this relation type and of its components to the type which
+    /// declares it.
+    pub fn property_provenance(&self, components: &[Component]) -> HashMap<String, PropertyProvenance> {
+        property_provenance(self.scoped_properties, &self.properties, &self.components, components)
+    }
+}
+
+impl EntityInstance {
+    /// Records the provenance of the properties of the entity instance as an extension. Replaces
+    /// previously recorded provenance.
+    pub fn with_property_provenance(mut self, provenance: &HashMap<String, PropertyProvenance>) -> Result<EntityInstance, serde_json::Error> {
+        set_property_provenance(&mut self.extensions, &self.properties, provenance)?;
+        Ok(self)
+    }
+
+    /// Returns the recorded provenance of the properties of the entity instance.
+    ///
+    /// Fails if the extension doesn't contain a valid provenance.
+    pub fn property_provenance(&self) -> Result<Option<HashMap<String, PropertyProvenance>>, serde_json::Error> {
+        typed_extension(&self.extensions)
+    }
+}
+
+impl RelationInstance {
+    /// Records the provenance of the properties of the relation instance as an extension.
+    /// Replaces previously recorded provenance.
+    pub fn with_property_provenance(mut self, provenance: &HashMap<String, PropertyProvenance>) -> Result<RelationInstance, serde_json::Error> {
+        set_property_provenance(&mut self.extensions, &self.properties, provenance)?;
+        Ok(self)
+    }
+
+    /// Returns the recorded provenance of the properties of the relation instance.
+    ///
+    /// Fails if the extension doesn't contain a valid provenance.
+    pub fn property_provenance(&self) -> Result<Option<HashMap<String, PropertyProvenance>>, serde_json::Error> {
+        typed_extension(&self.extensions)
+    }
+}
+
+/// Records the provenance of the given properties in the given extensions. Properties with
+/// unknown provenance are omitted.
+fn set_property_provenance(
+    extensions: &mut Vec<Extension>,
+    properties: &HashMap<String, Value>,
+    provenance: &HashMap<String, PropertyProvenance>,
+) -> Result<(), serde_json::Error> {
+    let provenance: HashMap<String, PropertyProvenance> = provenance
+        .iter()
+        .filter(|(property_name, _)| properties.contains_key(*property_name))
+        .map(|(property_name, provenance)| (property_name.clone(), provenance.clone()))
+        .collect();
+    set_typed_extension(extensions, &provenance)
+}
@@ -1,12 +1,19 @@
+use std::collections::HashMap;
+
 use serde::Deserialize;
 use serde::Serialize;
 use serde_json::Value;
 use uuid::Uuid;
 
 use crate::extension::Extension;
+use crate::identifier_string;
 use crate::DataType;
+use crate::Documentation;
 use crate::ExtensionTypeId;
+use crate::IdentifierString;
+use crate::LanguageTag;
 use crate::Mutability;
+use crate::PropertyError;
 use crate::SocketType;
 
 pub static NAMESPACE_PROPERTY_TYPE: Uuid = Uuid::from_u128(0x1ab7c8109dcd11c180b400d02fd540c7);
@@ -14,15 +21,28 @@ pub static NAMESPACE_PROPERTY_TYPE: Uuid = Uuid::from_u128(0x1ab7c8109dcd11c180b
 /// Definition of a property. The definition contains
 /// the name of the property, the data type and the socket
 /// type.
-#[derive(Serialize, Deserialize, Clone, Debug)]
+///
+/// Property types can be built with struct update syntax based on
+/// `PropertyType::default()`.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct PropertyType {
     /// The name of the property
-    pub name: String,
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
+    pub name: IdentifierString,
 
     /// The description of the property.
     #[serde(default = "String::new")]
     pub description: String,
 
+    /// The descriptions in other languages, by language tag.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty", serialize_with = "crate::serialize_descriptions")]
+    pub descriptions: HashMap<LanguageTag, String>,
+
+    /// The structured documentation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub documentation: Option<Documentation>,
+
     /// The data type of the property
     pub data_type: DataType,
 
@@ -33,6 +53,14 @@ pub struct PropertyType {
     #[serde(default = "Mutability::mutable")]
     pub mutability: Mutability,
 
+    /// The unit of the values of the property, for example `ms` or `km/h`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
+
+    /// Example values of the property.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub examples: Vec<Value>,
+
     /// Property specific extensions
     #[serde(default = "Vec::new")]
     pub extensions: Vec<Extension>,
@@ -41,44 +69,60 @@ pub struct PropertyType {
 impl PropertyType {
     pub fn new<S: Into<String>>(name: S, data_type: DataType) -> PropertyType {
         PropertyType {
-            name: name.into(),
+            name: identifier_string(name),
             description: String::new(),
+            descriptions: HashMap::new(),
+            documentation: None,
             data_type,
             socket_type: SocketType::None,
             mutability: Mutability::Mutable,
+            unit: None,
+            examples: Vec::new(),
             extensions: Vec::new(),
         }
     }
 
     pub fn new_with_socket<S: Into<String>>(name: S, data_type: DataType, socket_type: SocketType) -> PropertyType {
         PropertyType {
-            name: name.into(),
+            name: identifier_string(name),
             description: String::new(),
+            descriptions: HashMap::new(),
+            documentation: None,
             data_type,
             socket_type,
             mutability: Mutability::Mutable,
+            unit: None,
+            examples: Vec::new(),
             extensions: Vec::new(),
         }
     }
 
     pub fn input<S: Into<String>>(name: S, data_type: DataType) -> PropertyType {
         PropertyType {
-            name: name.into(),
+            name: identifier_string(name),
             description: String::new(),
+            descriptions: HashMap::new(),
+            documentation: None,
             data_type,
             socket_type: SocketType::Input,
             mutability: Mutability::Mutable,
+            unit: None,
+            examples: Vec::new(),
             extensions: Vec::new(),
         }
     }
 
     pub fn output<S: Into<String>>(name: S, data_type: DataType) -> PropertyType {
         PropertyType {
-            name: name.into(),
+            name: identifier_string(name),
             description: String::new(),
+            descriptions: HashMap::new(),
+            documentation: None,
             data_type,
             socket_type: SocketType::Output,
             mutability: Mutability::Immutable,
+            unit: None,
+            examples: Vec::new(),
             extensions: Vec::new(),
         }
     }
@@ -92,11 +136,15 @@ impl PropertyType {
         extensions: Vec<Extension>,
     ) -> PropertyType {
         PropertyType {
-            name: name.into(),
+            name: identifier_string(name),
             description: description.into(),
+            descriptions: HashMap::new(),
+            documentation: None,
             data_type,
             socket_type,
             mutability,
+            unit: None,
+            examples: Vec::new(),
             extensions,
         }
     }
@@ -121,10 +169,56 @@ impl PropertyType {
         PropertyType::new(name, DataType::Object)
     }
 
+    /// Sets the description of the property type.
+    pub fn with_description<S: Into<String>>(mut self, description: S) -> PropertyType {
+        self.description = description.into();
+        self
+    }
+
+    /// Adds the given extension to the property type.
+    pub fn with_extension(mut self, extension: Extension) -> PropertyType {
+        self.extensions.push(extension);
+        self
+    }
+
+    /// Sets the socket type of the property type.
+    pub fn with_socket_type(mut self, socket_type: SocketType) -> PropertyType {
+        self.socket_type = socket_type;
+        self
+    }
+
+    /// Sets the mutability of the property type.
+    pub fn with_mutability(mut self, mutability: Mutability) -> PropertyType {
+        self.mutability = mutability;
+        self
+    }
+
     /// Returns true, if the property contains an extension with the given type.
     pub fn has_extension(&self, ty: &ExtensionTypeId) -> bool {
         self.extensions.iter().any(|extension| &extension.ty == ty)
     }
+
+    /// Validates the given value against the data type of the property.
+    pub fn validate_value(&self, value: &Value) -> Result<(), PropertyError> {
+        if self.data_type.accepts(value) {
+            Ok(())
+        } else {
+            Err(PropertyError::InvalidDataType {
+                name: self.name.to_string(),
+                expected: self.data_type,
+                actual: DataType::from(value),
+            })
+        }
+    }
+
+    /// Validates that the given properties contain this property with a value of the data type
+    /// of the property.
+    pub fn validate_property(&self, properties: &HashMap<String, Value>) -> Result<(), PropertyError> {
+        let value = properties
+            .get(self.name.as_str())
+            .ok_or_else(|| PropertyError::MissingProperty(self.name.to_string()))?;
+        self.validate_value(value)
+    }
 }
 
 pub trait PropertyTypeDefinition {
@@ -15,6 +15,7 @@ use crate::ComponentContainer;
 use crate::ComponentTypeId;
 use crate::EntityInstance;
 use crate::EntityTypeId;
+use crate::ModelError;
 use crate::Mutability;
 use crate::Mutability::Mutable;
 use crate::NamespacedTypeGetter;
@@ -76,8 +77,8 @@ impl ReactivePropertyContainer for ReactiveEntityInstance {
     }
 
     fn add_property_by_type(&self, property: &PropertyType) {
-        let property_instance = ReactivePropertyInstance::new(self.id, &property.name, property.mutability, property.data_type.default_value());
-        self.properties.insert(property.name.clone(), property_instance);
+        let property_instance = ReactivePropertyInstance::new(self.id, property.name.as_str(), property.mutability, property.data_type.default_value());
+        self.properties.insert(property.name.to_string(), property_instance);
     }
 
     fn remove_property<S: Into<String>>(&self, name: S) {
@@ -113,7 +114,7 @@ impl ComponentContainer for ReactiveEntityInstance {
     fn add_component_with_properties(&self, component: &Component) {
         self.add_component(component.ty.clone());
         for property_type in component.properties.iter() {
-            if !self.properties.contains_key(&property_type.name) {
+            if !self.properties.contains_key(property_type.name.as_str()) {
                 self.add_property_by_type(property_type);
             }
         }
@@ -147,7 +148,7 @@ impl ReactiveBehaviourContainer for ReactiveEntityInstance {
 }
 
 impl TryFrom<VertexProperties> for ReactiveEntityInstance {
-    type Error = ();
+    type Error = ModelError;
 
     fn try_from(properties: VertexProperties) -> Result<Self, Self::Error> {
         let ty = EntityTypeId::try_from(&properties.vertex.t)?;
@@ -204,6 +205,12 @@ impl From<Arc<ReactiveEntityInstance>> for EntityInstance {
             description: instance.description.clone(),
             properties,
             extensions: Vec::new(),
+            created_at: None,
+            updated_at: None,
+            created_by: None,
+            deleted_at: None,
+            revision: 0,
+            extra: Map::new(),
         }
     }
 }
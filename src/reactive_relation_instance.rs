@@ -7,6 +7,7 @@ use dashmap::DashMap;
 use dashmap::DashSet;
 use indradb::EdgeKey;
 use indradb::EdgeProperties;
+use indradb::Identifier;
 use serde_json::Map;
 use serde_json::Value;
 use uuid::Uuid;
@@ -15,6 +16,7 @@ use crate::BehaviourTypeId;
 use crate::Component;
 use crate::ComponentContainer;
 use crate::ComponentTypeId;
+use crate::ModelError;
 use crate::Mutability;
 use crate::Mutability::Mutable;
 use crate::NamespacedTypeGetter;
@@ -77,13 +79,12 @@ pub struct ReactiveRelationInstance {
     pub behaviours: DashSet<BehaviourTypeId>,
 }
 
-#[allow(clippy::result_unit_err)]
 impl ReactiveRelationInstance {
     pub fn new_from_properties(
         outbound: Arc<ReactiveEntityInstance>,
         inbound: Arc<ReactiveEntityInstance>,
         properties: EdgeProperties,
-    ) -> Result<ReactiveRelationInstance, ()> {
+    ) -> Result<ReactiveRelationInstance, ModelError> {
         let ty = RelationInstanceTypeId::try_from(&properties.edge.key.t)?;
         let properties = properties
             .props
@@ -208,8 +209,8 @@ impl ReactivePropertyContainer for ReactiveRelationInstance {
     }
 
     fn add_property_by_type(&self, property: &PropertyType) {
-        let property_instance = ReactivePropertyInstance::new(Uuid::new_v4(), &property.name, property.mutability, property.data_type.default_value());
-        self.properties.insert(property.name.clone(), property_instance);
+        let property_instance = ReactivePropertyInstance::new(Uuid::new_v4(), property.name.as_str(), property.mutability, property.data_type.default_value());
+        self.properties.insert(property.name.to_string(), property_instance);
     }
 
     fn remove_property<S: Into<String>>(&self, name: S) {
@@ -245,7 +246,7 @@ impl ComponentContainer for ReactiveRelationInstance {
     fn add_component_with_properties(&self, component: &Component) {
         self.add_component(component.ty.clone());
         for property_type in component.properties.iter() {
-            if !self.properties.contains_key(&property_type.name) {
+            if !self.properties.contains_key(property_type.name.as_str()) {
                 self.add_property_by_type(property_type);
             }
         }
@@ -292,6 +293,12 @@ impl From<Arc<ReactiveRelationInstance>> for RelationInstance {
             description: instance.description.clone(),
             properties,
             extensions: Vec::new(),
+            created_at: None,
+            updated_at: None,
+            created_by: None,
+            deleted_at: None,
+            revision: 0,
+            extra: Map::new(),
         }
     }
 }
@@ -380,6 +387,10 @@ impl TypeDefinitionGetter for ReactiveRelationInstance {
     fn type_definition(&self) -> TypeDefinition {
         self.ty.type_definition()
     }
+
+    fn type_id(&self) -> Identifier {
+        self.ty.type_id()
+    }
 }
 
 impl Display for ReactiveRelationInstance {
@@ -8,7 +8,8 @@ use crate::NamespacedType;
 use crate::RelationTypeId;
 
 /// The behaviour of a relation type.
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct RelationBehaviourTypeId {
     /// The relation type.
     pub relation_ty: RelationTypeId,
@@ -26,6 +27,11 @@ impl RelationBehaviourTypeId {
         let namespaced_type = NamespacedType::new(namespace, type_name);
         RelationBehaviourTypeId::new(namespaced_type.clone().into(), namespaced_type.into())
     }
+
+    /// Returns true, if the behaviour applies to the given relation type.
+    pub fn applies_to(&self, ty: &RelationTypeId) -> bool {
+        &self.relation_ty == ty
+    }
 }
 
 impl From<NamespacedType> for RelationBehaviourTypeId {
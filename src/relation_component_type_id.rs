@@ -6,6 +6,7 @@ use crate::RelationTypeId;
 
 /// Addresses the component of a relation type.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct RelationComponentTypeId {
     /// The relation type.
     pub relation_ty: RelationTypeId,
@@ -0,0 +1,186 @@
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use serde::Deserialize;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::type_matches;
+use crate::ComponentOrEntityTypeId;
+use crate::ComponentTypeId;
+use crate::EntityInstance;
+use crate::EntityType;
+use crate::RelationInstance;
+use crate::RelationType;
+use crate::TypeSystem;
+use crate::ValidationError;
+
+/// The endpoint of a relation.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum RelationEndpoint {
+    /// The entity instance the relation starts at.
+    Outbound,
+
+    /// The entity instance the relation ends at.
+    Inbound,
+}
+
+impl Display for RelationEndpoint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            RelationEndpoint::Outbound => write!(f, "outbound"),
+            RelationEndpoint::Inbound => write!(f, "inbound"),
+        }
+    }
+}
+
+impl RelationType {
+    /// Returns the component or entity type which is required at the given endpoint.
+    pub fn endpoint_type(&self, endpoint: RelationEndpoint) -> &ComponentOrEntityTypeId {
+        match endpoint {
+            RelationEndpoint::Outbound => &self.outbound_type,
+            RelationEndpoint::Inbound => &self.inbound_type,
+        }
+    }
+
+    /// Returns all component or entity types which are accepted at the given endpoint, starting
+    /// with the primary endpoint type.
+    pub fn endpoint_types(&self, endpoint: RelationEndpoint) -> Vec<&ComponentOrEntityTypeId> {
+        let further_types = match endpoint {
+            RelationEndpoint::Outbound => &self.outbound_types,
+            RelationEndpoint::Inbound => &self.inbound_types,
+        };
+        std::iter::once(self.endpoint_type(endpoint)).chain(further_types.iter()).collect()
+    }
+
+    /// Additionally accepts the given component or entity type as outbound.
+    pub fn with_outbound_type<T: Into<ComponentOrEntityTypeId>>(mut self, ty: T) -> RelationType {
+        self.outbound_types.push(ty.into());
+        self
+    }
+
+    /// Additionally accepts the given component or entity type as inbound.
+    pub fn with_inbound_type<T: Into<ComponentOrEntityTypeId>>(mut self, ty: T) -> RelationType {
+        self.inbound_types.push(ty.into());
+        self
+    }
+
+    /// Returns true, if the given entity type is accepted at the given endpoint.
+    pub fn accepts_endpoint(&self, endpoint: RelationEndpoint, entity_type: &EntityType) -> bool {
+        self.endpoint_types(endpoint).iter().any(|ty| ty.accepts_entity_type(entity_type))
+    }
+
+    /// Returns true, if the given outbound and inbound entity types are accepted by the relation
+    /// type.
+    ///
+    /// Endpoint types may contain wildcards, see [`ComponentOrEntityTypeId::accepts_entity_type`].
+    /// Inherited components and parent entity types are only considered by
+    /// [`TypeSystem::satisfies_endpoint`].
+    pub fn accepts(&self, outbound_ty: &EntityType, inbound_ty: &EntityType) -> bool {
+        self.accepts_endpoint(RelationEndpoint::Outbound, outbound_ty) && self.accepts_endpoint(RelationEndpoint::Inbound, inbound_ty)
+    }
+}
+
+impl RelationInstance {
+    /// Returns the id of the entity instance at the given endpoint.
+    pub fn endpoint_id(&self, endpoint: RelationEndpoint) -> Uuid {
+        match endpoint {
+            RelationEndpoint::Outbound => self.outbound_id,
+            RelationEndpoint::Inbound => self.inbound_id,
+        }
+    }
+}
+
+impl TypeSystem {
+    /// Returns true, if entity instances of the given entity type satisfy the given endpoint
+    /// constraint.
+    ///
+    /// An entity type constraint is satisfied by the entity type and by the entity types which
+    /// inherit from it. A component constraint is satisfied by every entity type which has the
+    /// component, including the inherited components. Constraints may contain wildcards.
+    pub fn satisfies_endpoint(&self, entity_type: &EntityType, endpoint_type: &ComponentOrEntityTypeId) -> bool {
+        match endpoint_type {
+            ComponentOrEntityTypeId::EntityType(_) => {
+                endpoint_type.accepts_entity_type(entity_type)
+                    || self
+                        .entity_type_ancestors(&entity_type.ty)
+                        .is_ok_and(|ancestors| ancestors.iter().any(|ancestor| endpoint_type.accepts_entity_type(ancestor)))
+            }
+            ComponentOrEntityTypeId::Component(ty) => {
+                let components = match entity_type.extends {
+                    Some(_) => self
+                        .resolve_effective_entity_type(&entity_type.ty)
+                        .map(|entity_type| entity_type.components)
+                        .unwrap_or_else(|_| entity_type.components.clone()),
+                    None => entity_type.components.clone(),
+                };
+                components
+                    .iter()
+                    .any(|component_ty| type_matches(ty, component_ty) || self.is_same_component(component_ty, ty))
+            }
+        }
+    }
+
+    /// Returns true, if entity instances of the given entity type satisfy one of the endpoint
+    /// constraints of the given relation type at the given endpoint.
+    pub fn satisfies_relation_endpoint(&self, entity_type: &EntityType, relation_type: &RelationType, endpoint: RelationEndpoint) -> bool {
+        relation_type
+            .endpoint_types(endpoint)
+            .iter()
+            .any(|endpoint_type| self.satisfies_endpoint(entity_type, endpoint_type))
+    }
+
+    /// Returns the entity types which satisfy the given endpoint constraint.
+    pub fn endpoint_entity_types(&self, endpoint_type: &ComponentOrEntityTypeId) -> Vec<&EntityType> {
+        self.entity_types
+            .iter()
+            .filter(|entity_type| self.satisfies_endpoint(entity_type, endpoint_type))
+            .collect()
+    }
+
+    /// Returns the relation types which accept entity instances of the given entity type at the
+    /// given endpoint.
+    pub fn relation_types_at_endpoint(&self, entity_type: &EntityType, endpoint: RelationEndpoint) -> Vec<&RelationType> {
+        self.relation_types
+            .iter()
+            .filter(|relation_type| self.satisfies_relation_endpoint(entity_type, relation_type, endpoint))
+            .collect()
+    }
+
+    /// Validates that the given outbound and inbound entity instances satisfy the endpoint
+    /// constraints of the relation type of the given relation instance.
+    pub fn validate_relation_endpoints(
+        &self,
+        relation_instance: &RelationInstance,
+        outbound: &EntityInstance,
+        inbound: &EntityInstance,
+    ) -> Result<(), ValidationError> {
+        let relation_ty = relation_instance.relation_type_id();
+        let relation_type = self
+            .resolve_relation_type(&relation_ty)
+            .ok_or(ValidationError::UnknownRelationType(relation_ty))?;
+        for (endpoint, entity_instance) in [(RelationEndpoint::Outbound, outbound), (RelationEndpoint::Inbound, inbound)] {
+            let entity_type = self
+                .resolve_entity_type(&entity_instance.ty)
+                .ok_or_else(|| ValidationError::UnknownEntityType(entity_instance.ty.clone()))?;
+            if !self.satisfies_relation_endpoint(entity_type, relation_type, endpoint) {
+                return Err(ValidationError::UnsatisfiedEndpoint {
+                    ty: relation_type.ty.clone(),
+                    endpoint,
+                    expected: relation_type.endpoint_type(endpoint).clone(),
+                    actual: entity_type.ty.clone(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns true, if both component type ids refer to the same component, either directly or
+    /// as previous type id.
+    fn is_same_component(&self, a: &ComponentTypeId, b: &ComponentTypeId) -> bool {
+        a == b || self.resolve_component(a).is_some_and(|component| component.is_known_as(b))
+    }
+}
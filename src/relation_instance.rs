@@ -2,22 +2,34 @@ use std::collections::HashMap;
 use std::fmt::Display;
 use std::fmt::Formatter;
 
+use chrono::DateTime;
+use chrono::Utc;
+use indradb::BulkInsertItem;
 use indradb::EdgeKey;
 use indradb::EdgeProperties;
+use indradb::Identifier;
 use serde::Deserialize;
 use serde::Serialize;
 use serde_json::Map;
 use serde_json::Value;
 use uuid::Uuid;
 
+use crate::check_revision;
+use crate::is_initial_revision;
+use crate::property_identifier;
+use crate::CompactDebug;
+use crate::CowProperties;
 use crate::Extension;
 use crate::ExtensionContainer;
 use crate::ExtensionTypeId;
+use crate::ModelError;
 use crate::MutablePropertyInstanceSetter;
 use crate::NamespacedTypeGetter;
 use crate::PropertyInstanceGetter;
+use crate::PropertyInstanceRefGetter;
 use crate::RelationInstanceTypeId;
 use crate::RelationTypeId;
+use crate::RevisionError;
 use crate::TypeDefinition;
 use crate::TypeDefinitionGetter;
 
@@ -32,6 +44,8 @@ use crate::TypeDefinitionGetter;
 /// In contrast to the relation type, the relation instance stores values/
 /// documents in it's properties.
 #[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct RelationInstance {
     /// The id of the outbound vertex.
     pub outbound_id: Uuid,
@@ -53,12 +67,41 @@ pub struct RelationInstance {
     /// a representation of a JSON. Therefore the value can be boolean, number, string,
     /// array or an object. For more information about the data types please look at
     /// https://docs.serde.rs/serde_json/value/enum.Value.html
-    #[serde(default = "HashMap::new")]
-    pub properties: HashMap<String, Value>,
+    ///
+    /// Clones of the instance share the property map until one of them is mutated.
+    #[serde(default = "CowProperties::new")]
+    pub properties: CowProperties,
 
     /// Relation instance specific extensions.
     #[serde(default = "Vec::new")]
     pub extensions: Vec<Extension>,
+
+    /// The point of time when the relation instance has been created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+
+    /// The point of time when the relation instance has been updated for the last time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+
+    /// The user or the service which has created the relation instance.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<String>,
+
+    /// The point of time when the relation instance has been deleted. Deleted relation instances are kept as
+    /// tombstones until they are purged, so that the deletion can be undone or synchronized.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<DateTime<Utc>>,
+
+    /// The revision of the relation instance, which is incremented by each modification. Concurrent editors
+    /// compare the revision in order to detect conflicting writes.
+    #[serde(default, skip_serializing_if = "is_initial_revision")]
+    pub revision: u64,
+
+    /// Unknown fields of the data access object, which are re-emitted when converting back into
+    /// a data access object.
+    #[serde(skip)]
+    pub extra: Map<String, Value>,
 }
 
 impl RelationInstance {
@@ -69,8 +112,14 @@ impl RelationInstance {
             ty: ty.into(),
             inbound_id,
             description: String::new(),
-            properties,
+            properties: properties.into(),
             extensions: Vec::new(),
+            created_at: None,
+            updated_at: None,
+            created_by: None,
+            deleted_at: None,
+            revision: 0,
+            extra: Map::new(),
         }
     }
 
@@ -87,8 +136,14 @@ impl RelationInstance {
             ty: RelationInstanceTypeId::new_from_type_unique_id(namespace, type_name),
             inbound_id,
             description: String::new(),
-            properties,
+            properties: properties.into(),
             extensions: Vec::new(),
+            created_at: None,
+            updated_at: None,
+            created_by: None,
+            deleted_at: None,
+            revision: 0,
+            extra: Map::new(),
         }
     }
 
@@ -106,8 +161,14 @@ impl RelationInstance {
             ty: RelationInstanceTypeId::new_from_type_unique_for_instance_id(namespace, type_name, instance_id),
             inbound_id,
             description: String::new(),
-            properties,
+            properties: properties.into(),
             extensions: Vec::new(),
+            created_at: None,
+            updated_at: None,
+            created_by: None,
+            deleted_at: None,
+            revision: 0,
+            extra: Map::new(),
         }
     }
 
@@ -124,8 +185,14 @@ impl RelationInstance {
             ty: RelationInstanceTypeId::new_from_type_with_random_instance_id(namespace, type_name),
             inbound_id,
             description: String::new(),
-            properties,
+            properties: properties.into(),
             extensions: Vec::new(),
+            created_at: None,
+            updated_at: None,
+            created_by: None,
+            deleted_at: None,
+            revision: 0,
+            extra: Map::new(),
         }
     }
 
@@ -136,9 +203,103 @@ impl RelationInstance {
             ty: ty.into(),
             inbound_id,
             description: String::new(),
-            properties: HashMap::new(),
+            properties: CowProperties::new(),
             extensions: Vec::new(),
+            created_at: None,
+            updated_at: None,
+            created_by: None,
+            deleted_at: None,
+            revision: 0,
+            extra: Map::new(),
+        }
+    }
+
+    /// Sets the description of the relation instance.
+    pub fn with_description<S: Into<String>>(mut self, description: S) -> RelationInstance {
+        self.description = description.into();
+        self
+    }
+
+    /// Adds the given extension to the relation instance.
+    pub fn with_extension(mut self, extension: Extension) -> RelationInstance {
+        self.extensions.push(extension);
+        self
+    }
+
+    /// Sets the user or the service which has created the relation instance and marks the relation instance as
+    /// created now.
+    pub fn with_created_by<S: Into<String>>(mut self, created_by: S) -> RelationInstance {
+        self.created_by = Some(created_by.into());
+        self.touch();
+        self
+    }
+
+    /// Marks the relation instance as updated now. If the relation instance has no creation time, it is marked
+    /// as created now, too.
+    pub fn touch(&mut self) {
+        self.touch_at(Utc::now());
+    }
+
+    /// Marks the relation instance as updated at the given point of time. If the relation instance has no
+    /// creation time, it is marked as created at the given point of time, too.
+    pub fn touch_at(&mut self, now: DateTime<Utc>) {
+        self.created_at.get_or_insert(now);
+        self.updated_at = Some(now);
+    }
+
+    /// Returns true, if the relation instance is marked as deleted.
+    pub fn is_deleted(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+
+    /// Marks the relation instance as deleted now without removing it.
+    pub fn mark_deleted(&mut self) {
+        self.mark_deleted_at(Utc::now());
+    }
+
+    /// Marks the relation instance as deleted at the given point of time without removing it. An
+    /// relation instance which is already marked as deleted keeps its point of time of deletion.
+    pub fn mark_deleted_at(&mut self, now: DateTime<Utc>) {
+        self.touch_at(now);
+        self.deleted_at = Some(now);
+        self.increment_revision();
+        if self.deleted_at.is_none() {
+            self.touch_at(now);
+            self.deleted_at = Some(now);
+        }
+    }
+
+    /// Undoes the deletion of the relation instance.
+    pub fn restore(&mut self) {
+        if self.deleted_at.take().is_some() {
+            self.touch();
+            self.increment_revision();
+        }
+    }
+
+    /// Increments the revision of the relation instance. The setters increment the revision themselves,
+    /// only direct modifications of the fields have to be followed by an increment.
+    pub fn increment_revision(&mut self) {
+        self.revision = self.revision.wrapping_add(1);
+    }
+
+    /// Sets the value of the given property, if the relation instance still has the expected revision.
+    ///
+    /// Returns the new revision. Fails without modification, if another write happened since the
+    /// expected revision or if the property doesn't exist.
+    pub fn check_and_set<S: Into<String>>(&mut self, expected_revision: u64, property_name: S, value: Value) -> Result<u64, RevisionError> {
+        check_revision(expected_revision, self.revision)?;
+        let property_name = property_name.into();
+        if !self.properties.contains_key(&property_name) {
+            return Err(RevisionError::MissingProperty(property_name));
         }
+        self.set(property_name, value);
+        Ok(self.revision)
+    }
+
+    /// Returns a debug formatter which truncates large property values and elides binary content.
+    pub fn debug_compact(&self) -> CompactDebug<'_, RelationInstance> {
+        CompactDebug::new(self)
     }
 
     /// Returns the inner relation type id.
@@ -155,10 +316,26 @@ impl RelationInstance {
     pub fn get_key(&self) -> EdgeKey {
         EdgeKey::new(self.outbound_id, self.type_id(), self.inbound_id)
     }
+
+    /// Returns the bulk insert items of the edge and of the properties of the relation instance.
+    ///
+    /// The edge precedes the properties. This is the inverse of the conversion from
+    /// `EdgeProperties`.
+    pub fn to_bulk_insert_items(&self) -> Vec<BulkInsertItem> {
+        let key = self.get_key();
+        let mut items = Vec::with_capacity(self.properties.len() + 1);
+        items.push(BulkInsertItem::Edge(key.clone()));
+        items.extend(
+            self.properties
+                .iter()
+                .map(|(property_name, value)| BulkInsertItem::EdgeProperty(key.clone(), property_identifier(property_name), value.clone())),
+        );
+        items
+    }
 }
 
 impl TryFrom<EdgeProperties> for RelationInstance {
-    type Error = ();
+    type Error = ModelError;
 
     fn try_from(properties: EdgeProperties) -> Result<Self, Self::Error> {
         let ty = RelationInstanceTypeId::try_from(&properties.edge.key.t)?;
@@ -169,6 +346,12 @@ impl TryFrom<EdgeProperties> for RelationInstance {
             description: String::new(),
             properties: properties.props.iter().map(|p| (p.name.to_string(), p.value.clone())).collect(),
             extensions: Vec::new(),
+            created_at: None,
+            updated_at: None,
+            created_by: None,
+            deleted_at: None,
+            revision: 0,
+            extra: Map::new(),
         })
     }
 }
@@ -207,10 +390,17 @@ impl PropertyInstanceGetter for RelationInstance {
     }
 }
 
+impl PropertyInstanceRefGetter for RelationInstance {
+    fn get_ref(&self, property_name: &str) -> Option<&Value> {
+        self.properties.get(property_name)
+    }
+}
+
 impl MutablePropertyInstanceSetter for RelationInstance {
     fn set<S: Into<String>>(&mut self, property_name: S, value: Value) {
         if let Some(property_value) = self.properties.get_mut(&property_name.into()) {
-            *property_value = value
+            *property_value = value;
+            self.increment_revision();
         }
     }
 }
@@ -239,10 +429,20 @@ impl TypeDefinitionGetter for RelationInstance {
     fn type_definition(&self) -> TypeDefinition {
         self.ty.type_definition()
     }
+
+    fn type_id(&self) -> Identifier {
+        self.ty.type_id()
+    }
 }
 
+/// Formats the outbound id, the type and the inbound id of the relation instance. The alternate
+/// form `{:#}` appends the number of properties.
 impl Display for RelationInstance {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}--[{}]-->{}", self.outbound_id, &self.ty, self.inbound_id)
+        write!(f, "{}--[{}]-->{}", self.outbound_id, &self.ty, self.inbound_id)?;
+        if f.alternate() {
+            write!(f, " ({} properties)", self.properties.len())?;
+        }
+        Ok(())
     }
 }
@@ -0,0 +1,125 @@
+use std::collections::HashMap;
+
+use chrono::DateTime;
+use chrono::Utc;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Map;
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::is_initial_revision;
+use crate::Extension;
+use crate::NamespacedTypeGetter;
+use crate::RelationInstance;
+use crate::RelationInstanceTypeId;
+use crate::RelationTypeId;
+
+/// Data access object of a relation instance.
+///
+/// Defines the stable external representation of a relation instance. The in-memory
+/// representation (`RelationInstance`) can evolve without breaking existing files.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct RelationInstanceDao {
+    /// The id of the outbound entity instance.
+    #[serde(alias = "outbound")]
+    pub outbound_id: Uuid,
+
+    /// The namespace of the relation type.
+    #[serde(default = "String::new")]
+    pub namespace: String,
+
+    /// The name of the relation type.
+    #[serde(alias = "name")]
+    pub type_name: String,
+
+    /// The instance id of the relation instance.
+    #[serde(default = "String::new")]
+    pub instance_id: String,
+
+    /// The id of the inbound entity instance.
+    #[serde(alias = "inbound")]
+    pub inbound_id: Uuid,
+
+    /// The description of the relation instance.
+    #[serde(default = "String::new")]
+    pub description: String,
+
+    /// The properties of the relation instance.
+    #[serde(default = "HashMap::new")]
+    #[cfg_attr(feature = "ordered_properties", serde(serialize_with = "crate::serialize_ordered_map"))]
+    pub properties: HashMap<String, Value>,
+
+    /// Relation instance specific extensions.
+    #[serde(default = "Vec::new")]
+    pub extensions: Vec<Extension>,
+
+    /// The point of time when the relation instance has been created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+
+    /// The point of time when the relation instance has been updated for the last time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+
+    /// The user or the service which has created the relation instance.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<String>,
+
+    /// The point of time when the relation instance has been deleted. Deleted relation instances are kept as
+    /// tombstones until they are purged, so that the deletion can be undone or synchronized.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<DateTime<Utc>>,
+
+    /// The revision of the relation instance, which is incremented by each modification. Concurrent editors
+    /// compare the revision in order to detect conflicting writes.
+    #[serde(default, skip_serializing_if = "is_initial_revision")]
+    pub revision: u64,
+
+    /// Unknown fields, for example written by a newer version, which are re-emitted on save.
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl From<RelationInstance> for RelationInstanceDao {
+    fn from(relation_instance: RelationInstance) -> Self {
+        let ty = relation_instance.relation_type_id();
+        RelationInstanceDao {
+            outbound_id: relation_instance.outbound_id,
+            namespace: ty.namespace(),
+            type_name: ty.type_name(),
+            instance_id: relation_instance.instance_id(),
+            inbound_id: relation_instance.inbound_id,
+            description: relation_instance.description,
+            properties: relation_instance.properties.into(),
+            extensions: relation_instance.extensions,
+            created_at: relation_instance.created_at,
+            updated_at: relation_instance.updated_at,
+            created_by: relation_instance.created_by,
+            deleted_at: relation_instance.deleted_at,
+            revision: relation_instance.revision,
+            extra: relation_instance.extra,
+        }
+    }
+}
+
+impl From<RelationInstanceDao> for RelationInstance {
+    fn from(dao: RelationInstanceDao) -> Self {
+        let ty = RelationInstanceTypeId::new_unique_for_instance_id(RelationTypeId::new_from_type(dao.namespace, dao.type_name), dao.instance_id);
+        RelationInstance {
+            outbound_id: dao.outbound_id,
+            ty,
+            inbound_id: dao.inbound_id,
+            description: dao.description,
+            properties: dao.properties.into(),
+            extensions: dao.extensions,
+            created_at: dao.created_at,
+            updated_at: dao.updated_at,
+            created_by: dao.created_by,
+            deleted_at: dao.deleted_at,
+            revision: dao.revision,
+            extra: dao.extra,
+        }
+    }
+}
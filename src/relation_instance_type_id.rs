@@ -1,10 +1,15 @@
 use indradb::Identifier;
 use serde::Deserialize;
 use serde::Serialize;
+use std::fmt::Debug;
 use std::fmt::Display;
 use std::fmt::Formatter;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::sync::OnceLock;
 use uuid::Uuid;
 
+use crate::ModelError;
 use crate::NamespacedType;
 use crate::NamespacedTypeGetter;
 use crate::RelationTypeId;
@@ -15,6 +20,7 @@ use crate::TYPE_ID_TYPE_SEPARATOR;
 
 /// Type identifier of a relation instance.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct RelationInstanceTypeId {
     /// The type definition of the relation type.
     #[serde(flatten)]
@@ -22,6 +28,10 @@ pub struct RelationInstanceTypeId {
 
     /// The instance id.
     instance_id: String,
+
+    /// The identifier of the relation instance type id, which is computed once on first access.
+    #[serde(skip)]
+    identifier: IdentifierCache,
 }
 
 impl RelationInstanceTypeId {
@@ -30,6 +40,7 @@ impl RelationInstanceTypeId {
         RelationInstanceTypeId {
             ty: ty.into(),
             instance_id: String::new(),
+            identifier: IdentifierCache::default(),
         }
     }
 
@@ -42,6 +53,7 @@ impl RelationInstanceTypeId {
         RelationInstanceTypeId {
             ty: ty.into(),
             instance_id: instance_id.into(),
+            identifier: IdentifierCache::default(),
         }
     }
 
@@ -51,6 +63,7 @@ impl RelationInstanceTypeId {
         RelationInstanceTypeId {
             ty: ty.into(),
             instance_id: Uuid::new_v4().to_string(),
+            identifier: IdentifierCache::default(),
         }
     }
 
@@ -105,6 +118,10 @@ impl TypeDefinitionGetter for RelationInstanceTypeId {
     fn type_definition(&self) -> TypeDefinition {
         self.into()
     }
+
+    fn type_id(&self) -> Identifier {
+        self.identifier.0.get_or_init(|| (&self.type_definition()).into()).clone()
+    }
 }
 
 impl From<&RelationInstanceTypeId> for RelationInstanceTypeId {
@@ -127,55 +144,34 @@ impl From<&RelationInstanceTypeId> for NamespacedType {
 }
 
 impl TryFrom<&Identifier> for RelationInstanceTypeId {
-    type Error = ();
+    type Error = ModelError;
 
     fn try_from(t: &Identifier) -> Result<Self, Self::Error> {
-        let s = t.to_string();
-        let mut s = s.splitn(4, &TYPE_ID_TYPE_SEPARATOR);
-        let type_id_type = s.next().ok_or(())?.try_into()?;
-        if TypeIdType::RelationType == type_id_type {
-            let namespace = s.next().ok_or(())?;
-            if namespace.is_empty() {
-                return Err(());
-            }
-            let type_name = s.next().ok_or(())?;
-            if type_name.is_empty() {
-                return Err(());
-            }
-            let rty = RelationTypeId::new_from_type(namespace, type_name);
-            let ty = match s.next() {
-                Some(instance_id) => RelationInstanceTypeId::new_unique_for_instance_id(rty, instance_id),
-                None => RelationInstanceTypeId::new_unique_id(rty),
-            };
-            return Ok(ty);
-        }
-        Err(())
+        RelationInstanceTypeId::try_from(&t.to_string())
     }
 }
 
 impl TryFrom<&String> for RelationInstanceTypeId {
-    type Error = ();
+    type Error = ModelError;
 
     fn try_from(s: &String) -> Result<Self, Self::Error> {
-        let mut s = s.splitn(4, &TYPE_ID_TYPE_SEPARATOR);
-        let type_id_type = s.next().ok_or(())?.try_into()?;
-        if TypeIdType::RelationType == type_id_type {
-            let namespace = s.next().ok_or(())?;
-            if namespace.is_empty() {
-                return Err(());
-            }
-            let type_name = s.next().ok_or(())?;
-            if type_name.is_empty() {
-                return Err(());
-            }
-            let rty = RelationTypeId::new_from_type(namespace, type_name);
-            let ty = match s.next() {
-                Some(instance_id) => RelationInstanceTypeId::new_unique_for_instance_id(rty, instance_id),
-                None => RelationInstanceTypeId::new_unique_id(rty),
-            };
-            return Ok(ty);
+        let invalid = || ModelError::InvalidTypeId(s.clone());
+        let mut parts = s.splitn(4, &TYPE_ID_TYPE_SEPARATOR);
+        let type_id_type = TypeIdType::try_from(parts.next().ok_or_else(invalid)?)?;
+        if TypeIdType::RelationType != type_id_type {
+            return Err(ModelError::UnexpectedTypeIdType {
+                expected: TypeIdType::RelationType,
+                actual: type_id_type,
+            });
         }
-        Err(())
+        let namespace = parts.next().filter(|namespace| !namespace.is_empty()).ok_or_else(invalid)?;
+        let type_name = parts.next().filter(|type_name| !type_name.is_empty()).ok_or_else(invalid)?;
+        let rty = RelationTypeId::new_from_type(namespace, type_name);
+        let ty = match parts.next() {
+            Some(instance_id) => RelationInstanceTypeId::new_unique_for_instance_id(rty, instance_id),
+            None => RelationInstanceTypeId::new_unique_id(rty),
+        };
+        Ok(ty)
     }
 }
 
@@ -184,3 +180,26 @@ impl Display for RelationInstanceTypeId {
         write!(f, "{}", &self.type_definition().to_string())
     }
 }
+
+/// Lazily computed identifier which is ignored by comparisons and hashing, because it is
+/// derived from the other fields.
+#[derive(Clone, Default)]
+struct IdentifierCache(OnceLock<Identifier>);
+
+impl PartialEq for IdentifierCache {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for IdentifierCache {}
+
+impl Hash for IdentifierCache {
+    fn hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+impl Debug for IdentifierCache {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "..")
+    }
+}
@@ -1,24 +1,42 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::hash::Hasher;
+
 use serde::Deserialize;
 use serde::Serialize;
+use serde_json::Map;
+use serde_json::Value;
 
 use crate::extension::Extension;
+use crate::validate_instance_properties;
+use crate::Component;
 use crate::ComponentOrEntityTypeId;
 use crate::ComponentTypeId;
+use crate::Deprecation;
+use crate::Documentation;
 use crate::ExtensionContainer;
 use crate::ExtensionTypeId;
+use crate::LanguageTag;
 use crate::NamespacedTypeGetter;
 use crate::PropertyType;
+use crate::RelationCardinality;
+use crate::RelationInstance;
+use crate::RelationInstanceTypeId;
 use crate::RelationTypeId;
+use crate::TagContainer;
 use crate::TypeContainer;
 use crate::TypeDefinition;
 use crate::TypeDefinitionGetter;
 use crate::TypeIdType;
+use crate::ValidationError;
 
 /// A relation type defines the type of an relation instance.
 ///
 /// The relation type defines the entity types of the outbound and inbound entity instances.
 /// Also the relation type defines the properties of the relation instance.
 #[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct RelationType {
     /// The outbound component or entity type.
     #[serde(rename = "outbound", alias = "outbound")]
@@ -36,6 +54,70 @@ pub struct RelationType {
     #[serde(default = "String::new")]
     pub description: String,
 
+    /// The descriptions in other languages, by language tag.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty", serialize_with = "crate::serialize_descriptions")]
+    pub descriptions: HashMap<LanguageTag, String>,
+
+    /// The structured documentation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub documentation: Option<Documentation>,
+
+    /// The tags of the relation type, for grouping types into categories.
+    #[serde(default = "Vec::new")]
+    pub tags: Vec<String>,
+
+    /// The previous type ids of the relation type. References to a previous type id are resolved
+    /// to the relation type.
+    #[serde(default = "Vec::new")]
+    pub aliases: Vec<RelationTypeId>,
+
+    /// The deprecation of the relation type, if the relation type is deprecated.
+    #[serde(default)]
+    pub deprecation: Option<Deprecation>,
+
+    /// The further component or entity types which are accepted as outbound.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub outbound_types: Vec<ComponentOrEntityTypeId>,
+
+    /// The further component or entity types which are accepted as inbound.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub inbound_types: Vec<ComponentOrEntityTypeId>,
+
+    /// The cardinality constraints of the relation type. Without constraints any number of
+    /// entity instances can be related.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cardinality: Option<RelationCardinality>,
+
+    /// If true, the relation is traversed in both directions.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub bidirectional: bool,
+
+    /// The type name of the inverse relation type in the same namespace, for example
+    /// `contained_by` as the inverse of `contains`. Bidirectional relation types without an
+    /// inverse name are their own inverse.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inverse_name: Option<String>,
+
+    /// If true, every relation from an entity instance to another entity instance implies the
+    /// relation in the opposite direction.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub symmetric: bool,
+
+    /// If true, relations from a first to a second and from the second to a third entity
+    /// instance imply the relation from the first to the third entity instance.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub transitive: bool,
+
+    /// If true, every entity instance which satisfies the endpoint constraints is implicitly
+    /// related to itself.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub reflexive: bool,
+
+    /// If true, the properties of the components are scoped by the type names of the components,
+    /// for example `labeled.label`.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub scoped_properties: bool,
+
     /// The names of the components of the relation type.
     #[serde(default = "Vec::new")]
     pub components: Vec<ComponentTypeId>,
@@ -47,6 +129,11 @@ pub struct RelationType {
     /// Relation type specific extensions.
     #[serde(default = "Vec::new")]
     pub extensions: Vec<Extension>,
+
+    /// Unknown fields of the data access object, which are re-emitted when converting back into
+    /// a data access object.
+    #[serde(skip)]
+    pub extra: Map<String, Value>,
 }
 
 impl RelationType {
@@ -66,9 +153,141 @@ impl RelationType {
             ty,
             inbound_type: inbound_type.into(),
             description: description.into(),
+            descriptions: HashMap::new(),
+            documentation: None,
+            tags: Vec::new(),
+            aliases: Vec::new(),
+            deprecation: None,
+            outbound_types: Vec::new(),
+            inbound_types: Vec::new(),
+            cardinality: None,
+            bidirectional: false,
+            inverse_name: None,
+            symmetric: false,
+            transitive: false,
+            reflexive: false,
+            scoped_properties: false,
             components,
             properties,
             extensions,
+            extra: Map::new(),
+        }
+    }
+
+    /// Adds the given tag to the relation type.
+    pub fn with_tag<S: Into<String>>(mut self, tag: S) -> RelationType {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Adds the given previous type id to the relation type.
+    pub fn with_alias<A: Into<RelationTypeId>>(mut self, alias: A) -> RelationType {
+        self.aliases.push(alias.into());
+        self
+    }
+
+    /// Returns true, if the given type id is the type id or a previous type id of the relation type.
+    pub fn is_known_as(&self, ty: &RelationTypeId) -> bool {
+        &self.ty == ty || self.aliases.contains(ty)
+    }
+
+    /// Deprecates the relation type.
+    pub fn with_deprecation(mut self, deprecation: Deprecation) -> RelationType {
+        self.deprecation = Some(deprecation);
+        self
+    }
+
+    /// Returns true, if the relation type is deprecated.
+    pub fn is_deprecated(&self) -> bool {
+        self.deprecation.is_some()
+    }
+
+    /// Declares the relation type as bidirectional with the given type name of the inverse
+    /// relation type.
+    pub fn with_inverse<S: Into<String>>(mut self, inverse_name: S) -> RelationType {
+        self.bidirectional = true;
+        self.inverse_name = Some(inverse_name.into());
+        self
+    }
+
+    /// Declares the relation type as bidirectional and as its own inverse.
+    pub fn with_bidirectional(mut self) -> RelationType {
+        self.bidirectional = true;
+        self
+    }
+
+    /// Declares the relation type as symmetric.
+    pub fn with_symmetric(mut self) -> RelationType {
+        self.symmetric = true;
+        self
+    }
+
+    /// Declares the relation type as transitive.
+    pub fn with_transitive(mut self) -> RelationType {
+        self.transitive = true;
+        self
+    }
+
+    /// Declares the relation type as reflexive.
+    pub fn with_reflexive(mut self) -> RelationType {
+        self.reflexive = true;
+        self
+    }
+
+    /// Returns the type id of the inverse relation type, if the relation type is bidirectional.
+    ///
+    /// Symmetric relation types are their own inverse.
+    pub fn inverse_type_id(&self) -> Option<RelationTypeId> {
+        if self.symmetric {
+            return Some(self.ty.clone());
+        }
+        if !self.bidirectional {
+            return None;
+        }
+        match &self.inverse_name {
+            Some(inverse_name) => Some(RelationTypeId::new_from_type(self.namespace(), inverse_name.clone())),
+            None => Some(self.ty.clone()),
+        }
+    }
+
+    /// Returns the inverse of the given relation instance of this relation type, if the relation
+    /// type is bidirectional.
+    ///
+    /// The inverse relation instance is of the inverse relation type and connects the inbound
+    /// entity instance with the outbound entity instance. The instance id, the properties and
+    /// the extensions are kept.
+    pub fn inverse_relation_instance(&self, relation_instance: &RelationInstance) -> Option<RelationInstance> {
+        let inverse_ty = self.inverse_type_id()?;
+        let mut inverse = relation_instance.clone();
+        inverse.outbound_id = relation_instance.inbound_id;
+        inverse.inbound_id = relation_instance.outbound_id;
+        inverse.ty = RelationInstanceTypeId::new_unique_for_instance_id(inverse_ty, relation_instance.instance_id());
+        Some(inverse)
+    }
+
+    /// Validates that the given relation instance is of this relation type and that it contains
+    /// the properties of this relation type and of its components with values of the right data
+    /// type.
+    ///
+    /// The given components must contain every component of this relation type.
+    pub fn validate_instance(&self, relation_instance: &RelationInstance, components: &[Component]) -> Result<(), ValidationError> {
+        let relation_ty = relation_instance.relation_type_id();
+        if !self.is_known_as(&relation_ty) {
+            return Err(ValidationError::UnexpectedType {
+                expected: self.ty.to_string(),
+                actual: relation_ty.to_string(),
+            });
+        }
+        validate_instance_properties(self.scoped_properties, &self.properties, &self.components, components, &relation_instance.properties)
+    }
+
+    /// Returns true, if both relation types have the same type and the same definition.
+    ///
+    /// In contrast, `==` compares only the type ids.
+    pub fn structurally_equals(&self, other: &RelationType) -> bool {
+        match (serde_json::to_value(self), serde_json::to_value(other)) {
+            (Ok(a), Ok(b)) => a == b,
+            _ => false,
         }
     }
 }
@@ -99,6 +318,16 @@ impl ExtensionContainer for RelationType {
     }
 }
 
+impl TagContainer for RelationType {
+    fn get_tags(&self) -> Vec<String> {
+        self.tags.clone()
+    }
+
+    fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t == tag)
+    }
+}
+
 impl NamespacedTypeGetter for RelationType {
     fn namespace(&self) -> String {
         self.ty.namespace()
@@ -115,6 +344,21 @@ impl TypeDefinitionGetter for RelationType {
     }
 }
 
+/// Two relation types are equal, if they have the same type id.
+impl PartialEq for RelationType {
+    fn eq(&self, other: &Self) -> bool {
+        self.ty == other.ty
+    }
+}
+
+impl Eq for RelationType {}
+
+impl Hash for RelationType {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.ty.hash(state);
+    }
+}
+
 impl From<&RelationType> for TypeDefinition {
     fn from(relation_type: &RelationType) -> Self {
         TypeDefinition {
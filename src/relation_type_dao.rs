@@ -0,0 +1,185 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::ComponentOrEntityTypeId;
+use crate::ComponentTypeId;
+use crate::Deprecation;
+use crate::Documentation;
+use crate::Extension;
+use crate::LanguageTag;
+use crate::NamespacedTypeGetter;
+use crate::PropertyType;
+use crate::RelationCardinality;
+use crate::RelationType;
+use crate::RelationTypeId;
+
+/// Data access object of a relation type.
+///
+/// Defines the stable external representation of a relation type. The in-memory
+/// representation (`RelationType`) can evolve without breaking existing files.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct RelationTypeDao {
+    /// The outbound component or entity type.
+    #[serde(rename = "outbound", alias = "outbound_type")]
+    pub outbound_type: ComponentOrEntityTypeId,
+
+    /// The namespace of the relation type.
+    #[serde(default = "String::new")]
+    pub namespace: String,
+
+    /// The name of the relation type.
+    #[serde(alias = "name")]
+    pub type_name: String,
+
+    /// The inbound component or entity type.
+    #[serde(rename = "inbound", alias = "inbound_type")]
+    pub inbound_type: ComponentOrEntityTypeId,
+
+    /// Textual description of the relation type.
+    #[serde(default = "String::new")]
+    pub description: String,
+
+    /// The descriptions in other languages, by language tag.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty", serialize_with = "crate::serialize_descriptions")]
+    pub descriptions: HashMap<LanguageTag, String>,
+
+    /// The structured documentation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub documentation: Option<Documentation>,
+
+    /// The tags of the relation type, for grouping types into categories.
+    #[serde(default = "Vec::new")]
+    pub tags: Vec<String>,
+
+    /// The previous type ids of the relation type. References to a previous type id are resolved
+    /// to the relation type.
+    #[serde(default = "Vec::new")]
+    pub aliases: Vec<RelationTypeId>,
+
+    /// The deprecation of the relation type, if the relation type is deprecated.
+    #[serde(default)]
+    pub deprecation: Option<Deprecation>,
+
+    /// The further component or entity types which are accepted as outbound.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub outbound_types: Vec<ComponentOrEntityTypeId>,
+
+    /// The further component or entity types which are accepted as inbound.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub inbound_types: Vec<ComponentOrEntityTypeId>,
+
+    /// The cardinality constraints of the relation type. Without constraints any number of
+    /// entity instances can be related.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cardinality: Option<RelationCardinality>,
+
+    /// If true, the relation is traversed in both directions.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub bidirectional: bool,
+
+    /// The type name of the inverse relation type in the same namespace, for example
+    /// `contained_by` as the inverse of `contains`. Bidirectional relation types without an
+    /// inverse name are their own inverse.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inverse_name: Option<String>,
+
+    /// If true, every relation from an entity instance to another entity instance implies the
+    /// relation in the opposite direction.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub symmetric: bool,
+
+    /// If true, relations from a first to a second and from the second to a third entity
+    /// instance imply the relation from the first to the third entity instance.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub transitive: bool,
+
+    /// If true, every entity instance which satisfies the endpoint constraints is implicitly
+    /// related to itself.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub reflexive: bool,
+
+    /// If true, the properties of the components are scoped by the type names of the components,
+    /// for example `labeled.label`.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub scoped_properties: bool,
+
+    /// The components of the relation type.
+    #[serde(default = "Vec::new")]
+    pub components: Vec<ComponentTypeId>,
+
+    /// The properties which are defined by the relation type.
+    #[serde(default = "Vec::new")]
+    pub properties: Vec<PropertyType>,
+
+    /// Relation type specific extensions.
+    #[serde(default = "Vec::new")]
+    pub extensions: Vec<Extension>,
+
+    /// Unknown fields, for example written by a newer version, which are re-emitted on save.
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl From<RelationType> for RelationTypeDao {
+    fn from(relation_type: RelationType) -> Self {
+        RelationTypeDao {
+            namespace: relation_type.namespace(),
+            type_name: relation_type.type_name(),
+            outbound_type: relation_type.outbound_type,
+            inbound_type: relation_type.inbound_type,
+            description: relation_type.description,
+            descriptions: relation_type.descriptions,
+            documentation: relation_type.documentation,
+            tags: relation_type.tags,
+            aliases: relation_type.aliases,
+            deprecation: relation_type.deprecation,
+            outbound_types: relation_type.outbound_types,
+            inbound_types: relation_type.inbound_types,
+            cardinality: relation_type.cardinality,
+            bidirectional: relation_type.bidirectional,
+            inverse_name: relation_type.inverse_name,
+            symmetric: relation_type.symmetric,
+            transitive: relation_type.transitive,
+            reflexive: relation_type.reflexive,
+            scoped_properties: relation_type.scoped_properties,
+            components: relation_type.components,
+            properties: relation_type.properties,
+            extensions: relation_type.extensions,
+            extra: relation_type.extra,
+        }
+    }
+}
+
+impl From<RelationTypeDao> for RelationType {
+    fn from(dao: RelationTypeDao) -> Self {
+        RelationType {
+            outbound_type: dao.outbound_type,
+            ty: RelationTypeId::new_from_type(dao.namespace, dao.type_name),
+            inbound_type: dao.inbound_type,
+            description: dao.description,
+            descriptions: dao.descriptions,
+            documentation: dao.documentation,
+            tags: dao.tags,
+            aliases: dao.aliases,
+            deprecation: dao.deprecation,
+            outbound_types: dao.outbound_types,
+            inbound_types: dao.inbound_types,
+            cardinality: dao.cardinality,
+            bidirectional: dao.bidirectional,
+            inverse_name: dao.inverse_name,
+            symmetric: dao.symmetric,
+            transitive: dao.transitive,
+            reflexive: dao.reflexive,
+            scoped_properties: dao.scoped_properties,
+            components: dao.components,
+            properties: dao.properties,
+            extensions: dao.extensions,
+            extra: dao.extra,
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use crate::Namespace;
+use crate::NamespacedTypeGetter;
+use crate::TypeDefinition;
+use crate::TypeSystem;
+use crate::ValidationError;
+
+/// The namespaces which are reserved for the built-in types by default.
+pub const DEFAULT_RESERVED_NAMESPACES: &[&str] = &["core"];
+
+/// The registry of the namespaces which are reserved for built-in types.
+///
+/// Reserving a namespace also reserves its descendant namespaces, for example reserving `core`
+/// reserves `core::math`. User defined types must not be defined in a reserved namespace, so
+/// built-in types can't be shadowed accidentally.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReservedNamespaces {
+    namespaces: Vec<Namespace>,
+}
+
+impl ReservedNamespaces {
+    /// Constructs a registry without reserved namespaces.
+    pub fn new() -> ReservedNamespaces {
+        ReservedNamespaces { namespaces: Vec::new() }
+    }
+
+    /// Reserves the given namespace.
+    pub fn with<N: Into<Namespace>>(mut self, namespace: N) -> ReservedNamespaces {
+        self.reserve(namespace);
+        self
+    }
+
+    /// Reserves the given namespace.
+    pub fn reserve<N: Into<Namespace>>(&mut self, namespace: N) {
+        let namespace = namespace.into();
+        if !self.namespaces.contains(&namespace) {
+            self.namespaces.push(namespace);
+        }
+    }
+
+    /// Returns the reserved namespaces.
+    pub fn namespaces(&self) -> &[Namespace] {
+        &self.namespaces
+    }
+
+    /// Returns true, if the given namespace is reserved or is a descendant of a reserved namespace.
+    pub fn is_reserved(&self, namespace: &Namespace) -> bool {
+        self.namespaces.iter().any(|reserved| !reserved.is_root() && namespace.starts_with(reserved))
+    }
+
+    /// Validates that the given user defined type is not defined in a reserved namespace.
+    pub fn validate_type<T: NamespacedTypeGetter + ToString>(&self, ty: &T) -> Result<(), ValidationError> {
+        let namespace = ty.hierarchical_namespace();
+        if self.is_reserved(&namespace) {
+            return Err(ValidationError::ReservedNamespace {
+                ty: ty.to_string(),
+                namespace: namespace.to_flat(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Returns the types of the given type system which are defined in a reserved namespace.
+    pub fn violations(&self, type_system: &TypeSystem) -> Vec<TypeDefinition> {
+        type_system
+            .type_definitions()
+            .into_iter()
+            .filter(|ty| self.is_reserved(&ty.hierarchical_namespace()))
+            .collect()
+    }
+}
+
+/// The default registry reserves the `DEFAULT_RESERVED_NAMESPACES`.
+impl Default for ReservedNamespaces {
+    fn default() -> Self {
+        let mut reserved_namespaces = ReservedNamespaces::new();
+        for namespace in DEFAULT_RESERVED_NAMESPACES {
+            reserved_namespaces.reserve(*namespace);
+        }
+        reserved_namespaces
+    }
+}
@@ -0,0 +1,30 @@
+use thiserror::Error;
+
+/// The revision of an instance which hasn't been modified yet.
+pub const INITIAL_REVISION: u64 = 0;
+
+/// Error of a conditional write to an instance.
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum RevisionError {
+    /// The instance has been modified since the expected revision.
+    #[error("The instance has revision {actual} but revision {expected} was expected")]
+    Conflict { expected: u64, actual: u64 },
+
+    /// The property doesn't exist.
+    #[error("The property {0} doesn't exist")]
+    MissingProperty(String),
+}
+
+/// Returns true, if the given revision is the revision of an unmodified instance.
+pub(crate) fn is_initial_revision(revision: &u64) -> bool {
+    *revision == INITIAL_REVISION
+}
+
+/// Returns an error if the actual revision differs from the expected revision.
+pub fn check_revision(expected: u64, actual: u64) -> Result<(), RevisionError> {
+    if expected == actual {
+        Ok(())
+    } else {
+        Err(RevisionError::Conflict { expected, actual })
+    }
+}
@@ -0,0 +1,146 @@
+use std::collections::HashMap;
+
+use serde_json::json;
+use serde_json::Value;
+
+use crate::canonical_json;
+use crate::fnv1a_64;
+use crate::glob_matches;
+use crate::EntityInstance;
+use crate::FlowInstance;
+use crate::RelationInstance;
+
+/// The value which replaces redacted property values.
+pub const REDACTED_VALUE: &str = "<redacted>";
+
+/// What happens to the value of a sanitized property.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SanitizeAction {
+    /// The property is removed from the instance.
+    Remove,
+
+    /// The value is replaced by `REDACTED_VALUE`.
+    Redact,
+
+    /// The value is replaced by a hash of the value. Equal values result in equal hashes, so that
+    /// the data flow stays comprehensible. The hash is not cryptographic, so low entropy values
+    /// should be hashed with a salt.
+    Hash,
+}
+
+/// The policy of sanitizing instances before sharing them.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SanitizePolicy {
+    /// The actions by property name. The property names may contain the wildcards `*` and `?`.
+    /// The first matching rule applies.
+    pub rules: Vec<(String, SanitizeAction)>,
+
+    /// If true, the descriptions of the instances are cleared.
+    pub strip_descriptions: bool,
+
+    /// The salt which is prepended to the values before hashing.
+    pub salt: String,
+}
+
+impl SanitizePolicy {
+    pub fn new() -> SanitizePolicy {
+        SanitizePolicy::default()
+    }
+
+    /// Removes the properties which match the given property name pattern.
+    pub fn remove<S: Into<String>>(mut self, property_name: S) -> SanitizePolicy {
+        self.rules.push((property_name.into(), SanitizeAction::Remove));
+        self
+    }
+
+    /// Redacts the properties which match the given property name pattern.
+    pub fn redact<S: Into<String>>(mut self, property_name: S) -> SanitizePolicy {
+        self.rules.push((property_name.into(), SanitizeAction::Redact));
+        self
+    }
+
+    /// Hashes the properties which match the given property name pattern.
+    pub fn hash<S: Into<String>>(mut self, property_name: S) -> SanitizePolicy {
+        self.rules.push((property_name.into(), SanitizeAction::Hash));
+        self
+    }
+
+    /// Clears the descriptions of the instances.
+    pub fn strip_descriptions(mut self) -> SanitizePolicy {
+        self.strip_descriptions = true;
+        self
+    }
+
+    /// Sets the salt which is prepended to the values before hashing.
+    pub fn with_salt<S: Into<String>>(mut self, salt: S) -> SanitizePolicy {
+        self.salt = salt.into();
+        self
+    }
+
+    /// Returns the action of the first rule which matches the given property name.
+    pub fn action(&self, property_name: &str) -> Option<SanitizeAction> {
+        self.rules
+            .iter()
+            .find(|(pattern, _)| glob_matches(pattern, property_name))
+            .map(|(_, action)| *action)
+    }
+
+    fn sanitize_properties(&self, properties: &mut HashMap<String, Value>) {
+        properties.retain(|property_name, _| self.action(property_name) != Some(SanitizeAction::Remove));
+        for (property_name, value) in properties.iter_mut() {
+            match self.action(property_name) {
+                Some(SanitizeAction::Redact) => *value = json!(REDACTED_VALUE),
+                Some(SanitizeAction::Hash) => *value = json!(self.hash_value(value)),
+                _ => {}
+            }
+        }
+    }
+
+    fn hash_value(&self, value: &Value) -> String {
+        format!("{:016x}", fnv1a_64(format!("{}{}", self.salt, canonical_json(value)).as_bytes()))
+    }
+}
+
+impl EntityInstance {
+    /// Returns a copy of the entity instance whose properties and description are sanitized by
+    /// the given policy.
+    pub fn sanitized(&self, policy: &SanitizePolicy) -> EntityInstance {
+        let mut entity_instance = self.clone();
+        policy.sanitize_properties(&mut entity_instance.properties);
+        if policy.strip_descriptions {
+            entity_instance.description = String::new();
+        }
+        entity_instance
+    }
+}
+
+impl RelationInstance {
+    /// Returns a copy of the relation instance whose properties and description are sanitized by
+    /// the given policy.
+    pub fn sanitized(&self, policy: &SanitizePolicy) -> RelationInstance {
+        let mut relation_instance = self.clone();
+        policy.sanitize_properties(&mut relation_instance.properties);
+        if policy.strip_descriptions {
+            relation_instance.description = String::new();
+        }
+        relation_instance
+    }
+}
+
+impl FlowInstance {
+    /// Returns a copy of the flow instance whose entity instances and relation instances are
+    /// sanitized by the given policy. The description of the flow instance is sanitized too.
+    pub fn sanitized(&self, policy: &SanitizePolicy) -> FlowInstance {
+        let mut flow_instance = self.clone();
+        flow_instance.entity_instances = self.entity_instances.iter().map(|entity_instance| entity_instance.sanitized(policy)).collect();
+        flow_instance.relation_instances = self
+            .relation_instances
+            .iter()
+            .map(|relation_instance| relation_instance.sanitized(policy))
+            .collect();
+        if policy.strip_descriptions {
+            flow_instance.description = String::new();
+        }
+        flow_instance
+    }
+}
@@ -0,0 +1,112 @@
+use crate::Component;
+use crate::ComponentTypeId;
+use crate::EntityType;
+use crate::NamespacedTypeGetter;
+use crate::PropertyType;
+use crate::RelationType;
+
+/// The separator of the type name of the component and the property name in qualified property
+/// names, for example `labeled.label`.
+pub const PROPERTY_SCOPE_SEPARATOR: char = '.';
+
+/// Returns the qualified name of the given property of the given component, for example
+/// `labeled.label`.
+pub fn qualified_property_name(component_ty: &ComponentTypeId, property_name: &str) -> String {
+    format!("{}{}{}", component_ty.type_name(), PROPERTY_SCOPE_SEPARATOR, property_name)
+}
+
+/// Splits the given qualified property name into the type name of the component and the
+/// property name. Returns `None` if the property name isn't qualified.
+pub fn split_qualified_property_name(name: &str) -> Option<(&str, &str)> {
+    name.split_once(PROPERTY_SCOPE_SEPARATOR)
+}
+
+/// Returns the key of the given property of the given component in the properties of instances.
+///
+/// If the properties of components are scoped, the key is the qualified property name.
+/// Otherwise the key is the property name.
+pub fn component_property_key(scoped: bool, component_ty: &ComponentTypeId, property_name: &str) -> String {
+    match scoped {
+        true => qualified_property_name(component_ty, property_name),
+        false => property_name.to_string(),
+    }
+}
+
+/// Resolves the given qualified or unqualified property name to the key of the property in the
+/// properties of instances.
+///
+/// Own properties are resolved first. A qualified property name is resolved to the property of
+/// the component with the given type name. An unqualified property name is resolved to the
+/// property of the components, if exactly one component declares it. Components which are missing
+/// in the given components are skipped.
+pub fn resolve_property_key(
+    scoped: bool,
+    property_types: &[PropertyType],
+    component_tys: &[ComponentTypeId],
+    components: &[Component],
+    name: &str,
+) -> Option<String> {
+    if property_types.iter().any(|property_type| property_type.name == name) {
+        return Some(name.to_string());
+    }
+    let declares = |component_ty: &ComponentTypeId, property_name: &str| {
+        components
+            .iter()
+            .find(|component| component.is_known_as(component_ty))
+            .is_some_and(|component| component.has_property(property_name))
+    };
+    if let Some((component_type_name, property_name)) = split_qualified_property_name(name) {
+        return component_tys
+            .iter()
+            .find(|component_ty| component_ty.type_name() == component_type_name && declares(component_ty, property_name))
+            .map(|component_ty| component_property_key(scoped, component_ty, property_name));
+    }
+    let mut declaring = component_tys.iter().filter(|component_ty| declares(component_ty, name));
+    match (declaring.next(), declaring.next()) {
+        (Some(component_ty), None) => Some(component_property_key(scoped, component_ty, name)),
+        (Some(_), Some(_)) if !scoped => Some(name.to_string()),
+        _ => None,
+    }
+}
+
+impl EntityType {
+    /// Scopes the properties of the components of the entity type by the type names of the
+    /// components, so that multiple components can declare properties with the same name.
+    pub fn with_scoped_properties(mut self) -> EntityType {
+        self.scoped_properties = true;
+        self
+    }
+
+    /// Returns the key of the given property of the given component in the properties of entity
+    /// instances of this entity type.
+    pub fn component_property_key(&self, component_ty: &ComponentTypeId, property_name: &str) -> String {
+        component_property_key(self.scoped_properties, component_ty, property_name)
+    }
+
+    /// Resolves the given qualified or unqualified property name to the key of the property in
+    /// the properties of entity instances of this entity type.
+    pub fn resolve_property_key(&self, name: &str, components: &[Component]) -> Option<String> {
+        resolve_property_key(self.scoped_properties, &self.properties, &self.components, components, name)
+    }
+}
+
+impl RelationType {
+    /// Scopes the properties of the components of the relation type by the type names of the
+    /// components, so that multiple components can declare properties with the same name.
+    pub fn with_scoped_properties(mut self) -> RelationType {
+        self.scoped_properties = true;
+        self
+    }
+
+    /// Returns the key of the given property of the given component in the properties of
+    /// relation instances of this relation type.
+    pub fn component_property_key(&self, component_ty: &ComponentTypeId, property_name: &str) -> String {
+        component_property_key(self.scoped_properties, component_ty, property_name)
+    }
+
+    /// Resolves the given qualified or unqualified property name to the key of the property in
+    /// the properties of relation instances of this relation type.
+    pub fn resolve_property_key(&self, name: &str, components: &[Component]) -> Option<String> {
+        resolve_property_key(self.scoped_properties, &self.properties, &self.components, components, name)
+    }
+}
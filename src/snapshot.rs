@@ -0,0 +1,121 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+
+use serde::Serialize;
+use serde_json::Map;
+use serde_json::Value;
+use thiserror::Error;
+
+/// If this environment variable is set, the snapshot files are (re-)written instead of compared.
+pub const UPDATE_SNAPSHOTS_ENV: &str = "UPDATE_SNAPSHOTS";
+
+/// Error of comparing a DAO with a snapshot file.
+#[derive(Debug, Error)]
+pub enum SnapshotError {
+    /// The DAO can't be serialized.
+    #[error(transparent)]
+    Serialization(#[from] serde_json::Error),
+
+    /// The snapshot file can't be read or written.
+    #[error("Failed to access the snapshot {path}: {source}")]
+    Io { path: PathBuf, source: io::Error },
+
+    /// The serialized DAO differs from the snapshot file.
+    #[error("The snapshot {path} differs in line {line}:\n  expected: {expected}\n    actual: {actual}")]
+    Mismatch {
+        path: PathBuf,
+        line: usize,
+        expected: String,
+        actual: String,
+    },
+}
+
+/// Returns the canonical serialized form of the given value.
+///
+/// In contrast to `canonical_json` the canonical form is pretty printed with an indentation of
+/// two spaces and ends with a newline, so that changes of the format result in readable diffs. The
+/// keys of objects are sorted regardless of whether serde_json preserves the insertion order.
+pub fn canonical_pretty_json(value: &Value) -> String {
+    let mut json = serde_json::to_string_pretty(&sort_keys(value)).unwrap_or_default();
+    json.push('\n');
+    json
+}
+
+/// Returns the canonical serialized form of the given DAO.
+pub fn to_snapshot<T: Serialize>(dao: &T) -> Result<String, serde_json::Error> {
+    Ok(canonical_pretty_json(&serde_json::to_value(dao)?))
+}
+
+/// Compares the canonical serialized form of the given DAO with the given snapshot file.
+///
+/// If the environment variable `UPDATE_SNAPSHOTS` is set, the snapshot file is written instead.
+pub fn compare_snapshot<T: Serialize, P: AsRef<Path>>(dao: &T, path: P) -> Result<(), SnapshotError> {
+    if env::var_os(UPDATE_SNAPSHOTS_ENV).is_some() {
+        let path = path.as_ref();
+        return fs::write(path, to_snapshot(dao)?).map_err(|source| SnapshotError::Io {
+            path: path.to_path_buf(),
+            source,
+        });
+    }
+    verify_snapshot(dao, path)
+}
+
+/// Compares the canonical serialized form of the given DAO with the given snapshot file without
+/// ever writing the snapshot file.
+pub fn verify_snapshot<T: Serialize, P: AsRef<Path>>(dao: &T, path: P) -> Result<(), SnapshotError> {
+    let path = path.as_ref();
+    let actual = to_snapshot(dao)?;
+    let expected = fs::read_to_string(path).map_err(|source| SnapshotError::Io {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    compare_lines(path, &expected, &actual)
+}
+
+/// Asserts that the canonical serialized form of the given DAO equals the given snapshot file.
+///
+/// # Panics
+///
+/// Panics with the first differing line if the serialized DAO differs from the snapshot file.
+pub fn assert_snapshot<T: Serialize, P: AsRef<Path>>(dao: &T, path: P) {
+    if let Err(e) = compare_snapshot(dao, path) {
+        panic!("{e}\nRun the tests with {UPDATE_SNAPSHOTS_ENV}=1 to update the snapshots.");
+    }
+}
+
+fn compare_lines(path: &Path, expected: &str, actual: &str) -> Result<(), SnapshotError> {
+    // Comparing lines ignores the line endings of snapshot files which were checked out on windows.
+    let mut expected_lines = expected.lines();
+    let mut actual_lines = actual.lines();
+    let mut line = 1;
+    loop {
+        match (expected_lines.next(), actual_lines.next()) {
+            (None, None) => return Ok(()),
+            (expected, actual) if expected == actual => line += 1,
+            (expected, actual) => {
+                return Err(SnapshotError::Mismatch {
+                    path: path.to_path_buf(),
+                    line,
+                    expected: expected.unwrap_or("<end of file>").to_string(),
+                    actual: actual.unwrap_or("<end of file>").to_string(),
+                });
+            }
+        }
+    }
+}
+
+fn sort_keys(value: &Value) -> Value {
+    match value {
+        Value::Array(values) => Value::Array(values.iter().map(sort_keys).collect()),
+        Value::Object(object) => {
+            let mut entries: Vec<(&String, &Value)> = object.iter().collect();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+            let sorted: Map<String, Value> = entries.into_iter().map(|(key, value)| (key.clone(), sort_keys(value))).collect();
+            Value::Object(sorted)
+        }
+        value => value.clone(),
+    }
+}
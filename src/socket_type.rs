@@ -7,10 +7,13 @@ use serde::Serialize;
 
 /// The socket type defines if the property acts as an input or output socket
 /// or is an hidden property
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Copy, Eq)]
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Copy, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "lowercase")]
+#[non_exhaustive]
 pub enum SocketType {
     /// The property doesn't act as input or output socket.
+    #[default]
     None,
 
     /// The property acts as input socket and accepts incoming connections.
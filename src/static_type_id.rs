@@ -0,0 +1,77 @@
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use crate::BehaviourTypeId;
+use crate::ComponentTypeId;
+use crate::EntityTypeId;
+use crate::ExtensionTypeId;
+use crate::FlowTypeId;
+use crate::NamespacedType;
+use crate::NamespacedTypeGetter;
+use crate::RelationTypeId;
+
+/// A type id whose namespace and type name are known at compile time.
+///
+/// Static type ids can be used in constants and are usually created by the macro `ty!`, which
+/// validates the type id at compile time:
+///
+/// ```ignore
+/// const LABELED: StaticTypeId = ty!("core__labeled");
+/// let component_ty: ComponentTypeId = LABELED.into();
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StaticTypeId {
+    /// The namespace of the type.
+    pub namespace: &'static str,
+
+    /// The name of the type.
+    pub type_name: &'static str,
+}
+
+impl StaticTypeId {
+    /// Constructs a static type id. The namespace and the type name are not validated.
+    pub const fn new(namespace: &'static str, type_name: &'static str) -> StaticTypeId {
+        StaticTypeId { namespace, type_name }
+    }
+
+    /// Converts the static type id into a type id of the given kind.
+    pub fn into_type_id<T: From<NamespacedType>>(self) -> T {
+        T::from(NamespacedType::from(self))
+    }
+}
+
+impl NamespacedTypeGetter for StaticTypeId {
+    fn namespace(&self) -> String {
+        self.namespace.to_string()
+    }
+
+    fn type_name(&self) -> String {
+        self.type_name.to_string()
+    }
+}
+
+impl Display for StaticTypeId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}__{}", self.namespace, self.type_name)
+    }
+}
+
+impl From<StaticTypeId> for NamespacedType {
+    fn from(ty: StaticTypeId) -> Self {
+        NamespacedType::new(ty.namespace, ty.type_name)
+    }
+}
+
+macro_rules! from_static_type_id {
+    ($($type_id: ty),*) => {
+        $(
+            impl From<StaticTypeId> for $type_id {
+                fn from(ty: StaticTypeId) -> Self {
+                    ty.into_type_id()
+                }
+            }
+        )*
+    };
+}
+
+from_static_type_id!(BehaviourTypeId, ComponentTypeId, EntityTypeId, ExtensionTypeId, FlowTypeId, RelationTypeId);
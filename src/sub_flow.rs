@@ -0,0 +1,127 @@
+use std::fmt;
+
+use serde::Deserialize;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::Connector;
+use crate::RelationInstance;
+use crate::CONNECTOR_NAMESPACE;
+use crate::DEFAULT_CONNECTOR_TYPE_NAME;
+
+/// The namespace of the connector relation type which is used to wire sub flows.
+pub const SUB_FLOW_CONNECTOR_NAMESPACE: &str = CONNECTOR_NAMESPACE;
+
+/// The type name of the connector relation type which is used to wire sub flows.
+pub const SUB_FLOW_CONNECTOR_TYPE_NAME: &str = DEFAULT_CONNECTOR_TYPE_NAME;
+
+/// The direction of a port binding of a sub flow.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Copy, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum SubFlowBindingDirection {
+    /// The property of the outer entity instance is propagated into the sub flow.
+    Input,
+
+    /// The property of the inner entity instance is propagated out of the sub flow.
+    Output,
+}
+
+/// Binds a property of an entity instance of the outer flow to a property of an entity
+/// instance of the sub flow.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SubFlowBinding {
+    /// The direction of the binding.
+    pub direction: SubFlowBindingDirection,
+
+    /// The id of the entity instance of the outer flow.
+    pub outer_entity_id: Uuid,
+
+    /// The name of the property of the entity instance of the outer flow.
+    pub outer_property_name: String,
+
+    /// The id of the entity instance of the sub flow.
+    pub inner_entity_id: Uuid,
+
+    /// The name of the property of the entity instance of the sub flow.
+    pub inner_property_name: String,
+}
+
+impl SubFlowBinding {
+    /// Constructs a binding which propagates the outer property into the sub flow.
+    pub fn input<S: Into<String>>(outer_entity_id: Uuid, outer_property_name: S, inner_entity_id: Uuid, inner_property_name: S) -> SubFlowBinding {
+        SubFlowBinding {
+            direction: SubFlowBindingDirection::Input,
+            outer_entity_id,
+            outer_property_name: outer_property_name.into(),
+            inner_entity_id,
+            inner_property_name: inner_property_name.into(),
+        }
+    }
+
+    /// Constructs a binding which propagates the inner property out of the sub flow.
+    pub fn output<S: Into<String>>(inner_entity_id: Uuid, inner_property_name: S, outer_entity_id: Uuid, outer_property_name: S) -> SubFlowBinding {
+        SubFlowBinding {
+            direction: SubFlowBindingDirection::Output,
+            outer_entity_id,
+            outer_property_name: outer_property_name.into(),
+            inner_entity_id,
+            inner_property_name: inner_property_name.into(),
+        }
+    }
+
+    /// Returns the connector which realizes the binding.
+    pub fn to_connector(&self) -> Connector {
+        match self.direction {
+            SubFlowBindingDirection::Input => {
+                Connector::new(self.outer_entity_id, self.outer_property_name.clone(), self.inner_entity_id, self.inner_property_name.clone())
+            }
+            SubFlowBindingDirection::Output => {
+                Connector::new(self.inner_entity_id, self.inner_property_name.clone(), self.outer_entity_id, self.outer_property_name.clone())
+            }
+        }
+    }
+
+    /// Returns the connector relation instance which realizes the binding.
+    pub fn to_relation_instance(&self) -> RelationInstance {
+        self.to_connector().to_relation_instance()
+    }
+}
+
+/// References a child flow instance which is embedded into a flow instance.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SubFlowReference {
+    /// The id of the child flow instance.
+    pub flow_id: Uuid,
+
+    /// The port bindings between the outer flow and the child flow.
+    #[serde(default = "Vec::new")]
+    pub bindings: Vec<SubFlowBinding>,
+}
+
+impl SubFlowReference {
+    pub fn new(flow_id: Uuid, bindings: Vec<SubFlowBinding>) -> SubFlowReference {
+        SubFlowReference { flow_id, bindings }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum FlowInstanceFlattenError {
+    /// The sub flow with the given id cannot be resolved.
+    MissingSubFlow(Uuid),
+
+    /// The sub flow with the given id embeds itself directly or indirectly.
+    CyclicSubFlow(Uuid),
+}
+
+impl fmt::Display for FlowInstanceFlattenError {
+    #[cfg(not(tarpaulin_include))]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FlowInstanceFlattenError::MissingSubFlow(id) => write!(f, "The sub flow {} cannot be found", id),
+            FlowInstanceFlattenError::CyclicSubFlow(id) => write!(f, "The sub flow {} embeds itself", id),
+        }
+    }
+}
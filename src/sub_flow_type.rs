@@ -0,0 +1,235 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use serde::Deserialize;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::deserialize_local_id;
+use crate::FlowType;
+use crate::FlowTypeId;
+use crate::FlowTypeInstantiation;
+use crate::SubFlowBinding;
+use crate::SubFlowBindingDirection;
+use crate::TypeSystem;
+
+/// Binds a property of an entity instance of the including flow type to a port of an included
+/// flow type.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SubFlowTypeBinding {
+    /// The direction of the binding. Input bindings are bound to an input port and output
+    /// bindings are bound to an output port of the included flow type.
+    pub direction: SubFlowBindingDirection,
+
+    /// The id of the entity instance of the including flow type. May be a symbolic local id.
+    #[serde(deserialize_with = "deserialize_local_id")]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
+    pub outer_entity_id: Uuid,
+
+    /// The name of the property of the entity instance of the including flow type.
+    pub outer_property_name: String,
+
+    /// The name of the port of the included flow type.
+    pub port: String,
+}
+
+impl SubFlowTypeBinding {
+    /// Constructs a binding which propagates the outer property into the given input port.
+    pub fn input<S: Into<String>>(outer_entity_id: Uuid, outer_property_name: S, port: S) -> SubFlowTypeBinding {
+        SubFlowTypeBinding {
+            direction: SubFlowBindingDirection::Input,
+            outer_entity_id,
+            outer_property_name: outer_property_name.into(),
+            port: port.into(),
+        }
+    }
+
+    /// Constructs a binding which propagates the given output port to the outer property.
+    pub fn output<S: Into<String>>(port: S, outer_entity_id: Uuid, outer_property_name: S) -> SubFlowTypeBinding {
+        SubFlowTypeBinding {
+            direction: SubFlowBindingDirection::Output,
+            outer_entity_id,
+            outer_property_name: outer_property_name.into(),
+            port: port.into(),
+        }
+    }
+}
+
+/// References a flow type which is included into another flow type.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SubFlowTypeReference {
+    /// The name of the inclusion, which is unique within the including flow type.
+    ///
+    /// The template ids of the entity instances of the included flow type are derived from the
+    /// name, see [`sub_flow_type_entity_id`]. Therefore a flow type can be included multiple times.
+    pub name: String,
+
+    /// The type of the included flow type.
+    #[serde(flatten)]
+    pub ty: FlowTypeId,
+
+    /// The port bindings between the including flow type and the included flow type.
+    #[serde(default = "Vec::new")]
+    pub bindings: Vec<SubFlowTypeBinding>,
+}
+
+impl SubFlowTypeReference {
+    pub fn new<S: Into<String>, T: Into<FlowTypeId>>(name: S, ty: T, bindings: Vec<SubFlowTypeBinding>) -> SubFlowTypeReference {
+        SubFlowTypeReference {
+            name: name.into(),
+            ty: ty.into(),
+            bindings,
+        }
+    }
+}
+
+/// Returns the template id of the entity instance with the given template id of the included flow
+/// type with the given inclusion name in the flattened flow type.
+pub fn sub_flow_type_entity_id(sub_flow_name: &str, entity_id: Uuid) -> Uuid {
+    Uuid::new_v5(&entity_id, sub_flow_name.as_bytes())
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum FlowTypeFlattenError {
+    /// The included flow type cannot be resolved.
+    MissingSubFlowType(FlowTypeId),
+
+    /// The flow type includes itself directly or indirectly.
+    CyclicSubFlowType(FlowTypeId),
+
+    /// The included flow type has no port with the given name in the direction of the binding.
+    MissingPort { ty: FlowTypeId, port: String },
+}
+
+impl fmt::Display for FlowTypeFlattenError {
+    #[cfg(not(tarpaulin_include))]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FlowTypeFlattenError::MissingSubFlowType(ty) => write!(f, "The included flow type {} cannot be found", ty),
+            FlowTypeFlattenError::CyclicSubFlowType(ty) => write!(f, "The flow type {} includes itself", ty),
+            FlowTypeFlattenError::MissingPort { ty, port } => write!(f, "The included flow type {} has no port {}", ty, port),
+        }
+    }
+}
+
+impl FlowType {
+    /// Includes the flow type referenced by the given sub flow type reference.
+    pub fn with_sub_flow(mut self, sub_flow: SubFlowTypeReference) -> FlowType {
+        self.sub_flows.push(sub_flow);
+        self
+    }
+
+    /// Returns the types of the directly included flow types in the order of their first
+    /// inclusion.
+    pub fn uses_flow_types(&self) -> Vec<FlowTypeId> {
+        let mut flow_types: Vec<FlowTypeId> = Vec::new();
+        for sub_flow in self.sub_flows.iter() {
+            if !flow_types.contains(&sub_flow.ty) {
+                flow_types.push(sub_flow.ty.clone());
+            }
+        }
+        flow_types
+    }
+
+    /// Expands the included flow types into a single flow type without included flow types.
+    ///
+    /// The resolver returns the flow type with the given type. The entity instances, including the
+    /// wrapper entity instances, and the relation instances of the included flow types are added
+    /// recursively with template ids which are derived from the inclusion names. The port
+    /// bindings are converted into connector relation instances. Variables of included flow types
+    /// are added, unless the including flow type defines a variable with the same name.
+    /// Likewise, type parameters of included flow types are added, unless the including flow type
+    /// declares a type parameter with the same name.
+    pub fn flatten<F>(&self, resolver: F) -> Result<FlowType, FlowTypeFlattenError>
+    where
+        F: Fn(&FlowTypeId) -> Option<FlowType>,
+    {
+        let mut path = vec![self.ty.clone()];
+        self.flatten_recursive(&resolver, &mut path)
+    }
+
+    /// Flattens the flow type and creates a new flow instance from the flattened template.
+    pub fn instantiate_flattened<F>(&self, resolver: F) -> Result<FlowTypeInstantiation, FlowTypeFlattenError>
+    where
+        F: Fn(&FlowTypeId) -> Option<FlowType>,
+    {
+        Ok(self.flatten(resolver)?.instantiate())
+    }
+
+    fn flatten_recursive(&self, resolver: &dyn Fn(&FlowTypeId) -> Option<FlowType>, path: &mut Vec<FlowTypeId>) -> Result<FlowType, FlowTypeFlattenError> {
+        let mut flattened = self.clone();
+        flattened.sub_flows.clear();
+        for sub_flow in self.sub_flows.iter() {
+            if path.contains(&sub_flow.ty) {
+                return Err(FlowTypeFlattenError::CyclicSubFlowType(sub_flow.ty.clone()));
+            }
+            let child = resolver(&sub_flow.ty).ok_or_else(|| FlowTypeFlattenError::MissingSubFlowType(sub_flow.ty.clone()))?;
+            path.push(sub_flow.ty.clone());
+            let child = child.flatten_recursive(resolver, path)?;
+            path.pop();
+            let ids: HashMap<Uuid, Uuid> = child
+                .entity_instances()
+                .iter()
+                .map(|entity_instance| (entity_instance.id, sub_flow_type_entity_id(&sub_flow.name, entity_instance.id)))
+                .collect();
+            let scoped_id = |id: Uuid| ids.get(&id).copied().unwrap_or(id);
+            let mut connectors = Vec::new();
+            for binding in sub_flow.bindings.iter() {
+                let port = match binding.direction {
+                    SubFlowBindingDirection::Input => child.get_input(binding.port.as_str()),
+                    SubFlowBindingDirection::Output => child.get_output(binding.port.as_str()),
+                }
+                .ok_or_else(|| FlowTypeFlattenError::MissingPort {
+                    ty: sub_flow.ty.clone(),
+                    port: binding.port.clone(),
+                })?;
+                let binding = SubFlowBinding {
+                    direction: binding.direction,
+                    outer_entity_id: binding.outer_entity_id,
+                    outer_property_name: binding.outer_property_name.clone(),
+                    inner_entity_id: scoped_id(port.entity_id),
+                    inner_property_name: port.property_name,
+                };
+                connectors.push(binding.to_relation_instance());
+            }
+            for mut entity_instance in child.entity_instances() {
+                entity_instance.id = scoped_id(entity_instance.id);
+                if !flattened.has_entity_instance(entity_instance.id) {
+                    flattened.entity_instances.push(entity_instance);
+                }
+            }
+            let relation_instances = child.relation_instances.into_iter().map(|mut relation_instance| {
+                relation_instance.outbound_id = scoped_id(relation_instance.outbound_id);
+                relation_instance.inbound_id = scoped_id(relation_instance.inbound_id);
+                relation_instance
+            });
+            for relation_instance in relation_instances.chain(connectors) {
+                let edge_key = relation_instance.get_key();
+                if !flattened.relation_instances.iter().any(|r| r.get_key() == edge_key) {
+                    flattened.relation_instances.push(relation_instance);
+                }
+            }
+            for variable in child.variables {
+                if !flattened.has_variable(variable.name.as_str()) {
+                    flattened.variables.push(variable);
+                }
+            }
+            for type_parameter in child.type_parameters {
+                if flattened.get_type_parameter(&type_parameter.name).is_none() {
+                    flattened.type_parameters.push(type_parameter);
+                }
+            }
+        }
+        Ok(flattened)
+    }
+}
+
+impl TypeSystem {
+    /// Expands the included flow types of the given flow type, which are resolved from the type
+    /// system.
+    pub fn flatten_flow_type(&self, flow_type: &FlowType) -> Result<FlowType, FlowTypeFlattenError> {
+        flow_type.flatten(|ty| self.resolve_flow_type(ty).cloned())
+    }
+}
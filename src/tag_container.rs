@@ -0,0 +1,7 @@
+pub trait TagContainer {
+    /// Returns the tags of the container.
+    fn get_tags(&self) -> Vec<String>;
+
+    /// Returns true, if the container is tagged with the given tag.
+    fn has_tag(&self, tag: &str) -> bool;
+}
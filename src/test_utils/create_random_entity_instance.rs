@@ -7,16 +7,18 @@ use serde_json::json;
 use uuid::Uuid;
 
 use crate::property_identifier;
-use crate::tests::utils::r_string;
+use crate::test_utils::r_string;
 use crate::EntityInstance;
 use crate::EntityTypeId;
 use crate::ReactiveEntityInstance;
 use crate::TypeDefinitionGetter;
 
+/// Creates a reactive entity instance of a random type with the given property of a random string value.
 pub fn create_random_entity_instance<S: Into<String>>(property_name: S) -> ReactiveEntityInstance {
     create_random_entity_instance_with_type(r_string(), r_string(), property_name.into())
 }
 
+/// Creates a reactive entity instance of the given type with the given property of a random string value.
 pub fn create_random_entity_instance_with_type<S: Into<String>>(namespace: S, type_name: S, property_name: S) -> ReactiveEntityInstance {
     let ty = EntityTypeId::new_from_type(namespace, type_name);
     let uuid = Uuid::new_v4();
@@ -34,14 +36,17 @@ pub fn create_random_entity_instance_with_type<S: Into<String>>(namespace: S, ty
     ReactiveEntityInstance::try_from(vertex_properties).unwrap()
 }
 
+/// Creates an entity instance of a random type with the given property of a random string value.
 pub fn create_entity_instance<S: Into<String>>(property_name: S) -> EntityInstance {
     create_entity_instance_from_type_with_property(r_string(), r_string(), property_name.into())
 }
 
+/// Creates an entity instance of the given type with the given property of a random string value.
 pub fn create_entity_instance_from_type_with_property<S: Into<String>>(namespace: S, type_name: S, property_name: S) -> EntityInstance {
     create_entity_instance_with_property(EntityTypeId::new_from_type(namespace, type_name), property_name)
 }
 
+/// Creates an entity instance of the given type with the given property of a random string value.
 pub fn create_entity_instance_with_property<T: Into<EntityTypeId>, S: Into<String>>(ty: T, property_name: S) -> EntityInstance {
     let id = Uuid::new_v4();
     let property_value = json!(r_string());
@@ -50,6 +55,7 @@ pub fn create_entity_instance_with_property<T: Into<EntityTypeId>, S: Into<Strin
     EntityInstance::new(ty, id, properties)
 }
 
+/// Creates an entity instance of the given type without properties.
 pub fn create_entity_instance_from_type<S: Into<String>>(namespace: S, type_name: S) -> EntityInstance {
     EntityInstance::new_from_type(namespace, type_name, Uuid::new_v4(), HashMap::new())
 }
@@ -8,12 +8,14 @@ use indradb::NamedProperty;
 use serde_json::json;
 
 use crate::property_identifier;
-use crate::tests::utils::r_string;
+use crate::test_utils::r_string;
 use crate::ReactiveEntityInstance;
 use crate::ReactiveRelationInstance;
 use crate::RelationTypeId;
 use crate::TypeDefinitionGetter;
 
+/// Creates a reactive relation instance of a random type between the given entity instances with the given property of
+/// a random string value.
 pub fn create_random_relation_instance(
     outbound_entity: Arc<ReactiveEntityInstance>,
     inbound_entity: Arc<ReactiveEntityInstance>,
@@ -33,11 +35,11 @@ pub fn create_random_relation_instance(
     let inbound_id = inbound_entity.id;
     let edge_key = EdgeKey::new(outbound_id, ty.type_id(), inbound_id);
     let edge_properties = EdgeProperties::new(Edge::new_with_current_datetime(edge_key), properties.clone());
-    let outbound_entity = outbound_entity.clone();
-    let inbound_entity = outbound_entity.clone();
     ReactiveRelationInstance::new_from_properties(outbound_entity, inbound_entity, edge_properties).unwrap()
 }
 
+/// Creates a reactive relation instance of a random type between the given entity instances with the given property of
+/// a random string value, without an underlying edge.
 pub fn create_random_relation_instance_with_properties(
     outbound_entity: Arc<ReactiveEntityInstance>,
     inbound_entity: Arc<ReactiveEntityInstance>,
@@ -4,24 +4,30 @@ use serde_json::Value;
 
 pub use create_random_entity_instance::*;
 pub use create_random_relation_instance::*;
+pub use roundtrip::*;
 
 pub mod create_random_entity_instance;
 pub mod create_random_relation_instance;
+pub mod roundtrip;
 
 const CHARSET_LETTERS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
 
+/// Returns a random string of 10 letters.
 pub fn r_string() -> String {
     generate(10, CHARSET_LETTERS).to_string()
 }
 
+/// Returns a random string of 255 letters.
 pub fn r_string_255() -> String {
     generate(255, CHARSET_LETTERS).to_string()
 }
 
+/// Returns a random string of 1000 letters.
 pub fn r_string_1000() -> String {
     generate(1000, CHARSET_LETTERS).to_string()
 }
 
+/// Returns a JSON string value of a random string of 10 letters.
 pub fn r_json_string() -> Value {
     json!(r_string())
 }
@@ -0,0 +1,53 @@
+use std::fmt::Debug;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+/// Asserts that the given value survives a JSON round trip and returns the deserialized value.
+///
+/// The value and the deserialized value are compared by their JSON representation, so that the
+/// invariant can be checked for model structs which don't implement `PartialEq`.
+pub fn assert_json_roundtrip<T: Serialize + DeserializeOwned>(value: &T) -> T {
+    let json = to_json(value);
+    let deserialized: T = serde_json::from_str(&json.to_string()).unwrap_or_else(|e| panic!("Failed to deserialize {json}: {e}"));
+    assert_json_eq(&json, &to_json(&deserialized));
+    deserialized
+}
+
+/// Asserts that the given value survives the conversion into its DAO, a JSON round trip of the DAO
+/// and the conversion back from the DAO. Returns the converted value.
+pub fn assert_dao_roundtrip<T, D>(value: &T) -> T
+where
+    T: Clone + Serialize + Into<D>,
+    D: Serialize + DeserializeOwned + Into<T>,
+{
+    let dao: D = value.clone().into();
+    let deserialized: D = assert_json_roundtrip(&dao);
+    let converted: T = deserialized.into();
+    assert_json_eq(&to_json(value), &to_json(&converted));
+    let expected: D = value.clone().into();
+    let actual: D = converted.clone().into();
+    assert_json_eq(&to_json(&expected), &to_json(&actual));
+    converted
+}
+
+/// Asserts that the given value survives a JSON round trip and that the deserialized value equals
+/// the given value.
+pub fn assert_json_roundtrip_eq<T: Serialize + DeserializeOwned + PartialEq + Debug>(value: &T) {
+    assert_eq!(value, &assert_json_roundtrip(value));
+}
+
+fn to_json<T: Serialize>(value: &T) -> Value {
+    serde_json::to_value(value).unwrap_or_else(|e| panic!("Failed to serialize: {e}"))
+}
+
+fn assert_json_eq(expected: &Value, actual: &Value) {
+    if expected != actual {
+        panic!(
+            "The round trip changed the serialized form:\n  expected: {}\n    actual: {}",
+            serde_json::to_string_pretty(expected).unwrap_or_default(),
+            serde_json::to_string_pretty(actual).unwrap_or_default()
+        );
+    }
+}
@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+
+use rand::Rng;
+use serde_json::json;
+use serde_json::Map;
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::component_property_key;
+use crate::test_utils::r_string;
+use crate::Component;
+use crate::ComponentTypeId;
+use crate::DataType;
+use crate::EntityInstance;
+use crate::EntityType;
+use crate::EntityTypeId;
+use crate::PropertyType;
+use crate::RelationInstance;
+use crate::RelationInstanceTypeId;
+use crate::RelationType;
+use crate::TypeSystem;
+use crate::ValidationError;
+
+/// The maximum number of elements of random arrays and objects.
+const RANDOM_CONTAINER_MAX_LENGTH: usize = 5;
+
+/// Returns a random value which matches the given data type.
+///
+/// Arrays and objects contain random strings. For `DataType::Any` the data type of the value is
+/// chosen randomly.
+pub fn random_value(data_type: DataType) -> Value {
+    let mut rng = rand::thread_rng();
+    match data_type {
+        DataType::Null => Value::Null,
+        DataType::Bool => json!(rng.gen::<bool>()),
+        DataType::Number => json!(rng.gen_range(-1000..1000)),
+        DataType::String => json!(r_string()),
+        DataType::Array => Value::Array((0..rng.gen_range(0..=RANDOM_CONTAINER_MAX_LENGTH)).map(|_| json!(r_string())).collect()),
+        DataType::Object => Value::Object(
+            (0..rng.gen_range(0..=RANDOM_CONTAINER_MAX_LENGTH))
+                .map(|_| (r_string(), json!(r_string())))
+                .collect::<Map<String, Value>>(),
+        ),
+        DataType::Any => {
+            let data_types = [
+                DataType::Null,
+                DataType::Bool,
+                DataType::Number,
+                DataType::String,
+                DataType::Array,
+                DataType::Object,
+            ];
+            random_value(data_types[rng.gen_range(0..data_types.len())])
+        }
+    }
+}
+
+/// Returns random values for the given property types and for the property types of the given
+/// components.
+pub fn random_properties(property_types: &[PropertyType], component_tys: &[ComponentTypeId], components: &[Component]) -> HashMap<String, Value> {
+    random_properties_scoped(false, property_types, component_tys, components)
+}
+
+/// Returns random values for the given property types and for the property types of the given
+/// components. If the properties of the components are scoped, their keys are the qualified
+/// property names.
+pub fn random_properties_scoped(
+    scoped: bool,
+    property_types: &[PropertyType],
+    component_tys: &[ComponentTypeId],
+    components: &[Component],
+) -> HashMap<String, Value> {
+    let component_properties = components
+        .iter()
+        .filter(|component| component_tys.contains(&component.ty))
+        .flat_map(|component| {
+            component
+                .properties
+                .iter()
+                .map(|property_type| (component_property_key(scoped, &component.ty, &property_type.name), property_type))
+        });
+    component_properties
+        .chain(property_types.iter().map(|property_type| (property_type.name.to_string(), property_type)))
+        .map(|(key, property_type)| (key, random_value(property_type.data_type)))
+        .collect()
+}
+
+/// Returns an entity instance of the given entity type with a random id and random values of
+/// the properties of the entity type and of its components.
+///
+/// The given components should contain the components of the entity type. Components which are
+/// not given are skipped. The properties inherited from the parent entity types are not
+/// generated, use [`random_entity_instance_of_type`] for entity types which extend a parent.
+pub fn random_entity_instance(entity_type: &EntityType, components: &[Component]) -> EntityInstance {
+    let properties = random_properties_scoped(entity_type.scoped_properties, &entity_type.properties, &entity_type.components, components);
+    EntityInstance::new(entity_type.ty.clone(), Uuid::new_v4(), properties)
+}
+
+/// Returns an entity instance of the entity type with the given id with a random id and random
+/// values of the properties of the effective entity type, including the properties which are
+/// inherited from the parent entity types.
+///
+/// Fails if the entity type or one of its ancestors is unknown to the type system.
+pub fn random_entity_instance_of_type(type_system: &TypeSystem, ty: &EntityTypeId) -> Result<EntityInstance, ValidationError> {
+    let entity_type = type_system.resolve_effective_entity_type(ty)?;
+    Ok(random_entity_instance(&entity_type, &type_system.components))
+}
+
+/// Returns a relation instance of the given relation type between the given entity instances
+/// with random values of the properties of the relation type and of its components.
+///
+/// The relation instance has a random instance id.
+pub fn random_relation_instance(outbound_id: Uuid, relation_type: &RelationType, inbound_id: Uuid, components: &[Component]) -> RelationInstance {
+    let properties = random_properties_scoped(relation_type.scoped_properties, &relation_type.properties, &relation_type.components, components);
+    let ty = RelationInstanceTypeId::new_with_random_instance_id(relation_type.ty.clone());
+    RelationInstance::new(outbound_id, ty, inbound_id, properties)
+}
@@ -0,0 +1,46 @@
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::test_utils::assert_dao_roundtrip;
+use crate::ComponentOrEntityTypeId;
+use crate::EntityInstance;
+use crate::EntityType;
+use crate::EntityTypeDao;
+use crate::EntityTypeId;
+use crate::TypeSystem;
+use crate::ValidationError;
+
+fn create_type_system() -> TypeSystem {
+    let vehicle = EntityType::new(EntityTypeId::new_from_type("game", "vehicle"), "", Vec::new(), Vec::new(), Vec::new()).with_abstract();
+    let car = EntityType::new(EntityTypeId::new_from_type("game", "car"), "", Vec::new(), Vec::new(), Vec::new()).with_parent(vehicle.ty.clone());
+    TypeSystem::new(Vec::new(), vec![vehicle, car], Vec::new(), Vec::new())
+}
+
+#[test]
+fn validate_abstract_entity_instance_test() {
+    let type_system = create_type_system();
+    let vehicle = EntityInstance::new_without_properties(EntityTypeId::new_from_type("game", "vehicle"), Uuid::new_v4());
+    assert_eq!(
+        Err(ValidationError::AbstractEntityType(EntityTypeId::new_from_type("game", "vehicle"))),
+        type_system.validate_entity_instance(&vehicle)
+    );
+    let car = EntityInstance::new_without_properties(EntityTypeId::new_from_type("game", "car"), Uuid::new_v4());
+    assert_eq!(Ok(()), type_system.validate_entity_instance(&car));
+}
+
+#[test]
+fn abstract_entity_type_as_endpoint_constraint_test() {
+    let type_system = create_type_system();
+    let vehicle = ComponentOrEntityTypeId::EntityType(EntityTypeId::new_from_type("game", "vehicle"));
+    assert_eq!(2, type_system.endpoint_entity_types(&vehicle).len());
+}
+
+#[test]
+fn abstract_entity_type_serde_test() {
+    let entity_type = EntityType::new(EntityTypeId::new_from_type("game", "vehicle"), "", Vec::new(), Vec::new(), Vec::new());
+    assert!(serde_json::to_value(&entity_type).unwrap().get("abstract").is_none());
+    let entity_type = entity_type.with_abstract();
+    assert_eq!(Some(&json!(true)), serde_json::to_value(&entity_type).unwrap().get("abstract"));
+    let deserialized = assert_dao_roundtrip::<EntityType, EntityTypeDao>(&entity_type);
+    assert!(deserialized.is_abstract);
+}
@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+
+use serde_json::json;
+use serde_json::Value;
+
+use crate::test_utils::assert_json_roundtrip_eq;
+use crate::AggregateFunction;
+use crate::Aggregation;
+use crate::AggregationGroup;
+
+fn instances() -> Vec<HashMap<String, Value>> {
+    [
+        json!({ "kind": "a", "value": 1, "name": "x" }),
+        json!({ "kind": "b", "value": 2.5, "name": "y" }),
+        json!({ "kind": "a", "value": 3 }),
+        json!({ "kind": "b", "value": "not a number" }),
+        json!({ "value": 4 }),
+    ]
+    .into_iter()
+    .map(|properties| serde_json::from_value(properties).unwrap())
+    .collect()
+}
+
+fn values(aggregation: Aggregation) -> Vec<Value> {
+    aggregation.aggregate(&instances()).into_iter().map(|group| group.value).collect()
+}
+
+#[test]
+fn aggregation_test() {
+    assert_eq!(vec![json!(5)], values(Aggregation::count()));
+    assert_eq!(vec![json!(2)], values(Aggregation::new(AggregateFunction::Count, Some("name".to_string()))));
+    assert_eq!(vec![json!(10.5)], values(Aggregation::sum("value")));
+    assert_eq!(vec![json!(2.625)], values(Aggregation::average("value")));
+    assert_eq!(vec![json!(1)], values(Aggregation::min("value")));
+    assert_eq!(vec![json!(4)], values(Aggregation::max("value")));
+    assert_eq!(vec![json!("y")], values(Aggregation::max("name")));
+    assert_eq!(vec![Value::Null], values(Aggregation::average("name")));
+    assert_eq!(vec![Value::Null], values(Aggregation::min("missing")));
+    assert_eq!(vec![Value::Null], values(Aggregation::sum("missing")));
+    assert_eq!(vec![Value::Null], values(Aggregation::sum("name")));
+    // Without grouping there is a result even without instances
+    assert_eq!(
+        vec![json!(0)],
+        Aggregation::count()
+            .aggregate(&Vec::<HashMap<String, Value>>::new())
+            .into_iter()
+            .map(|group| group.value)
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn aggregation_group_by_test() {
+    let groups = Aggregation::sum("value").group_by("kind").aggregate(&instances());
+    assert_eq!(
+        vec![
+            AggregationGroup {
+                group: json!("a"),
+                value: json!(4)
+            },
+            AggregationGroup {
+                group: json!("b"),
+                value: json!(2.5)
+            },
+            AggregationGroup {
+                group: Value::Null,
+                value: json!(4)
+            },
+        ],
+        groups
+    );
+    // Numbers are grouped by their numeric value
+    let instances: Vec<HashMap<String, Value>> = [json!({ "kind": 1, "value": 1 }), json!({ "kind": 1.0, "value": 2 })]
+        .into_iter()
+        .map(|properties| serde_json::from_value(properties).unwrap())
+        .collect();
+    assert_eq!(
+        vec![AggregationGroup {
+            group: json!(1),
+            value: json!(3)
+        }],
+        Aggregation::sum("value").group_by("kind").aggregate(&instances)
+    );
+    assert!(Aggregation::count()
+        .group_by("kind")
+        .aggregate(&Vec::<HashMap<String, Value>>::new())
+        .is_empty());
+}
+
+#[test]
+fn aggregation_serde_test() {
+    let aggregation: Aggregation = serde_json::from_value(json!({ "function": "avg", "property": "value", "group_by": "kind" })).unwrap();
+    assert_eq!(Aggregation::average("value").group_by("kind"), aggregation);
+    assert_eq!(json!({ "function": "count" }), serde_json::to_value(Aggregation::count()).unwrap());
+    assert_json_roundtrip_eq(&aggregation);
+}
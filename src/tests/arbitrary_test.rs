@@ -0,0 +1,70 @@
+use proptest::prelude::*;
+
+use crate::arb_identifier;
+use crate::arb_value_of;
+use crate::Component;
+use crate::ComponentTypeId;
+use crate::DataType;
+use crate::EntityInstance;
+use crate::EntityTypeId;
+use crate::RelationInstance;
+use crate::RelationTypeId;
+
+proptest! {
+    #[test]
+    fn arb_identifier_test(identifier in arb_identifier()) {
+        prop_assert!(!identifier.is_empty());
+        prop_assert!(!identifier.contains("__"));
+    }
+
+    #[test]
+    fn arb_value_of_test((data_type, value) in any::<DataType>().prop_flat_map(|data_type| (Just(data_type), arb_value_of(data_type)))) {
+        prop_assert!(data_type.accepts(&value));
+    }
+
+    #[test]
+    fn component_type_id_parse_test(ty in any::<ComponentTypeId>()) {
+        prop_assert_eq!(ty.clone(), ty.to_string().parse::<ComponentTypeId>().unwrap());
+    }
+
+    #[test]
+    fn entity_type_id_parse_test(ty in any::<EntityTypeId>()) {
+        prop_assert_eq!(ty.clone(), ty.to_string().parse::<EntityTypeId>().unwrap());
+    }
+
+    #[test]
+    fn relation_type_id_parse_test(ty in any::<RelationTypeId>()) {
+        prop_assert_eq!(ty.clone(), ty.to_string().parse::<RelationTypeId>().unwrap());
+    }
+
+    #[test]
+    fn component_json_roundtrip_test(component in any::<Component>()) {
+        let json = serde_json::to_string(&component).unwrap();
+        let deserialized: Component = serde_json::from_str(&json).unwrap();
+        prop_assert_eq!(&component.description, &deserialized.description);
+        let property_names: Vec<&str> = component.properties.iter().map(|property| property.name.as_str()).collect();
+        let deserialized_property_names: Vec<&str> = deserialized.properties.iter().map(|property| property.name.as_str()).collect();
+        prop_assert_eq!(property_names, deserialized_property_names);
+        // == only compares the type ids
+        prop_assert!(component.structurally_equals(&deserialized));
+    }
+
+    #[test]
+    fn entity_instance_json_roundtrip_test(entity_instance in any::<EntityInstance>()) {
+        let json = serde_json::to_string(&entity_instance).unwrap();
+        let deserialized: EntityInstance = serde_json::from_str(&json).unwrap();
+        prop_assert_eq!(&entity_instance.ty, &deserialized.ty);
+        prop_assert_eq!(entity_instance.id, deserialized.id);
+        prop_assert_eq!(serde_json::to_value(&entity_instance).unwrap(), serde_json::to_value(&deserialized).unwrap());
+    }
+
+    #[test]
+    fn relation_instance_json_roundtrip_test(relation_instance in any::<RelationInstance>()) {
+        let json = serde_json::to_string(&relation_instance).unwrap();
+        let deserialized: RelationInstance = serde_json::from_str(&json).unwrap();
+        prop_assert_eq!(&relation_instance.ty, &deserialized.ty);
+        prop_assert_eq!(relation_instance.outbound_id, deserialized.outbound_id);
+        prop_assert_eq!(relation_instance.inbound_id, deserialized.inbound_id);
+        prop_assert_eq!(serde_json::to_value(&relation_instance).unwrap(), serde_json::to_value(&deserialized).unwrap());
+    }
+}
@@ -0,0 +1,95 @@
+use serde_json::json;
+
+use crate::export_type_system_bundle;
+use crate::import_type_system_bundle;
+use crate::test_utils::r_string;
+use crate::BehaviourTypeId;
+use crate::ComponentBehaviourTypeId;
+use crate::ComponentBehaviourTypeIdDao;
+use crate::ComponentTypeId;
+use crate::EntityBehaviourTypeId;
+use crate::EntityBehaviourTypeIdDao;
+use crate::EntityTypeId;
+use crate::RelationBehaviourTypeId;
+use crate::RelationBehaviourTypeIdDao;
+use crate::RelationTypeId;
+use crate::TypeSystem;
+
+#[test]
+fn component_behaviour_type_id_dao_test() {
+    let ty = ComponentBehaviourTypeId::new(ComponentTypeId::new_from_type("value", "value_number"), BehaviourTypeId::new_from_type("value", "debug"));
+    assert_eq!("c__value__value_number__b__value__debug", ty.to_string());
+    let dao = ComponentBehaviourTypeIdDao::from(ty.clone());
+    let value = serde_json::to_value(&dao).unwrap();
+    assert_eq!(json!("value_number"), value["type_name"]);
+    assert_eq!(json!("debug"), value["behaviour_type_name"]);
+    assert_eq!(ty, ComponentBehaviourTypeId::from(serde_json::from_value::<ComponentBehaviourTypeIdDao>(value).unwrap()));
+}
+
+#[test]
+fn entity_behaviour_type_id_dao_test() {
+    let ty = EntityBehaviourTypeId::new(EntityTypeId::new_from_type("logical", "and"), BehaviourTypeId::new_from_type("logical", "and_gate"));
+    let dao = EntityBehaviourTypeIdDao::from(ty.clone());
+    assert_eq!(
+        json!({
+            "namespace": "logical",
+            "type_name": "and",
+            "behaviour_namespace": "logical",
+            "behaviour_type_name": "and_gate"
+        }),
+        serde_json::to_value(&dao).unwrap()
+    );
+    assert_eq!(ty, EntityBehaviourTypeId::from(dao));
+}
+
+#[test]
+fn relation_behaviour_type_id_dao_test() {
+    let ty = RelationBehaviourTypeId::new(
+        RelationTypeId::new_from_type("core", "default_connector"),
+        BehaviourTypeId::new_from_type("core", "propagate"),
+    );
+    let dao: RelationBehaviourTypeIdDao = serde_json::from_value(json!({
+        "namespace": "core",
+        "name": "default_connector",
+        "behaviour_namespace": "core",
+        "behaviour": "propagate"
+    }))
+    .unwrap();
+    assert_eq!(ty, RelationBehaviourTypeId::from(dao.clone()));
+    assert_eq!(dao, RelationBehaviourTypeIdDao::from(ty));
+}
+
+#[test]
+fn type_system_behaviours_test() {
+    let entity_ty = EntityTypeId::new_from_type(r_string(), r_string());
+    let relation_ty = RelationTypeId::new_from_type(r_string(), r_string());
+    let component_ty = ComponentTypeId::new_from_type(r_string(), r_string());
+    let behaviour_ty_1 = BehaviourTypeId::new_from_type(r_string(), r_string());
+    let behaviour_ty_2 = BehaviourTypeId::new_from_type(r_string(), r_string());
+    let mut type_system = TypeSystem::new(Vec::new(), Vec::new(), Vec::new(), Vec::new());
+    type_system
+        .entity_behaviours
+        .push(EntityBehaviourTypeId::new(entity_ty.clone(), behaviour_ty_1.clone()));
+    type_system
+        .entity_behaviours
+        .push(EntityBehaviourTypeId::new(entity_ty.clone(), behaviour_ty_2.clone()));
+    type_system
+        .component_behaviours
+        .push(ComponentBehaviourTypeId::new(component_ty.clone(), behaviour_ty_2.clone()));
+    type_system
+        .relation_behaviours
+        .push(RelationBehaviourTypeId::new(relation_ty.clone(), behaviour_ty_1.clone()));
+
+    assert_eq!(vec![&behaviour_ty_1, &behaviour_ty_2], type_system.get_entity_behaviours(&entity_ty));
+    assert_eq!(vec![&behaviour_ty_1], type_system.get_relation_behaviours(&relation_ty));
+    assert_eq!(vec![&behaviour_ty_2], type_system.get_component_behaviours(&component_ty));
+    assert!(type_system
+        .get_entity_behaviours(&EntityTypeId::new_from_type(r_string(), r_string()))
+        .is_empty());
+
+    let document = export_type_system_bundle(&type_system).unwrap();
+    let imported = import_type_system_bundle(&document).unwrap();
+    assert_eq!(type_system.component_behaviours, imported.component_behaviours);
+    assert_eq!(type_system.entity_behaviours, imported.entity_behaviours);
+    assert_eq!(type_system.relation_behaviours, imported.relation_behaviours);
+}
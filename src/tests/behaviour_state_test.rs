@@ -0,0 +1,44 @@
+use std::str::FromStr;
+
+use serde_json::json;
+
+use crate::BehaviourState;
+use crate::BehaviourStateParseError;
+use crate::BehaviourTransitionError;
+
+#[test]
+fn behaviour_state_lifecycle_test() {
+    let state = BehaviourState::default();
+    assert_eq!(BehaviourState::Created, state);
+    let state = state.transition(BehaviourState::Valid).unwrap();
+    let state = state.transition(BehaviourState::Ready).unwrap();
+    let state = state.transition(BehaviourState::Connected).unwrap();
+    assert!(state.is_connected());
+    let state = state.transition(BehaviourState::Ready).unwrap();
+    assert!(!state.is_connected());
+    assert_eq!(BehaviourState::Valid, state.transition(BehaviourState::Valid).unwrap());
+}
+
+#[test]
+fn behaviour_state_invalid_transition_test() {
+    assert!(!BehaviourState::Created.can_transition_to(BehaviourState::Connected));
+    assert!(!BehaviourState::Connected.can_transition_to(BehaviourState::Connected));
+    let error = BehaviourState::Created.transition(BehaviourState::Connected).unwrap_err();
+    assert_eq!(
+        BehaviourTransitionError::InvalidTransition {
+            from: BehaviourState::Created,
+            to: BehaviourState::Connected
+        },
+        error
+    );
+    assert_eq!("The behaviour can't transition from Created to Connected", error.to_string());
+}
+
+#[test]
+fn behaviour_state_serde_test() {
+    assert_eq!(json!("connected"), serde_json::to_value(BehaviourState::Connected).unwrap());
+    assert_eq!(BehaviourState::Ready, serde_json::from_value(json!("ready")).unwrap());
+    assert_eq!(Ok(BehaviourState::Valid), BehaviourState::from_str("VALID"));
+    assert_eq!(Ok(BehaviourState::Created), "created".parse());
+    assert_eq!(Err(BehaviourStateParseError("unknown".to_string())), BehaviourState::from_str("unknown"));
+}
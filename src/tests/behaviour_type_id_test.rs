@@ -1,7 +1,10 @@
+use std::str::FromStr;
+
 use indradb::Identifier;
 
-use crate::tests::utils::r_string;
+use crate::test_utils::r_string;
 use crate::BehaviourTypeId;
+use crate::ModelError;
 use crate::NamespacedType;
 use crate::NamespacedTypeGetter;
 use crate::TypeDefinition;
@@ -119,3 +122,20 @@ fn behaviour_type_id_from_string_test() {
     let ty7 = BehaviourTypeId::try_from(&s7);
     assert!(ty7.is_err());
 }
+
+#[test]
+fn behaviour_type_id_from_str_test() {
+    let namespace = r_string();
+    let type_name = r_string();
+    let ty = BehaviourTypeId::new_from_type(&namespace, &type_name);
+    assert_eq!(Ok(ty.clone()), ty.to_string().parse::<BehaviourTypeId>());
+    assert_eq!(Ok(ty), BehaviourTypeId::from_str(&format!("b__{namespace}__{type_name}")));
+    assert_eq!(
+        Err(ModelError::UnexpectedTypeIdType {
+            expected: TypeIdType::Behaviour,
+            actual: TypeIdType::EntityType
+        }),
+        "e__logical__and".parse::<BehaviourTypeId>()
+    );
+    assert_eq!(Err(ModelError::InvalidTypeId("b__logical".to_string())), BehaviourTypeId::from_str("b__logical"));
+}
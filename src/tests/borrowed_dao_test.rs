@@ -0,0 +1,70 @@
+use std::borrow::Cow;
+
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::FlowInstance;
+use crate::FlowInstanceDao;
+use crate::FlowInstanceRefDao;
+use crate::NamespacedTypeGetter;
+use crate::PropertyInstanceGetter;
+
+#[test]
+fn flow_instance_ref_dao_test() {
+    let id = Uuid::new_v4();
+    let inbound_id = Uuid::new_v4();
+    let s = json!({
+        "id": id,
+        "namespace": "logical",
+        "type_name": "and",
+        "name": "flow",
+        "description": "Escaped \"description\"",
+        "entity_instances": [
+            {
+                "id": id,
+                "namespace": "logical",
+                "type_name": "and",
+                "properties": { "result": true },
+                "future_field": 1
+            },
+            {
+                "id": inbound_id,
+                "namespace": "logical",
+                "name": "not"
+            }
+        ],
+        "relation_instances": [
+            {
+                "outbound_id": id,
+                "namespace": "connector",
+                "type_name": "default_connector",
+                "instance_id": "result__input",
+                "inbound_id": inbound_id
+            }
+        ]
+    })
+    .to_string();
+    let dao: FlowInstanceRefDao = serde_json::from_str(&s).unwrap();
+    assert!(matches!(dao.type_name, Cow::Borrowed("and")));
+    assert!(matches!(dao.description, Cow::Owned(_)));
+    assert_eq!("Escaped \"description\"", dao.description);
+    assert!(matches!(dao.entity_instances[1].type_name, Cow::Borrowed("not")));
+    assert!(matches!(dao.relation_instances[0].instance_id, Cow::Borrowed("result__input")));
+    let (name, value) = dao.entity_instances[0].properties.iter().next().unwrap();
+    assert!(matches!(name, Cow::Borrowed("result")));
+    assert_eq!("true", value.get());
+
+    let flow_dao = FlowInstanceDao::try_from(dao.clone()).unwrap();
+    assert_eq!("and", flow_dao.type_name);
+    assert_eq!(2, flow_dao.entity_instances.len());
+    assert_eq!(Some(&json!(1)), flow_dao.entity_instances[0].extra.get("future_field"));
+
+    let flow_instance = FlowInstance::try_from(dao).unwrap();
+    assert_eq!(id, flow_instance.id);
+    assert_eq!("logical", flow_instance.namespace());
+    assert_eq!("flow", flow_instance.name);
+    assert_eq!(Some(true), flow_instance.entity_instances[0].as_bool("result"));
+    assert_eq!("result__input", flow_instance.relation_instances[0].instance_id());
+    assert_eq!(inbound_id, flow_instance.relation_instances[0].inbound_id);
+    assert_eq!(Some(&json!(1)), flow_instance.entity_instances[0].extra.get("future_field"));
+}
@@ -0,0 +1,98 @@
+use std::collections::HashMap;
+
+use indradb::BulkInsertItem;
+use indradb::EdgeKey;
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::property_identifier;
+use crate::test_utils::r_string;
+use crate::to_bulk_insert_items;
+use crate::EntityInstance;
+use crate::EntityTypeId;
+use crate::FlowInstance;
+use crate::RelationInstance;
+use crate::RelationInstanceTypeId;
+use crate::TypeDefinitionGetter;
+
+fn entity_instance(ty: &EntityTypeId, property_name: &str) -> EntityInstance {
+    let mut properties = HashMap::new();
+    properties.insert(property_name.to_string(), json!(r_string()));
+    EntityInstance::new(ty.clone(), Uuid::new_v4(), properties)
+}
+
+fn relation_instance(outbound: &EntityInstance, ty: &RelationInstanceTypeId, inbound: &EntityInstance, property_name: &str) -> RelationInstance {
+    let mut properties = HashMap::new();
+    properties.insert(property_name.to_string(), json!(r_string()));
+    RelationInstance::new(outbound.id, ty.clone(), inbound.id, properties)
+}
+
+fn is_vertex_item(item: &BulkInsertItem) -> bool {
+    matches!(item, BulkInsertItem::Vertex(_) | BulkInsertItem::VertexProperty(..))
+}
+
+#[test]
+fn relation_instance_to_bulk_insert_items_test() {
+    let ty = EntityTypeId::new_from_type(r_string(), r_string());
+    let property_name = r_string();
+    let outbound = entity_instance(&ty, &property_name);
+    let inbound = entity_instance(&ty, &property_name);
+    let relation_ty = RelationInstanceTypeId::new_from_type_unique_id(r_string(), r_string());
+    let relation_instance = relation_instance(&outbound, &relation_ty, &inbound, &property_name);
+    let items = relation_instance.to_bulk_insert_items();
+    assert_eq!(2, items.len());
+    let key = EdgeKey::new(outbound.id, relation_ty.type_id(), inbound.id);
+    assert!(matches!(&items[0], BulkInsertItem::Edge(edge_key) if *edge_key == key));
+    let BulkInsertItem::EdgeProperty(edge_key, name, value) = &items[1] else {
+        panic!("The edge must be followed by its property");
+    };
+    assert_eq!(key, *edge_key);
+    assert_eq!(property_identifier(&property_name), *name);
+    assert_eq!(relation_instance.properties[&property_name], *value);
+}
+
+#[test]
+fn to_bulk_insert_items_test() {
+    let ty = EntityTypeId::new_from_type(r_string(), r_string());
+    let property_name = r_string();
+    let entity_instances: Vec<EntityInstance> = (0..3).map(|_| entity_instance(&ty, &property_name)).collect();
+    let relation_ty = RelationInstanceTypeId::new_from_type_unique_id(r_string(), r_string());
+    let relation_instances = vec![
+        relation_instance(&entity_instances[0], &relation_ty, &entity_instances[1], &property_name),
+        relation_instance(&entity_instances[1], &relation_ty, &entity_instances[2], &property_name),
+    ];
+    let items = to_bulk_insert_items(&entity_instances, &relation_instances);
+    assert_eq!(10, items.len());
+    assert!(items[..6].iter().all(is_vertex_item));
+    assert!(items[6..].iter().all(|item| !is_vertex_item(item)));
+    for (i, entity_instance) in entity_instances.iter().enumerate() {
+        assert!(matches!(&items[i * 2], BulkInsertItem::Vertex(vertex) if vertex.id == entity_instance.id && vertex.t == ty.type_id()));
+        let BulkInsertItem::VertexProperty(id, name, _) = &items[i * 2 + 1] else {
+            panic!("The vertex must be followed by its property");
+        };
+        assert_eq!(entity_instance.id, *id);
+        assert_eq!(property_identifier(&property_name), *name);
+    }
+    for (i, relation_instance) in relation_instances.iter().enumerate() {
+        assert!(matches!(&items[6 + i * 2], BulkInsertItem::Edge(key) if *key == relation_instance.get_key()));
+        assert!(matches!(&items[7 + i * 2], BulkInsertItem::EdgeProperty(key, _, _) if *key == relation_instance.get_key()));
+    }
+}
+
+#[test]
+fn flow_instance_to_bulk_insert_items_test() {
+    let ty = EntityTypeId::new_from_type(r_string(), r_string());
+    let property_name = r_string();
+    let wrapper_entity_instance = entity_instance(&ty, &property_name);
+    let entity_instance = entity_instance(&ty, &property_name);
+    let relation_ty = RelationInstanceTypeId::new_from_type_unique_id(r_string(), r_string());
+    let relation_instance = relation_instance(&wrapper_entity_instance, &relation_ty, &entity_instance, &property_name);
+    let mut flow_instance = FlowInstance::from(wrapper_entity_instance.clone());
+    flow_instance.relation_instances.push(relation_instance);
+    flow_instance.entity_instances.push(entity_instance);
+    let items = flow_instance.to_bulk_insert_items();
+    assert_eq!(6, items.len());
+    assert!(matches!(&items[0], BulkInsertItem::Vertex(vertex) if vertex.id == wrapper_entity_instance.id));
+    assert!(items[..4].iter().all(is_vertex_item));
+    assert!(matches!(&items[4], BulkInsertItem::Edge(_)));
+}
@@ -0,0 +1,124 @@
+use uuid::Uuid;
+
+use crate::test_utils::assert_dao_roundtrip;
+use crate::test_utils::r_string;
+use crate::Cardinality;
+use crate::ComponentOrEntityTypeId;
+use crate::EntityTypeId;
+use crate::RelationCardinality;
+use crate::RelationEndpoint;
+use crate::RelationInstance;
+use crate::RelationInstanceTypeId;
+use crate::RelationType;
+use crate::RelationTypeDao;
+use crate::RelationTypeId;
+use crate::TypeSystem;
+use crate::ValidationError;
+
+fn create_relation_type(cardinality: RelationCardinality) -> RelationType {
+    let entity_ty = EntityTypeId::new_from_type(r_string(), r_string());
+    RelationType::new(
+        ComponentOrEntityTypeId::EntityType(entity_ty.clone()),
+        RelationTypeId::new_from_type(r_string(), r_string()),
+        ComponentOrEntityTypeId::EntityType(entity_ty),
+        r_string(),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+    )
+    .with_cardinality(cardinality)
+}
+
+fn create_relation_instance(relation_type: &RelationType, outbound_id: Uuid, inbound_id: Uuid) -> RelationInstance {
+    RelationInstance::new_without_properties(outbound_id, RelationInstanceTypeId::new_unique_id(relation_type.ty.clone()), inbound_id)
+}
+
+#[test]
+fn cardinality_max_test() {
+    assert_eq!(Some(1), Cardinality::OneToOne.max(RelationEndpoint::Outbound));
+    assert_eq!(Some(1), Cardinality::OneToOne.max(RelationEndpoint::Inbound));
+    assert_eq!(None, Cardinality::OneToMany.max(RelationEndpoint::Outbound));
+    assert_eq!(Some(1), Cardinality::OneToMany.max(RelationEndpoint::Inbound));
+    assert_eq!(Some(1), Cardinality::ManyToOne.max(RelationEndpoint::Outbound));
+    assert_eq!(None, Cardinality::ManyToMany.max(RelationEndpoint::Inbound));
+    assert_eq!(Cardinality::ManyToMany, Cardinality::default());
+
+    let cardinality = RelationCardinality::many_to_one().with_max_outbound(3).with_max_inbound(2);
+    assert_eq!(Some(1), cardinality.max(RelationEndpoint::Outbound));
+    assert_eq!(Some(2), cardinality.max(RelationEndpoint::Inbound));
+}
+
+#[test]
+fn validate_one_to_many_cardinality_test() {
+    let relation_type = create_relation_type(RelationCardinality::one_to_many().with_max_outbound(2));
+    let parent = Uuid::new_v4();
+    let first_child = Uuid::new_v4();
+    let second_child = Uuid::new_v4();
+    let mut relation_instances = vec![
+        create_relation_instance(&relation_type, parent, first_child),
+        create_relation_instance(&relation_type, parent, second_child),
+    ];
+    assert_eq!(Ok(()), relation_type.validate_cardinality(&relation_instances));
+
+    // Multiple relation instances between the same entity instances relate them once
+    relation_instances.push(create_relation_instance(&relation_type, parent, first_child));
+    assert_eq!(Ok(()), relation_type.validate_cardinality(&relation_instances));
+    relation_instances.pop();
+
+    relation_instances.push(create_relation_instance(&relation_type, Uuid::new_v4(), first_child));
+    assert_eq!(
+        Err(ValidationError::CardinalityExceeded {
+            ty: relation_type.ty.clone(),
+            id: first_child,
+            endpoint: RelationEndpoint::Inbound,
+            max: 1,
+        }),
+        relation_type.validate_cardinality(&relation_instances)
+    );
+    relation_instances[2].mark_deleted();
+    assert_eq!(Ok(()), relation_type.validate_cardinality(&relation_instances));
+
+    relation_instances.push(create_relation_instance(&relation_type, parent, Uuid::new_v4()));
+    assert_eq!(
+        Err(ValidationError::CardinalityExceeded {
+            ty: relation_type.ty.clone(),
+            id: parent,
+            endpoint: RelationEndpoint::Outbound,
+            max: 2,
+        }),
+        relation_type.validate_cardinality(&relation_instances)
+    );
+}
+
+#[test]
+fn validate_cardinalities_test() {
+    let one_to_one = create_relation_type(RelationCardinality::one_to_one());
+    let unconstrained = create_relation_type(RelationCardinality::many_to_many());
+    let outbound_id = Uuid::new_v4();
+    let inbound_id = Uuid::new_v4();
+    let mut relation_instances = vec![
+        create_relation_instance(&one_to_one, outbound_id, inbound_id),
+        create_relation_instance(&one_to_one, outbound_id, inbound_id),
+        create_relation_instance(&unconstrained, outbound_id, inbound_id),
+        create_relation_instance(&unconstrained, outbound_id, inbound_id),
+    ];
+    let type_system = TypeSystem::new(Vec::new(), Vec::new(), vec![one_to_one.clone(), unconstrained], Vec::new());
+    assert_eq!(Ok(()), type_system.validate_cardinalities(&relation_instances));
+
+    relation_instances.push(create_relation_instance(&one_to_one, outbound_id, Uuid::new_v4()));
+    assert!(matches!(
+        type_system.validate_cardinalities(&relation_instances),
+        Err(ValidationError::CardinalityExceeded { ty, endpoint: RelationEndpoint::Outbound, .. }) if ty == one_to_one.ty
+    ));
+}
+
+#[test]
+fn relation_type_cardinality_dao_test() {
+    let relation_type = create_relation_type(RelationCardinality::one_to_one().with_max_inbound(1));
+    let json = serde_json::to_value(&relation_type).unwrap();
+    assert_eq!("one_to_one", json["cardinality"]["cardinality"]);
+    assert_eq!(1, json["cardinality"]["max_inbound"]);
+    assert!(json["cardinality"].get("max_outbound").is_none());
+    let deserialized = assert_dao_roundtrip::<RelationType, RelationTypeDao>(&relation_type);
+    assert_eq!(relation_type.cardinality, deserialized.cardinality);
+}
@@ -0,0 +1,43 @@
+use std::collections::HashMap;
+
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::compact_value;
+use crate::test_utils::r_string_1000;
+use crate::EntityInstance;
+use crate::EntityTypeId;
+use crate::FlowInstance;
+use crate::COMPACT_DEBUG_MAX_VALUE_LENGTH;
+
+#[test]
+fn compact_value_test() {
+    assert_eq!("true", compact_value(&json!(true)));
+    assert_eq!("\"label\"", compact_value(&json!("label")));
+    let long = compact_value(&json!(r_string_1000()));
+    assert!(long.len() < COMPACT_DEBUG_MAX_VALUE_LENGTH + 20);
+    assert!(long.ends_with("... (1000 bytes)"));
+    assert_eq!("<binary 26 bytes>", compact_value(&json!("data:image/png;base64,AAAA")));
+    assert_eq!("<binary 100 bytes>", compact_value(&json!(vec![255u8; 100])));
+    assert_eq!("[1,2,3]", compact_value(&json!(vec![1, 2, 3])));
+    assert!(compact_value(&json!("ä".repeat(100))).ends_with("ä... (200 bytes)"));
+    assert!(compact_value(&json!((0..100).collect::<Vec<u32>>())).ends_with("... (100 items)"));
+    let object: serde_json::Map<String, serde_json::Value> = (0..100).map(|i| (format!("key_{i}"), json!(i))).collect();
+    assert!(compact_value(&json!(object)).ends_with("... (100 entries)"));
+}
+
+#[test]
+fn entity_instance_debug_compact_test() {
+    let id = Uuid::new_v4();
+    let properties = HashMap::from([("b".to_string(), json!(r_string_1000())), ("a".to_string(), json!(1))]);
+    let entity_instance = EntityInstance::new(EntityTypeId::new_from_type("logical", "and"), id, properties);
+    let debug = format!("{:?}", entity_instance.debug_compact());
+    assert!(debug.starts_with(&format!("EntityInstance {{ ty: \"e__logical__and\", id: {id}, properties: {{\"a\": 1, \"b\": \"")));
+    assert!(debug.ends_with("... (1000 bytes)}, extensions: 0 }"));
+    assert!(debug.len() < 200);
+
+    let flow_instance = FlowInstance::from(entity_instance);
+    let debug = format!("{:?}", flow_instance.debug_compact());
+    assert!(debug.starts_with("FlowInstance {"));
+    assert!(debug.len() < 300);
+}
@@ -0,0 +1,64 @@
+use serde_json::json;
+
+use crate::test_utils::assert_dao_roundtrip;
+use crate::test_utils::r_string;
+use crate::Component;
+use crate::ComponentDao;
+use crate::ComponentTypeId;
+use crate::Extension;
+use crate::ExtensionTypeId;
+use crate::NamespacedTypeGetter;
+use crate::PropertyType;
+
+#[test]
+fn component_dao_conversion_test() {
+    let ty = ComponentTypeId::new_from_type(r_string(), r_string());
+    let extension = Extension::new(ExtensionTypeId::new_from_type(r_string(), r_string()), r_string(), json!(r_string()));
+    let component = Component::new(ty.clone(), r_string(), vec![PropertyType::string(r_string())], vec![extension]);
+
+    assert_dao_roundtrip::<Component, ComponentDao>(&component);
+    let dao = ComponentDao::from(component.clone());
+    assert_eq!(ty.namespace(), dao.namespace);
+    assert_eq!(ty.type_name(), dao.type_name);
+    assert_eq!(component.description, dao.description);
+    assert_eq!(1, dao.properties.len());
+    assert_eq!(1, dao.extensions.len());
+
+    let converted = Component::from(dao);
+    assert_eq!(ty, converted.ty);
+    assert_eq!(component.description, converted.description);
+    assert_eq!(component.properties.first().unwrap().name, converted.properties.first().unwrap().name);
+    assert_eq!(component.extensions.first().unwrap().ty, converted.extensions.first().unwrap().ty);
+}
+
+#[test]
+fn component_dao_deserialize_with_aliases_and_defaults_test() {
+    let type_name = r_string();
+    let dao: ComponentDao = serde_json::from_value(json!({
+        "name": type_name,
+    }))
+    .unwrap();
+    assert_eq!(String::new(), dao.namespace);
+    assert_eq!(type_name, dao.type_name);
+    assert_eq!(String::new(), dao.description);
+    assert!(dao.properties.is_empty());
+    assert!(dao.extensions.is_empty());
+}
+
+#[test]
+fn component_dao_preserves_unknown_fields_test() {
+    let dao: ComponentDao = serde_json::from_value(json!({
+        "namespace": "namespace",
+        "name": "labeled",
+        "future_field": 42,
+    }))
+    .unwrap();
+    assert_eq!(Some(&json!(42)), dao.extra.get("future_field"));
+    assert!(!dao.extra.contains_key("name"));
+    let serialized = serde_json::to_value(&dao).unwrap();
+    assert_eq!(json!(42), serialized["future_field"]);
+
+    // The unknown fields survive the conversion into the model and back
+    let round_tripped = serde_json::to_value(ComponentDao::from(Component::from(dao))).unwrap();
+    assert_eq!(serialized, round_tripped);
+}
@@ -1,6 +1,11 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
 use serde_json::json;
+use serde_json::Map;
 
-use crate::tests::utils::r_string;
+use crate::test_utils::assert_json_roundtrip;
+use crate::test_utils::r_string;
 use crate::Component;
 use crate::ComponentTypeId;
 use crate::DataType;
@@ -39,8 +44,14 @@ fn component_test() {
     let component = Component {
         ty,
         description: description.clone(),
+        descriptions: HashMap::new(),
+        documentation: None,
+        tags: Vec::new(),
+        aliases: Vec::new(),
+        deprecation: None,
         properties: property_types,
         extensions,
+        extra: Map::new(),
     };
 
     assert_eq!(namespace, component.namespace());
@@ -159,6 +170,23 @@ fn component_has_property_test() {
     assert!(!component.has_property(r_string()));
 }
 
+#[test]
+fn component_equality_test() {
+    let ty = ComponentTypeId::new_from_type(r_string(), r_string());
+    let component = Component::new_without_extensions(ty.clone(), r_string(), vec![PropertyType::string("label")]);
+    let mut changed = component.clone();
+    changed.description = r_string();
+    let other = Component::new_without_extensions(ComponentTypeId::new_from_type(r_string(), r_string()), r_string(), Vec::new());
+
+    assert_eq!(component, changed);
+    assert_ne!(component, other);
+    assert!(component.structurally_equals(&component.clone()));
+    assert!(!component.structurally_equals(&changed));
+
+    let components: HashSet<Component> = vec![component, changed, other].into_iter().collect();
+    assert_eq!(2, components.len());
+}
+
 #[test]
 fn component_type_ser_test() {
     let ty = ComponentTypeId::new_from_type("cnc", "ctc");
@@ -185,6 +213,7 @@ fn component_ser_test() {
   "extensions": []
 }"#;
     let component: Component = serde_json::from_str(s).unwrap();
+    assert_json_roundtrip(&component);
     assert_eq!("abc", component.namespace());
     assert_eq!("def", component.type_name());
     assert_eq!("c__abc__def", component.ty.to_string());
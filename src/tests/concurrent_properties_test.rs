@@ -0,0 +1,50 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::thread;
+
+use serde_json::json;
+
+use crate::ConcurrentProperties;
+use crate::PropertyInstanceGetter;
+
+#[test]
+fn concurrent_properties_test() {
+    let mut map = HashMap::new();
+    map.insert("value".to_string(), json!(1));
+    let properties = ConcurrentProperties::from(map);
+    assert_eq!(1, properties.len());
+    assert_eq!(Some(1), properties.as_u64("value"));
+
+    properties.set("value", json!(2));
+    properties.set("unknown", json!(3));
+    assert_eq!(Some(2), properties.as_u64("value"));
+    assert!(!properties.contains("unknown"));
+
+    assert!(properties.update("value", |v| *v = json!(v.as_u64().unwrap() + 1)));
+    assert!(!properties.update("unknown", |_| {}));
+    assert_eq!(Some(3), properties.as_u64("value"));
+
+    assert_eq!(None, properties.insert("label", json!("label")));
+    assert_eq!(Some("label".to_string()), properties.as_string("label"));
+    assert_eq!(Some(json!("label")), properties.remove("label"));
+    assert_eq!(vec!["value".to_string()], properties.names());
+    assert_eq!(Some(&json!(3)), properties.to_map().get("value"));
+}
+
+#[test]
+fn concurrent_properties_threads_test() {
+    let properties = Arc::new(ConcurrentProperties::new());
+    let handles: Vec<_> = (0..8)
+        .map(|i| {
+            let properties = properties.clone();
+            thread::spawn(move || {
+                properties.insert(format!("p{}", i), json!(i));
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    assert_eq!(8, properties.len());
+    assert_eq!(Some(7), properties.as_i64("p7"));
+}
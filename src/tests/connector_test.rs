@@ -0,0 +1,92 @@
+use std::collections::HashMap;
+
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::default_connector_type_id;
+use crate::test_utils::assert_json_roundtrip_eq;
+use crate::Connector;
+use crate::ConnectorError;
+use crate::EntityInstance;
+use crate::EntityTypeId;
+use crate::FlowInstance;
+use crate::PropertyInstanceGetter;
+use crate::RelationInstance;
+use crate::RelationInstanceTypeId;
+use crate::RelationTypeId;
+use crate::SubFlowBinding;
+
+#[test]
+fn connector_to_relation_instance_test() {
+    let outbound_id = Uuid::new_v4();
+    let inbound_id = Uuid::new_v4();
+    let connector = Connector::new(outbound_id, "result", inbound_id, "lhs");
+    assert_eq!(default_connector_type_id(), connector.ty);
+    assert_eq!("result__lhs", connector.instance_id());
+
+    let relation_instance = RelationInstance::from(&connector);
+    assert!(relation_instance.is_connector());
+    assert_eq!(outbound_id, relation_instance.outbound_id);
+    assert_eq!(inbound_id, relation_instance.inbound_id);
+    assert_eq!(default_connector_type_id(), relation_instance.relation_type_id());
+    assert_eq!("result__lhs", relation_instance.instance_id());
+    assert_eq!(Some("result".to_string()), relation_instance.as_string("outbound_property_name"));
+    assert_eq!(Some("lhs".to_string()), relation_instance.as_string("inbound_property_name"));
+
+    assert_eq!(Ok(connector.clone()), Connector::try_from(relation_instance));
+    assert_json_roundtrip_eq(&connector);
+}
+
+#[test]
+fn connector_from_relation_instance_test() {
+    let outbound_id = Uuid::new_v4();
+    let inbound_id = Uuid::new_v4();
+    let ty = RelationTypeId::new_from_type("connector", "debounce_connector");
+
+    // The property names are parsed from the instance id if the properties are missing.
+    let relation_instance = RelationInstance::new(
+        outbound_id,
+        RelationInstanceTypeId::new_unique_for_instance_id(ty.clone(), "value__trigger"),
+        inbound_id,
+        HashMap::new(),
+    );
+    let connector = Connector::try_from(&relation_instance).unwrap();
+    assert_eq!(Connector::new(outbound_id, "value", inbound_id, "trigger").with_type(ty.clone()), connector);
+
+    // The properties take precedence over the instance id.
+    let properties = HashMap::from([
+        ("outbound_property_name".to_string(), json!("a__b")),
+        ("inbound_property_name".to_string(), json!("c")),
+    ]);
+    let relation_instance =
+        RelationInstance::new(outbound_id, RelationInstanceTypeId::new_unique_for_instance_id(ty.clone(), "a__b__c"), inbound_id, properties);
+    let connector = Connector::try_from(&relation_instance).unwrap();
+    assert_eq!("a__b", connector.outbound_property_name);
+    assert_eq!("c", connector.inbound_property_name);
+
+    let relation_instance = RelationInstance::new(outbound_id, RelationInstanceTypeId::new_unique_id(ty), inbound_id, HashMap::new());
+    assert!(matches!(Connector::try_from(&relation_instance), Err(ConnectorError::MissingPropertyNames(_))));
+
+    let other_ty = RelationTypeId::new_from_type("core", "contains");
+    let relation_instance = RelationInstance::new(outbound_id, RelationInstanceTypeId::new_unique_id(other_ty.clone()), inbound_id, HashMap::new());
+    assert!(!relation_instance.is_connector());
+    assert_eq!(Err(ConnectorError::NotAConnector(other_ty)), Connector::try_from(&relation_instance));
+}
+
+#[test]
+fn flow_instance_connectors_test() {
+    let wrapper = EntityInstance::new_without_properties(EntityTypeId::new_from_type("flow", "generic_flow"), Uuid::new_v4());
+    let mut flow_instance = FlowInstance::from(wrapper);
+    let outer_id = Uuid::new_v4();
+    let inner_id = Uuid::new_v4();
+    let binding = SubFlowBinding::output(inner_id, "result", outer_id, "value");
+    assert_eq!(Connector::new(inner_id, "result", outer_id, "value"), binding.to_connector());
+    flow_instance.relation_instances.push(binding.to_relation_instance());
+    flow_instance.relation_instances.push(RelationInstance::new(
+        outer_id,
+        RelationInstanceTypeId::new_unique_id(RelationTypeId::new_from_type("core", "contains")),
+        inner_id,
+        HashMap::new(),
+    ));
+    assert_eq!(vec![binding.to_connector()], flow_instance.connectors());
+}
@@ -0,0 +1,65 @@
+use std::collections::HashMap;
+
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::canonical_json;
+use crate::content_hash;
+use crate::fnv1a_64;
+use crate::test_utils::r_string;
+use crate::ContentHash;
+use crate::EntityInstance;
+use crate::EntityType;
+use crate::PropertyType;
+
+#[test]
+fn canonical_json_test() {
+    assert_eq!(
+        r#"{"a":[1,2.5,null],"b":{"c":true,"d":"\"e\""}}"#,
+        canonical_json(&json!({ "b": { "d": "\"e\"", "c": true }, "a": [1, 2.5, null] }))
+    );
+    assert_eq!("\"string\"", canonical_json(&json!("string")));
+}
+
+#[test]
+fn fnv1a_64_test() {
+    assert_eq!(0xcbf29ce484222325, fnv1a_64(b""));
+    assert_eq!(0xaf63dc4c8601ec8c, fnv1a_64(b"a"));
+    assert_eq!(0x85944171f73967e8, fnv1a_64(b"foobar"));
+}
+
+#[test]
+fn entity_instance_content_hash_test() {
+    let id = Uuid::new_v4();
+    let names: Vec<String> = (0..20).map(|_| r_string()).collect();
+    let mut properties = HashMap::new();
+    for name in names.iter() {
+        properties.insert(name.clone(), json!(name));
+    }
+    let mut reversed_properties = HashMap::new();
+    for name in names.iter().rev() {
+        reversed_properties.insert(name.clone(), json!(name));
+    }
+    let entity_instance = EntityInstance::new_from_type("namespace", "type_name", id, properties);
+    let other_entity_instance = EntityInstance::new_from_type("namespace", "type_name", id, reversed_properties);
+    assert_eq!(entity_instance.content_hash().unwrap(), other_entity_instance.content_hash().unwrap());
+
+    let mut changed_entity_instance = entity_instance.clone();
+    changed_entity_instance.properties.insert(names.first().unwrap().clone(), json!(42));
+    assert_ne!(entity_instance.content_hash().unwrap(), changed_entity_instance.content_hash().unwrap());
+}
+
+#[test]
+fn entity_type_content_hash_test() {
+    let entity_type = EntityType::new_from_type("namespace", "type_name", "description", Vec::new(), vec![PropertyType::string("name")], Vec::new());
+    assert_eq!(entity_type.content_hash().unwrap(), entity_type.clone().content_hash().unwrap());
+    let mut changed_entity_type = entity_type.clone();
+    changed_entity_type.description = r_string();
+    assert_ne!(entity_type.content_hash().unwrap(), changed_entity_type.content_hash().unwrap());
+}
+
+#[test]
+fn content_hash_of_unserializable_value_test() {
+    // JSON objects can't have tuples as keys
+    assert!(content_hash(&HashMap::from([((1, 2), 3)])).is_err());
+}
@@ -0,0 +1,42 @@
+use std::collections::HashMap;
+
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::CowProperties;
+use crate::EntityInstance;
+use crate::EntityTypeId;
+use crate::MutablePropertyInstanceSetter;
+use crate::PropertyInstanceGetter;
+
+#[test]
+fn cow_properties_test() {
+    let properties = CowProperties::from(HashMap::from([("label".to_string(), json!("label"))]));
+    assert!(!properties.is_shared());
+    let mut cloned = properties.clone();
+    assert!(properties.ptr_eq(&cloned));
+    assert!(properties.is_shared());
+    assert_eq!(Some(&json!("label")), cloned.get("label"));
+
+    cloned.insert("value".to_string(), json!(1));
+    assert!(!properties.ptr_eq(&cloned));
+    assert!(!properties.is_shared());
+    assert_eq!(1, properties.len());
+    assert_eq!(2, cloned.len());
+    assert_eq!(HashMap::from([("label".to_string(), json!("label"))]), properties);
+    assert_eq!(r#"{"label":"label"}"#, serde_json::to_string(&properties).unwrap());
+    assert_eq!(properties, serde_json::from_str::<CowProperties>(r#"{"label":"label"}"#).unwrap());
+}
+
+#[test]
+fn entity_instance_clone_shares_properties_test() {
+    let properties = HashMap::from([("result".to_string(), json!(false))]);
+    let entity_instance = EntityInstance::new(EntityTypeId::new_from_type("logical", "and"), Uuid::new_v4(), properties);
+    let mut snapshot = entity_instance.clone();
+    assert!(entity_instance.properties.ptr_eq(&snapshot.properties));
+
+    snapshot.set("result", json!(true));
+    assert!(!entity_instance.properties.ptr_eq(&snapshot.properties));
+    assert_eq!(Some(false), entity_instance.as_bool("result"));
+    assert_eq!(Some(true), snapshot.as_bool("result"));
+}
@@ -0,0 +1,52 @@
+use crate::ComponentDao;
+use crate::DaoParseError;
+use crate::FlowInstanceDao;
+use crate::ParseLimits;
+
+#[test]
+fn from_slice_test() {
+    let document = br#"{ "namespace": "base", "type_name": "labeled", "properties": [{ "name": "label", "data_type": "string" }] }"#;
+    let dao = ComponentDao::from_slice(document).unwrap();
+    assert_eq!("labeled", dao.type_name);
+    assert_eq!(1, dao.properties.len());
+}
+
+#[test]
+fn from_slice_malformed_test() {
+    assert!(matches!(ComponentDao::from_slice(b"{ \"type_name\": "), Err(DaoParseError::Json(_))));
+    assert!(matches!(ComponentDao::from_slice(&[0xff, 0xfe, 0x00]), Err(DaoParseError::Json(_))));
+    assert!(matches!(FlowInstanceDao::from_slice(b""), Err(DaoParseError::Json(_))));
+}
+
+#[test]
+fn from_slice_too_large_test() {
+    let limits = ParseLimits::default().with_max_size(16);
+    let result = ComponentDao::from_slice_with_limits(br#"{ "type_name": "labeled" }"#, &limits);
+    assert!(matches!(result, Err(DaoParseError::TooLarge { size: 26, max_size: 16 })));
+}
+
+#[test]
+fn from_slice_too_deeply_nested_test() {
+    let document = format!("{{ \"type_name\": \"labeled\", \"x\": {}{} }}", "[".repeat(100_000), "]".repeat(100_000));
+    let result = ComponentDao::from_slice(document.as_bytes());
+    assert!(matches!(result, Err(DaoParseError::TooDeeplyNested { max_depth: 64 })));
+}
+
+#[test]
+fn from_slice_string_too_long_test() {
+    let limits = ParseLimits::default().with_max_string_length(8);
+    let result = ComponentDao::from_slice_with_limits(br#"{ "type_name": "a_very_long_name" }"#, &limits);
+    assert!(matches!(result, Err(DaoParseError::StringTooLong { max_string_length: 8 })));
+    // Escaped quotes don't terminate the string
+    let limits = ParseLimits::default().with_max_string_length(12);
+    assert!(ComponentDao::from_slice_with_limits(br#"{ "type_name": "a\"b" }"#, &limits).is_ok());
+    let result = ComponentDao::from_slice_with_limits(br#"{ "type_name": "a\"bcdefghijklm" }"#, &limits);
+    assert!(matches!(result, Err(DaoParseError::StringTooLong { .. })));
+}
+
+#[test]
+fn check_ignores_brackets_in_strings_test() {
+    let limits = ParseLimits::default().with_max_depth(2);
+    assert!(limits.check(br#"{ "description": "[[[[{{{{" }"#).is_ok());
+    assert!(limits.check(br#"{ "a": [[1]] }"#).is_err());
+}
@@ -0,0 +1,81 @@
+use uuid::Uuid;
+
+use crate::from_json_str_with_mode;
+use crate::ComponentDao;
+use crate::DaoParseError;
+use crate::EntityTypeDao;
+use crate::FlowInstanceDao;
+use crate::ParseMode;
+
+#[test]
+fn component_dao_parse_mode_test() {
+    let document = r#"{"namespace": "base", "type_name": "labeled", "version": 2}"#;
+    let dao: ComponentDao = from_json_str_with_mode(document, ParseMode::Lenient).unwrap();
+    assert_eq!("labeled", dao.type_name);
+    assert!(dao.extra.contains_key("version"));
+    assert!(matches!(
+        from_json_str_with_mode::<ComponentDao>(document, ParseMode::Strict),
+        Err(DaoParseError::UnknownFields(fields)) if fields == vec!["version".to_string()]
+    ));
+
+    let document = r#"{"type_name": "labeled"}"#;
+    let dao: ComponentDao = from_json_str_with_mode(document, ParseMode::default()).unwrap();
+    assert_eq!("", dao.namespace);
+    let error = from_json_str_with_mode::<ComponentDao>(document, ParseMode::Strict).unwrap_err();
+    assert_eq!("Missing fields: namespace", error.to_string());
+
+    let document = r#"{"namespace": "base", "type_name": "labeled", "description": "Labeled"}"#;
+    assert!(from_json_str_with_mode::<ComponentDao>(document, ParseMode::Strict).is_ok());
+    assert!(matches!(
+        from_json_str_with_mode::<ComponentDao>(r#"{"namespace": "base"}"#, ParseMode::Strict),
+        Err(DaoParseError::Json(_))
+    ));
+}
+
+#[test]
+fn flow_instance_dao_parse_mode_test() {
+    let id = Uuid::new_v4();
+    let document =
+        format!(r#"{{"id": "{id}", "namespace": "logical", "type_name": "and", "entities": [{{"id": "{id}", "type_name": "and", "color": "red"}}]}}"#);
+    assert!(from_json_str_with_mode::<FlowInstanceDao>(&document, ParseMode::Lenient).is_ok());
+    assert!(matches!(
+        from_json_str_with_mode::<FlowInstanceDao>(&document, ParseMode::Strict),
+        Err(DaoParseError::MissingFields(fields)) if fields == vec!["entities[0].namespace".to_string()]
+    ));
+
+    let document = document.replace(r#""type_name": "and", "color""#, r#""namespace": "logical", "type_name": "and", "color""#);
+    assert!(matches!(
+        from_json_str_with_mode::<FlowInstanceDao>(&document, ParseMode::Strict),
+        Err(DaoParseError::UnknownFields(fields)) if fields == vec!["entity_instances[0].color".to_string()]
+    ));
+}
+
+#[test]
+fn entity_type_dao_parse_mode_nested_unknown_fields_test() {
+    let document = r#"{"namespace": "base", "type_name": "item", "properties": [{"name": "label", "datatype": "string"}]}"#;
+    assert!(matches!(
+        from_json_str_with_mode::<EntityTypeDao>(document, ParseMode::Strict),
+        Err(DaoParseError::UnknownFields(fields)) if fields == vec!["properties[0].datatype".to_string()]
+    ));
+    assert!(matches!(from_json_str_with_mode::<EntityTypeDao>(document, ParseMode::Lenient), Err(DaoParseError::Json(_))));
+
+    let document = r#"{
+        "namespace": "base",
+        "type_name": "item",
+        "version": 2,
+        "properties": [{"name": "label", "data_type": "string", "extensions": [{"namespace": "ui", "type_name": "widget", "extension": {}, "order": 1}]}],
+        "extensions": [{"namespace": "ui", "name": "icon", "extension": "box", "size": 16}]
+    }"#;
+    assert!(matches!(
+        from_json_str_with_mode::<EntityTypeDao>(document, ParseMode::Strict),
+        Err(DaoParseError::UnknownFields(fields)) if fields == vec![
+            "version".to_string(),
+            "properties[0].extensions[0].order".to_string(),
+            "extensions[0].size".to_string()
+        ]
+    ));
+    assert!(from_json_str_with_mode::<EntityTypeDao>(document, ParseMode::Lenient).is_ok());
+
+    let document = r#"{"namespace": "base", "type_name": "item", "properties": [{"name": "label", "data_type": "string", "socket_type": "input"}]}"#;
+    assert!(from_json_str_with_mode::<EntityTypeDao>(document, ParseMode::Strict).is_ok());
+}
@@ -1,6 +1,6 @@
 use serde_json::Value;
 
-use crate::tests::utils::r_string;
+use crate::test_utils::r_string;
 use crate::DataType;
 
 #[test]
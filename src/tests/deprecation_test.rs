@@ -0,0 +1,56 @@
+use serde_json::json;
+
+use crate::test_utils::r_string;
+use crate::ComponentDao;
+use crate::Deprecation;
+use crate::EntityType;
+use crate::EntityTypeDao;
+use crate::EntityTypeId;
+use crate::NamespacedType;
+use crate::TypeDefinition;
+use crate::TypeSystem;
+
+#[test]
+fn deprecation_test() {
+    let and = EntityTypeId::new_from_type("logical", "and");
+    let deprecation = Deprecation::new("Use logical::and instead").since("0.9.0").replaced_by(&and);
+    let entity_type = EntityType::new(EntityTypeId::new_from_type("logical", "and_gate"), r_string(), Vec::new(), Vec::new(), Vec::new())
+        .with_deprecation(deprecation.clone());
+    assert!(entity_type.is_deprecated());
+    assert_eq!(Some("0.9.0".to_string()), entity_type.deprecation.as_ref().unwrap().since);
+    assert_eq!(Some(NamespacedType::new("logical", "and")), entity_type.deprecation.as_ref().unwrap().replacement);
+
+    let dao = EntityTypeDao::from(entity_type);
+    assert_eq!(
+        json!({
+            "since": "0.9.0",
+            "replacement": { "namespace": "logical", "type_name": "and" },
+            "message": "Use logical::and instead"
+        }),
+        serde_json::to_value(&dao).unwrap()["deprecation"]
+    );
+    assert_eq!(Some(deprecation), EntityType::from(dao).deprecation);
+}
+
+#[test]
+fn deprecation_defaults_test() {
+    let dao: ComponentDao = serde_json::from_value(json!({
+        "namespace": "base",
+        "type_name": "labeled",
+        "deprecation": {}
+    }))
+    .unwrap();
+    assert_eq!(Some(Deprecation::default()), dao.deprecation);
+    let dao: ComponentDao = serde_json::from_value(json!({ "namespace": "base", "type_name": "labeled" })).unwrap();
+    assert_eq!(None, dao.deprecation);
+}
+
+#[test]
+fn type_system_deprecated_types_test() {
+    let deprecated = EntityType::new(EntityTypeId::new_from_type("logical", "and_gate"), r_string(), Vec::new(), Vec::new(), Vec::new())
+        .with_deprecation(Deprecation::new(r_string()));
+    let entity_type = EntityType::new(EntityTypeId::new_from_type("logical", "and"), r_string(), Vec::new(), Vec::new(), Vec::new());
+    assert!(!entity_type.is_deprecated());
+    let type_system = TypeSystem::new(Vec::new(), vec![deprecated, entity_type], Vec::new(), Vec::new());
+    assert_eq!(vec![TypeDefinition::entity_type("logical", "and_gate")], type_system.deprecated_types());
+}
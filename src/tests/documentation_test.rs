@@ -0,0 +1,56 @@
+use serde_json::json;
+
+use crate::test_utils::assert_json_roundtrip;
+use crate::Component;
+use crate::ComponentTypeId;
+use crate::DataType;
+use crate::Documentation;
+use crate::EntityType;
+use crate::EntityTypeId;
+use crate::PropertyType;
+
+fn create_documentation() -> Documentation {
+    Documentation::new("Computes the logical and of two inputs.")
+        .with_body("The result is `true` if and only if both inputs are `true`.")
+        .with_link("Logical conjunction", "https://en.wikipedia.org/wiki/Logical_conjunction")
+}
+
+#[test]
+fn entity_type_documentation_test() {
+    let entity_type = EntityType::new(EntityTypeId::new_from_type("logical", "and"), "Logical and", Vec::new(), Vec::new(), Vec::new());
+    assert_eq!(None, entity_type.documentation);
+    assert_eq!("Logical and", entity_type.summary());
+    assert!(serde_json::to_value(&entity_type).unwrap().get("documentation").is_none());
+
+    let entity_type = entity_type.with_documentation(create_documentation());
+    assert_eq!("Computes the logical and of two inputs.", entity_type.summary());
+    assert_eq!(
+        json!({
+            "summary": "Computes the logical and of two inputs.",
+            "body": "The result is `true` if and only if both inputs are `true`.",
+            "links": [{ "title": "Logical conjunction", "url": "https://en.wikipedia.org/wiki/Logical_conjunction" }]
+        }),
+        serde_json::to_value(&entity_type).unwrap()["documentation"]
+    );
+
+    let deserialized = assert_json_roundtrip(&entity_type);
+    assert_eq!(Some(create_documentation()), deserialized.documentation);
+}
+
+#[test]
+fn documentation_summary_fallback_test() {
+    let component = Component::new_without_extensions(ComponentTypeId::new_from_type("base", "labeled"), "Labeled", Vec::new())
+        .with_documentation(Documentation::default().with_body("Has a label."));
+    assert_eq!("Labeled", component.summary());
+    assert_eq!(json!({ "summary": "", "body": "Has a label." }), serde_json::to_value(&component).unwrap()["documentation"]);
+
+    let property_type = PropertyType::new("label", DataType::String).with_documentation(Documentation::new("The label"));
+    assert_eq!("The label", property_type.summary());
+    let property_type: PropertyType = serde_json::from_value(json!({
+        "name": "label",
+        "data_type": "string",
+        "documentation": { "summary": "The label" }
+    }))
+    .unwrap();
+    assert_eq!(Some(Documentation::new("The label")), property_type.documentation);
+}
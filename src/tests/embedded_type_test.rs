@@ -0,0 +1,34 @@
+use crate::embedded_component;
+use crate::embedded_entity_type;
+use crate::parse_embedded_type;
+use crate::Component;
+use crate::ComponentTypeId;
+use crate::EntityTypeId;
+use crate::SocketType;
+
+fn labeled() -> &'static Component {
+    embedded_component!("types/labeled.json")
+}
+
+#[test]
+fn embedded_component_test() {
+    let component = labeled();
+    assert_eq!(ComponentTypeId::new_from_type("base", "labeled"), component.ty);
+    assert!(component.has_property("label"));
+    // The component is parsed only once
+    assert!(std::ptr::eq(component, labeled()));
+}
+
+#[test]
+fn embedded_entity_type_test() {
+    let entity_type = embedded_entity_type!("types/and.json");
+    assert_eq!(EntityTypeId::new_from_type("logical", "and"), entity_type.ty);
+    assert_eq!(vec![ComponentTypeId::new_from_type("base", "labeled")], entity_type.components);
+    assert_eq!(SocketType::Output, entity_type.properties.iter().find(|p| p.name == "result").unwrap().socket_type);
+}
+
+#[test]
+#[should_panic(expected = "The embedded type types/invalid.json is invalid")]
+fn parse_invalid_embedded_type_test() {
+    parse_embedded_type::<Component>("{ \"namespace\": 1 }", "types/invalid.json");
+}
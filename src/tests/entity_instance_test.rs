@@ -1,15 +1,22 @@
 use std::collections::HashMap;
 use std::ops::Index;
 
+use chrono::TimeZone;
+use chrono::Utc;
+use indradb::BulkInsertItem;
 use indradb::NamedProperty;
 use indradb::Vertex;
 use indradb::VertexProperties;
 use serde_json::json;
+use serde_json::Map;
 use uuid::Uuid;
 
 use crate::property_identifier;
-use crate::tests::utils::r_string;
+use crate::test_utils::assert_dao_roundtrip;
+use crate::test_utils::assert_json_roundtrip;
+use crate::test_utils::r_string;
 use crate::EntityInstance;
+use crate::EntityInstanceDao;
 use crate::EntityTypeId;
 use crate::Extension;
 use crate::ExtensionContainer;
@@ -17,6 +24,8 @@ use crate::ExtensionTypeId;
 use crate::MutablePropertyInstanceSetter;
 use crate::NamespacedTypeGetter;
 use crate::PropertyInstanceGetter;
+use crate::PropertyInstanceRefGetter;
+use crate::RevisionError;
 use crate::TypeDefinitionGetter;
 
 #[test]
@@ -50,8 +59,14 @@ fn entity_instance_test() {
         ty: ty.clone(),
         id: uuid.clone(),
         description: description.to_string(),
-        properties: properties.clone(),
+        properties: properties.clone().into(),
         extensions: extensions.clone(),
+        created_at: None,
+        updated_at: None,
+        created_by: None,
+        deleted_at: None,
+        revision: 0,
+        extra: Map::new(),
     };
     assert_eq!(namespace, entity_instance.namespace());
     assert_eq!(type_name, entity_instance.type_name());
@@ -68,6 +83,7 @@ fn entity_instance_test() {
     assert!(!entity_instance.has_own_extension(&non_existing_extension));
     assert_eq!(extension.extension, entity_instance.get_own_extension(&extension_ty).unwrap().extension);
     assert_eq!(format!("{}__{}", entity_instance.ty, entity_instance.id), format!("{}", entity_instance));
+    assert_eq!(format!("{}__{} (1 properties)", entity_instance.ty, entity_instance.id), format!("{:#}", entity_instance));
 }
 
 #[test]
@@ -128,6 +144,40 @@ fn create_entity_instance_from_vertex_properties() {
     assert_eq!(property_value.as_str(), entity_instance.properties.get(property_name.as_str()).unwrap().as_str().unwrap());
 }
 
+#[test]
+fn entity_instance_to_bulk_insert_items_test() {
+    let uuid = Uuid::new_v4();
+    let ty = EntityTypeId::new_from_type(r_string(), r_string());
+    let mut properties = HashMap::new();
+    properties.insert(r_string(), json!(r_string()));
+    properties.insert(r_string(), json!(123));
+    let entity_instance = EntityInstance::new(ty.clone(), uuid, properties.clone());
+    let items = entity_instance.to_bulk_insert_items();
+    assert_eq!(properties.len() + 1, items.len());
+    let vertex = match &items[0] {
+        BulkInsertItem::Vertex(vertex) => vertex.clone(),
+        _ => panic!("The first item must be the vertex"),
+    };
+    assert_eq!(uuid, vertex.id);
+    assert_eq!(ty.type_id(), vertex.t);
+    let props = items[1..]
+        .iter()
+        .map(|item| match item {
+            BulkInsertItem::VertexProperty(id, name, value) => {
+                assert_eq!(uuid, *id);
+                NamedProperty {
+                    name: name.clone(),
+                    value: value.clone(),
+                }
+            }
+            _ => panic!("The following items must be vertex properties"),
+        })
+        .collect();
+    let entity_instance = EntityInstance::try_from(VertexProperties { vertex, props }).unwrap();
+    assert_eq!(ty, entity_instance.ty);
+    assert_eq!(properties, entity_instance.properties);
+}
+
 #[test]
 fn entity_instance_typed_getter_test() {
     let uuid = Uuid::new_v4();
@@ -163,6 +213,22 @@ fn entity_instance_typed_getter_test() {
     assert_eq!(json!("v"), i.as_object(property_name.clone()).unwrap().index("k").clone());
 }
 
+#[test]
+fn entity_instance_ref_getter_test() {
+    let mut properties = HashMap::new();
+    properties.insert("label".to_string(), json!("label"));
+    properties.insert("values".to_string(), json!([1, 2]));
+    properties.insert("config".to_string(), json!({ "k": "v" }));
+    let i = EntityInstance::new_from_type(r_string(), r_string(), Uuid::new_v4(), properties);
+    assert_eq!(Some(&json!("label")), i.get_ref("label"));
+    assert_eq!(Some("label"), i.as_str("label"));
+    assert_eq!(None, i.as_str("values"));
+    assert_eq!(2, i.as_array_ref("values").unwrap().len());
+    assert_eq!(Some(&json!("v")), i.as_object_ref("config").unwrap().get("k"));
+    assert_eq!(None, i.get_ref("unknown"));
+    assert_eq!(Some("label"), i.properties.as_str("label"));
+}
+
 #[test]
 fn entity_instance_ser_test() {
     let uuid = Uuid::new_v4();
@@ -194,8 +260,14 @@ fn entity_instance_ser_test() {
         ty: ty.clone(),
         id: uuid.clone(),
         description: description.to_string(),
-        properties: properties.clone(),
+        properties: properties.clone().into(),
         extensions: extensions.clone(),
+        created_at: None,
+        updated_at: None,
+        created_by: None,
+        deleted_at: None,
+        revision: 0,
+        extra: Map::new(),
     };
     println!("{}", serde_json::to_string_pretty(&entity_instance).expect("Failed to serialize entity instance"));
 }
@@ -224,6 +296,7 @@ fn entity_instance_de_test() {
   ]
 }"#;
     let entity_instance: EntityInstance = serde_json::from_str(s).unwrap();
+    assert_json_roundtrip(&entity_instance);
     assert_eq!("XARPbZkHrU", entity_instance.namespace());
     assert_eq!("zHMZhLUpeH", entity_instance.type_name());
     assert_eq!("e__XARPbZkHrU__zHMZhLUpeH", entity_instance.ty.to_string());
@@ -236,4 +309,89 @@ fn entity_instance_de_test() {
     assert_eq!("ext_namespace", extension.ty.namespace());
     assert_eq!("ext_name", extension.ty.type_name());
     assert_eq!(json!("extension_value"), extension.extension);
+    assert!(entity_instance.created_at.is_none());
+    assert!(entity_instance.created_by.is_none());
+}
+
+#[test]
+fn entity_instance_audit_metadata_test() {
+    let mut entity_instance = EntityInstance::new(EntityTypeId::new_from_type(r_string(), r_string()), Uuid::new_v4(), HashMap::new());
+    let json = serde_json::to_value(&entity_instance).unwrap();
+    assert!(json.get("created_at").is_none());
+    assert!(json.get("updated_at").is_none());
+    assert!(json.get("created_by").is_none());
+
+    let created_at = Utc.with_ymd_and_hms(2023, 1, 1, 12, 0, 0).unwrap();
+    entity_instance.touch_at(created_at);
+    assert_eq!(Some(created_at), entity_instance.created_at);
+    assert_eq!(Some(created_at), entity_instance.updated_at);
+    entity_instance.touch();
+    assert_eq!(Some(created_at), entity_instance.created_at);
+    assert!(entity_instance.updated_at.unwrap() > created_at);
+
+    let created_by = r_string();
+    let entity_instance = entity_instance.with_created_by(&created_by);
+    assert_eq!(Some(created_by.clone()), entity_instance.created_by);
+    assert_eq!(Some(created_at), entity_instance.created_at);
+    let json = serde_json::to_value(&entity_instance).unwrap();
+    assert_eq!(json!("2023-01-01T12:00:00Z"), json["created_at"]);
+    assert_eq!(json!(created_by), json["created_by"]);
+    let deserialized = assert_json_roundtrip(&entity_instance);
+    assert_eq!(entity_instance.updated_at, deserialized.updated_at);
+    let deserialized = assert_dao_roundtrip::<EntityInstance, EntityInstanceDao>(&entity_instance);
+    assert_eq!(entity_instance.created_by, deserialized.created_by);
+}
+
+#[test]
+fn entity_instance_mark_deleted_test() {
+    let mut entity_instance = EntityInstance::new(EntityTypeId::new_from_type(r_string(), r_string()), Uuid::new_v4(), HashMap::new());
+    assert!(!entity_instance.is_deleted());
+    let deleted_at = Utc.with_ymd_and_hms(2023, 1, 1, 12, 0, 0).unwrap();
+    entity_instance.mark_deleted_at(deleted_at);
+    assert!(entity_instance.is_deleted());
+    assert_eq!(Some(deleted_at), entity_instance.deleted_at);
+    assert_eq!(Some(deleted_at), entity_instance.updated_at);
+    assert_eq!(json!("2023-01-01T12:00:00Z"), serde_json::to_value(&entity_instance).unwrap()["deleted_at"]);
+    let deserialized = assert_dao_roundtrip::<EntityInstance, EntityInstanceDao>(&entity_instance);
+    assert_eq!(entity_instance.deleted_at, deserialized.deleted_at);
+
+    entity_instance.restore();
+    assert!(!entity_instance.is_deleted());
+    assert!(entity_instance.updated_at.unwrap() > deleted_at);
+    assert!(serde_json::to_value(&entity_instance).unwrap().get("deleted_at").is_none());
+}
+
+#[test]
+fn entity_instance_revision_test() {
+    let property_name = r_string();
+    let mut properties = HashMap::new();
+    properties.insert(property_name.clone(), json!(1));
+    let mut entity_instance = EntityInstance::new(EntityTypeId::new_from_type(r_string(), r_string()), Uuid::new_v4(), properties);
+    assert_eq!(0, entity_instance.revision);
+    assert!(serde_json::to_value(&entity_instance).unwrap().get("revision").is_none());
+
+    entity_instance.set(property_name.clone(), json!(2));
+    assert_eq!(1, entity_instance.revision);
+    entity_instance.set(r_string(), json!(3));
+    assert_eq!(1, entity_instance.revision);
+
+    assert_eq!(Ok(2), entity_instance.check_and_set(1, property_name.clone(), json!(4)));
+    assert_eq!(
+        Err(RevisionError::Conflict { expected: 1, actual: 2 }),
+        entity_instance.check_and_set(1, property_name.clone(), json!(5))
+    );
+    assert_eq!(json!(4), entity_instance.get(property_name.clone()).unwrap());
+    let missing_property_name = r_string();
+    assert_eq!(
+        Err(RevisionError::MissingProperty(missing_property_name.clone())),
+        entity_instance.check_and_set(2, missing_property_name, json!(5))
+    );
+    assert_eq!(2, entity_instance.revision);
+
+    entity_instance.mark_deleted();
+    entity_instance.restore();
+    assert_eq!(4, entity_instance.revision);
+    assert_eq!(json!(4), serde_json::to_value(&entity_instance).unwrap()["revision"]);
+    let deserialized = assert_dao_roundtrip::<EntityInstance, EntityInstanceDao>(&entity_instance);
+    assert_eq!(4, deserialized.revision);
 }
@@ -1,6 +1,6 @@
 use indradb::Identifier;
 
-use crate::tests::utils::r_string;
+use crate::test_utils::r_string;
 use crate::EntityTypeId;
 use crate::NamespacedType;
 use crate::NamespacedTypeGetter;
@@ -119,3 +119,34 @@ fn entity_type_id_from_string_test() {
     let ty7 = EntityTypeId::try_from(&s7);
     assert!(ty7.is_err());
 }
+
+#[test]
+fn entity_type_id_cached_identifier_test() {
+    let ty = EntityTypeId::new_from_type(r_string(), r_string());
+    let identifier = Identifier::from(&ty.type_definition());
+    assert_eq!(identifier, ty.type_id());
+    // The cached identifier is shared with the clones
+    let cloned = ty.clone();
+    assert_eq!(identifier, cloned.type_id());
+    assert_eq!(ty, cloned);
+    assert_eq!(format!("{:?}", EntityTypeId::from(NamespacedType::from(&ty))), format!("{:?}", ty));
+}
+
+#[test]
+fn entity_type_id_ord_test() {
+    let mut types = vec![
+        EntityTypeId::new_from_type("logical", "or"),
+        EntityTypeId::new_from_type("arithmetic", "add"),
+        EntityTypeId::new_from_type("logical", "and"),
+    ];
+    types.sort();
+    assert_eq!(
+        vec![
+            EntityTypeId::new_from_type("arithmetic", "add"),
+            EntityTypeId::new_from_type("logical", "and"),
+            EntityTypeId::new_from_type("logical", "or"),
+        ],
+        types
+    );
+    assert!(EntityTypeId::new_from_type("a", "z") < EntityTypeId::new_from_type("b", "a"));
+}
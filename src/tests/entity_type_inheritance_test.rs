@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::test_utils::assert_dao_roundtrip;
+use crate::Component;
+use crate::ComponentTypeId;
+use crate::DataType;
+use crate::EntityInstance;
+use crate::EntityType;
+use crate::EntityTypeDao;
+use crate::EntityTypeId;
+use crate::PropertyError;
+use crate::PropertyType;
+use crate::TypeSystem;
+use crate::ValidationError;
+
+fn create_type_system() -> TypeSystem {
+    let labeled = Component::new_without_extensions(ComponentTypeId::new_from_type("base", "labeled"), "", vec![PropertyType::string("label")]);
+    let positioned = Component::new_without_extensions(ComponentTypeId::new_from_type("base", "positioned"), "", vec![PropertyType::number("x")]);
+    let item = EntityType::new(
+        EntityTypeId::new_from_type("game", "item"),
+        "",
+        vec![labeled.ty.clone()],
+        vec![PropertyType::number("weight"), PropertyType::string("rarity")],
+        Vec::new(),
+    );
+    let weapon = EntityType::new(
+        EntityTypeId::new_from_type("game", "weapon"),
+        "",
+        vec![positioned.ty.clone(), labeled.ty.clone()],
+        vec![PropertyType::number("damage"), PropertyType::number("rarity")],
+        Vec::new(),
+    )
+    .with_parent(item.ty.clone());
+    let sword = EntityType::new(EntityTypeId::new_from_type("game", "sword"), "", Vec::new(), vec![PropertyType::bool("sharp")], Vec::new())
+        .with_parent(weapon.ty.clone());
+    TypeSystem::new(vec![labeled, positioned], vec![item, weapon, sword], Vec::new(), Vec::new())
+}
+
+fn create_entity_type(type_name: &str) -> EntityType {
+    EntityType::new(EntityTypeId::new_from_type("test", type_name), "", Vec::new(), Vec::new(), Vec::new())
+}
+
+#[test]
+fn entity_type_ancestors_test() {
+    let type_system = create_type_system();
+    let ancestors = type_system.entity_type_ancestors(&EntityTypeId::new_from_type("game", "sword")).unwrap();
+    let ancestors: Vec<EntityTypeId> = ancestors.into_iter().map(|entity_type| entity_type.ty.clone()).collect();
+    assert_eq!(vec![EntityTypeId::new_from_type("game", "weapon"), EntityTypeId::new_from_type("game", "item")], ancestors);
+    assert!(type_system
+        .entity_type_ancestors(&EntityTypeId::new_from_type("game", "item"))
+        .unwrap()
+        .is_empty());
+    assert!(type_system.validate_entity_type_inheritance().is_ok());
+}
+
+#[test]
+fn resolve_effective_entity_type_test() {
+    let type_system = create_type_system();
+    let sword = type_system
+        .resolve_effective_entity_type(&EntityTypeId::new_from_type("game", "sword"))
+        .unwrap();
+    assert_eq!(
+        vec![
+            ComponentTypeId::new_from_type("base", "labeled"),
+            ComponentTypeId::new_from_type("base", "positioned")
+        ],
+        sword.components
+    );
+    let property_names: Vec<&str> = sword.properties.iter().map(|property_type| property_type.name.as_str()).collect();
+    assert_eq!(vec!["weight", "damage", "rarity", "sharp"], property_names);
+    assert_eq!(DataType::Number, sword.properties[2].data_type);
+    assert_eq!(Some(EntityTypeId::new_from_type("game", "weapon")), sword.extends);
+}
+
+#[test]
+fn entity_type_inheritance_cycle_test() {
+    let a = create_entity_type("a").with_parent(EntityTypeId::new_from_type("test", "b"));
+    let b = create_entity_type("b").with_parent(EntityTypeId::new_from_type("test", "c"));
+    let c = create_entity_type("c").with_parent(EntityTypeId::new_from_type("test", "b"));
+    let type_system = TypeSystem::new(Vec::new(), vec![a.clone(), b.clone(), c], Vec::new(), Vec::new());
+    assert_eq!(Err(ValidationError::InheritanceCycle(b.ty.clone())), type_system.entity_type_ancestors(&a.ty).map(|_| ()));
+    assert!(type_system.validate_entity_type_inheritance().is_err());
+
+    let orphan = create_entity_type("orphan").with_parent(a.ty.clone());
+    let type_system = TypeSystem::new(Vec::new(), vec![orphan.clone()], Vec::new(), Vec::new());
+    assert_eq!(
+        Err(ValidationError::UnknownEntityType(a.ty)),
+        type_system.resolve_effective_entity_type(&orphan.ty).map(|_| ())
+    );
+}
+
+#[test]
+fn validate_entity_instance_with_inherited_properties_test() {
+    let type_system = create_type_system();
+    let mut properties = HashMap::new();
+    properties.insert("sharp".to_string(), json!(true));
+    properties.insert("damage".to_string(), json!(10));
+    properties.insert("rarity".to_string(), json!(3));
+    properties.insert("label".to_string(), json!("Excalibur"));
+    properties.insert("x".to_string(), json!(0));
+    let entity_instance = EntityInstance::new(EntityTypeId::new_from_type("game", "sword"), Uuid::new_v4(), properties.clone());
+    assert_eq!(
+        Err(ValidationError::Property(PropertyError::MissingProperty("weight".to_string()))),
+        type_system.validate_entity_instance(&entity_instance)
+    );
+    properties.insert("weight".to_string(), json!(1.5));
+    let entity_instance = EntityInstance::new(EntityTypeId::new_from_type("game", "sword"), Uuid::new_v4(), properties);
+    assert_eq!(Ok(()), type_system.validate_entity_instance(&entity_instance));
+}
+
+#[test]
+fn entity_type_extends_dao_test() {
+    let entity_type = EntityType::new(EntityTypeId::new_from_type("game", "sword"), "", Vec::new(), Vec::new(), Vec::new());
+    assert!(serde_json::to_value(&entity_type).unwrap().get("extends").is_none());
+    let entity_type = entity_type.with_parent(EntityTypeId::new_from_type("game", "weapon"));
+    let deserialized = assert_dao_roundtrip::<EntityType, EntityTypeDao>(&entity_type);
+    assert_eq!(entity_type.extends, deserialized.extends);
+}
+
+#[test]
+fn final_entity_type_test() {
+    let item = create_entity_type("item").with_final();
+    let weapon = create_entity_type("weapon").with_parent(item.ty.clone());
+    let type_system = TypeSystem::new(Vec::new(), vec![item.clone(), weapon.clone()], Vec::new(), Vec::new());
+    assert_eq!(
+        Err(ValidationError::FinalEntityTypeExtended {
+            ty: weapon.ty.clone(),
+            parent: item.ty.clone(),
+        }),
+        type_system.validate_entity_type_inheritance()
+    );
+    // The instances of the entity types which extend a final entity type are invalid, too
+    let sword = create_entity_type("sword").with_parent(weapon.ty.clone());
+    let type_system = TypeSystem::new(Vec::new(), vec![item.clone(), weapon.clone(), sword.clone()], Vec::new(), Vec::new());
+    let entity_instance = EntityInstance::new(sword.ty.clone(), Uuid::new_v4(), HashMap::new());
+    assert_eq!(
+        Err(ValidationError::FinalEntityTypeExtended {
+            ty: weapon.ty.clone(),
+            parent: item.ty.clone(),
+        }),
+        type_system.validate_entity_instance(&entity_instance)
+    );
+    let type_system = TypeSystem::new(Vec::new(), vec![item.clone()], Vec::new(), Vec::new());
+    assert_eq!(Ok(()), type_system.validate_entity_type_inheritance());
+    let entity_instance = EntityInstance::new(item.ty.clone(), Uuid::new_v4(), HashMap::new());
+    assert_eq!(Ok(()), type_system.validate_entity_instance(&entity_instance));
+
+    assert_eq!(
+        ValidationError::FinalEntityTypeExtended {
+            ty: weapon.ty.clone(),
+            parent: item.ty.clone(),
+        },
+        weapon.inherit(&item).unwrap_err()
+    );
+
+    assert!(serde_json::to_value(create_entity_type("item")).unwrap().get("final").is_none());
+    assert_eq!(Some(&json!(true)), serde_json::to_value(&item).unwrap().get("final"));
+    let deserialized = assert_dao_roundtrip::<EntityType, EntityTypeDao>(&item);
+    assert!(deserialized.is_final);
+}
+
+#[test]
+fn final_entity_type_component_composition_test() {
+    let labeled = ComponentTypeId::new_from_type("base", "labeled");
+    let positioned = ComponentTypeId::new_from_type("base", "positioned");
+    let item = EntityType::new(EntityTypeId::new_from_type("game", "item"), "", vec![labeled.clone()], Vec::new(), Vec::new());
+    let coin = create_entity_type("coin").with_parent(item.ty.clone()).with_final();
+    let type_system = TypeSystem::new(Vec::new(), vec![item.clone(), coin.clone()], Vec::new(), Vec::new());
+    assert_eq!(Ok(()), type_system.validate_component_composition(&item.ty, &[labeled.clone(), positioned.clone()]));
+    // The inherited components belong to the final entity type
+    assert_eq!(Ok(()), type_system.validate_component_composition(&coin.ty, &[labeled.clone()]));
+    assert_eq!(
+        Err(ValidationError::FinalEntityTypeComposed {
+            ty: coin.ty.clone(),
+            component: positioned.clone(),
+        }),
+        type_system.validate_component_composition(&coin.ty, &[labeled, positioned])
+    );
+}
@@ -1,8 +1,9 @@
 use indradb::Identifier;
 use serde_json::json;
 
-use crate::tests::utils::r_string;
-use crate::tests::utils::r_string_1000;
+use crate::test_utils::assert_json_roundtrip;
+use crate::test_utils::r_string;
+use crate::test_utils::r_string_1000;
 use crate::ComponentTypeId;
 use crate::DataType;
 use crate::EntityType;
@@ -129,6 +130,7 @@ fn entity_type_de_test() {
   ]
 }"#;
     let entity_type: EntityType = serde_json::from_str(s).unwrap();
+    assert_json_roundtrip(&entity_type);
     assert_eq!("abc", entity_type.namespace());
     assert_eq!("def", entity_type.type_name());
     assert_eq!("e__abc__def", entity_type.ty.to_string());
@@ -0,0 +1,41 @@
+use crate::example_flow_instance;
+use crate::example_flow_type;
+use crate::fixture_type_system;
+use crate::labeled_component;
+use crate::logical_and_entity_type;
+use crate::ComponentContainer;
+use crate::EXAMPLE_FLOW_FIRST_AND_ID;
+use crate::EXAMPLE_FLOW_ID;
+
+#[test]
+fn fixture_types_test() {
+    let labeled_component = labeled_component();
+    assert!(labeled_component.has_property("label"));
+    let logical_and_entity_type = logical_and_entity_type();
+    assert!(logical_and_entity_type.is_a(&labeled_component.ty));
+    assert_eq!(3, logical_and_entity_type.properties.len());
+}
+
+#[test]
+fn fixture_instances_conform_to_fixture_types_test() {
+    let type_system = fixture_type_system();
+    let flow_instance = example_flow_instance();
+    assert!(flow_instance.validate().is_ok());
+    assert_eq!(EXAMPLE_FLOW_ID, flow_instance.id);
+    assert!(flow_instance.has_entity_instance(EXAMPLE_FLOW_FIRST_AND_ID));
+    for entity_instance in flow_instance.entity_instances.iter() {
+        assert!(type_system.validate_entity_instance(entity_instance).is_ok());
+    }
+    for relation_instance in flow_instance.relation_instances.iter() {
+        assert!(type_system.validate_relation_instance(relation_instance).is_ok());
+    }
+}
+
+#[test]
+fn fixtures_are_deterministic_test() {
+    assert_eq!(example_flow_instance().merkle_root().unwrap(), example_flow_instance().merkle_root().unwrap());
+    let flow_type = example_flow_type();
+    assert_eq!(EXAMPLE_FLOW_ID, flow_type.wrapper_entity_instance.id);
+    assert_eq!(2, flow_type.entity_instances.len());
+    assert_eq!(3, flow_type.inputs.len());
+}
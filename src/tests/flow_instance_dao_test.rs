@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::test_utils::assert_dao_roundtrip;
+use crate::test_utils::create_entity_instance;
+use crate::test_utils::create_entity_instance_from_type;
+use crate::test_utils::r_string;
+use crate::FlowInstance;
+use crate::FlowInstanceDao;
+use crate::NamespacedTypeGetter;
+use crate::RelationInstance;
+use crate::RelationInstanceTypeId;
+
+#[test]
+fn flow_instance_dao_conversion_test() {
+    let wrapper_entity_instance = create_entity_instance_from_type(r_string(), r_string());
+    let entity_instance = create_entity_instance(r_string());
+    let ty = RelationInstanceTypeId::new_from_type_unique_for_instance_id(r_string(), r_string(), r_string());
+    let relation_instance = RelationInstance::new(wrapper_entity_instance.id, ty.clone(), entity_instance.id, HashMap::new());
+    let mut flow_instance = FlowInstance::from_instance_with_name(wrapper_entity_instance.clone(), r_string());
+    flow_instance.description = r_string();
+    flow_instance.entity_instances.push(entity_instance.clone());
+    flow_instance.relation_instances.push(relation_instance.clone());
+
+    assert_dao_roundtrip::<FlowInstance, FlowInstanceDao>(&flow_instance);
+    let dao = FlowInstanceDao::from(flow_instance.clone());
+    assert_eq!(flow_instance.id, dao.id);
+    assert_eq!(flow_instance.namespace(), dao.namespace);
+    assert_eq!(flow_instance.type_name(), dao.type_name);
+    assert_eq!(flow_instance.name, dao.name);
+    assert_eq!(flow_instance.description, dao.description);
+    assert_eq!(2, dao.entity_instances.len());
+    assert_eq!(1, dao.relation_instances.len());
+    let relation_instance_dao = dao.relation_instances.first().unwrap();
+    assert_eq!(ty.relation_type_id().type_name(), relation_instance_dao.type_name);
+    assert_eq!(ty.instance_id(), relation_instance_dao.instance_id);
+
+    let converted = FlowInstance::from(dao);
+    assert_eq!(flow_instance.id, converted.id);
+    assert_eq!(flow_instance.ty, converted.ty);
+    assert_eq!(flow_instance.name, converted.name);
+    assert_eq!(entity_instance.properties, converted.entity_instances.get(1).unwrap().properties);
+    assert_eq!(relation_instance.ty, converted.relation_instances.first().unwrap().ty);
+    assert_eq!(relation_instance.get_key(), converted.relation_instances.first().unwrap().get_key());
+}
+
+#[test]
+fn flow_instance_dao_deserialize_with_aliases_and_defaults_test() {
+    let flow_id = Uuid::new_v4();
+    let entity_id = Uuid::new_v4();
+    let namespace = r_string();
+    let type_name = r_string();
+    let relation_type_name = r_string();
+    let dao: FlowInstanceDao = serde_json::from_value(json!({
+        "id": flow_id,
+        "namespace": namespace,
+        "type_name": type_name,
+        "flow_instance_name": "flow",
+        "entities": [
+            {
+                "namespace": namespace,
+                "name": type_name,
+                "id": flow_id
+            },
+            {
+                "namespace": namespace,
+                "type_name": type_name,
+                "id": entity_id,
+                "properties": {
+                    "value": 1
+                }
+            }
+        ],
+        "relations": [
+            {
+                "outbound": flow_id,
+                "namespace": namespace,
+                "type_name": relation_type_name,
+                "inbound": entity_id
+            }
+        ]
+    }))
+    .expect("Failed to deserialize flow instance dao");
+    assert_eq!("flow", dao.name);
+    assert_eq!(String::new(), dao.description);
+    assert_eq!(2, dao.entity_instances.len());
+    assert_eq!(type_name, dao.entity_instances.first().unwrap().type_name);
+    assert!(dao.entity_instances.first().unwrap().properties.is_empty());
+    assert_eq!(json!(1), *dao.entity_instances.get(1).unwrap().properties.get("value").unwrap());
+    let relation_instance_dao = dao.relation_instances.first().unwrap();
+    assert_eq!(flow_id, relation_instance_dao.outbound_id);
+    assert_eq!(entity_id, relation_instance_dao.inbound_id);
+    assert_eq!(String::new(), relation_instance_dao.instance_id);
+
+    let flow_instance = FlowInstance::from(dao);
+    assert_eq!(namespace, flow_instance.namespace());
+    assert_eq!(relation_type_name, flow_instance.relation_instances.first().unwrap().type_name());
+}
+
+#[test]
+fn flow_instance_dao_serialize_test() {
+    let flow_instance = FlowInstance::from(create_entity_instance_from_type(r_string(), r_string()));
+    let dao = FlowInstanceDao::from(flow_instance.clone());
+    let value = serde_json::to_value(&dao).expect("Failed to serialize flow instance dao");
+    assert_eq!(json!(flow_instance.id), value["id"]);
+    assert_eq!(json!(flow_instance.namespace()), value["namespace"]);
+    assert_eq!(json!(flow_instance.type_name()), value["type_name"]);
+    assert!(value["entity_instances"].is_array());
+    assert!(value["relation_instances"].is_array());
+    let deserialized: FlowInstanceDao = serde_json::from_value(value).unwrap();
+    assert_eq!(dao.id, deserialized.id);
+    assert_eq!(1, deserialized.entity_instances.len());
+}
+
+#[test]
+fn flow_instance_dao_preserves_unknown_fields_test() {
+    let flow_id = Uuid::new_v4();
+    let entity_id = Uuid::new_v4();
+    let json = json!({
+        "id": flow_id,
+        "namespace": "namespace",
+        "type_name": "type_name",
+        "future_field": { "a": [1, 2, 3] },
+        "entity_instances": [
+            {
+                "namespace": "namespace",
+                "type_name": "type_name",
+                "id": entity_id,
+                "future_entity_field": true,
+            }
+        ],
+        "relation_instances": [
+            {
+                "outbound_id": flow_id,
+                "type_name": "relation",
+                "inbound_id": entity_id,
+                "future_relation_field": "value",
+            }
+        ],
+    });
+    let dao: FlowInstanceDao = serde_json::from_value(json).unwrap();
+    assert_eq!(Some(&json!({ "a": [1, 2, 3] })), dao.extra.get("future_field"));
+    assert_eq!(1, dao.extra.len());
+    assert_eq!(Some(&json!(true)), dao.entity_instances.first().unwrap().extra.get("future_entity_field"));
+    assert_eq!(Some(&json!("value")), dao.relation_instances.first().unwrap().extra.get("future_relation_field"));
+
+    let serialized = serde_json::to_value(&dao).unwrap();
+    assert_eq!(json!({ "a": [1, 2, 3] }), serialized["future_field"]);
+    assert_eq!(json!(true), serialized["entity_instances"][0]["future_entity_field"]);
+    assert_eq!(json!("value"), serialized["relation_instances"][0]["future_relation_field"]);
+    assert!(serialized.get("extra").is_none());
+
+    // The unknown fields survive the conversion into the model and back
+    let round_tripped = serde_json::to_value(FlowInstanceDao::from(FlowInstance::from(dao))).unwrap();
+    assert_eq!(serialized, round_tripped);
+}
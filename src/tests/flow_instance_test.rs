@@ -1,10 +1,27 @@
+use std::collections::HashMap;
+
+use chrono::Duration;
+use chrono::Utc;
+use serde_json::json;
+use serde_json::Map;
 use uuid::Uuid;
 
-use crate::tests::utils::create_entity_instance_from_type;
-use crate::tests::utils::r_string;
+use crate::test_utils::create_entity_instance;
+use crate::test_utils::create_entity_instance_from_type;
+use crate::test_utils::create_entity_instance_with_property;
+use crate::test_utils::r_string;
 use crate::EntityTypeId;
 use crate::FlowInstance;
+use crate::FlowInstanceFlattenError;
+use crate::FlowInstanceMergeConflict;
+use crate::FlowInstanceMergeError;
+use crate::FlowInstanceValidationError;
+use crate::MergeStrategy;
 use crate::NamespacedTypeGetter;
+use crate::RelationInstance;
+use crate::RelationInstanceTypeId;
+use crate::SubFlowBinding;
+use crate::SubFlowReference;
 
 #[test]
 fn flow_instance_test() {
@@ -22,6 +39,9 @@ fn flow_instance_test() {
         description: flow_description.to_string(),
         entity_instances: Vec::new(),
         relation_instances: Vec::new(),
+        external_entity_ids: Vec::new(),
+        sub_flows: Vec::new(),
+        extra: Map::new(),
     };
 
     assert_eq!(namespace, flow_instance.namespace());
@@ -31,6 +51,8 @@ fn flow_instance_test() {
     assert_eq!(flow_description.clone(), flow_instance.description.clone());
     assert_eq!(0, flow_instance.entity_instances.len());
     assert_eq!(0, flow_instance.relation_instances.len());
+    assert_eq!(format!("{}__{}", ty, flow_id), format!("{}", flow_instance));
+    assert_eq!(format!("{}__{} (0 entity instances, 0 relation instances)", ty, flow_id), format!("{:#}", flow_instance));
 }
 
 #[test]
@@ -57,3 +79,513 @@ fn flow_instance_from_entity_instance_with_name_test() {
     assert_eq!(wrapper_entity_instance.id, flow_instance.id);
     assert_eq!(flow_name, flow_instance.name);
 }
+
+#[test]
+fn flow_instance_merge_without_conflicts_test() {
+    let mut flow_instance = FlowInstance::from(create_entity_instance_from_type(r_string(), r_string()));
+    let other_wrapper_entity_instance = create_entity_instance_from_type(r_string(), r_string());
+    let entity_instance = create_entity_instance(r_string());
+    let mut other = FlowInstance::from(other_wrapper_entity_instance.clone());
+    other.entity_instances.push(entity_instance.clone());
+    let ty = RelationInstanceTypeId::new_from_type_unique_id(r_string(), r_string());
+    let relation_instance = RelationInstance::new(other_wrapper_entity_instance.id, ty, entity_instance.id, HashMap::new());
+    other.relation_instances.push(relation_instance.clone());
+
+    let result = flow_instance
+        .merge(other, MergeStrategy::Abort)
+        .expect("Failed to merge flow instances without conflicts");
+    assert!(result.is_conflict_free());
+    assert_eq!(vec![other_wrapper_entity_instance.id, entity_instance.id], result.entity_instances_added);
+    assert_eq!(vec![relation_instance.get_key()], result.relation_instances_added);
+    assert_eq!(3, flow_instance.entity_instances.len());
+    assert_eq!(1, flow_instance.relation_instances.len());
+}
+
+#[test]
+fn flow_instance_merge_with_conflicts_test() {
+    let wrapper_entity_instance = create_entity_instance_from_type(r_string(), r_string());
+    let entity_instance = create_entity_instance(r_string());
+    let ty = RelationInstanceTypeId::new_from_type_unique_id(r_string(), r_string());
+    let relation_instance = RelationInstance::new(wrapper_entity_instance.id, ty, entity_instance.id, HashMap::new());
+    let mut flow_instance = FlowInstance::from(wrapper_entity_instance.clone());
+    flow_instance.entity_instances.push(entity_instance.clone());
+    flow_instance.relation_instances.push(relation_instance.clone());
+
+    let mut other = FlowInstance::from(create_entity_instance_from_type(r_string(), r_string()));
+    let mut conflicting_entity_instance = entity_instance.clone();
+    conflicting_entity_instance.description = r_string();
+    other.entity_instances.push(conflicting_entity_instance.clone());
+    let mut conflicting_relation_instance = relation_instance.clone();
+    conflicting_relation_instance.description = r_string();
+    other.relation_instances.push(conflicting_relation_instance.clone());
+
+    let expected_conflicts = vec![
+        FlowInstanceMergeConflict::EntityInstance(entity_instance.id),
+        FlowInstanceMergeConflict::RelationInstance(relation_instance.get_key()),
+    ];
+
+    // Abort: the flow instance remains unchanged
+    let mut aborted = flow_instance.clone();
+    match aborted.merge(other.clone(), MergeStrategy::Abort) {
+        Err(FlowInstanceMergeError::Conflicts(conflicts)) => assert_eq!(expected_conflicts, conflicts),
+        Ok(_) => panic!("The merge should have been aborted"),
+    }
+    assert_eq!(2, aborted.entity_instances.len());
+    assert_eq!(1, aborted.relation_instances.len());
+
+    // Skip: the existing instances are kept
+    let mut skipped = flow_instance.clone();
+    let result = skipped.merge(other.clone(), MergeStrategy::Skip).unwrap();
+    assert_eq!(expected_conflicts, result.conflicts);
+    assert_eq!(vec![other.id], result.entity_instances_added);
+    assert!(result.relation_instances_added.is_empty());
+    assert_eq!(3, skipped.entity_instances.len());
+    assert_eq!(entity_instance.description, skipped.entity_instances.get(1).unwrap().description);
+    assert_eq!(relation_instance.description, skipped.relation_instances.first().unwrap().description);
+
+    // Replace: the existing instances are replaced
+    let mut replaced = flow_instance.clone();
+    let result = replaced.merge(other.clone(), MergeStrategy::Replace).unwrap();
+    assert_eq!(expected_conflicts, result.conflicts);
+    assert_eq!(3, replaced.entity_instances.len());
+    assert_eq!(1, replaced.relation_instances.len());
+    assert_eq!(conflicting_entity_instance.description, replaced.entity_instances.get(1).unwrap().description);
+    assert_eq!(conflicting_relation_instance.description, replaced.relation_instances.first().unwrap().description);
+}
+
+#[test]
+fn flow_instance_deep_clone_with_new_ids_test() {
+    let wrapper_entity_instance = create_entity_instance_from_type(r_string(), r_string());
+    let entity_instance = create_entity_instance(r_string());
+    let external_id = Uuid::new_v4();
+    let ty = RelationInstanceTypeId::new_from_type_unique_id(r_string(), r_string());
+    let mut flow_instance = FlowInstance::from(wrapper_entity_instance.clone());
+    flow_instance.entity_instances.push(entity_instance.clone());
+    flow_instance
+        .relation_instances
+        .push(RelationInstance::new(wrapper_entity_instance.id, ty.clone(), entity_instance.id, HashMap::new()));
+    flow_instance
+        .relation_instances
+        .push(RelationInstance::new(entity_instance.id, ty, external_id, HashMap::new()));
+
+    let cloned = flow_instance.deep_clone_with_new_ids();
+    assert_ne!(flow_instance.id, cloned.id);
+    assert_eq!(flow_instance.ty, cloned.ty);
+    assert_eq!(flow_instance.name, cloned.name);
+    assert_eq!(2, cloned.entity_instances.len());
+    let cloned_wrapper_entity_instance = cloned.entity_instances.first().unwrap();
+    let cloned_entity_instance = cloned.entity_instances.get(1).unwrap();
+    assert_eq!(cloned.id, cloned_wrapper_entity_instance.id);
+    assert_ne!(entity_instance.id, cloned_entity_instance.id);
+    assert_eq!(entity_instance.properties, cloned_entity_instance.properties);
+
+    let first = cloned.relation_instances.first().unwrap();
+    assert_eq!(cloned_wrapper_entity_instance.id, first.outbound_id);
+    assert_eq!(cloned_entity_instance.id, first.inbound_id);
+    let second = cloned.relation_instances.get(1).unwrap();
+    assert_eq!(cloned_entity_instance.id, second.outbound_id);
+    assert_eq!(external_id, second.inbound_id);
+}
+
+#[test]
+fn flow_instance_structurally_equals_test() {
+    let wrapper_entity_instance = create_entity_instance_from_type(r_string(), r_string());
+    let ty = EntityTypeId::new_from_type(r_string(), r_string());
+    let property_name = r_string();
+    let first = create_entity_instance_with_property(ty.clone(), property_name.clone());
+    let mut second = first.clone();
+    second.id = Uuid::new_v4();
+    let third = create_entity_instance_with_property(ty, property_name);
+    let relation_ty = RelationInstanceTypeId::new_from_type_unique_id(r_string(), r_string());
+    let mut flow_instance = FlowInstance::from(wrapper_entity_instance.clone());
+    flow_instance.entity_instances.push(first.clone());
+    flow_instance.entity_instances.push(second.clone());
+    flow_instance.entity_instances.push(third.clone());
+    flow_instance
+        .relation_instances
+        .push(RelationInstance::new(first.id, relation_ty.clone(), second.id, HashMap::new()));
+    flow_instance
+        .relation_instances
+        .push(RelationInstance::new(second.id, relation_ty.clone(), third.id, HashMap::new()));
+
+    assert!(flow_instance.structurally_equals(&flow_instance));
+    let (mut cloned, ids) = flow_instance.deep_clone_with_new_ids_mapping();
+    cloned.name = r_string();
+    cloned.entity_instances.reverse();
+    cloned.relation_instances.reverse();
+    assert!(flow_instance.structurally_equals(&cloned));
+    let mapping = flow_instance.isomorphism(&cloned).unwrap();
+    assert_eq!(ids.get(&first.id), mapping.get(&first.id));
+    assert_eq!(ids.get(&second.id), mapping.get(&second.id));
+    assert_eq!(ids.get(&third.id), mapping.get(&third.id));
+    assert_eq!(Some(&cloned.id), mapping.get(&flow_instance.id));
+
+    // The first and the second entity instance have the same properties, but swapping them changes the connections
+    let mut swapped = flow_instance.clone();
+    swapped.relation_instances[1] = RelationInstance::new(first.id, relation_ty, third.id, HashMap::new());
+    assert!(!flow_instance.structurally_equals(&swapped));
+
+    let mut changed = cloned.clone();
+    changed.entity_instances[0].properties.insert(r_string(), json!(r_string()));
+    assert!(!flow_instance.structurally_equals(&changed));
+
+    let mut reversed = flow_instance.clone();
+    for relation_instance in reversed.relation_instances.iter_mut() {
+        std::mem::swap(&mut relation_instance.outbound_id, &mut relation_instance.inbound_id);
+    }
+    assert!(!flow_instance.structurally_equals(&reversed));
+
+    // The metadata doesn't belong to the structure
+    let mut touched = cloned.clone();
+    touched.entity_instances[0].touch();
+    touched.entity_instances[1].created_by = Some(r_string());
+    touched.relation_instances[0].touch();
+    assert!(flow_instance.structurally_equals(&touched));
+}
+
+#[test]
+fn flow_instance_to_dot_test() {
+    let wrapper_entity_instance = create_entity_instance_from_type(r_string(), r_string());
+    let entity_instance = create_entity_instance(r_string());
+    let ty = RelationInstanceTypeId::new_from_type_unique_for_instance_id(r_string(), r_string(), r_string());
+    let relation_instance = RelationInstance::new(wrapper_entity_instance.id, ty, entity_instance.id, HashMap::new());
+    let mut flow_instance = FlowInstance::from_instance_with_name(wrapper_entity_instance.clone(), "flow \"name\"");
+    flow_instance.entity_instances.push(entity_instance.clone());
+    flow_instance.relation_instances.push(relation_instance.clone());
+
+    let dot = flow_instance.to_dot();
+    assert!(dot.starts_with("digraph \"flow \\\"name\\\"\" {\n"));
+    assert!(dot.contains(&format!("  \"{}\" [label=\"{}\", peripheries=2];\n", wrapper_entity_instance.id, wrapper_entity_instance.ty)));
+    assert!(dot.contains(&format!("  \"{}\" [label=\"{}\"];\n", entity_instance.id, entity_instance.ty)));
+    assert!(dot.contains(&format!(
+        "  \"{}\" -> \"{}\" [label=\"{}\"];\n",
+        wrapper_entity_instance.id,
+        entity_instance.id,
+        relation_instance.relation_type_id()
+    )));
+    assert!(dot.ends_with("}\n"));
+}
+
+#[test]
+fn flow_instance_external_entity_instances_test() {
+    let wrapper_entity_instance = create_entity_instance_from_type(r_string(), r_string());
+    let external_id = Uuid::new_v4();
+    let ty = RelationInstanceTypeId::new_from_type_unique_id(r_string(), r_string());
+    let relation_instance = RelationInstance::new(wrapper_entity_instance.id, ty, external_id, HashMap::new());
+    let mut flow_instance = FlowInstance::from(wrapper_entity_instance.clone());
+    assert!(flow_instance.validate().is_ok());
+    flow_instance.relation_instances.push(relation_instance.clone());
+
+    assert!(flow_instance.has_entity_instance(wrapper_entity_instance.id));
+    assert!(!flow_instance.has_entity_instance(external_id));
+    assert!(flow_instance.external_relation_instances().is_empty());
+    assert_eq!(1, flow_instance.dangling_relation_instances().len());
+    assert_eq!(
+        Err(FlowInstanceValidationError::DanglingRelationInstance(relation_instance.get_key(), external_id)),
+        flow_instance.validate()
+    );
+
+    flow_instance.add_external_entity_id(external_id);
+    flow_instance.add_external_entity_id(external_id);
+    assert_eq!(1, flow_instance.external_entity_ids.len());
+    assert!(flow_instance.is_external_entity_id(external_id));
+    assert_eq!(1, flow_instance.external_relation_instances().len());
+    assert!(flow_instance.dangling_relation_instances().is_empty());
+    assert!(flow_instance.validate().is_ok());
+
+    flow_instance.remove_external_entity_id(external_id);
+    assert!(!flow_instance.is_external_entity_id(external_id));
+    assert!(flow_instance.validate().is_err());
+
+    flow_instance.entity_instances.clear();
+    assert_eq!(Err(FlowInstanceValidationError::MissingWrapperEntityInstance), flow_instance.validate());
+}
+
+#[test]
+fn flow_instance_deleted_instances_test() {
+    let wrapper_entity_instance = create_entity_instance_from_type(r_string(), r_string());
+    let entity_instance = create_entity_instance_from_type(r_string(), r_string());
+    let other_entity_instance = create_entity_instance_from_type(r_string(), r_string());
+    let ty = RelationInstanceTypeId::new_from_type_unique_id(r_string(), r_string());
+    let mut flow_instance = FlowInstance::from(wrapper_entity_instance.clone());
+    flow_instance.entity_instances.push(entity_instance.clone());
+    flow_instance.entity_instances.push(other_entity_instance.clone());
+    flow_instance
+        .relation_instances
+        .push(RelationInstance::new(wrapper_entity_instance.id, ty.clone(), entity_instance.id, HashMap::new()));
+    flow_instance
+        .relation_instances
+        .push(RelationInstance::new(wrapper_entity_instance.id, ty.clone(), other_entity_instance.id, HashMap::new()));
+
+    assert!(!flow_instance.mark_entity_instance_deleted(Uuid::new_v4()));
+    assert!(!flow_instance.mark_entity_instance_deleted(wrapper_entity_instance.id));
+    assert!(flow_instance.deleted_entity_instances().is_empty());
+    assert!(flow_instance.deleted_relation_instances().is_empty());
+    let undeleted = flow_instance.clone();
+    assert!(flow_instance.mark_entity_instance_deleted(entity_instance.id));
+    let deleted_at = flow_instance.deleted_entity_instances().first().unwrap().deleted_at;
+    // A second deletion is a no-op
+    assert!(!flow_instance.mark_entity_instance_deleted(entity_instance.id));
+    assert_eq!(deleted_at, flow_instance.deleted_entity_instances().first().unwrap().deleted_at);
+    // The deletion markers belong to the structure
+    assert!(!flow_instance.structurally_equals(&undeleted));
+    assert_eq!(1, flow_instance.deleted_entity_instances().len());
+    assert_eq!(entity_instance.id, flow_instance.deleted_entity_instances().first().unwrap().id);
+    assert_eq!(1, flow_instance.deleted_relation_instances().len());
+    // The tombstones are kept
+    assert_eq!(3, flow_instance.entity_instances.len());
+    assert_eq!(2, flow_instance.relation_instances.len());
+
+    let without_deleted = flow_instance.without_deleted();
+    assert_eq!(2, without_deleted.entity_instances.len());
+    assert_eq!(1, without_deleted.relation_instances.len());
+    assert!(without_deleted.validate().is_ok());
+
+    assert!(flow_instance.restore_entity_instance(entity_instance.id));
+    assert!(!flow_instance.restore_entity_instance(entity_instance.id));
+    assert!(flow_instance.deleted_entity_instances().is_empty());
+    assert!(flow_instance.deleted_relation_instances().is_empty());
+    assert!(flow_instance.structurally_equals(&undeleted));
+
+    // Relation instances which have been deleted before are not restored
+    flow_instance.relation_instances[0].mark_deleted_at(Utc::now() - Duration::minutes(1));
+    assert!(flow_instance.mark_entity_instance_deleted(entity_instance.id));
+    assert!(flow_instance.restore_entity_instance(entity_instance.id));
+    assert_eq!(1, flow_instance.deleted_relation_instances().len());
+
+    flow_instance.purge_deleted_before(Some(Utc::now() - Duration::hours(1)));
+    assert_eq!(2, flow_instance.relation_instances.len());
+    flow_instance.purge_deleted();
+    assert_eq!(3, flow_instance.entity_instances.len());
+    assert_eq!(1, flow_instance.relation_instances.len());
+    assert_eq!(other_entity_instance.id, flow_instance.relation_instances[0].inbound_id);
+}
+
+#[test]
+fn flow_instance_restore_entity_instance_test() {
+    let wrapper_entity_instance = create_entity_instance_from_type(r_string(), r_string());
+    let entity_instance = create_entity_instance_from_type(r_string(), r_string());
+    let other_entity_instance = create_entity_instance_from_type(r_string(), r_string());
+    let ty = RelationInstanceTypeId::new_from_type_unique_id(r_string(), r_string());
+    let mut flow_instance = FlowInstance::from(wrapper_entity_instance);
+    flow_instance.entity_instances.push(entity_instance.clone());
+    flow_instance.entity_instances.push(other_entity_instance.clone());
+    flow_instance
+        .relation_instances
+        .push(RelationInstance::new(entity_instance.id, ty, other_entity_instance.id, HashMap::new()));
+
+    assert!(flow_instance.mark_entity_instance_deleted(entity_instance.id));
+    assert!(flow_instance.mark_entity_instance_deleted(other_entity_instance.id));
+    // The other entity instance is still deleted
+    assert!(flow_instance.restore_entity_instance(entity_instance.id));
+    assert_eq!(1, flow_instance.deleted_relation_instances().len());
+    assert!(flow_instance.without_deleted().validate().is_ok());
+    // The relation instance is restored together with the other entity instance
+    assert!(flow_instance.restore_entity_instance(other_entity_instance.id));
+    assert!(flow_instance.deleted_relation_instances().is_empty());
+}
+
+#[test]
+fn flow_instance_entity_by_name_test() {
+    let wrapper_entity_instance = create_entity_instance_from_type(r_string(), r_string());
+    let mut named_entity_instance = create_entity_instance_from_type(r_string(), r_string());
+    named_entity_instance.properties.insert("name".to_string(), json!("counter"));
+    let mut labeled_entity_instance = create_entity_instance_from_type(r_string(), r_string());
+    labeled_entity_instance.properties.insert("label".to_string(), json!("/org/inexor/counter"));
+    let mut duplicate_entity_instance = create_entity_instance_from_type(r_string(), r_string());
+    duplicate_entity_instance.properties.insert("name".to_string(), json!("counter"));
+    duplicate_entity_instance.properties.insert("label".to_string(), json!("duplicate"));
+    let mut flow_instance = FlowInstance::from(wrapper_entity_instance.clone());
+    flow_instance.entity_instances.push(named_entity_instance.clone());
+    flow_instance.entity_instances.push(labeled_entity_instance.clone());
+    flow_instance.entity_instances.push(duplicate_entity_instance.clone());
+
+    assert_eq!(named_entity_instance.id, flow_instance.entity_by_name("counter").unwrap().id);
+    assert_eq!(labeled_entity_instance.id, flow_instance.entity_by_name("/org/inexor/counter").unwrap().id);
+    assert!(flow_instance.entity_by_name("duplicate").is_none());
+    assert!(flow_instance.entity_by_name(r_string()).is_none());
+
+    let index = flow_instance.entity_name_index();
+    assert_eq!(2, index.len());
+    assert_eq!(Some(&named_entity_instance.id), index.get("counter"));
+    assert_eq!(Some(&labeled_entity_instance.id), index.get("/org/inexor/counter"));
+}
+
+#[test]
+fn flow_instance_flatten_sub_flows_test() {
+    let outer_wrapper = create_entity_instance("value");
+    let inner_wrapper = create_entity_instance("value");
+    let inner_entity_instance = create_entity_instance("value");
+    let innermost_wrapper = create_entity_instance("value");
+
+    let ty = RelationInstanceTypeId::new_from_type_unique_id(r_string(), r_string());
+    let mut innermost = FlowInstance::from(innermost_wrapper.clone());
+    innermost.add_external_entity_id(outer_wrapper.id);
+    let mut inner = FlowInstance::from(inner_wrapper.clone());
+    inner.entity_instances.push(inner_entity_instance.clone());
+    inner
+        .relation_instances
+        .push(RelationInstance::new(inner_wrapper.id, ty, inner_entity_instance.id, HashMap::new()));
+    inner.add_sub_flow(SubFlowReference::new(innermost.id, Vec::new()));
+    let mut outer = FlowInstance::from(outer_wrapper.clone());
+    outer.add_sub_flow(SubFlowReference::new(
+        inner.id,
+        vec![
+            SubFlowBinding::input(outer_wrapper.id, "value", inner_wrapper.id, "value"),
+            SubFlowBinding::output(inner_entity_instance.id, "value", outer_wrapper.id, "result"),
+        ],
+    ));
+
+    let flows: HashMap<Uuid, FlowInstance> = vec![(inner.id, inner.clone()), (innermost.id, innermost.clone())].into_iter().collect();
+    let flattened = outer.flatten(|id| flows.get(&id).cloned()).expect("Failed to flatten flow instance");
+    assert_eq!(outer.id, flattened.id);
+    assert!(flattened.sub_flows.is_empty());
+    assert_eq!(4, flattened.entity_instances.len());
+    assert!(flattened.has_entity_instance(innermost_wrapper.id));
+    assert_eq!(3, flattened.relation_instances.len());
+    assert!(flattened
+        .relation_instances
+        .iter()
+        .any(|r| r.outbound_id == outer_wrapper.id && r.inbound_id == inner_wrapper.id && r.instance_id() == "value__value"));
+    assert!(flattened
+        .relation_instances
+        .iter()
+        .any(|r| r.outbound_id == inner_entity_instance.id && r.inbound_id == outer_wrapper.id && r.instance_id() == "value__result"));
+    assert!(flattened.external_entity_ids.is_empty());
+    assert!(flattened.validate().is_ok());
+
+    outer.remove_sub_flow(inner.id);
+    assert!(outer.sub_flows.is_empty());
+}
+
+#[test]
+fn flow_instance_flatten_sub_flows_errors_test() {
+    let mut outer = FlowInstance::from(create_entity_instance_from_type(r_string(), r_string()));
+    let mut inner = FlowInstance::from(create_entity_instance_from_type(r_string(), r_string()));
+    let missing_id = Uuid::new_v4();
+    inner.add_sub_flow(SubFlowReference::new(missing_id, Vec::new()));
+    outer.add_sub_flow(SubFlowReference::new(inner.id, Vec::new()));
+    let inner_clone = inner.clone();
+    assert_eq!(
+        Err(FlowInstanceFlattenError::MissingSubFlow(missing_id)),
+        outer
+            .flatten(|id| if id == inner_clone.id { Some(inner_clone.clone()) } else { None })
+            .map(|f| f.id)
+    );
+
+    inner.sub_flows.clear();
+    inner.add_sub_flow(SubFlowReference::new(outer.id, Vec::new()));
+    let inner_clone = inner.clone();
+    assert_eq!(
+        Err(FlowInstanceFlattenError::CyclicSubFlow(outer.id)),
+        outer
+            .flatten(|id| if id == inner_clone.id { Some(inner_clone.clone()) } else { None })
+            .map(|f| f.id)
+    );
+}
+
+#[test]
+fn flow_instance_stats_test() {
+    let wrapper_entity_instance = create_entity_instance("value");
+    let ty = wrapper_entity_instance.ty.clone();
+    let entity_instance_1 = create_entity_instance_with_property(ty.clone(), "value");
+    let entity_instance_2 = create_entity_instance("value");
+    let entity_instance_3 = create_entity_instance_from_type(r_string(), r_string());
+    let r_ty_1 = RelationInstanceTypeId::new_from_type_unique_id(r_string(), r_string());
+    let r_ty_2 = RelationInstanceTypeId::new_from_type_unique_id(r_string(), r_string());
+    let mut flow_instance = FlowInstance::from(wrapper_entity_instance.clone());
+    flow_instance.entity_instances.push(entity_instance_1.clone());
+    flow_instance.entity_instances.push(entity_instance_2.clone());
+    flow_instance.entity_instances.push(entity_instance_3.clone());
+    let mut relation_properties = HashMap::new();
+    relation_properties.insert(r_string(), json!(1));
+    flow_instance.relation_instances = vec![
+        RelationInstance::new(wrapper_entity_instance.id, r_ty_1.clone(), entity_instance_1.id, relation_properties),
+        RelationInstance::new(wrapper_entity_instance.id, r_ty_1.clone(), entity_instance_2.id, HashMap::new()),
+        RelationInstance::new(wrapper_entity_instance.id, r_ty_2.clone(), entity_instance_3.id, HashMap::new()),
+        RelationInstance::new(entity_instance_1.id, r_ty_1.clone(), entity_instance_2.id, HashMap::new()),
+        RelationInstance::new(entity_instance_2.id, r_ty_1.clone(), entity_instance_3.id, HashMap::new()),
+        // cycle
+        RelationInstance::new(entity_instance_3.id, r_ty_2.clone(), entity_instance_2.id, HashMap::new()),
+    ];
+
+    let stats = flow_instance.stats();
+    assert_eq!(4, stats.entity_instance_count);
+    assert_eq!(6, stats.relation_instance_count);
+    assert_eq!(Some(&2), stats.entity_instances_per_type.get(&ty));
+    assert_eq!(Some(&1), stats.entity_instances_per_type.get(&entity_instance_3.ty));
+    assert_eq!(Some(&4), stats.relation_instances_per_type.get(&r_ty_1.relation_type_id()));
+    assert_eq!(Some(&2), stats.relation_instances_per_type.get(&r_ty_2.relation_type_id()));
+    assert_eq!(4, stats.property_count);
+    assert_eq!(3, stats.max_fan_out);
+    // The cycle of entity_instance_2 and entity_instance_3 counts as a single entity instance
+    assert_eq!(2, stats.depth);
+
+    flow_instance.relation_instances.pop();
+    assert_eq!(3, flow_instance.stats().depth);
+
+    let empty = FlowInstance::from(create_entity_instance_from_type(r_string(), r_string())).stats();
+    assert_eq!(1, empty.entity_instance_count);
+    assert_eq!(0, empty.depth);
+    assert_eq!(0, empty.max_fan_out);
+}
+
+#[test]
+fn flow_instance_merkle_tree_test() {
+    let wrapper_entity_instance = create_entity_instance(r_string());
+    let entity_instance = create_entity_instance(r_string());
+    let ty = RelationInstanceTypeId::new_from_type_unique_for_instance_id(r_string(), r_string(), r_string());
+    let relation_instance = RelationInstance::new(wrapper_entity_instance.id, ty, entity_instance.id, HashMap::new());
+    let mut flow_instance = FlowInstance::from_instance_with_name(wrapper_entity_instance.clone(), r_string());
+    flow_instance.entity_instances.push(entity_instance.clone());
+    flow_instance.relation_instances.push(relation_instance.clone());
+
+    let mut reordered_flow_instance = flow_instance.clone();
+    reordered_flow_instance.entity_instances.reverse();
+    let tree = flow_instance.merkle_tree().unwrap();
+    assert_eq!(tree, reordered_flow_instance.merkle_tree().unwrap());
+    assert_eq!(tree.root, flow_instance.merkle_root().unwrap());
+    assert_eq!(2, tree.entity_instances.len());
+    assert_eq!(1, tree.relation_instances.len());
+    assert!(tree.diff(&reordered_flow_instance.merkle_tree().unwrap()).is_empty());
+
+    let mut changed_flow_instance = flow_instance.clone();
+    changed_flow_instance
+        .entity_instances
+        .get_mut(1)
+        .unwrap()
+        .properties
+        .insert(r_string(), json!(42));
+    let added_entity_instance = create_entity_instance(r_string());
+    changed_flow_instance.entity_instances.push(added_entity_instance.clone());
+    let changed_tree = changed_flow_instance.merkle_tree().unwrap();
+    assert_ne!(tree.root, changed_tree.root);
+    assert_eq!(tree.header, changed_tree.header);
+    assert_eq!(tree.relation_instances_hash, changed_tree.relation_instances_hash);
+    let diff = tree.diff(&changed_tree);
+    assert!(!diff.header_changed);
+    assert_eq!(vec![entity_instance.id, added_entity_instance.id], diff.entity_instances);
+    assert!(diff.relation_instances.is_empty());
+
+    let mut renamed_flow_instance = flow_instance.clone();
+    renamed_flow_instance.name = r_string();
+    renamed_flow_instance.relation_instances.clear();
+    let diff = tree.diff(&renamed_flow_instance.merkle_tree().unwrap());
+    assert!(diff.header_changed);
+    assert!(diff.entity_instances.is_empty());
+    assert_eq!(vec![relation_instance.get_key()], diff.relation_instances);
+}
+
+#[test]
+fn flow_instance_default_test() {
+    let flow_instance = FlowInstance {
+        name: "flow".to_string(),
+        ..Default::default()
+    };
+    assert_eq!("flow", flow_instance.name);
+    assert_eq!(EntityTypeId::default(), flow_instance.ty);
+    assert_eq!(1, flow_instance.entity_instances.len());
+    assert_eq!(flow_instance.id, flow_instance.entity_instances[0].id);
+    assert_eq!(Ok(()), flow_instance.validate());
+    assert_ne!(FlowInstance::default().id, FlowInstance::default().id);
+}
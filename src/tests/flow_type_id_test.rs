@@ -1,6 +1,6 @@
 use indradb::Identifier;
 
-use crate::tests::utils::r_string;
+use crate::test_utils::r_string;
 use crate::FlowTypeId;
 use crate::NamespacedType;
 use crate::NamespacedTypeGetter;
@@ -0,0 +1,172 @@
+use std::collections::HashMap;
+
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::flow_type_local_id;
+use crate::sub_flow_type_entity_id;
+use crate::test_utils::assert_json_roundtrip;
+use crate::type_parameter_placeholder;
+use crate::ComponentOrEntityTypeId;
+use crate::ComponentTypeId;
+use crate::EntityInstance;
+use crate::EntityType;
+use crate::EntityTypeId;
+use crate::FlowType;
+use crate::FlowTypeDao;
+use crate::FlowTypeId;
+use crate::FlowTypeParameter;
+use crate::SubFlowTypeReference;
+use crate::TypeSystem;
+use crate::ValidationError;
+
+fn create_generic_flow_type() -> FlowType {
+    let wrapper = EntityInstance::new_without_properties(EntityTypeId::new_from_type("flow", "generic_flow"), Uuid::new_v4());
+    let element = EntityInstance::new_without_properties(type_parameter_placeholder("T"), flow_type_local_id("element"));
+    let counter = EntityInstance::new_without_properties(EntityTypeId::new_from_type("arithmetic", "counter"), flow_type_local_id("counter"));
+    FlowType::new(
+        FlowTypeId::new_from_type("generic", "count_elements"),
+        "",
+        wrapper,
+        vec![element, counter],
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+    )
+    .with_type_parameter(
+        FlowTypeParameter::new("T")
+            .with_description("The element entity type")
+            .with_constraint(ComponentTypeId::new_from_type("base", "labeled")),
+    )
+}
+
+fn arguments(type_name: &str) -> HashMap<String, EntityTypeId> {
+    HashMap::from([("T".to_string(), EntityTypeId::new_from_type("logical", type_name))])
+}
+
+#[test]
+fn substitute_type_parameters_test() {
+    let flow_type = create_generic_flow_type();
+    assert!(flow_type.is_generic());
+    assert_eq!(type_parameter_placeholder("T"), flow_type.get_type_parameter("T").unwrap().placeholder());
+
+    assert_eq!(
+        vec![
+            EntityTypeId::new_from_type("arithmetic", "counter"),
+            EntityTypeId::new_from_type("flow", "generic_flow")
+        ],
+        flow_type.uses_entity_types()
+    );
+
+    let substituted = flow_type.substitute_type_parameters(&arguments("and")).unwrap();
+    assert!(!substituted.is_generic());
+    assert!(substituted.uses_entity_types().contains(&EntityTypeId::new_from_type("logical", "and")));
+    let element = substituted.entity_instances.iter().find(|e| e.id == flow_type_local_id("element")).unwrap();
+    assert_eq!(EntityTypeId::new_from_type("logical", "and"), element.ty);
+    let counter = substituted.entity_instances.iter().find(|e| e.id == flow_type_local_id("counter")).unwrap();
+    assert_eq!(EntityTypeId::new_from_type("arithmetic", "counter"), counter.ty);
+
+    let instantiation = flow_type.instantiate_with_type_arguments(&arguments("or")).unwrap();
+    let element_id = instantiation.resolve_local_id("element").unwrap();
+    let element = instantiation.flow_instance.entity_instances.iter().find(|e| e.id == element_id).unwrap();
+    assert_eq!(EntityTypeId::new_from_type("logical", "or"), element.ty);
+}
+
+#[test]
+fn flatten_generic_flow_type_test() {
+    let wrapper = EntityInstance::new_without_properties(EntityTypeId::new_from_type("flow", "generic_flow"), Uuid::new_v4());
+    let outer_flow_type = FlowType::new(FlowTypeId::new_from_type("generic", "outer"), "", wrapper, Vec::new(), Vec::new(), Vec::new(), Vec::new())
+        .with_sub_flow(SubFlowTypeReference::new("inner", FlowTypeId::new_from_type("generic", "count_elements"), Vec::new()));
+
+    let flattened = outer_flow_type.flatten(|_| Some(create_generic_flow_type())).unwrap();
+    assert!(flattened.is_generic());
+    assert_eq!(create_generic_flow_type().get_type_parameter("T"), flattened.get_type_parameter("T"));
+    let element_id = sub_flow_type_entity_id("inner", flow_type_local_id("element"));
+    let substituted = flattened.substitute_type_parameters(&arguments("and")).unwrap();
+    let element = substituted.entity_instances.iter().find(|e| e.id == element_id).unwrap();
+    assert_eq!(EntityTypeId::new_from_type("logical", "and"), element.ty);
+
+    // The type parameters of the including flow type take precedence
+    let outer_flow_type = outer_flow_type.with_type_parameter(FlowTypeParameter::new("T").with_description("The outer element entity type"));
+    let flattened = outer_flow_type.flatten(|_| Some(create_generic_flow_type())).unwrap();
+    assert_eq!(1, flattened.type_parameters.len());
+    assert_eq!("The outer element entity type", flattened.get_type_parameter("T").unwrap().description);
+}
+
+#[test]
+fn type_arguments_test() {
+    let flow_type = create_generic_flow_type();
+    assert_eq!(
+        Err(ValidationError::MissingTypeArgument {
+            ty: flow_type.ty.clone(),
+            parameter: "T".to_string(),
+        }),
+        flow_type.substitute_type_parameters(&HashMap::new()).map(|_| ())
+    );
+    let unknown_arguments = HashMap::from([("U".to_string(), EntityTypeId::new_from_type("logical", "and"))]);
+    assert_eq!(
+        Err(ValidationError::UnknownTypeParameter {
+            ty: flow_type.ty.clone(),
+            parameter: "U".to_string(),
+        }),
+        flow_type.resolve_type_arguments(&unknown_arguments)
+    );
+
+    let flow_type = FlowType {
+        type_parameters: vec![FlowTypeParameter::new("T").with_default(EntityTypeId::new_from_type("logical", "and"))],
+        ..flow_type
+    };
+    assert_eq!(Ok(arguments("and")), flow_type.resolve_type_arguments(&HashMap::new()));
+    assert_eq!(Ok(arguments("or")), flow_type.resolve_type_arguments(&arguments("or")));
+
+    let flow_type = FlowType {
+        type_parameters: Vec::new(),
+        ..flow_type
+    };
+    assert_eq!(
+        Err(ValidationError::UnknownTypeParameter {
+            ty: flow_type.ty.clone(),
+            parameter: "T".to_string(),
+        }),
+        flow_type.substitute_type_parameters(&HashMap::new()).map(|_| ())
+    );
+}
+
+#[test]
+fn type_parameter_constraint_test() {
+    let labeled = EntityType::new(
+        EntityTypeId::new_from_type("logical", "and"),
+        "",
+        vec![ComponentTypeId::new_from_type("base", "labeled")],
+        Vec::new(),
+        Vec::new(),
+    );
+    let unlabeled = EntityType::new(EntityTypeId::new_from_type("logical", "or"), "", Vec::new(), Vec::new(), Vec::new());
+    let type_system = TypeSystem::new(Vec::new(), vec![labeled, unlabeled], Vec::new(), Vec::new());
+    let flow_type = create_generic_flow_type();
+
+    assert!(type_system.substitute_flow_type_parameters(&flow_type, &arguments("and")).is_ok());
+    assert_eq!(
+        Err(ValidationError::UnsatisfiedTypeParameterConstraint {
+            ty: flow_type.ty.clone(),
+            parameter: "T".to_string(),
+            constraint: ComponentOrEntityTypeId::Component(ComponentTypeId::new_from_type("base", "labeled")),
+            argument: EntityTypeId::new_from_type("logical", "or"),
+        }),
+        type_system.substitute_flow_type_parameters(&flow_type, &arguments("or")).map(|_| ())
+    );
+    assert_eq!(
+        Err(ValidationError::UnknownEntityType(EntityTypeId::new_from_type("logical", "xor"))),
+        type_system.substitute_flow_type_parameters(&flow_type, &arguments("xor")).map(|_| ())
+    );
+}
+
+#[test]
+fn type_parameter_serde_test() {
+    let flow_type = create_generic_flow_type();
+    let value = serde_json::to_value(FlowTypeDao::from(flow_type.clone())).unwrap();
+    assert_eq!(json!("T"), value["type_parameters"][0]["name"]);
+    assert_eq!(json!("type_parameter"), value["entity_instances"][0]["namespace"]);
+    let deserialized = FlowType::from(assert_json_roundtrip(&FlowTypeDao::from(flow_type.clone())));
+    assert_eq!(flow_type.type_parameters, deserialized.type_parameters);
+}
@@ -2,13 +2,15 @@ use serde_json::json;
 use std::collections::HashMap;
 use uuid::Uuid;
 
-use crate::tests::utils::create_entity_instance;
-use crate::tests::utils::r_string;
+use crate::flow_type_local_id;
+use crate::test_utils::create_entity_instance;
+use crate::test_utils::r_string;
 use crate::DataType;
 use crate::Extension;
 use crate::ExtensionTypeId;
 use crate::FlowType;
 use crate::FlowTypeId;
+use crate::FlowTypePort;
 use crate::NamespacedTypeGetter;
 use crate::PropertyType;
 use crate::RelationInstance;
@@ -156,3 +158,170 @@ fn create_flow_type_test() {
     assert_eq!(2, flow_type.extensions.len());
     assert!(!flow_type.has_extension(&extension_3_ty));
 }
+
+#[test]
+fn flow_type_to_dot_test() {
+    let wrapper_entity_instance = create_entity_instance("property");
+    let entity_instance = create_entity_instance("property2");
+    let r_ty = RelationInstanceTypeId::new_from_type_unique_id(r_string(), r_string());
+    let relation_instance = RelationInstance::new(wrapper_entity_instance.id, r_ty, entity_instance.id, HashMap::new());
+    let ty = FlowTypeId::new_from_type(r_string(), r_string());
+    let flow_type = FlowType::new(
+        ty.clone(),
+        r_string(),
+        wrapper_entity_instance.clone(),
+        vec![entity_instance.clone()],
+        vec![relation_instance.clone()],
+        Vec::new(),
+        Vec::new(),
+    );
+
+    let dot = flow_type.to_dot();
+    assert!(dot.starts_with(&format!("digraph \"{}\" {{\n", ty)));
+    assert!(dot.contains(&format!("  \"{}\" [label=\"{}\", peripheries=2];\n", wrapper_entity_instance.id, wrapper_entity_instance.ty)));
+    assert!(dot.contains(&format!("  \"{}\" [label=\"{}\"];\n", entity_instance.id, entity_instance.ty)));
+    assert!(dot.contains(&format!(
+        "  \"{}\" -> \"{}\" [label=\"{}\"];\n",
+        wrapper_entity_instance.id,
+        entity_instance.id,
+        relation_instance.relation_type_id()
+    )));
+}
+
+#[test]
+fn flow_type_with_local_ids_test() {
+    let namespace = r_string();
+    let entity_type_name = r_string();
+    let relation_type_name = r_string();
+    let flow_type: FlowType = serde_json::from_value(json!({
+        "namespace": namespace,
+        "type_name": r_string(),
+        "wrapper_entity_instance": {
+            "namespace": namespace,
+            "type_name": entity_type_name,
+            "id": "wrapper"
+        },
+        "entity_instances": [
+            {
+                "namespace": namespace,
+                "type_name": entity_type_name,
+                "id": "inner"
+            }
+        ],
+        "relation_instances": [
+            {
+                "outbound_id": "wrapper",
+                "namespace": namespace,
+                "type_name": relation_type_name,
+                "inbound_id": "inner",
+                "instance_id": ""
+            }
+        ]
+    }))
+    .expect("Failed to deserialize flow type with local ids");
+    assert_eq!(flow_type_local_id("wrapper"), flow_type.id());
+    assert_eq!(flow_type_local_id("inner"), flow_type.entity_instances.first().unwrap().id);
+    assert_eq!(flow_type_local_id("wrapper"), flow_type.relation_instances.first().unwrap().outbound_id);
+    assert_eq!(flow_type_local_id("inner"), flow_type.relation_instances.first().unwrap().inbound_id);
+
+    let instantiation = flow_type.instantiate();
+    let flow_instance = &instantiation.flow_instance;
+    assert_eq!(2, flow_instance.entity_instances.len());
+    assert_eq!(1, flow_instance.relation_instances.len());
+    let wrapper_id = instantiation.resolve_local_id("wrapper").unwrap();
+    let inner_id = instantiation.resolve_local_id("inner").unwrap();
+    assert_ne!(flow_type.id(), wrapper_id);
+    assert_eq!(wrapper_id, flow_instance.id);
+    assert!(flow_instance.entity_instances.iter().any(|e| e.id == inner_id));
+    assert_eq!(wrapper_id, flow_instance.relation_instances.first().unwrap().outbound_id);
+    assert_eq!(inner_id, flow_instance.relation_instances.first().unwrap().inbound_id);
+    assert!(instantiation.resolve_local_id(r_string()).is_none());
+}
+
+#[test]
+fn flow_type_with_concrete_ids_test() {
+    let wrapper_entity_instance = create_entity_instance("property");
+    let flow_type = FlowType::new(
+        FlowTypeId::new_from_type(r_string(), r_string()),
+        r_string(),
+        wrapper_entity_instance.clone(),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+    );
+    let value = serde_json::to_value(&flow_type).unwrap();
+    let deserialized: FlowType = serde_json::from_value(value).unwrap();
+    assert_eq!(wrapper_entity_instance.id, deserialized.id());
+    let instantiation = deserialized.instantiate();
+    assert_eq!(Some(instantiation.flow_instance.id), instantiation.resolve(wrapper_entity_instance.id));
+}
+
+#[test]
+fn flow_type_ports_test() {
+    let wrapper_entity_instance = create_entity_instance("input");
+    let entity_instance = create_entity_instance("result");
+    let mut flow_type = FlowType::new(
+        FlowTypeId::new_from_type(r_string(), r_string()),
+        r_string(),
+        wrapper_entity_instance.clone(),
+        vec![entity_instance.clone()],
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+    );
+    assert!(flow_type.inputs.is_empty());
+    assert!(flow_type.outputs.is_empty());
+
+    flow_type.add_input(FlowTypePort::new("in", wrapper_entity_instance.id, "input"));
+    flow_type.add_output(FlowTypePort::new("out", entity_instance.id, "result"));
+    assert!(flow_type.has_input("in"));
+    assert!(!flow_type.has_input("out"));
+    assert!(flow_type.has_output("out"));
+    assert_eq!(entity_instance.id, flow_type.get_output("out").unwrap().entity_id);
+    assert_eq!("input", flow_type.get_input("in").unwrap().property_name);
+    assert!(flow_type.get_input(r_string()).is_none());
+    assert!(flow_type.invalid_ports().is_empty());
+
+    let invalid_property = FlowTypePort::new("invalid_property", entity_instance.id, "non_existing");
+    let invalid_entity = FlowTypePort::new("invalid_entity", Uuid::new_v4(), "result");
+    flow_type.add_input(invalid_property.clone());
+    flow_type.add_output(invalid_entity.clone());
+    assert_eq!(vec![invalid_property, invalid_entity], flow_type.invalid_ports());
+
+    flow_type.remove_input("invalid_property");
+    flow_type.remove_output("invalid_entity");
+    assert_eq!(1, flow_type.inputs.len());
+    assert_eq!(1, flow_type.outputs.len());
+    assert!(flow_type.invalid_ports().is_empty());
+}
+
+#[test]
+fn flow_type_ports_with_local_ids_test() {
+    let namespace = r_string();
+    let flow_type: FlowType = serde_json::from_value(json!({
+        "namespace": namespace,
+        "type_name": r_string(),
+        "wrapper_entity_instance": {
+            "namespace": namespace,
+            "type_name": r_string(),
+            "id": "wrapper",
+            "properties": {
+                "lhs": 0
+            }
+        },
+        "inputs": [
+            {
+                "name": "lhs",
+                "entity_id": "wrapper",
+                "property": "lhs"
+            }
+        ]
+    }))
+    .expect("Failed to deserialize flow type with ports");
+    let input = flow_type.get_input("lhs").unwrap();
+    assert_eq!(flow_type_local_id("wrapper"), input.entity_id);
+    assert_eq!("lhs", input.property_name);
+    assert!(flow_type.outputs.is_empty());
+    assert!(flow_type.invalid_ports().is_empty());
+}
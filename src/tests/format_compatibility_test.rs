@@ -0,0 +1,111 @@
+use serde_json::json;
+
+use crate::check_format_compatibility;
+use crate::check_json_format_compatibility;
+use crate::ComponentDao;
+use crate::FlowInstanceDao;
+
+#[test]
+fn current_format_test() {
+    let dao = ComponentDao {
+        namespace: "base".to_string(),
+        type_name: "labeled".to_string(),
+        description: String::new(),
+        tags: Vec::new(),
+        aliases: Vec::new(),
+        deprecation: None,
+        properties: Vec::new(),
+        extensions: Vec::new(),
+        extra: Default::default(),
+    };
+    let document = serde_json::to_value(&dao).unwrap();
+    let (_, report) = check_format_compatibility::<ComponentDao>(&document).unwrap();
+    assert!(report.is_current());
+    assert!(report.is_lossless());
+    assert_eq!("", report.to_string());
+}
+
+#[test]
+fn older_format_test() {
+    let document = json!({
+        "name": "labeled",
+        "properties": [{ "name": "label", "data_type": "string", "socket": "none" }]
+    });
+    let (dao, report) = check_format_compatibility::<ComponentDao>(&document).unwrap();
+    assert_eq!("labeled", dao.type_name);
+    assert_eq!(vec![("name".to_string(), "type_name".to_string())], report.renamed);
+    assert_eq!(
+        vec![
+            "aliases",
+            "deprecation",
+            "description",
+            "extensions",
+            "namespace",
+            "properties[0].description",
+            "properties[0].extensions",
+            "properties[0].mutability",
+            "properties[0].socket_type",
+            "tags",
+        ],
+        report.defaulted
+    );
+    assert_eq!(vec!["properties[0].socket"], report.dropped);
+    assert!(!report.is_lossless());
+    assert!(report.to_string().contains("renamed: name -> type_name\n"));
+    assert!(report.to_string().contains("dropped: properties[0].socket\n"));
+}
+
+#[test]
+fn unknown_fields_are_not_dropped_test() {
+    let (_, report) = check_json_format_compatibility::<FlowInstanceDao>(
+        r#"{
+            "id": "6ba7b810-9dad-11d1-80b4-00c04fd430c8",
+            "namespace": "flows",
+            "type_name": "example",
+            "flow_instance_name": "example",
+            "entities": [],
+            "created_by": "newer version"
+        }"#,
+    )
+    .unwrap();
+    assert!(report.renamed.contains(&("flow_instance_name".to_string(), "name".to_string())));
+    assert!(report.renamed.contains(&("entities".to_string(), "entity_instances".to_string())));
+    assert_eq!(vec!["created_by"], report.unknown);
+    assert!(report.dropped.is_empty());
+    assert!(report.is_lossless());
+}
+
+#[test]
+fn nested_renamed_and_dropped_fields_test() {
+    let (_, report) = check_json_format_compatibility::<FlowInstanceDao>(
+        r#"{
+            "id": "6ba7b810-9dad-11d1-80b4-00c04fd430c8",
+            "namespace": "flows",
+            "type_name": "example",
+            "name": "flow",
+            "entities": [{
+                "id": "6ba7b810-9dad-11d1-80b4-00c04fd430c8",
+                "namespace": "logical",
+                "name": "and",
+                "extensions": [{ "namespace": "ui", "name": "icon", "extension": "and", "size": 16 }]
+            }]
+        }"#,
+    )
+    .unwrap();
+    assert!(report
+        .renamed
+        .contains(&("entity_instances[0].name".to_string(), "entity_instances[0].type_name".to_string())));
+    assert!(report.renamed.contains(&(
+        "entity_instances[0].extensions[0].name".to_string(),
+        "entity_instances[0].extensions[0].type_name".to_string()
+    )));
+    // The fields are renamed by path, the name of the flow instance is the current name
+    assert!(!report.renamed.iter().any(|(previous, _)| previous == "name"));
+    assert_eq!(vec!["entities[0].extensions[0].size"], report.dropped);
+    assert!(report.unknown.is_empty());
+}
+
+#[test]
+fn invalid_document_test() {
+    assert!(check_json_format_compatibility::<ComponentDao>("{ \"namespace\": 1 }").is_err());
+}
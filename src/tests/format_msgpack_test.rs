@@ -0,0 +1,50 @@
+use std::collections::HashMap;
+
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::test_utils::create_entity_instance;
+use crate::test_utils::r_string;
+use crate::EntityInstance;
+use crate::FlowInstance;
+use crate::RelationInstance;
+use crate::RelationInstanceTypeId;
+
+#[test]
+fn entity_instance_bytes_round_trip_test() {
+    let mut entity_instance = create_entity_instance(r_string());
+    entity_instance.properties.insert(r_string(), json!({ "x": [1, 2.5, null, true] }));
+    let bytes = entity_instance.to_bytes().unwrap();
+    let decoded = EntityInstance::from_bytes(&bytes).unwrap();
+    assert_eq!(entity_instance.ty, decoded.ty);
+    assert_eq!(entity_instance.id, decoded.id);
+    assert_eq!(entity_instance.properties, decoded.properties);
+    assert!(bytes.len() < serde_json::to_vec(&entity_instance).unwrap().len());
+}
+
+#[test]
+fn relation_instance_bytes_round_trip_test() {
+    let mut properties = HashMap::new();
+    properties.insert(r_string(), json!(r_string()));
+    let ty = RelationInstanceTypeId::new_from_type_unique_for_instance_id(r_string(), r_string(), r_string());
+    let relation_instance = RelationInstance::new(Uuid::new_v4(), ty, Uuid::new_v4(), properties);
+    let decoded = RelationInstance::from_bytes(&relation_instance.to_bytes().unwrap()).unwrap();
+    assert_eq!(relation_instance.get_key(), decoded.get_key());
+    assert_eq!(relation_instance.properties, decoded.properties);
+}
+
+#[test]
+fn flow_instance_bytes_round_trip_test() {
+    let mut flow_instance = FlowInstance::from_instance_with_name(create_entity_instance(r_string()), r_string());
+    flow_instance.entity_instances.push(create_entity_instance(r_string()));
+    let decoded = FlowInstance::from_bytes(&flow_instance.to_bytes().unwrap()).unwrap();
+    assert_eq!(flow_instance.id, decoded.id);
+    assert_eq!(flow_instance.name, decoded.name);
+    assert_eq!(flow_instance.entity_instances.len(), decoded.entity_instances.len());
+}
+
+#[test]
+fn from_bytes_with_invalid_bytes_test() {
+    assert!(EntityInstance::from_bytes(&[0xc1]).is_err());
+    assert!(FlowInstance::from_bytes(&[]).is_err());
+}
@@ -0,0 +1,58 @@
+use serde_json::json;
+use serde_json::Map;
+use uuid::Uuid;
+
+use crate::from_ron_str;
+use crate::test_utils::create_entity_instance;
+use crate::test_utils::r_string;
+use crate::to_ron_string;
+use crate::ComponentDao;
+use crate::DataType;
+use crate::Extension;
+use crate::ExtensionTypeId;
+use crate::FlowInstance;
+use crate::FlowInstanceDao;
+use crate::PropertyType;
+
+#[test]
+fn component_dao_ron_round_trip_test() {
+    let dao = ComponentDao {
+        namespace: r_string(),
+        type_name: r_string(),
+        description: r_string(),
+        tags: Vec::new(),
+        aliases: Vec::new(),
+        deprecation: None,
+        properties: vec![PropertyType::input(r_string(), DataType::Number), PropertyType::string(r_string())],
+        extensions: vec![Extension::new(
+            ExtensionTypeId::new_from_type(r_string(), r_string()),
+            r_string(),
+            json!({ "x": [1, 2.5, null] }),
+        )],
+        extra: Map::new(),
+    };
+    let ron = to_ron_string(&dao).unwrap();
+    let deserialized: ComponentDao = from_ron_str(&ron).unwrap();
+    assert_eq!(serde_json::to_value(&dao).unwrap(), serde_json::to_value(&deserialized).unwrap());
+}
+
+#[test]
+fn component_dao_from_ron_with_defaults_test() {
+    let dao: ComponentDao = from_ron_str(r#"(type_name: "labeled", properties: [(name: "label", data_type: string)])"#).unwrap();
+    assert_eq!(String::new(), dao.namespace);
+    assert_eq!("labeled", dao.type_name);
+    assert_eq!(1, dao.properties.len());
+    assert_eq!(DataType::String, dao.properties.first().unwrap().data_type);
+}
+
+#[test]
+fn flow_instance_dao_ron_round_trip_test() {
+    let wrapper_entity_instance = create_entity_instance(r_string());
+    let mut flow_instance = FlowInstance::from_instance_with_name(wrapper_entity_instance, r_string());
+    flow_instance.entity_instances.push(create_entity_instance(r_string()));
+    flow_instance.external_entity_ids.push(Uuid::new_v4());
+    let dao = FlowInstanceDao::from(flow_instance);
+    let ron = to_ron_string(&dao).unwrap();
+    let deserialized: FlowInstanceDao = from_ron_str(&ron).unwrap();
+    assert_eq!(serde_json::to_value(&dao).unwrap(), serde_json::to_value(&deserialized).unwrap());
+}
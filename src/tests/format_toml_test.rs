@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+
+use serde_json::json;
+use serde_json::Map;
+use uuid::Uuid;
+
+use crate::from_toml_str;
+use crate::test_utils::r_string;
+use crate::to_toml_string;
+use crate::ComponentDao;
+use crate::ComponentTypeId;
+use crate::DataType;
+use crate::EntityInstanceDao;
+use crate::EntityType;
+use crate::EntityTypeDao;
+use crate::EntityTypeId;
+use crate::Extension;
+use crate::ExtensionTypeId;
+use crate::Mutability;
+use crate::PropertyType;
+use crate::RelationType;
+use crate::RelationTypeDao;
+use crate::RelationTypeId;
+use crate::SocketType;
+
+#[test]
+fn component_dao_toml_round_trip_test() {
+    let dao = ComponentDao {
+        namespace: r_string(),
+        type_name: r_string(),
+        description: r_string(),
+        tags: Vec::new(),
+        aliases: Vec::new(),
+        deprecation: None,
+        properties: vec![
+            PropertyType::new_with_all(r_string(), r_string(), DataType::Number, SocketType::Input, Mutability::Immutable, Vec::new()),
+            PropertyType::output(r_string(), DataType::Array),
+        ],
+        extensions: vec![Extension::new(
+            ExtensionTypeId::new_from_type(r_string(), r_string()),
+            r_string(),
+            json!({ "nested": [1, 2.5, "three", true] }),
+        )],
+        extra: Map::new(),
+    };
+    let toml = to_toml_string(&dao).unwrap();
+    let deserialized: ComponentDao = from_toml_str(&toml).unwrap();
+    assert_eq!(serde_json::to_value(&dao).unwrap(), serde_json::to_value(&deserialized).unwrap());
+    assert_eq!(toml, to_toml_string(&deserialized).unwrap());
+}
+
+#[test]
+fn component_dao_from_toml_with_aliases_and_defaults_test() {
+    let dao: ComponentDao = from_toml_str(
+        r#"
+        name = "labeled"
+
+        [[properties]]
+        name = "label"
+        data_type = "string"
+        "#,
+    )
+    .unwrap();
+    assert_eq!(String::new(), dao.namespace);
+    assert_eq!("labeled", dao.type_name);
+    assert_eq!(1, dao.properties.len());
+    let property = dao.properties.first().unwrap();
+    assert_eq!("label", property.name);
+    assert_eq!(DataType::String, property.data_type);
+    assert_eq!(SocketType::None, property.socket_type);
+    assert_eq!(Mutability::Mutable, property.mutability);
+}
+
+#[test]
+fn entity_instance_dao_toml_round_trip_test() {
+    let mut properties = HashMap::new();
+    properties.insert(r_string(), json!(r_string()));
+    properties.insert(r_string(), json!(42));
+    properties.insert(r_string(), json!({ "x": 1.5, "y": [true, false] }));
+    let dao = EntityInstanceDao {
+        namespace: r_string(),
+        type_name: r_string(),
+        id: Uuid::new_v4(),
+        description: r_string(),
+        properties,
+        extensions: Vec::new(),
+        created_at: None,
+        updated_at: None,
+        created_by: None,
+        deleted_at: None,
+        revision: 0,
+        extra: Map::new(),
+    };
+    let toml = to_toml_string(&dao).unwrap();
+    let deserialized: EntityInstanceDao = from_toml_str(&toml).unwrap();
+    assert_eq!(serde_json::to_value(&dao).unwrap(), serde_json::to_value(&deserialized).unwrap());
+}
+
+#[test]
+fn type_daos_toml_round_trip_test() {
+    let entity_type_dao = EntityTypeDao::from(EntityType::new(
+        EntityTypeId::new_from_type(r_string(), r_string()),
+        r_string(),
+        vec![ComponentTypeId::new_from_type(r_string(), r_string())],
+        vec![PropertyType::string(r_string())],
+        Vec::new(),
+    ));
+    let toml = to_toml_string(&entity_type_dao).unwrap();
+    let deserialized: EntityTypeDao = from_toml_str(&toml).unwrap();
+    assert_eq!(serde_json::to_value(&entity_type_dao).unwrap(), serde_json::to_value(&deserialized).unwrap());
+
+    let relation_type_dao = RelationTypeDao::from(RelationType::new(
+        ComponentTypeId::new_from_type(r_string(), r_string()),
+        RelationTypeId::new_from_type(r_string(), r_string()),
+        EntityTypeId::new_from_type(r_string(), r_string()),
+        r_string(),
+        Vec::new(),
+        vec![PropertyType::bool(r_string())],
+        Vec::new(),
+    ));
+    let toml = to_toml_string(&relation_type_dao).unwrap();
+    let deserialized: RelationTypeDao = from_toml_str(&toml).unwrap();
+    assert_eq!(serde_json::to_value(&relation_type_dao).unwrap(), serde_json::to_value(&deserialized).unwrap());
+}
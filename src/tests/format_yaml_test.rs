@@ -0,0 +1,274 @@
+use std::collections::HashMap;
+
+use serde_json::json;
+use serde_json::Map;
+use uuid::Uuid;
+
+use crate::flow_type_local_id;
+use crate::from_yaml_str;
+use crate::test_utils::create_entity_instance;
+use crate::test_utils::r_string;
+use crate::to_yaml_string;
+use crate::ComponentDao;
+use crate::ComponentOrEntityTypeId;
+use crate::ComponentTypeId;
+use crate::DataType;
+use crate::EntityInstanceDao;
+use crate::EntityType;
+use crate::EntityTypeDao;
+use crate::EntityTypeId;
+use crate::FlowInstanceDao;
+use crate::FlowType;
+use crate::FlowTypeDao;
+use crate::FlowTypeId;
+use crate::PropertyType;
+use crate::RelationInstance;
+use crate::RelationInstanceDao;
+use crate::RelationInstanceTypeId;
+use crate::RelationType;
+use crate::RelationTypeDao;
+use crate::RelationTypeId;
+use crate::SocketType;
+
+#[test]
+fn component_dao_yaml_round_trip_test() {
+    let dao = ComponentDao {
+        namespace: r_string(),
+        type_name: r_string(),
+        description: r_string(),
+        tags: Vec::new(),
+        aliases: Vec::new(),
+        deprecation: None,
+        properties: vec![PropertyType::input(r_string(), DataType::Bool), PropertyType::object(r_string())],
+        extensions: Vec::new(),
+        extra: Map::new(),
+    };
+    let yaml = to_yaml_string(&dao).unwrap();
+    let deserialized: ComponentDao = from_yaml_str(&yaml).unwrap();
+    assert_eq!(serde_json::to_value(&dao).unwrap(), serde_json::to_value(&deserialized).unwrap());
+}
+
+#[test]
+fn component_dao_from_yaml_with_aliases_and_defaults_test() {
+    let dao: ComponentDao = from_yaml_str(
+        r#"
+name: labeled
+properties:
+  - name: label
+    data_type: string
+"#,
+    )
+    .unwrap();
+    assert_eq!(String::new(), dao.namespace);
+    assert_eq!("labeled", dao.type_name);
+    assert_eq!(String::new(), dao.description);
+    assert!(dao.extensions.is_empty());
+    let property = dao.properties.first().unwrap();
+    assert_eq!(DataType::String, property.data_type);
+    assert_eq!(SocketType::None, property.socket_type);
+}
+
+#[test]
+fn entity_instance_dao_yaml_round_trip_test() {
+    let mut properties = HashMap::new();
+    properties.insert(r_string(), json!(r_string()));
+    properties.insert(r_string(), json!(null));
+    properties.insert(r_string(), json!([1, 2.5, { "x": true }]));
+    let dao = EntityInstanceDao {
+        namespace: r_string(),
+        type_name: r_string(),
+        id: Uuid::new_v4(),
+        description: r_string(),
+        properties,
+        extensions: Vec::new(),
+        created_at: None,
+        updated_at: None,
+        created_by: None,
+        deleted_at: None,
+        revision: 0,
+        extra: Map::new(),
+    };
+    let yaml = to_yaml_string(&dao).unwrap();
+    let deserialized: EntityInstanceDao = from_yaml_str(&yaml).unwrap();
+    assert_eq!(serde_json::to_value(&dao).unwrap(), serde_json::to_value(&deserialized).unwrap());
+}
+
+#[test]
+fn entity_instance_dao_from_yaml_with_aliases_and_defaults_test() {
+    let id = Uuid::new_v4();
+    let dao: EntityInstanceDao = from_yaml_str(&format!("name: and\nid: {}\n", id)).unwrap();
+    assert_eq!(String::new(), dao.namespace);
+    assert_eq!("and", dao.type_name);
+    assert_eq!(id, dao.id);
+    assert_eq!(String::new(), dao.description);
+    assert!(dao.properties.is_empty());
+    assert!(dao.extensions.is_empty());
+}
+
+#[test]
+fn relation_instance_dao_from_yaml_with_aliases_and_defaults_test() {
+    let outbound_id = Uuid::new_v4();
+    let inbound_id = Uuid::new_v4();
+    let dao: RelationInstanceDao = from_yaml_str(&format!("outbound: {}\nname: connector\ninbound: {}\n", outbound_id, inbound_id)).unwrap();
+    assert_eq!(outbound_id, dao.outbound_id);
+    assert_eq!(String::new(), dao.namespace);
+    assert_eq!("connector", dao.type_name);
+    assert_eq!(String::new(), dao.instance_id);
+    assert_eq!(inbound_id, dao.inbound_id);
+    assert!(dao.properties.is_empty());
+
+    let yaml = to_yaml_string(&dao).unwrap();
+    let deserialized: RelationInstanceDao = from_yaml_str(&yaml).unwrap();
+    assert_eq!(serde_json::to_value(&dao).unwrap(), serde_json::to_value(&deserialized).unwrap());
+}
+
+#[test]
+fn flow_instance_dao_from_yaml_with_aliases_and_defaults_test() {
+    let id = Uuid::new_v4();
+    let entity_id = Uuid::new_v4();
+    let yaml = format!(
+        r#"
+id: {id}
+type_name: flow
+flow_instance_name: my flow
+entities:
+  - name: and
+    id: {entity_id}
+relations:
+  - outbound: {id}
+    name: connector
+    inbound: {entity_id}
+externals: []
+"#
+    );
+    let dao: FlowInstanceDao = from_yaml_str(&yaml).unwrap();
+    assert_eq!(id, dao.id);
+    assert_eq!(String::new(), dao.namespace);
+    assert_eq!("flow", dao.type_name);
+    assert_eq!("my flow", dao.name);
+    assert_eq!(1, dao.entity_instances.len());
+    assert_eq!(1, dao.relation_instances.len());
+    assert!(dao.external_entity_ids.is_empty());
+    assert!(dao.sub_flows.is_empty());
+
+    let yaml = to_yaml_string(&dao).unwrap();
+    let deserialized: FlowInstanceDao = from_yaml_str(&yaml).unwrap();
+    assert_eq!(serde_json::to_value(&dao).unwrap(), serde_json::to_value(&deserialized).unwrap());
+}
+
+#[test]
+fn entity_type_dao_yaml_round_trip_test() {
+    let dao = EntityTypeDao::from(EntityType::new(
+        EntityTypeId::new_from_type(r_string(), r_string()),
+        r_string(),
+        vec![ComponentTypeId::new_from_type(r_string(), r_string())],
+        vec![PropertyType::string(r_string()), PropertyType::output(r_string(), DataType::Number)],
+        Vec::new(),
+    ));
+    let yaml = to_yaml_string(&dao).unwrap();
+    let deserialized: EntityTypeDao = from_yaml_str(&yaml).unwrap();
+    assert_eq!(serde_json::to_value(&dao).unwrap(), serde_json::to_value(&deserialized).unwrap());
+}
+
+#[test]
+fn entity_type_dao_from_yaml_with_aliases_and_defaults_test() {
+    let dao: EntityTypeDao = from_yaml_str("name: and\n").unwrap();
+    assert_eq!(String::new(), dao.namespace);
+    assert_eq!("and", dao.type_name);
+    assert_eq!(String::new(), dao.description);
+    assert!(dao.components.is_empty());
+    assert!(dao.properties.is_empty());
+    assert!(dao.extensions.is_empty());
+}
+
+#[test]
+fn relation_type_dao_yaml_round_trip_test() {
+    let dao = RelationTypeDao::from(RelationType::new(
+        ComponentTypeId::new_from_type(r_string(), r_string()),
+        RelationTypeId::new_from_type(r_string(), r_string()),
+        EntityTypeId::new_from_type(r_string(), r_string()),
+        r_string(),
+        vec![ComponentTypeId::new_from_type(r_string(), r_string())],
+        vec![PropertyType::bool(r_string())],
+        Vec::new(),
+    ));
+    let yaml = to_yaml_string(&dao).unwrap();
+    let deserialized: RelationTypeDao = from_yaml_str(&yaml).unwrap();
+    assert_eq!(serde_json::to_value(&dao).unwrap(), serde_json::to_value(&deserialized).unwrap());
+}
+
+#[test]
+fn relation_type_dao_from_yaml_with_aliases_and_defaults_test() {
+    let dao: RelationTypeDao = from_yaml_str(
+        r#"
+outbound:
+  component:
+    namespace: base
+    type_name: labeled
+name: contains
+inbound_type:
+  entity_type:
+    namespace: logical
+    type_name: and
+"#,
+    )
+    .unwrap();
+    assert_eq!(ComponentOrEntityTypeId::Component(ComponentTypeId::new_from_type("base", "labeled")), dao.outbound_type);
+    assert_eq!(String::new(), dao.namespace);
+    assert_eq!("contains", dao.type_name);
+    assert_eq!(ComponentOrEntityTypeId::EntityType(EntityTypeId::new_from_type("logical", "and")), dao.inbound_type);
+    assert_eq!(String::new(), dao.description);
+    assert!(dao.components.is_empty());
+    assert!(dao.properties.is_empty());
+}
+
+#[test]
+fn flow_type_dao_yaml_round_trip_test() {
+    let wrapper_entity_instance = create_entity_instance(r_string());
+    let entity_instance = create_entity_instance(r_string());
+    let ty = RelationInstanceTypeId::new_from_type_unique_for_instance_id(r_string(), r_string(), r_string());
+    let relation_instance = RelationInstance::new(wrapper_entity_instance.id, ty, entity_instance.id, HashMap::new());
+    let dao = FlowTypeDao::from(FlowType::new(
+        FlowTypeId::new_from_type(r_string(), r_string()),
+        r_string(),
+        wrapper_entity_instance,
+        vec![entity_instance],
+        vec![relation_instance],
+        vec![PropertyType::number(r_string())],
+        Vec::new(),
+    ));
+    let yaml = to_yaml_string(&dao).unwrap();
+    let deserialized: FlowTypeDao = from_yaml_str(&yaml).unwrap();
+    assert_eq!(serde_json::to_value(&dao).unwrap(), serde_json::to_value(&deserialized).unwrap());
+}
+
+#[test]
+fn flow_type_dao_from_yaml_with_aliases_and_defaults_test() {
+    let wrapper_id = Uuid::new_v4();
+    let yaml = format!(
+        r#"
+name: flow
+wrapper_entity_instance:
+  type_name: wrapper
+  id: {wrapper_id}
+entities:
+  - type_name: and
+    id: and1
+relations:
+  - outbound: {wrapper_id}
+    name: connector
+    inbound: and1
+"#
+    );
+    let dao: FlowTypeDao = from_yaml_str(&yaml).unwrap();
+    assert_eq!(String::new(), dao.namespace);
+    assert_eq!("flow", dao.type_name);
+    assert_eq!(wrapper_id, dao.wrapper_entity_instance.id);
+    assert_eq!(flow_type_local_id("and1"), dao.entity_instances.first().unwrap().id);
+    let relation_instance = dao.relation_instances.first().unwrap();
+    assert_eq!(wrapper_id, relation_instance.outbound_id);
+    assert_eq!(flow_type_local_id("and1"), relation_instance.inbound_id);
+    assert!(dao.variables.is_empty());
+    assert!(dao.inputs.is_empty());
+    assert!(dao.outputs.is_empty());
+}
@@ -0,0 +1,73 @@
+use crate::graphql_name;
+use crate::graphql_type_name;
+use crate::Component;
+use crate::ComponentOrEntityTypeId;
+use crate::ComponentTypeId;
+use crate::EntityType;
+use crate::EntityTypeId;
+use crate::PropertyType;
+use crate::RelationType;
+use crate::RelationTypeId;
+use crate::TypeSystem;
+
+#[test]
+fn graphql_name_test() {
+    assert_eq!("and_gate", graphql_name("and_gate"));
+    assert_eq!("and_gate", graphql_name("and-gate"));
+    assert_eq!("_1bit", graphql_name("1bit"));
+    assert_eq!("LogicalAndGate", graphql_type_name(&EntityTypeId::new_from_type("logical", "and_gate")));
+}
+
+#[test]
+fn type_system_to_graphql_sdl_test() {
+    let labeled_ty = ComponentTypeId::new_from_type("base", "labeled");
+    let labeled = Component::new_without_extensions(labeled_ty.clone(), "Labeled", vec![PropertyType::string("label")]);
+    let and_ty = EntityTypeId::new_from_type("logical", "and");
+    let and = EntityType::new(
+        and_ty.clone(),
+        "",
+        vec![labeled_ty.clone(), ComponentTypeId::new_from_type("unknown", "component")],
+        vec![PropertyType::bool("lhs"), PropertyType::bool("rhs"), PropertyType::bool("result")],
+        Vec::new(),
+    );
+    let connector = RelationType::new(
+        ComponentOrEntityTypeId::EntityType(and_ty.clone()),
+        RelationTypeId::new_from_type("connector", "default"),
+        ComponentOrEntityTypeId::Component(labeled_ty),
+        "",
+        Vec::new(),
+        vec![PropertyType::string("outbound_property_name")],
+        Vec::new(),
+    );
+    let type_system = TypeSystem::new(vec![labeled], vec![and], vec![connector], Vec::new());
+    let sdl = type_system.to_graphql_sdl();
+    assert_eq!(
+        "scalar JSON\n\n\"\"\"Labeled\"\"\"\ninterface BaseLabeled {\n  id: ID!\n  label: String\n}\n\ntype LogicalAnd implements BaseLabeled {\n  id: ID!\n  lhs: Boolean\n  rhs: Boolean\n  result: Boolean\n  label: String\n}\n\ntype ConnectorDefault {\n  outbound: LogicalAnd!\n  inbound: BaseLabeled!\n  outbound_property_name: String\n}\n",
+        sdl
+    );
+}
+
+#[test]
+fn scoped_properties_to_graphql_sdl_test() {
+    let labeled_ty = ComponentTypeId::new_from_type("base", "labeled");
+    let labeled = Component::new_without_extensions(labeled_ty.clone(), "", vec![PropertyType::string("label")]);
+    let item = EntityType::new(
+        EntityTypeId::new_from_type("game", "item"),
+        "",
+        vec![labeled_ty],
+        vec![PropertyType::number("label")],
+        Vec::new(),
+    )
+    .with_scoped_properties();
+    let type_system = TypeSystem::new(vec![labeled], vec![item], Vec::new(), Vec::new());
+    assert!(type_system
+        .to_graphql_sdl()
+        .ends_with("type GameItem {\n  id: ID!\n  label: Float\n  labeled_label: String\n}\n"));
+}
+
+#[test]
+fn empty_type_system_to_graphql_sdl_test() {
+    let type_system = TypeSystem::default();
+    assert!(type_system.is_empty());
+    assert_eq!("scalar JSON\n", type_system.to_graphql_sdl());
+}
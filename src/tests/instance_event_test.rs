@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::test_utils::assert_json_roundtrip_eq;
+use crate::test_utils::r_string;
+use crate::ComponentTypeId;
+use crate::EntityInstance;
+use crate::EntityTypeId;
+use crate::InstanceEvent;
+use crate::InstanceId;
+use crate::NamespacedTypeGetter;
+use crate::RelationInstance;
+use crate::RelationInstanceTypeId;
+
+#[test]
+fn instance_event_entity_instance_test() {
+    let entity_instance = EntityInstance::new(EntityTypeId::new_from_type(r_string(), r_string()), Uuid::new_v4(), HashMap::new());
+    let event = InstanceEvent::entity_instance_created(&entity_instance);
+    assert_eq!(InstanceId::Entity(entity_instance.id), event.instance());
+    assert_eq!(
+        json!({
+            "event": "entity_instance_created",
+            "id": entity_instance.id,
+            "ty": { "namespace": entity_instance.ty.namespace(), "type_name": entity_instance.ty.type_name() }
+        }),
+        serde_json::to_value(&event).unwrap()
+    );
+    assert_json_roundtrip_eq(&event);
+    assert_json_roundtrip_eq(&InstanceEvent::entity_instance_deleted(&entity_instance));
+    let event = InstanceEvent::ComponentAdded {
+        instance: InstanceId::from(&entity_instance),
+        component: ComponentTypeId::new_from_type(r_string(), r_string()),
+    };
+    assert_eq!(InstanceId::Entity(entity_instance.id), event.instance());
+    assert_json_roundtrip_eq(&event);
+}
+
+#[test]
+fn instance_event_relation_instance_test() {
+    let ty = RelationInstanceTypeId::new_from_type_unique_for_instance_id(r_string(), r_string(), r_string());
+    let relation_instance = RelationInstance::new(Uuid::new_v4(), ty, Uuid::new_v4(), HashMap::new());
+    let event = InstanceEvent::relation_instance_created(&relation_instance);
+    assert_eq!(InstanceId::from(&relation_instance), event.instance());
+    assert_json_roundtrip_eq(&event);
+    assert_json_roundtrip_eq(&InstanceEvent::relation_instance_deleted(&relation_instance));
+}
+
+#[test]
+fn instance_event_property_changes_test() {
+    let id = Uuid::new_v4();
+    let instance = InstanceId::Entity(id);
+    let mut old = HashMap::new();
+    old.insert("a".to_string(), json!(1));
+    old.insert("b".to_string(), json!(2));
+    old.insert("c".to_string(), json!(3));
+    let mut new = old.clone();
+    new.remove("a");
+    new.insert("b".to_string(), json!(20));
+    new.insert("d".to_string(), json!(4));
+    let events = InstanceEvent::property_changes(instance.clone(), &old, &new);
+    assert_eq!(
+        vec![
+            InstanceEvent::PropertyChanged {
+                instance: instance.clone(),
+                property_name: "a".to_string(),
+                old_value: Some(json!(1)),
+                new_value: None,
+            },
+            InstanceEvent::PropertyChanged {
+                instance: instance.clone(),
+                property_name: "b".to_string(),
+                old_value: Some(json!(2)),
+                new_value: Some(json!(20)),
+            },
+            InstanceEvent::PropertyChanged {
+                instance: instance.clone(),
+                property_name: "d".to_string(),
+                old_value: None,
+                new_value: Some(json!(4)),
+            },
+        ],
+        events
+    );
+    assert_eq!(
+        json!({ "event": "property_changed", "instance": { "entity": id }, "property_name": "a", "old_value": 1 }),
+        serde_json::to_value(&events[0]).unwrap()
+    );
+    events.iter().for_each(assert_json_roundtrip_eq);
+    assert!(InstanceEvent::property_changes(instance, &old, &old).is_empty());
+}
+
+#[test]
+fn instance_event_property_changed_null_value_test() {
+    let instance = InstanceId::Entity(Uuid::new_v4());
+    let event = InstanceEvent::PropertyChanged {
+        instance,
+        property_name: r_string(),
+        old_value: Some(json!(null)),
+        new_value: Some(json!(1)),
+    };
+    assert_eq!(json!(null), serde_json::to_value(&event).unwrap()["old_value"]);
+    assert_json_roundtrip_eq(&event);
+}
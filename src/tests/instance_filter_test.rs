@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::test_utils::assert_json_roundtrip_eq;
+use crate::test_utils::r_string;
+use crate::ComparisonOperator;
+use crate::EntityInstance;
+use crate::EntityTypeId;
+use crate::InstanceFilter;
+use crate::RelationInstance;
+use crate::RelationInstanceTypeId;
+
+fn entity_instance(ty: &EntityTypeId) -> EntityInstance {
+    let mut properties = HashMap::new();
+    properties.insert("name".to_string(), json!("inexor"));
+    properties.insert("count".to_string(), json!(5));
+    properties.insert("ratio".to_string(), json!(0.5));
+    properties.insert("tags".to_string(), json!(["a", "b"]));
+    EntityInstance::new(ty.clone(), Uuid::new_v4(), properties)
+}
+
+#[test]
+fn comparison_operator_test() {
+    assert!(ComparisonOperator::Equals.compare(&json!(5), &json!(5)));
+    assert!(ComparisonOperator::NotEquals.compare(&json!(5), &json!("5")));
+    assert!(ComparisonOperator::Equals.compare(&json!(1), &json!(1.0)));
+    assert!(!ComparisonOperator::NotEquals.compare(&json!(1.0), &json!(1)));
+    assert!(ComparisonOperator::NotEquals.compare(&json!(1), &json!(1.5)));
+    assert!(ComparisonOperator::LessThan.compare(&json!(4), &json!(5)));
+    assert!(ComparisonOperator::LessThan.compare(&json!(4.5), &json!(5)));
+    assert!(!ComparisonOperator::LessThan.compare(&json!(5), &json!(5)));
+    assert!(ComparisonOperator::LessThanOrEqual.compare(&json!(5), &json!(5)));
+    assert!(ComparisonOperator::GreaterThan.compare(&json!("b"), &json!("a")));
+    assert!(ComparisonOperator::GreaterThanOrEqual.compare(&json!(-1), &json!(-1)));
+    assert!(!ComparisonOperator::GreaterThan.compare(&json!(true), &json!(false)));
+    assert!(ComparisonOperator::Contains.compare(&json!("inexor"), &json!("exo")));
+    assert!(ComparisonOperator::Contains.compare(&json!([1, 2]), &json!(2)));
+    assert!(ComparisonOperator::StartsWith.compare(&json!("inexor"), &json!("in")));
+    assert!(ComparisonOperator::EndsWith.compare(&json!("inexor"), &json!("or")));
+    assert!(!ComparisonOperator::EndsWith.compare(&json!(1), &json!(1)));
+}
+
+#[test]
+fn instance_filter_entity_instance_test() {
+    let ty = EntityTypeId::new_from_type(r_string(), r_string());
+    let entity_instance = entity_instance(&ty);
+    assert!(InstanceFilter::type_equals(&ty).matches_entity_instance(&entity_instance));
+    assert!(!InstanceFilter::type_equals(&EntityTypeId::new_from_type(r_string(), r_string())).matches_entity_instance(&entity_instance));
+    assert!(InstanceFilter::has_property("tags").matches_entity_instance(&entity_instance));
+    assert!(!InstanceFilter::has_property(r_string()).matches_entity_instance(&entity_instance));
+    assert!(InstanceFilter::property_equals("name", json!("inexor")).matches_entity_instance(&entity_instance));
+    // Instances without the property don't match, not even a negative comparison
+    assert!(!InstanceFilter::property(r_string(), ComparisonOperator::NotEquals, json!(1)).matches_entity_instance(&entity_instance));
+
+    let filter = InstanceFilter::type_equals(&ty)
+        .and(InstanceFilter::property("count", ComparisonOperator::GreaterThan, json!(3)))
+        .and(InstanceFilter::property("ratio", ComparisonOperator::LessThan, json!(1)).or(InstanceFilter::has_property(r_string())));
+    assert!(filter.matches_entity_instance(&entity_instance));
+    assert!(!filter.clone().negate().matches_entity_instance(&entity_instance));
+    assert_eq!(filter, filter.clone().negate().negate());
+    assert!(matches!(&filter, InstanceFilter::And(filters) if filters.len() == 3));
+    assert!(!InstanceFilter::And(Vec::new()).negate().matches_entity_instance(&entity_instance));
+    assert!(!InstanceFilter::Or(Vec::new()).matches_entity_instance(&entity_instance));
+}
+
+#[test]
+fn instance_filter_relation_instance_test() {
+    let ty = RelationInstanceTypeId::new_from_type_unique_for_instance_id(r_string(), r_string(), r_string());
+    let mut properties = HashMap::new();
+    properties.insert("weight".to_string(), json!(1.5));
+    let relation_instance = RelationInstance::new(Uuid::new_v4(), ty.clone(), Uuid::new_v4(), properties);
+    // The instance id is ignored
+    assert!(InstanceFilter::type_equals(&ty.relation_type_id()).matches_relation_instance(&relation_instance));
+    assert!(!InstanceFilter::type_equals(&ty).matches_relation_instance(&relation_instance));
+    assert!(InstanceFilter::property("weight", ComparisonOperator::GreaterThanOrEqual, json!(1.5)).matches_relation_instance(&relation_instance));
+}
+
+#[test]
+fn instance_filter_serde_test() {
+    let filter = InstanceFilter::type_equals(&EntityTypeId::new_from_type("logical", "and"))
+        .and(InstanceFilter::property("result", ComparisonOperator::Equals, json!(true)).negate());
+    let json = serde_json::to_value(&filter).unwrap();
+    assert_eq!(
+        json!({
+            "and": [
+                { "type": { "namespace": "logical", "type_name": "and" } },
+                { "not": { "property": { "name": "result", "operator": "equals", "value": true } } }
+            ]
+        }),
+        json
+    );
+    assert_json_roundtrip_eq(&filter);
+}
@@ -0,0 +1,77 @@
+use serde_json::json;
+
+use crate::data_type_json_schema;
+use crate::entity_instance_openapi_schema_name;
+use crate::test_utils::r_string;
+use crate::Component;
+use crate::ComponentTypeId;
+use crate::DataType;
+use crate::EntityType;
+use crate::EntityTypeId;
+use crate::PropertyType;
+use crate::TypeSystem;
+
+#[test]
+fn data_type_json_schema_test() {
+    assert_eq!(json!({ "type": "null" }), data_type_json_schema(&DataType::Null));
+    assert_eq!(json!({ "type": "boolean" }), data_type_json_schema(&DataType::Bool));
+    assert_eq!(json!({ "type": "number" }), data_type_json_schema(&DataType::Number));
+    assert_eq!(json!({ "type": "string" }), data_type_json_schema(&DataType::String));
+    assert_eq!(json!({ "type": "array" }), data_type_json_schema(&DataType::Array));
+    assert_eq!(json!({ "type": "object" }), data_type_json_schema(&DataType::Object));
+    assert_eq!(json!({}), data_type_json_schema(&DataType::Any));
+}
+
+#[test]
+fn entity_instance_json_schema_test() {
+    let namespace = r_string();
+    let component_ty = ComponentTypeId::new_from_type(&namespace, &r_string());
+    let mut label = PropertyType::string("label");
+    label.description = "The label".to_string();
+    let component = Component::new_without_extensions(component_ty.clone(), r_string(), vec![label, PropertyType::bool("value")]);
+    let other_component =
+        Component::new_without_extensions(ComponentTypeId::new_from_type(&namespace, &r_string()), r_string(), vec![PropertyType::array("ignored")]);
+    let type_name = r_string();
+    let entity_type = EntityType::new_from_type(
+        namespace.clone(),
+        type_name.clone(),
+        r_string(),
+        vec![component_ty],
+        vec![PropertyType::number("value"), PropertyType::object("config")],
+        Vec::new(),
+    );
+
+    let schema = entity_type.instance_json_schema(&[component, other_component]);
+    assert_eq!(json!("object"), schema["type"]);
+    assert_eq!(json!(format!("e__{}__{}", &namespace, &type_name)), schema["title"]);
+    assert_eq!(json!(namespace), schema["properties"]["namespace"]["const"]);
+    assert_eq!(json!(type_name), schema["properties"]["type_name"]["const"]);
+    assert_eq!(json!("uuid"), schema["properties"]["id"]["format"]);
+    assert_eq!(json!(["namespace", "type_name", "id"]), schema["required"]);
+
+    let properties = &schema["properties"]["properties"];
+    assert_eq!(json!({ "type": "number" }), properties["properties"]["value"]);
+    assert_eq!(json!({ "type": "object" }), properties["properties"]["config"]);
+    assert_eq!(json!({ "type": "string", "description": "The label" }), properties["properties"]["label"]);
+    assert!(properties["properties"].get("ignored").is_none());
+    assert_eq!(json!(["value", "config", "label"]), properties["required"]);
+}
+
+#[test]
+fn openapi_components_test() {
+    let component_ty = ComponentTypeId::new_from_type("base", "labeled");
+    let component = Component::new_without_extensions(component_ty.clone(), "", vec![PropertyType::string("label")]);
+    let entity_type = EntityType::new_from_type("logical", "and", "", vec![component_ty], vec![PropertyType::bool("result")], Vec::new());
+    let entity_ty = entity_type.ty.clone();
+    let type_system = TypeSystem::new(vec![component], vec![entity_type], Vec::new(), Vec::new());
+
+    let components = type_system.to_openapi_components(&[entity_ty.clone(), EntityTypeId::new_from_type("unknown", "type")]);
+    let schemas = components.get("schemas").unwrap().as_object().unwrap();
+    assert_eq!(1, schemas.len());
+    let schema = schemas.get(&entity_instance_openapi_schema_name(&entity_ty)).unwrap();
+    assert!(schema.get("$schema").is_none());
+    assert_eq!(json!("e__logical__and"), schema["title"]);
+    let properties = &schema["properties"]["properties"]["properties"];
+    assert_eq!(json!({ "type": "boolean" }), properties["result"]);
+    assert_eq!(json!({ "type": "string" }), properties["label"]);
+}
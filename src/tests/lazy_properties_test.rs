@@ -0,0 +1,93 @@
+use std::collections::HashMap;
+
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::EntityInstance;
+use crate::FlowInstance;
+use crate::LazyEntityInstance;
+use crate::LazyFlowInstance;
+use crate::PropertyInstanceGetter;
+use crate::PropertyInstanceRefGetter;
+
+#[test]
+fn lazy_entity_instance_test() {
+    let id = Uuid::new_v4();
+    let s = json!({
+        "namespace": "base",
+        "type_name": "config",
+        "id": id,
+        "properties": {
+            "label": "config",
+            "config": { "values": [1, 2, 3] }
+        }
+    })
+    .to_string();
+    let entity_instance: LazyEntityInstance = serde_json::from_str(&s).unwrap();
+    let config = &entity_instance.properties;
+    assert_eq!(Some(r#"{"values":[1,2,3]}"#), config.get_raw("config"));
+    assert_eq!(vec![1, 2, 3], config.parse::<HashMap<String, Vec<u64>>>("config").unwrap().unwrap()["values"]);
+    assert_eq!(Some("config"), config.as_str("label"));
+    assert_eq!(Some(&json!([1, 2, 3])), config.as_object_ref("config").unwrap().get("values"));
+    assert_eq!(None, config.get("unknown"));
+
+    // Serializes the unparsed values
+    assert_eq!(serde_json::from_str::<serde_json::Value>(&s).unwrap(), serde_json::to_value(&entity_instance).unwrap());
+
+    let entity_instance = EntityInstance::try_from(entity_instance).unwrap();
+    assert_eq!(id, entity_instance.id);
+    assert_eq!(Some(json!({ "values": [1, 2, 3] })), entity_instance.get("config"));
+}
+
+#[test]
+fn lazy_flow_instance_test() {
+    let id = Uuid::new_v4();
+    let inbound_id = Uuid::new_v4();
+    let s = json!({
+        "id": id,
+        "namespace": "logical",
+        "type_name": "and",
+        "entity_instances": [
+            { "id": id, "namespace": "logical", "type_name": "and", "properties": { "result": true } },
+            { "id": inbound_id, "namespace": "logical", "type_name": "not" }
+        ],
+        "relation_instances": [
+            {
+                "outbound_id": id,
+                "namespace": "connector",
+                "type_name": "default_connector",
+                "instance_id": "result__input",
+                "inbound_id": inbound_id,
+                "properties": { "outbound_property_name": "result" }
+            }
+        ]
+    })
+    .to_string();
+    let flow_instance: LazyFlowInstance = serde_json::from_str(&s).unwrap();
+    let properties = &flow_instance.relation_instances[0].properties;
+    assert_eq!(Some(r#""result""#), properties.get_raw("outbound_property_name"));
+    assert_eq!(Some("result".to_string()), properties.as_string("outbound_property_name"));
+
+    let flow_instance = FlowInstance::try_from(flow_instance).unwrap();
+    assert_eq!(2, flow_instance.entity_instances.len());
+    assert_eq!(Some(true), flow_instance.entity_instances[0].as_bool("result"));
+    assert_eq!("result__input", flow_instance.relation_instances[0].instance_id());
+}
+
+#[test]
+fn lazy_entity_instance_unparsable_value_test() {
+    let s = json!({
+        "namespace": "base",
+        "type_name": "config",
+        "id": Uuid::new_v4(),
+        "properties": {}
+    })
+    .to_string()
+    .replace("{}", r#"{ "value": 1e400 }"#);
+    let entity_instance: LazyEntityInstance = serde_json::from_str(&s).unwrap();
+    assert_eq!(Some("1e400"), entity_instance.properties.get_raw("value"));
+    // The getters treat the out of range number as missing
+    assert!(entity_instance.properties.contains("value"));
+    assert_eq!(None, entity_instance.properties.get("value"));
+    assert!(EntityInstance::try_from(entity_instance).is_err());
+}
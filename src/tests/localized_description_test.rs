@@ -0,0 +1,71 @@
+use serde_json::json;
+
+use crate::test_utils::assert_json_roundtrip;
+use crate::DataType;
+use crate::EntityType;
+use crate::EntityTypeDao;
+use crate::EntityTypeId;
+use crate::LanguageTag;
+use crate::PropertyType;
+
+#[test]
+fn language_tag_test() {
+    assert_eq!("de-AT", LanguageTag::new("DE_at").as_str());
+    assert_eq!("zh-Hant-TW", LanguageTag::new("zh-hant-tw").as_str());
+    assert_eq!("en", LanguageTag::from("EN").to_string());
+    assert_eq!(Some(LanguageTag::new("zh-Hant")), LanguageTag::new("zh-Hant-TW").parent());
+    assert_eq!(None, LanguageTag::new("de").parent());
+    assert_eq!(json!("de-AT"), serde_json::to_value(LanguageTag::new("de_at")).unwrap());
+    assert_eq!(LanguageTag::new("de-AT"), serde_json::from_value::<LanguageTag>(json!("de_AT")).unwrap());
+}
+
+#[test]
+fn entity_type_localized_description_test() {
+    let entity_type = EntityType::new(EntityTypeId::new_from_type("logical", "and"), "Logical and", Vec::new(), Vec::new(), Vec::new());
+    assert_eq!("Logical and", entity_type.localized_description(&LanguageTag::new("de")));
+    assert!(serde_json::to_value(&entity_type).unwrap().get("descriptions").is_none());
+
+    let entity_type = entity_type
+        .with_localized_description("de", "Logisches Und")
+        .with_localized_description("de-CH", "Logisches Und (CH)");
+    assert_eq!("Logisches Und", entity_type.localized_description(&LanguageTag::new("de")));
+    assert_eq!("Logisches Und", entity_type.localized_description(&LanguageTag::new("de-AT")));
+    assert_eq!("Logisches Und (CH)", entity_type.localized_description(&LanguageTag::new("de-ch")));
+    assert_eq!("Logical and", entity_type.localized_description(&LanguageTag::new("fr")));
+    assert_eq!(
+        json!({ "de": "Logisches Und", "de-CH": "Logisches Und (CH)" }),
+        serde_json::to_value(&entity_type).unwrap()["descriptions"]
+    );
+
+    let deserialized = assert_json_roundtrip(&entity_type);
+    assert_eq!(entity_type.descriptions, deserialized.descriptions);
+}
+
+#[test]
+fn property_type_localized_description_test() {
+    let property_type = PropertyType::new("result", DataType::Bool)
+        .with_description("The result")
+        .with_localized_description("de", "Das Ergebnis");
+    assert_eq!("Das Ergebnis", property_type.localized_description(&LanguageTag::new("de-DE")));
+    assert_eq!("The result", property_type.localized_description(&LanguageTag::new("en")));
+
+    let property_type: PropertyType = serde_json::from_value(json!({
+        "name": "result",
+        "data_type": "bool",
+        "description": "The result",
+        "descriptions": { "de_de": "Das Ergebnis" }
+    }))
+    .unwrap();
+    assert_eq!("Das Ergebnis", property_type.localized_description(&LanguageTag::new("de-DE")));
+}
+
+#[test]
+fn descriptions_serialized_in_order_test() {
+    let entity_type = ["fr", "de-CH", "en", "de", "it", "es"].into_iter().fold(
+        EntityType::new(EntityTypeId::new_from_type("logical", "and"), "", Vec::new(), Vec::new(), Vec::new()),
+        |entity_type, language| entity_type.with_localized_description(language, language),
+    );
+    let expected = r#""descriptions":{"de":"de","de-CH":"de-CH","en":"en","es":"es","fr":"fr","it":"it"}"#;
+    assert!(serde_json::to_string(&entity_type).unwrap().contains(expected));
+    assert!(serde_json::to_string(&EntityTypeDao::from(entity_type)).unwrap().contains(expected));
+}
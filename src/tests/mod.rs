@@ -1,16 +1,50 @@
 mod data_type_test;
 mod socket_type_test;
 
+mod abstract_entity_type_test;
+mod borrowed_dao_test;
+mod cardinality_test;
+mod compact_debug_test;
+mod component_dao_test;
 mod component_test;
+mod dao_parse_limits_test;
+mod dao_parse_mode_test;
+mod embedded_type_test;
+mod entity_type_inheritance_test;
 mod entity_type_test;
+mod flow_type_parameter_test;
 mod flow_type_test;
 mod property_type_test;
 mod relation_type_test;
+mod sub_flow_type_test;
+mod type_dao_test;
 
+mod aggregation_test;
+mod bulk_insert_test;
+mod concurrent_properties_test;
+mod connector_test;
+mod cow_properties_test;
 mod entity_instance_test;
+mod instance_event_test;
+mod instance_filter_test;
+mod pagination_test;
+mod property_projection_test;
+mod property_provenance_test;
+mod relation_endpoint_test;
 mod relation_instance_test;
 
+mod flow_instance_dao_test;
 mod flow_instance_test;
+mod format_compatibility_test;
+#[cfg(feature = "rmp-serde")]
+mod format_msgpack_test;
+#[cfg(feature = "ron")]
+mod format_ron_test;
+#[cfg(feature = "toml")]
+mod format_toml_test;
+#[cfg(feature = "serde_yaml")]
+mod format_yaml_test;
+mod graphql_sdl_test;
 
 mod reactive_entity_instance_test;
 mod reactive_property_instance_test;
@@ -18,15 +52,54 @@ mod reactive_relation_instance_test;
 
 mod reactive_flow_instance_test;
 
+#[cfg(feature = "proptest")]
+mod arbitrary_test;
+mod behaviour_binding_test;
+mod behaviour_state_test;
 mod behaviour_type_id_test;
 mod component_type_id_test;
+mod content_hash_test;
+mod deprecation_test;
+mod documentation_test;
 mod entity_type_id_test;
+#[cfg(feature = "fixtures")]
+mod fixtures_test;
 mod flow_type_id_test;
+mod json_schema_test;
+#[cfg(feature = "raw_value")]
+mod lazy_properties_test;
+mod localized_description_test;
+mod model_error_test;
+mod namespace_test;
+mod node_geometry_test;
+mod ordered_properties_test;
+mod property_conflict_test;
+mod property_layout_test;
 mod relation_instance_type_id_test;
 mod relation_type_id_test;
+mod reserved_namespaces_test;
+mod sanitize_test;
+#[cfg(feature = "schemars")]
+mod schemars_test;
+mod scoped_property_test;
+mod snapshot_test;
+mod static_type_id_test;
+mod tag_container_test;
+#[cfg(feature = "testing")]
+mod testing_test;
+mod type_alias_test;
+mod type_appearance_test;
 mod type_definition_test;
+mod type_example_test;
+mod type_id_serde_test;
 mod type_id_type_test;
 mod type_namespaced_type_test;
-
-#[tarpaulin::skip]
-pub mod utils;
+mod type_package_test;
+mod type_provider_test;
+mod type_search_test;
+mod type_system_bundle_test;
+mod type_system_event_test;
+mod unit_test;
+mod validation_error_test;
+#[cfg(feature = "prost")]
+mod wire_test;
@@ -0,0 +1,46 @@
+use indradb::Edge;
+use indradb::EdgeKey;
+use indradb::EdgeProperties;
+use indradb::Identifier;
+use uuid::Uuid;
+
+use crate::ComponentOrEntityTypeId;
+use crate::ComponentTypeId;
+use crate::EntityTypeId;
+use crate::ModelError;
+use crate::RelationInstance;
+use crate::TypeIdType;
+
+#[test]
+fn type_id_model_error_test() {
+    assert_eq!(Err(ModelError::InvalidTypeIdType("x".to_string())), TypeIdType::try_from("x"));
+    assert_eq!(
+        Err(ModelError::UnexpectedTypeIdType {
+            expected: TypeIdType::Component,
+            actual: TypeIdType::EntityType
+        }),
+        ComponentTypeId::try_from(&"e__logical__and".to_string())
+    );
+    assert_eq!(Err(ModelError::InvalidTypeId("c__base".to_string())), ComponentTypeId::try_from(&"c__base".to_string()));
+    assert_eq!(Err(ModelError::InvalidTypeId("c__base__".to_string())), ComponentTypeId::try_from(&"c__base__".to_string()));
+    assert_eq!(
+        Ok(ComponentTypeId::new_from_type("base", "labeled")),
+        ComponentTypeId::try_from(&"c__base__labeled".to_string())
+    );
+    assert_eq!(
+        Err(ModelError::UnexpectedTypeIdType {
+            expected: TypeIdType::EntityType,
+            actual: TypeIdType::Component
+        }),
+        EntityTypeId::try_from(ComponentOrEntityTypeId::from(ComponentTypeId::new_from_type("base", "labeled")))
+    );
+    assert_eq!("x is not a valid type of types", ModelError::InvalidTypeIdType("x".to_string()).to_string());
+}
+
+#[test]
+fn relation_instance_from_edge_properties_model_error_test() {
+    let t = Identifier::new("e__logical__and").unwrap();
+    let edge = Edge::new_with_current_datetime(EdgeKey::new(Uuid::new_v4(), t, Uuid::new_v4()));
+    let properties = EdgeProperties::new(edge, Vec::new());
+    assert!(matches!(RelationInstance::try_from(properties), Err(ModelError::UnexpectedTypeIdType { .. })));
+}
@@ -0,0 +1,74 @@
+use serde_json::json;
+
+use crate::EntityType;
+use crate::EntityTypeId;
+use crate::Namespace;
+use crate::NamespacedTypeGetter;
+use crate::TypeDefinition;
+use crate::TypeQuery;
+use crate::TypeSystem;
+
+#[test]
+fn namespace_segments_test() {
+    let namespace = Namespace::from("core::math::arithmetic");
+    assert_eq!(&["core".to_string(), "math".to_string(), "arithmetic".to_string()], namespace.segments());
+    assert_eq!(3, namespace.depth());
+    assert_eq!(Some("arithmetic"), namespace.last_segment());
+    assert_eq!("core::math::arithmetic", namespace.to_flat());
+    assert_eq!("core::math::arithmetic", namespace.to_string());
+    assert_eq!(Namespace::new(["core", "math"]), namespace.parent().unwrap());
+    assert_eq!(namespace, Namespace::from("core::math").child("arithmetic"));
+    assert_eq!(Namespace::from("logical"), Namespace::new(vec!["logical"]));
+    assert!(Namespace::from("").is_root());
+    assert!(Namespace::root().parent().is_none());
+    // Empty segments are skipped
+    assert_eq!(Namespace::from("core::math"), Namespace::from("core::::math"));
+}
+
+#[test]
+fn namespace_prefix_test() {
+    let core = Namespace::from("core");
+    let math = Namespace::from("core::math");
+    let arithmetic = Namespace::from("core::math::arithmetic");
+    assert!(arithmetic.starts_with(&math));
+    assert!(arithmetic.starts_with(&arithmetic));
+    assert!(arithmetic.starts_with(&Namespace::root()));
+    assert!(!math.starts_with(&arithmetic));
+    assert!(!Namespace::from("core_extra").starts_with(&core));
+    assert!(core.is_ancestor_of(&arithmetic));
+    assert!(!arithmetic.is_ancestor_of(&arithmetic));
+    assert!(core < math && math < arithmetic);
+}
+
+#[test]
+fn namespace_serde_test() {
+    let namespace = Namespace::from("core::math");
+    assert_eq!(json!("core::math"), serde_json::to_value(&namespace).unwrap());
+    assert_eq!(namespace, serde_json::from_value::<Namespace>(json!("core::math")).unwrap());
+    assert_eq!(namespace, "core::math".parse::<Namespace>().unwrap());
+}
+
+#[test]
+fn hierarchical_namespace_of_types_test() {
+    let ty = EntityTypeId::new_from_type("core::math::arithmetic", "add");
+    assert_eq!(Namespace::from("core::math::arithmetic"), ty.hierarchical_namespace());
+    assert_eq!("e__core::math::arithmetic__add", ty.to_string());
+
+    let type_system = TypeSystem::new(
+        Vec::new(),
+        vec![
+            EntityType::new(ty, "", Vec::new(), Vec::new(), Vec::new()),
+            EntityType::new(EntityTypeId::new_from_type("core::math", "pi"), "", Vec::new(), Vec::new(), Vec::new()),
+            EntityType::new(EntityTypeId::new_from_type("core::logical", "and"), "", Vec::new(), Vec::new(), Vec::new()),
+        ],
+        Vec::new(),
+        Vec::new(),
+    );
+    assert_eq!(
+        vec![
+            TypeDefinition::entity_type("core::math::arithmetic", "add"),
+            TypeDefinition::entity_type("core::math", "pi")
+        ],
+        type_system.search(&TypeQuery::new().in_namespace("core::math"))
+    );
+}
@@ -0,0 +1,52 @@
+use uuid::Uuid;
+
+use crate::test_utils::assert_json_roundtrip;
+use crate::test_utils::create_entity_instance;
+use crate::test_utils::r_string;
+use crate::FlowInstance;
+use crate::NodeGeometry;
+
+#[test]
+fn node_geometry_test() {
+    let geometry = NodeGeometry::new(10.0, 20.0);
+    assert_eq!(None, geometry.width);
+    let geometry = geometry.with_size(100.0, 50.0).translate(5.0, -5.0);
+    assert_eq!(NodeGeometry::new(15.0, 15.0).with_size(100.0, 50.0), geometry);
+    assert_eq!(geometry, assert_json_roundtrip(&geometry));
+    assert!(serde_json::to_value(NodeGeometry::new(0.0, 0.0)).unwrap().get("width").is_none());
+}
+
+#[test]
+fn entity_instance_node_geometry_test() {
+    let entity_instance = create_entity_instance(r_string());
+    assert_eq!(None, entity_instance.node_geometry().unwrap());
+    let mut entity_instance = entity_instance.with_node_geometry(NodeGeometry::new(1.0, 2.0)).unwrap();
+    assert_eq!(Some(NodeGeometry::new(1.0, 2.0)), entity_instance.node_geometry().unwrap());
+    entity_instance.set_node_geometry(NodeGeometry::new(3.0, 4.0).with_size(5.0, 6.0)).unwrap();
+    assert_eq!(Some(NodeGeometry::new(3.0, 4.0).with_size(5.0, 6.0)), entity_instance.node_geometry().unwrap());
+    assert_eq!(1, entity_instance.extensions.len());
+}
+
+#[test]
+fn entity_instance_invalid_node_geometry_test() {
+    // Numbers which aren't finite are written as null, which isn't a valid coordinate
+    let entity_instance = create_entity_instance(r_string()).with_node_geometry(NodeGeometry::new(f64::NAN, 2.0)).unwrap();
+    assert!(entity_instance.node_geometry().is_err());
+    let mut flow_instance = FlowInstance::from_instance_with_name(create_entity_instance(r_string()), r_string());
+    flow_instance.entity_instances.push(entity_instance);
+    assert!(flow_instance.node_geometries().is_err());
+}
+
+#[test]
+fn flow_instance_node_geometries_test() {
+    let wrapper_entity_instance = create_entity_instance(r_string());
+    let mut flow_instance = FlowInstance::from_instance_with_name(wrapper_entity_instance, r_string());
+    let entity_instance = create_entity_instance(r_string()).with_node_geometry(NodeGeometry::new(1.0, 2.0)).unwrap();
+    let id = entity_instance.id;
+    flow_instance.entity_instances.push(entity_instance);
+    flow_instance.entity_instances.push(create_entity_instance(r_string()));
+    let geometries = flow_instance.node_geometries().unwrap();
+    assert_eq!(1, geometries.len());
+    assert_eq!(Some(&NodeGeometry::new(1.0, 2.0)), geometries.get(&id));
+    assert_eq!(None, geometries.get(&Uuid::new_v4()));
+}
@@ -0,0 +1,39 @@
+use std::collections::HashMap;
+
+use serde_json::json;
+
+use crate::serialize_ordered_map;
+
+#[test]
+fn serialize_ordered_map_test() {
+    let mut properties = HashMap::new();
+    for name in ["d", "b", "a", "e", "c"] {
+        properties.insert(name.to_string(), json!(name));
+    }
+    let mut serialized = Vec::new();
+    serialize_ordered_map(&properties, &mut serde_json::Serializer::new(&mut serialized)).unwrap();
+    assert_eq!(r#"{"a":"a","b":"b","c":"c","d":"d","e":"e"}"#, String::from_utf8(serialized).unwrap());
+}
+
+#[test]
+#[cfg(feature = "ordered_properties")]
+fn entity_instance_ordered_properties_test() {
+    use crate::EntityInstance;
+    use uuid::Uuid;
+
+    let mut properties = HashMap::new();
+    for i in 0..100 {
+        properties.insert(format!("property_{:03}", i), json!(i));
+    }
+    let mut reversed_properties = HashMap::new();
+    for i in (0..100).rev() {
+        reversed_properties.insert(format!("property_{:03}", i), json!(i));
+    }
+    let id = Uuid::new_v4();
+    let entity_instance = EntityInstance::new_from_type("namespace", "type_name", id, properties);
+    let serialized = serde_json::to_string(&entity_instance).unwrap();
+    let positions: Vec<usize> = (0..100).map(|i| serialized.find(&format!("\"property_{:03}\"", i)).unwrap()).collect();
+    assert!(positions.windows(2).all(|w| w[0] < w[1]));
+    let other_entity_instance = EntityInstance::new_from_type("namespace", "type_name", id, reversed_properties);
+    assert_eq!(serialized, serde_json::to_string(&other_entity_instance).unwrap());
+}
@@ -0,0 +1,106 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use serde_json::json;
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::sort_entity_instances;
+use crate::test_utils::assert_json_roundtrip_eq;
+use crate::test_utils::r_string;
+use crate::EntityInstance;
+use crate::EntityTypeId;
+use crate::OrderBy;
+use crate::Pagination;
+use crate::PropertyInstanceGetter;
+use crate::SortDirection;
+
+fn entity_instance(ty: &EntityTypeId, name: &str, count: Option<i64>) -> EntityInstance {
+    let mut properties = HashMap::new();
+    properties.insert("name".to_string(), json!(name));
+    if let Some(count) = count {
+        properties.insert("count".to_string(), json!(count));
+    }
+    EntityInstance::new(ty.clone(), Uuid::new_v4(), properties)
+}
+
+fn names(entity_instances: &[EntityInstance]) -> Vec<String> {
+    entity_instances
+        .iter()
+        .filter_map(|entity_instance| entity_instance.as_string("name"))
+        .collect()
+}
+
+#[test]
+fn sort_entity_instances_test() {
+    let ty = EntityTypeId::new_from_type(r_string(), r_string());
+    let mut entity_instances = vec![
+        entity_instance(&ty, "c", Some(1)),
+        entity_instance(&ty, "a", None),
+        entity_instance(&ty, "b", Some(2)),
+        entity_instance(&ty, "d", Some(1)),
+    ];
+    sort_entity_instances(&mut entity_instances, &[OrderBy::ascending("name")]);
+    assert_eq!(vec!["a", "b", "c", "d"], names(&entity_instances));
+    // Instances without the property are last
+    sort_entity_instances(&mut entity_instances, &[OrderBy::descending("count"), OrderBy::descending("name")]);
+    assert_eq!(vec!["b", "d", "c", "a"], names(&entity_instances));
+    sort_entity_instances(&mut entity_instances, &[OrderBy::ascending("count")]);
+    assert_eq!(vec!["d", "c", "b", "a"], names(&entity_instances));
+}
+
+#[test]
+fn order_by_compare_mixed_types_test() {
+    let order_by = OrderBy::ascending("value");
+    let values = vec![
+        json!(null),
+        json!(false),
+        json!(true),
+        json!(-1),
+        json!(1),
+        json!(1.5),
+        json!("a"),
+        json!("b"),
+        json!([1]),
+        json!([1, 2]),
+        json!([2]),
+        json!({ "a": 1 }),
+        json!({ "a": 2 }),
+        json!({ "b": 1 }),
+    ];
+    let properties: Vec<HashMap<String, Value>> = values.iter().map(|value| HashMap::from([("value".to_string(), value.clone())])).collect();
+    for (i, a) in properties.iter().enumerate() {
+        for (j, b) in properties.iter().enumerate() {
+            assert_eq!(i.cmp(&j), order_by.compare(a, b), "{} <=> {}", values[i], values[j]);
+        }
+    }
+    assert_eq!(Ordering::Equal, order_by.compare(&properties[4], &HashMap::from([("value".to_string(), json!(1.0))])));
+    assert_eq!(Ordering::Greater, OrderBy::descending("value").compare(&properties[0], &properties[13]));
+}
+
+#[test]
+fn pagination_test() {
+    let results: Vec<usize> = (0..10).collect();
+    assert_eq!(&results[..], Pagination::default().apply(&results));
+    assert_eq!(&[3, 4], Pagination::new(3, Some(2)).apply(&results));
+    assert_eq!(&[8, 9], Pagination::page(4, 2).apply(&results));
+    assert!(Pagination::page(5, 2).apply(&results).is_empty());
+    assert_eq!(&[9], Pagination::default().with_offset(9).with_limit(5).apply(&results));
+    assert!(Pagination::default().with_offset(20).apply(&results).is_empty());
+    assert!(Pagination::page(usize::MAX, usize::MAX).apply(&results).is_empty());
+}
+
+#[test]
+fn order_by_and_pagination_serde_test() {
+    let order_by: OrderBy = serde_json::from_value(json!({ "property": "name" })).unwrap();
+    assert_eq!(OrderBy::ascending("name"), order_by);
+    let order_by: OrderBy = serde_json::from_value(json!({ "property": "name", "direction": "desc" })).unwrap();
+    assert_eq!(OrderBy::new("name", SortDirection::Descending), order_by);
+    assert_eq!(json!({ "property": "name", "direction": "descending" }), serde_json::to_value(&order_by).unwrap());
+    assert_json_roundtrip_eq(&order_by);
+
+    let pagination: Pagination = serde_json::from_value(json!({})).unwrap();
+    assert_eq!(Pagination::default(), pagination);
+    assert_eq!(json!({ "offset": 10, "limit": 5 }), serde_json::to_value(Pagination::page(2, 5)).unwrap());
+    assert_json_roundtrip_eq(&Pagination::new(1, None));
+}
@@ -0,0 +1,83 @@
+use crate::Component;
+use crate::ComponentTypeId;
+use crate::DataType;
+use crate::EntityType;
+use crate::EntityTypeId;
+use crate::PropertyType;
+use crate::SocketType;
+use crate::TypeSystem;
+
+#[test]
+fn detect_property_conflicts_test() {
+    let labeled = Component::new_without_extensions(ComponentTypeId::new_from_type("base", "labeled"), "", vec![PropertyType::string("label")]);
+    let named = Component::new_without_extensions(
+        ComponentTypeId::new_from_type("base", "named"),
+        "",
+        vec![PropertyType::string("label"), PropertyType::input("value", DataType::Number)],
+    );
+    let counted = Component::new_without_extensions(
+        ComponentTypeId::new_from_type("base", "counted"),
+        "",
+        vec![PropertyType::number("label"), PropertyType::output("value", DataType::Number)],
+    );
+    let entity_type = EntityType::new(
+        EntityTypeId::new_from_type("test", "conflicting"),
+        "",
+        vec![labeled.ty.clone(), named.ty.clone(), counted.ty.clone()],
+        Vec::new(),
+        Vec::new(),
+    );
+    let type_system = TypeSystem::new(vec![labeled.clone(), named.clone(), counted.clone()], vec![entity_type.clone()], Vec::new(), Vec::new());
+
+    let conflicts = type_system.detect_property_conflicts(&entity_type);
+    assert_eq!(2, conflicts.len());
+    assert_eq!("label", conflicts[0].property_name);
+    assert_eq!(3, conflicts[0].declarations.len());
+    assert_eq!(counted.ty, conflicts[0].declarations[2].component);
+    assert_eq!(DataType::Number, conflicts[0].declarations[2].data_type);
+    assert!(conflicts[0].has_data_type_conflict());
+    assert!(!conflicts[0].has_socket_type_conflict());
+    assert_eq!("value", conflicts[1].property_name);
+    assert_eq!(SocketType::Output, conflicts[1].declarations[1].socket_type);
+    assert!(!conflicts[1].has_data_type_conflict());
+    assert!(conflicts[1].has_socket_type_conflict());
+    assert!(type_system.detect_property_conflicts(&entity_type.with_scoped_properties()).is_empty());
+}
+
+#[test]
+fn detect_scoped_property_conflicts_test() {
+    let core_labeled = Component::new_without_extensions(ComponentTypeId::new_from_type("core", "labeled"), "", vec![PropertyType::string("label")]);
+    let ui_labeled = Component::new_without_extensions(ComponentTypeId::new_from_type("ui", "labeled"), "", vec![PropertyType::number("label")]);
+    let entity_type = EntityType::new(
+        EntityTypeId::new_from_type("test", "scoped"),
+        "",
+        vec![core_labeled.ty.clone(), ui_labeled.ty.clone()],
+        Vec::new(),
+        Vec::new(),
+    )
+    .with_scoped_properties();
+    let type_system = TypeSystem::new(vec![core_labeled, ui_labeled.clone()], vec![entity_type.clone()], Vec::new(), Vec::new());
+
+    // Both components have the same type name, so their qualified property names collide
+    let conflicts = type_system.detect_property_conflicts(&entity_type);
+    assert_eq!(1, conflicts.len());
+    assert_eq!("labeled.label", conflicts[0].property_name);
+    assert_eq!(ui_labeled.ty, conflicts[0].declarations[1].component);
+    assert!(conflicts[0].has_data_type_conflict());
+}
+
+#[test]
+fn detect_no_property_conflicts_test() {
+    let labeled = Component::new_without_extensions(ComponentTypeId::new_from_type("base", "labeled"), "", vec![PropertyType::string("label")]);
+    let named = Component::new_without_extensions(ComponentTypeId::new_from_type("base", "named"), "", vec![PropertyType::string("label")]);
+    let unknown = ComponentTypeId::new_from_type("base", "unknown");
+    let entity_type = EntityType::new(
+        EntityTypeId::new_from_type("test", "compatible"),
+        "",
+        vec![labeled.ty.clone(), named.ty.clone(), unknown],
+        vec![PropertyType::number("label")],
+        Vec::new(),
+    );
+    let type_system = TypeSystem::new(vec![labeled, named], vec![entity_type.clone()], Vec::new(), Vec::new());
+    assert!(type_system.detect_property_conflicts(&entity_type).is_empty());
+}
@@ -0,0 +1,53 @@
+use serde_json::json;
+
+use crate::group_property_types;
+use crate::test_utils::assert_json_roundtrip;
+use crate::PropertyLayout;
+use crate::PropertyType;
+
+fn property_names(properties: &[&PropertyType]) -> Vec<String> {
+    properties.iter().map(|property_type| property_type.name.to_string()).collect()
+}
+
+#[test]
+fn property_layout_test() {
+    let property_type = PropertyType::string("label");
+    assert_eq!(None, property_type.layout().unwrap());
+    assert!(property_type.extensions.is_empty());
+
+    let property_type = property_type.with_group("appearance").unwrap().with_order(2).unwrap();
+    assert_eq!(Some(PropertyLayout::new("appearance", 2)), property_type.layout().unwrap());
+    assert_eq!(Some("appearance".to_string()), property_type.group().unwrap());
+    assert_eq!(Some(2), property_type.order().unwrap());
+    assert_eq!(1, property_type.extensions.len());
+
+    let property_type = property_type.with_group("general").unwrap();
+    assert_eq!(Some(PropertyLayout::new("general", 2)), property_type.layout().unwrap());
+    let deserialized = assert_json_roundtrip(&property_type);
+    assert_eq!(property_type.layout().unwrap(), deserialized.layout().unwrap());
+
+    let mut property_type = property_type;
+    property_type.extensions[0].extension = json!({"order": "first"});
+    assert!(property_type.layout().is_err());
+    assert!(property_type.with_order(1).is_err());
+}
+
+#[test]
+fn group_property_types_test() {
+    let property_types = vec![
+        PropertyType::string("note"),
+        PropertyType::number("y").with_group("position").unwrap().with_order(2).unwrap(),
+        PropertyType::string("label").with_group("general").unwrap().with_order(1).unwrap(),
+        PropertyType::number("x").with_group("position").unwrap().with_order(1).unwrap(),
+        PropertyType::string("comment"),
+        PropertyType::bool("visible").with_group("general").unwrap(),
+        PropertyType::number("z").with_group("position").unwrap().with_order(3).unwrap(),
+    ];
+    let groups = group_property_types(&property_types).unwrap();
+    let names: Vec<Option<String>> = groups.iter().map(|group| group.name.clone()).collect();
+    assert_eq!(vec![Some("general".to_string()), Some("position".to_string()), None], names);
+    assert_eq!(vec!["label", "visible"], property_names(&groups[0].properties));
+    assert_eq!(vec!["x", "y", "z"], property_names(&groups[1].properties));
+    assert_eq!(vec!["note", "comment"], property_names(&groups[2].properties));
+    assert!(group_property_types(&[]).unwrap().is_empty());
+}
@@ -0,0 +1,68 @@
+use std::collections::HashMap;
+
+use serde_json::json;
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::test_utils::assert_json_roundtrip_eq;
+use crate::test_utils::r_string;
+use crate::EntityInstance;
+use crate::EntityTypeId;
+use crate::PropertyProjection;
+use crate::RelationInstance;
+use crate::RelationInstanceTypeId;
+
+fn properties() -> HashMap<String, Value> {
+    let mut properties = HashMap::new();
+    properties.insert("name".to_string(), json!("inexor"));
+    properties.insert("position_x".to_string(), json!(1));
+    properties.insert("position_y".to_string(), json!(2));
+    properties
+}
+
+fn property_names(properties: &HashMap<String, Value>) -> Vec<&str> {
+    let mut property_names: Vec<&str> = properties.keys().map(String::as_str).collect();
+    property_names.sort();
+    property_names
+}
+
+#[test]
+fn property_projection_contains_test() {
+    assert!(PropertyProjection::All.contains("name"));
+    assert!(PropertyProjection::include(["name", "position_*"]).contains("position_x"));
+    assert!(!PropertyProjection::include(["name"]).contains("position_x"));
+    assert!(!PropertyProjection::exclude(["position_?"]).contains("position_x"));
+    assert!(PropertyProjection::exclude(["position_?"]).contains("name"));
+    assert!(!PropertyProjection::include(Vec::<String>::new()).contains("name"));
+}
+
+#[test]
+fn entity_instance_project_test() {
+    let entity_instance = EntityInstance::new(EntityTypeId::new_from_type(r_string(), r_string()), Uuid::new_v4(), properties()).with_description(r_string());
+    let projected = entity_instance.project(&PropertyProjection::include(["name"]));
+    assert_eq!(entity_instance.id, projected.id);
+    assert_eq!(entity_instance.ty, projected.ty);
+    assert_eq!(entity_instance.description, projected.description);
+    assert_eq!(vec!["name"], property_names(&projected.properties));
+    let projected = entity_instance.project(&PropertyProjection::exclude(["name"]));
+    assert_eq!(vec!["position_x", "position_y"], property_names(&projected.properties));
+    // Projecting all properties doesn't copy the properties
+    let projected = entity_instance.project(&PropertyProjection::All);
+    assert!(projected.properties.ptr_eq(&entity_instance.properties));
+}
+
+#[test]
+fn relation_instance_project_test() {
+    let ty = RelationInstanceTypeId::new_from_type_unique_id(r_string(), r_string());
+    let relation_instance = RelationInstance::new(Uuid::new_v4(), ty, Uuid::new_v4(), properties());
+    let projected = relation_instance.project(&PropertyProjection::include(["position_*"]));
+    assert_eq!(relation_instance.get_key(), projected.get_key());
+    assert_eq!(vec!["position_x", "position_y"], property_names(&projected.properties));
+}
+
+#[test]
+fn property_projection_serde_test() {
+    assert_eq!(json!("all"), serde_json::to_value(PropertyProjection::All).unwrap());
+    assert_eq!(json!({ "include": ["name"] }), serde_json::to_value(PropertyProjection::include(["name"])).unwrap());
+    assert_json_roundtrip_eq(&PropertyProjection::exclude(["position_*"]));
+}
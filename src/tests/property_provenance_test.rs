@@ -0,0 +1,71 @@
+use std::collections::HashMap;
+
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::test_utils::r_string;
+use crate::Component;
+use crate::ComponentTypeId;
+use crate::EntityInstance;
+use crate::EntityType;
+use crate::EntityTypeId;
+use crate::PropertyProvenance;
+use crate::PropertyType;
+
+fn component(property_names: &[&str]) -> Component {
+    let properties = property_names.iter().map(|property_name| PropertyType::string(*property_name)).collect();
+    Component::new_without_extensions(ComponentTypeId::new_from_type(r_string(), r_string()), r_string(), properties)
+}
+
+#[test]
+fn entity_type_property_provenance_test() {
+    let first_component = component(&["name", "x"]);
+    let second_component = component(&["x", "y"]);
+    let missing_component = component(&["z"]);
+    let entity_type = EntityType::new(
+        EntityTypeId::new_from_type(r_string(), r_string()),
+        r_string(),
+        vec![first_component.ty.clone(), second_component.ty.clone(), missing_component.ty.clone()],
+        vec![PropertyType::string("name")],
+        Vec::new(),
+    );
+    let provenance = entity_type.property_provenance(&[second_component.clone(), first_component.clone()]);
+    assert_eq!(3, provenance.len());
+    assert_eq!(Some(&PropertyProvenance::OwnType), provenance.get("name"));
+    assert_eq!(Some(&PropertyProvenance::Component(first_component.ty.clone())), provenance.get("x"));
+    assert_eq!(Some(&PropertyProvenance::Component(second_component.ty.clone())), provenance.get("y"));
+    assert!(!provenance.contains_key("z"));
+}
+
+#[test]
+fn entity_instance_property_provenance_test() {
+    let component = component(&["x"]);
+    let entity_type = EntityType::new(
+        EntityTypeId::new_from_type(r_string(), r_string()),
+        r_string(),
+        vec![component.ty.clone()],
+        vec![PropertyType::string("name"), PropertyType::string("unset")],
+        Vec::new(),
+    );
+    let mut properties = HashMap::new();
+    properties.insert("name".to_string(), json!("a"));
+    properties.insert("x".to_string(), json!("b"));
+    let entity_instance = EntityInstance::new(entity_type.ty.clone(), Uuid::new_v4(), properties);
+    assert!(entity_instance.property_provenance().unwrap().is_none());
+
+    let provenance = entity_type.property_provenance(&[component.clone()]);
+    let entity_instance = entity_instance
+        .with_property_provenance(&provenance)
+        .unwrap()
+        .with_property_provenance(&provenance)
+        .unwrap();
+    assert_eq!(1, entity_instance.extensions.len());
+    let recorded = entity_instance.property_provenance().unwrap().unwrap();
+    assert_eq!(2, recorded.len());
+    assert_eq!(Some(&PropertyProvenance::OwnType), recorded.get("name"));
+    assert_eq!(Some(&PropertyProvenance::Component(component.ty)), recorded.get("x"));
+
+    let mut entity_instance = entity_instance;
+    entity_instance.extensions[0].extension = json!(["name"]);
+    assert!(entity_instance.property_provenance().is_err());
+}
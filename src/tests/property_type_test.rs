@@ -1,4 +1,7 @@
-use crate::tests::utils::r_string;
+use std::collections::HashMap;
+
+use crate::identifier_string;
+use crate::test_utils::r_string;
 use crate::DataType;
 use crate::Mutability;
 use crate::PropertyType;
@@ -9,11 +12,15 @@ fn property_type_test() {
     let property_name = r_string();
 
     let property_type = PropertyType {
-        name: property_name.clone(),
+        name: identifier_string(property_name.clone()),
         description: String::new(),
+        descriptions: HashMap::new(),
+        documentation: None,
         data_type: DataType::String,
         socket_type: SocketType::None,
         mutability: Mutability::Mutable,
+        unit: None,
+        examples: Vec::new(),
         extensions: Vec::new(),
     };
 
@@ -26,11 +33,15 @@ fn property_type_serde_test() {
     let property_name = r_string();
 
     let property_type = PropertyType {
-        name: property_name.clone(),
+        name: identifier_string(property_name.clone()),
         description: String::new(),
+        descriptions: HashMap::new(),
+        documentation: None,
         data_type: DataType::String,
         socket_type: SocketType::None,
         mutability: Mutability::Mutable,
+        unit: None,
+        examples: Vec::new(),
         extensions: Vec::new(),
     };
 
@@ -54,6 +65,20 @@ fn property_type_new_test() {
     assert_eq!(SocketType::None, property_type.socket_type);
 }
 
+#[test]
+fn property_type_with_test() {
+    let property_name = r_string();
+    let description = r_string();
+    let property_type = PropertyType::new(property_name.clone(), DataType::Number)
+        .with_description(description.clone())
+        .with_socket_type(SocketType::Output)
+        .with_mutability(Mutability::Immutable);
+    assert_eq!(property_name, property_type.name);
+    assert_eq!(description, property_type.description);
+    assert_eq!(SocketType::Output, property_type.socket_type);
+    assert_eq!(Mutability::Immutable, property_type.mutability);
+}
+
 #[test]
 fn property_type_new_with_socket_test() {
     let property_name = r_string();
@@ -143,3 +168,16 @@ fn property_type_object_test() {
     assert_eq!(DataType::Object, property_type.data_type);
     assert_eq!(SocketType::None, property_type.socket_type);
 }
+
+#[test]
+fn property_type_default_test() {
+    let property_type = PropertyType {
+        name: identifier_string("value"),
+        ..Default::default()
+    };
+    assert_eq!("value", property_type.name);
+    assert_eq!(DataType::String, property_type.data_type);
+    assert_eq!(SocketType::None, property_type.socket_type);
+    assert_eq!(Mutability::Mutable, property_type.mutability);
+    assert!(property_type.extensions.is_empty());
+}
@@ -14,9 +14,9 @@ use serde_json::json;
 use uuid::Uuid;
 
 use crate::property_identifier;
-use crate::tests::utils::create_random_entity_instance;
-use crate::tests::utils::r_json_string;
-use crate::tests::utils::r_string;
+use crate::test_utils::create_random_entity_instance;
+use crate::test_utils::r_json_string;
+use crate::test_utils::r_string;
 use crate::BehaviourTypeId;
 use crate::Component;
 use crate::ComponentContainer;
@@ -5,10 +5,10 @@ use std::sync::Arc;
 use serde_json::json;
 use uuid::Uuid;
 
-use crate::tests::utils::create_random_entity_instance;
-use crate::tests::utils::create_random_entity_instance_with_type;
-use crate::tests::utils::create_random_relation_instance;
-use crate::tests::utils::r_string;
+use crate::test_utils::create_random_entity_instance;
+use crate::test_utils::create_random_entity_instance_with_type;
+use crate::test_utils::create_random_relation_instance;
+use crate::test_utils::r_string;
 use crate::FlowInstance;
 use crate::FlowInstanceCreationError;
 use crate::NamespacedTypeGetter;
@@ -118,6 +118,8 @@ fn reactive_flow_test_try_into() {
     reactive_flow_instance.add_entity(third_entity_instance.clone());
     let relation_instance = Arc::new(create_random_relation_instance(second_entity_instance.clone(), third_entity_instance.clone(), r_string()));
     reactive_flow_instance.add_relation(relation_instance.clone());
+    assert_eq!(second_entity_instance.id, relation_instance.outbound.id);
+    assert_eq!(third_entity_instance.id, relation_instance.inbound.id);
 
     let flow_instance: FlowInstance = reactive_flow_instance.try_into().unwrap();
     assert_eq!(wrapper_entity_instance.id, flow_instance.id);
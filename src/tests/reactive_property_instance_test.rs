@@ -17,7 +17,7 @@ use serde_json::json;
 use stopwatch2::Stopwatch;
 use uuid::Uuid;
 
-use crate::tests::utils::r_string;
+use crate::test_utils::r_string;
 use crate::ReactivePropertyInstance;
 
 #[test]
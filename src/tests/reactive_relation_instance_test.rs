@@ -12,9 +12,9 @@ use serde_json::json;
 use uuid::Uuid;
 
 use crate::property_identifier;
-use crate::tests::utils::create_random_entity_instance::create_random_entity_instance;
-use crate::tests::utils::r_json_string;
-use crate::tests::utils::r_string;
+use crate::test_utils::create_random_entity_instance::create_random_entity_instance;
+use crate::test_utils::r_json_string;
+use crate::test_utils::r_string;
 use crate::BehaviourTypeId;
 use crate::Component;
 use crate::ComponentContainer;
@@ -270,8 +270,6 @@ fn create_random_relation_instance(
     let inbound_id = inbound_entity.id;
     let edge_key = EdgeKey::new(outbound_id, ty.type_id(), inbound_id);
     let edge_properties = EdgeProperties::new(Edge::new_with_current_datetime(edge_key), properties.clone());
-    let outbound_entity = outbound_entity.clone();
-    let inbound_entity = outbound_entity.clone();
     ReactiveRelationInstance::new_from_properties(outbound_entity, inbound_entity, edge_properties).unwrap()
 }
 
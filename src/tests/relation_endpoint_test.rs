@@ -0,0 +1,173 @@
+use uuid::Uuid;
+
+use crate::Component;
+use crate::ComponentOrEntityTypeId;
+use crate::ComponentTypeId;
+use crate::EntityInstance;
+use crate::EntityType;
+use crate::EntityTypeId;
+use crate::NamespacedTypeGetter;
+use crate::RelationEndpoint;
+use crate::RelationInstance;
+use crate::RelationInstanceTypeId;
+use crate::RelationType;
+use crate::RelationTypeId;
+use crate::TypeSystem;
+use crate::ValidationError;
+
+fn create_type_system() -> TypeSystem {
+    let positionable = Component::new_without_extensions(ComponentTypeId::new_from_type("base", "positionable"), "", Vec::new())
+        .with_alias(ComponentTypeId::new_from_type("base", "positioned"));
+    let unit = EntityType::new(EntityTypeId::new_from_type("game", "unit"), "", vec![positionable.ty.clone()], Vec::new(), Vec::new());
+    let tank = EntityType::new(EntityTypeId::new_from_type("game", "tank"), "", Vec::new(), Vec::new(), Vec::new()).with_parent(unit.ty.clone());
+    let marker = EntityType::new(
+        EntityTypeId::new_from_type("game", "marker"),
+        "",
+        vec![ComponentTypeId::new_from_type("base", "positioned")],
+        Vec::new(),
+        Vec::new(),
+    );
+    let player = EntityType::new(EntityTypeId::new_from_type("game", "player"), "", Vec::new(), Vec::new(), Vec::new());
+    let near = RelationType::new(
+        ComponentOrEntityTypeId::Component(positionable.ty.clone()),
+        RelationTypeId::new_from_type("game", "near"),
+        ComponentOrEntityTypeId::Component(positionable.ty.clone()),
+        "",
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+    );
+    let commands = RelationType::new(
+        player.ty.clone(),
+        RelationTypeId::new_from_type("game", "commands"),
+        unit.ty.clone(),
+        "",
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+    );
+    TypeSystem::new(vec![positionable], vec![unit, tank, marker, player], vec![near, commands], Vec::new())
+}
+
+fn entity_type_names(entity_types: Vec<&EntityType>) -> Vec<String> {
+    entity_types.iter().map(|entity_type| entity_type.ty.type_name()).collect()
+}
+
+#[test]
+fn endpoint_entity_types_test() {
+    let type_system = create_type_system();
+    let positionable = ComponentOrEntityTypeId::Component(ComponentTypeId::new_from_type("base", "positionable"));
+    assert_eq!(vec!["unit", "tank", "marker"], entity_type_names(type_system.endpoint_entity_types(&positionable)));
+    let unit = ComponentOrEntityTypeId::EntityType(EntityTypeId::new_from_type("game", "unit"));
+    assert_eq!(vec!["unit", "tank"], entity_type_names(type_system.endpoint_entity_types(&unit)));
+}
+
+#[test]
+fn relation_types_at_endpoint_test() {
+    let type_system = create_type_system();
+    let tank = type_system.get_entity_type(&EntityTypeId::new_from_type("game", "tank")).unwrap();
+    let outbound: Vec<String> = type_system
+        .relation_types_at_endpoint(tank, RelationEndpoint::Outbound)
+        .iter()
+        .map(|relation_type| relation_type.ty.type_name())
+        .collect();
+    assert_eq!(vec!["near"], outbound);
+    let inbound: Vec<String> = type_system
+        .relation_types_at_endpoint(tank, RelationEndpoint::Inbound)
+        .iter()
+        .map(|relation_type| relation_type.ty.type_name())
+        .collect();
+    assert_eq!(vec!["near", "commands"], inbound);
+}
+
+#[test]
+fn validate_relation_endpoints_test() {
+    let type_system = create_type_system();
+    let marker = EntityInstance::new_without_properties(EntityTypeId::new_from_type("game", "marker"), Uuid::new_v4());
+    let tank = EntityInstance::new_without_properties(EntityTypeId::new_from_type("game", "tank"), Uuid::new_v4());
+    let player = EntityInstance::new_without_properties(EntityTypeId::new_from_type("game", "player"), Uuid::new_v4());
+    let near = RelationInstance::new_without_properties(marker.id, RelationInstanceTypeId::new_from_type_unique_id("game", "near"), tank.id);
+    assert_eq!(Ok(()), type_system.validate_relation_endpoints(&near, &marker, &tank));
+    assert_eq!(
+        Err(ValidationError::UnsatisfiedEndpoint {
+            ty: RelationTypeId::new_from_type("game", "near"),
+            endpoint: RelationEndpoint::Inbound,
+            expected: ComponentOrEntityTypeId::Component(ComponentTypeId::new_from_type("base", "positionable")),
+            actual: player.ty.clone(),
+        }),
+        type_system.validate_relation_endpoints(&near, &marker, &player)
+    );
+    let commands = RelationInstance::new_without_properties(player.id, RelationInstanceTypeId::new_from_type_unique_id("game", "commands"), tank.id);
+    assert_eq!(Ok(()), type_system.validate_relation_endpoints(&commands, &player, &tank));
+    assert!(type_system.validate_relation_endpoints(&commands, &tank, &player).is_err());
+    assert_eq!(tank.id, commands.endpoint_id(RelationEndpoint::Inbound));
+}
+
+#[test]
+fn relation_type_accepts_test() {
+    let type_system = create_type_system();
+    let player = type_system.get_entity_type(&EntityTypeId::new_from_type("game", "player")).unwrap();
+    let unit = type_system.get_entity_type(&EntityTypeId::new_from_type("game", "unit")).unwrap();
+    let marker = type_system.get_entity_type(&EntityTypeId::new_from_type("game", "marker")).unwrap();
+    let commands = type_system.get_relation_type(&RelationTypeId::new_from_type("game", "commands")).unwrap();
+    assert!(commands.accepts(player, unit));
+    assert!(!commands.accepts(player, marker));
+    let commands = commands.clone().with_inbound_type(marker.ty.clone());
+    assert!(commands.accepts(player, marker));
+    assert!(!commands.accepts(marker, marker));
+    assert_eq!(
+        vec![
+            &ComponentOrEntityTypeId::from(unit.ty.clone()),
+            &ComponentOrEntityTypeId::from(marker.ty.clone())
+        ],
+        commands.endpoint_types(RelationEndpoint::Inbound)
+    );
+}
+
+#[test]
+fn relation_type_accepts_wildcard_test() {
+    let type_system = create_type_system();
+    let player = type_system.get_entity_type(&EntityTypeId::new_from_type("game", "player")).unwrap();
+    let marker = type_system.get_entity_type(&EntityTypeId::new_from_type("game", "marker")).unwrap();
+    let references = RelationType::new(
+        ComponentOrEntityTypeId::any_entity_type(),
+        RelationTypeId::new_from_type("game", "references"),
+        ComponentOrEntityTypeId::Component(ComponentTypeId::new_from_type("base", "pos*")),
+        "",
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+    );
+    assert!(references.outbound_type.is_wildcard());
+    assert!(!ComponentOrEntityTypeId::from(player.ty.clone()).is_wildcard());
+    assert!(references.accepts(player, marker));
+    assert!(!references.accepts(marker, player));
+    let in_game = ComponentOrEntityTypeId::EntityType(EntityTypeId::new_from_type("game", "*"));
+    assert_eq!(4, type_system.endpoint_entity_types(&in_game).len());
+    let in_other = ComponentOrEntityTypeId::EntityType(EntityTypeId::new_from_type("other", "*"));
+    assert!(type_system.endpoint_entity_types(&in_other).is_empty());
+}
+
+#[test]
+fn validate_relation_endpoints_alternatives_test() {
+    let type_system = create_type_system();
+    let relation_type = type_system
+        .get_relation_type(&RelationTypeId::new_from_type("game", "commands"))
+        .unwrap()
+        .clone()
+        .with_outbound_type(EntityTypeId::new_from_type("game", "unit"));
+    let type_system = TypeSystem::new(type_system.components.clone(), type_system.entity_types.clone(), vec![relation_type], Vec::new());
+    let tank = EntityInstance::new_without_properties(EntityTypeId::new_from_type("game", "tank"), Uuid::new_v4());
+    let marker = EntityInstance::new_without_properties(EntityTypeId::new_from_type("game", "marker"), Uuid::new_v4());
+    let commands = RelationInstance::new_without_properties(tank.id, RelationInstanceTypeId::new_from_type_unique_id("game", "commands"), tank.id);
+    assert_eq!(Ok(()), type_system.validate_relation_endpoints(&commands, &tank, &tank));
+    assert_eq!(
+        Err(ValidationError::UnsatisfiedEndpoint {
+            ty: RelationTypeId::new_from_type("game", "commands"),
+            endpoint: RelationEndpoint::Outbound,
+            expected: ComponentOrEntityTypeId::EntityType(EntityTypeId::new_from_type("game", "player")),
+            actual: marker.ty.clone(),
+        }),
+        type_system.validate_relation_endpoints(&commands, &marker, &tank)
+    );
+}
@@ -3,13 +3,18 @@ use std::collections::HashMap;
 use indradb::Edge;
 use indradb::EdgeKey;
 use indradb::EdgeProperties;
+use indradb::Identifier;
 use indradb::NamedProperty;
 use serde_json::json;
+use serde_json::Map;
 use uuid::Uuid;
 
 use crate::property_identifier;
-use crate::tests::utils::r_string;
-use crate::tests::utils::r_string_1000;
+use crate::test_utils::assert_dao_roundtrip;
+use crate::test_utils::assert_json_roundtrip;
+use crate::test_utils::r_string;
+use crate::test_utils::r_string_1000;
+use crate::CowProperties;
 use crate::Extension;
 use crate::ExtensionContainer;
 use crate::ExtensionTypeId;
@@ -17,8 +22,10 @@ use crate::MutablePropertyInstanceSetter;
 use crate::NamespacedTypeGetter;
 use crate::PropertyInstanceGetter;
 use crate::RelationInstance;
+use crate::RelationInstanceDao;
 use crate::RelationInstanceTypeId;
 use crate::RelationTypeId;
+use crate::RevisionError;
 use crate::TypeDefinitionGetter;
 use crate::TypeIdType;
 
@@ -55,8 +62,14 @@ fn relation_instance_test() {
         ty: ty.clone(),
         inbound_id,
         description: description.to_string(),
-        properties: properties.clone(),
+        properties: properties.clone().into(),
         extensions: extensions.clone(),
+        created_at: None,
+        updated_at: None,
+        created_by: None,
+        deleted_at: None,
+        revision: 0,
+        extra: Map::new(),
     };
     assert_eq!(namespace, relation_instance.namespace());
     assert_eq!(outbound_id, relation_instance.outbound_id);
@@ -79,6 +92,13 @@ fn relation_instance_test() {
         format!("{}--[{}]-->{}", relation_instance.outbound_id, relation_instance.ty, relation_instance.inbound_id),
         format!("{}", relation_instance)
     );
+    assert_eq!(
+        format!(
+            "{}--[{}]-->{} (1 properties)",
+            relation_instance.outbound_id, relation_instance.ty, relation_instance.inbound_id
+        ),
+        format!("{:#}", relation_instance)
+    );
 }
 
 #[test]
@@ -96,8 +116,14 @@ fn edge_key_from_type_unique_id_test() {
         ty: ty.clone(),
         inbound_id,
         description: r_string(),
-        properties: HashMap::new(),
+        properties: CowProperties::new(),
         extensions: Vec::new(),
+        created_at: None,
+        updated_at: None,
+        created_by: None,
+        deleted_at: None,
+        revision: 0,
+        extra: Map::new(),
     };
     let edge_key = EdgeKey::new(outbound_id, ty.type_id(), inbound_id);
 
@@ -127,8 +153,14 @@ fn edge_key_from_type_unique_for_instance_id_test() {
         ty: ty.clone(),
         inbound_id,
         description: r_string(),
-        properties: HashMap::new(),
+        properties: CowProperties::new(),
         extensions: Vec::new(),
+        created_at: None,
+        updated_at: None,
+        created_by: None,
+        deleted_at: None,
+        revision: 0,
+        extra: Map::new(),
     };
     let edge_key = EdgeKey::new(outbound_id, ty.type_id(), inbound_id);
 
@@ -155,8 +187,14 @@ fn edge_key_from_type_with_random_instance_id_test() {
         ty: ty.clone(),
         inbound_id,
         description: r_string(),
-        properties: HashMap::new(),
+        properties: CowProperties::new(),
         extensions: Vec::new(),
+        created_at: None,
+        updated_at: None,
+        created_by: None,
+        deleted_at: None,
+        revision: 0,
+        extra: Map::new(),
     };
     let edge_key = EdgeKey::new(outbound_id, ty.type_id(), inbound_id);
 
@@ -178,8 +216,14 @@ fn edge_key_with_long_namespace_test() {
         ty: ty.clone(),
         inbound_id,
         description: r_string(),
-        properties: HashMap::new(),
+        properties: CowProperties::new(),
         extensions: Vec::new(),
+        created_at: None,
+        updated_at: None,
+        created_by: None,
+        deleted_at: None,
+        revision: 0,
+        extra: Map::new(),
     };
     let edge_key = EdgeKey::new(outbound_id, ty.type_id(), inbound_id);
 
@@ -200,8 +244,14 @@ fn edge_key_with_long_type_name_test() {
         ty: ty.clone(),
         inbound_id,
         description: r_string(),
-        properties: HashMap::new(),
+        properties: CowProperties::new(),
         extensions: Vec::new(),
+        created_at: None,
+        updated_at: None,
+        created_by: None,
+        deleted_at: None,
+        revision: 0,
+        extra: Map::new(),
     };
     let edge_key = EdgeKey::new(outbound_id, ty.type_id(), inbound_id);
 
@@ -222,8 +272,14 @@ fn edge_key_with_long_namespace_and_type_name_test() {
         ty: ty.clone(),
         inbound_id,
         description: r_string(),
-        properties: HashMap::new(),
+        properties: CowProperties::new(),
         extensions: Vec::new(),
+        created_at: None,
+        updated_at: None,
+        created_by: None,
+        deleted_at: None,
+        revision: 0,
+        extra: Map::new(),
     };
     let edge_key = EdgeKey::new(outbound_id, ty.type_id(), inbound_id);
 
@@ -331,15 +387,21 @@ fn relation_instance_get_key_test() {
     let inbound_id = Uuid::new_v4();
     let type_name = r_string();
     let description = r_string();
-    let properties = HashMap::new();
+    let properties = CowProperties::new();
     let ty = RelationInstanceTypeId::new_from_type_unique_id(&namespace, &type_name);
     let relation_instance = RelationInstance {
         outbound_id,
         ty: ty.clone(),
         inbound_id,
         description: description.to_string(),
-        properties: properties.clone(),
+        properties,
         extensions: Vec::new(),
+        created_at: None,
+        updated_at: None,
+        created_by: None,
+        deleted_at: None,
+        revision: 0,
+        extra: Map::new(),
     };
     assert_eq!(EdgeKey::new(outbound_id, ty.type_id(), inbound_id), relation_instance.get_key());
 }
@@ -380,6 +442,7 @@ fn relation_instance_de_test() {
   ]
 }"#;
     let relation_instance: RelationInstance = serde_json::from_str(s).unwrap();
+    assert_json_roundtrip(&relation_instance);
     assert_eq!("d82cc81a-e0e5-4de8-8b87-9b5bed0de795", relation_instance.outbound_id.to_string());
     assert_eq!("3f13400e-9286-441d-b85f-ef5df2177e7c", relation_instance.inbound_id.to_string());
     assert_eq!("rnr", relation_instance.namespace());
@@ -396,3 +459,55 @@ fn relation_instance_de_test() {
     assert_eq!("ext_name", extension.ty.type_name());
     assert_eq!(json!("ext_value"), extension.extension);
 }
+
+#[test]
+fn relation_instance_cached_key_test() {
+    let outbound_id = Uuid::new_v4();
+    let inbound_id = Uuid::new_v4();
+    let mut relation_instance =
+        RelationInstance::new_from_type_unique_for_instance_id(r_string(), outbound_id, r_string(), r_string(), inbound_id, HashMap::new());
+    let key = EdgeKey::new(outbound_id, Identifier::from(&relation_instance.type_definition()), inbound_id);
+    assert_eq!(key, relation_instance.get_key());
+    assert_eq!(key, relation_instance.get_key());
+    assert_eq!(relation_instance.ty, relation_instance.clone().ty);
+
+    // Replacing the type invalidates the cached identifier
+    let ty = RelationInstanceTypeId::new_from_type_unique_id(r_string(), r_string());
+    relation_instance.ty = ty.clone();
+    assert_eq!(EdgeKey::new(outbound_id, Identifier::from(&ty.type_definition()), inbound_id), relation_instance.get_key());
+    assert_ne!(key, relation_instance.get_key());
+}
+
+#[test]
+fn relation_instance_audit_metadata_test() {
+    let ty = RelationInstanceTypeId::new_from_type_unique_id(r_string(), r_string());
+    let mut relation_instance = RelationInstance::new(Uuid::new_v4(), ty, Uuid::new_v4(), HashMap::new());
+    assert!(relation_instance.created_at.is_none());
+    relation_instance.touch();
+    assert!(relation_instance.created_at.is_some());
+    assert_eq!(relation_instance.created_at, relation_instance.updated_at);
+    let created_by = r_string();
+    let relation_instance = relation_instance.with_created_by(&created_by);
+    assert_eq!(Some(created_by), relation_instance.created_by);
+    let deserialized = assert_dao_roundtrip::<RelationInstance, RelationInstanceDao>(&relation_instance);
+    assert_eq!(relation_instance.created_at, deserialized.created_at);
+    assert_eq!(relation_instance.updated_at, deserialized.updated_at);
+    assert_eq!(relation_instance.created_by, deserialized.created_by);
+}
+
+#[test]
+fn relation_instance_revision_test() {
+    let property_name = r_string();
+    let mut properties = HashMap::new();
+    properties.insert(property_name.clone(), json!(1));
+    let ty = RelationInstanceTypeId::new_from_type_unique_id(r_string(), r_string());
+    let mut relation_instance = RelationInstance::new(Uuid::new_v4(), ty, Uuid::new_v4(), properties);
+    assert_eq!(Ok(1), relation_instance.check_and_set(0, property_name.clone(), json!(2)));
+    assert_eq!(
+        Err(RevisionError::Conflict { expected: 0, actual: 1 }),
+        relation_instance.check_and_set(0, property_name.clone(), json!(3))
+    );
+    assert_eq!(json!(2), relation_instance.get(property_name).unwrap());
+    let deserialized = assert_dao_roundtrip::<RelationInstance, RelationInstanceDao>(&relation_instance);
+    assert_eq!(1, deserialized.revision);
+}
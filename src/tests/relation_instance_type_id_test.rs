@@ -1,4 +1,4 @@
-use crate::tests::utils::r_string;
+use crate::test_utils::r_string;
 use crate::NamespacedType;
 use crate::NamespacedTypeGetter;
 use crate::RelationInstanceTypeId;
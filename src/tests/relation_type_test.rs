@@ -1,8 +1,12 @@
+use std::collections::HashMap;
+
 use indradb::Identifier;
 use serde_json::json;
+use uuid::Uuid;
 
-use crate::tests::utils::r_string;
-use crate::tests::utils::r_string_1000;
+use crate::test_utils::assert_json_roundtrip;
+use crate::test_utils::r_string;
+use crate::test_utils::r_string_1000;
 use crate::ComponentTypeId;
 use crate::DataType;
 use crate::EntityTypeId;
@@ -10,7 +14,10 @@ use crate::Extension;
 use crate::ExtensionContainer;
 use crate::ExtensionTypeId;
 use crate::NamespacedTypeGetter;
+use crate::PropertyInstanceGetter;
 use crate::PropertyType;
+use crate::RelationInstance;
+use crate::RelationInstanceTypeId;
 use crate::RelationType;
 use crate::RelationTypeId;
 use crate::SocketType;
@@ -158,6 +165,7 @@ fn relation_type_de_test() {
   ]
 }"#;
     let relation_type: RelationType = serde_json::from_str(s).unwrap();
+    assert_json_roundtrip(&relation_type);
     assert_eq!("rnr", relation_type.namespace());
     assert_eq!("rtr", relation_type.type_name());
     assert_eq!("r__rnr__rtr", relation_type.ty.to_string());
@@ -183,3 +191,89 @@ fn relation_type_de_test() {
     assert_eq!("ext_name", extension.ty.type_name());
     assert_eq!(json!("ext_value"), extension.extension);
 }
+
+#[test]
+fn relation_type_inverse_test() {
+    let entity_ty = EntityTypeId::new_from_type("game", "container");
+    let contains = RelationType::new(
+        entity_ty.clone(),
+        RelationTypeId::new_from_type("game", "contains"),
+        entity_ty.clone(),
+        "",
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+    );
+    assert_eq!(None, contains.inverse_type_id());
+    let json = serde_json::to_value(&contains).unwrap();
+    assert!(json.get("bidirectional").is_none());
+    assert!(json.get("inverse_name").is_none());
+
+    let contains = contains.with_inverse("contained_by");
+    assert_eq!(Some(RelationTypeId::new_from_type("game", "contained_by")), contains.inverse_type_id());
+    let deserialized = assert_json_roundtrip(&contains);
+    assert!(deserialized.bidirectional);
+    assert_eq!(Some("contained_by".to_string()), deserialized.inverse_name);
+
+    let mut properties = HashMap::new();
+    properties.insert("slot".to_string(), json!(3));
+    let ty = RelationInstanceTypeId::new_unique_for_instance_id(contains.ty.clone(), "first");
+    let relation_instance = RelationInstance::new(Uuid::new_v4(), ty, Uuid::new_v4(), properties);
+    let inverse = contains.inverse_relation_instance(&relation_instance).unwrap();
+    assert_eq!(relation_instance.inbound_id, inverse.outbound_id);
+    assert_eq!(relation_instance.outbound_id, inverse.inbound_id);
+    assert_eq!(RelationTypeId::new_from_type("game", "contained_by"), inverse.relation_type_id());
+    assert_eq!("first", inverse.instance_id());
+    assert_eq!(json!(3), inverse.get("slot").unwrap());
+
+    let adjacent = RelationType::new(
+        entity_ty.clone(),
+        RelationTypeId::new_from_type("game", "adjacent"),
+        entity_ty,
+        "",
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+    );
+    let adjacent = adjacent.with_bidirectional();
+    assert_eq!(Some(adjacent.ty.clone()), adjacent.inverse_type_id());
+    assert!(contains.clone().with_bidirectional().inverse_name.is_some());
+}
+
+#[test]
+fn relation_type_semantics_test() {
+    let entity_ty = EntityTypeId::new_from_type("game", "region");
+    let connected = RelationType::new(
+        entity_ty.clone(),
+        RelationTypeId::new_from_type("game", "connected"),
+        entity_ty,
+        "",
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+    );
+    let json = serde_json::to_value(&connected).unwrap();
+    assert!(json.get("symmetric").is_none());
+    assert!(json.get("transitive").is_none());
+    assert!(json.get("reflexive").is_none());
+    assert_eq!(None, connected.inverse_type_id());
+
+    let connected = connected.with_symmetric().with_transitive().with_reflexive();
+    assert_eq!(Some(connected.ty.clone()), connected.inverse_type_id());
+    let deserialized = assert_json_roundtrip(&connected);
+    assert!(deserialized.symmetric);
+    assert!(deserialized.transitive);
+    assert!(deserialized.reflexive);
+    assert!(!deserialized.bidirectional);
+
+    let deserialized: RelationType = serde_json::from_value(json!({
+        "outbound": { "entity_type": { "namespace": "game", "type_name": "region" } },
+        "namespace": "game",
+        "type_name": "connected",
+        "inbound": { "entity_type": { "namespace": "game", "type_name": "region" } },
+        "transitive": true
+    }))
+    .unwrap();
+    assert!(!deserialized.symmetric);
+    assert!(deserialized.transitive);
+}
@@ -0,0 +1,56 @@
+use crate::test_utils::r_string;
+use crate::EntityType;
+use crate::EntityTypeId;
+use crate::Namespace;
+use crate::ReservedNamespaces;
+use crate::TypeDefinition;
+use crate::TypeImportStrategy;
+use crate::TypeSystem;
+use crate::TypeSystemImportError;
+use crate::ValidationError;
+
+fn create_type_system(namespace: &str) -> TypeSystem {
+    let entity_type = EntityType::new(EntityTypeId::new_from_type(namespace, "and"), r_string(), Vec::new(), Vec::new(), Vec::new());
+    TypeSystem::new(Vec::new(), vec![entity_type], Vec::new(), Vec::new())
+}
+
+#[test]
+fn reserved_namespaces_test() {
+    let reserved_namespaces = ReservedNamespaces::default().with("logical");
+    assert_eq!(&[Namespace::from("core"), Namespace::from("logical")], reserved_namespaces.namespaces());
+    assert!(reserved_namespaces.is_reserved(&Namespace::from("core")));
+    assert!(reserved_namespaces.is_reserved(&Namespace::from("core::math")));
+    assert!(!reserved_namespaces.is_reserved(&Namespace::from("core_extra")));
+    assert!(!reserved_namespaces.is_reserved(&Namespace::from("user::core")));
+    assert!(!ReservedNamespaces::new().is_reserved(&Namespace::from("core")));
+}
+
+#[test]
+fn reserved_namespaces_validate_type_test() {
+    let reserved_namespaces = ReservedNamespaces::default();
+    assert!(reserved_namespaces.validate_type(&EntityTypeId::new_from_type("user", "and")).is_ok());
+    assert_eq!(
+        Err(ValidationError::ReservedNamespace {
+            ty: "e__core::logical__and".to_string(),
+            namespace: "core::logical".to_string(),
+        }),
+        reserved_namespaces.validate_type(&EntityTypeId::new_from_type("core::logical", "and"))
+    );
+    assert_eq!(vec![TypeDefinition::entity_type("core", "and")], reserved_namespaces.violations(&create_type_system("core")));
+}
+
+#[test]
+fn import_user_types_test() {
+    let reserved_namespaces = ReservedNamespaces::default();
+    let mut type_system = create_type_system("core");
+    match type_system.import_user_types(create_type_system("core::logical"), TypeImportStrategy::Fail, &reserved_namespaces) {
+        Err(TypeSystemImportError::ReservedNamespaces(types)) => assert_eq!(vec![TypeDefinition::entity_type("core::logical", "and")], types),
+        _ => panic!("Expected the import to be rejected"),
+    }
+    assert_eq!(1, type_system.entity_types.len());
+
+    let report = type_system
+        .import_user_types(create_type_system("user"), TypeImportStrategy::Fail, &reserved_namespaces)
+        .unwrap();
+    assert_eq!(vec![TypeDefinition::entity_type("user", "and")], report.added);
+}
@@ -0,0 +1,82 @@
+use std::collections::HashMap;
+
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::test_utils::r_string;
+use crate::EntityInstance;
+use crate::EntityTypeId;
+use crate::FlowInstance;
+use crate::PropertyInstanceGetter;
+use crate::RelationInstance;
+use crate::RelationInstanceTypeId;
+use crate::SanitizeAction;
+use crate::SanitizePolicy;
+use crate::REDACTED_VALUE;
+
+fn entity_instance() -> EntityInstance {
+    let mut properties = HashMap::new();
+    properties.insert("password".to_string(), json!("secret"));
+    properties.insert("api_token".to_string(), json!("token"));
+    properties.insert("email".to_string(), json!("user@example.com"));
+    properties.insert("value".to_string(), json!(42));
+    EntityInstance::new(EntityTypeId::new_from_type(r_string(), r_string()), Uuid::new_v4(), properties).with_description("Owned by user@example.com")
+}
+
+#[test]
+fn sanitize_policy_action_test() {
+    let policy = SanitizePolicy::new().remove("password").hash("*_token").redact("*");
+    assert_eq!(Some(SanitizeAction::Remove), policy.action("password"));
+    assert_eq!(Some(SanitizeAction::Hash), policy.action("api_token"));
+    assert_eq!(Some(SanitizeAction::Redact), policy.action("email"));
+    assert_eq!(None, SanitizePolicy::new().action("email"));
+}
+
+#[test]
+fn entity_instance_sanitized_test() {
+    let entity_instance = entity_instance();
+    let policy = SanitizePolicy::new().remove("password").hash("*_token").redact("email").strip_descriptions();
+    let sanitized = entity_instance.sanitized(&policy);
+    assert_eq!(entity_instance.id, sanitized.id);
+    assert_eq!(3, sanitized.properties.len());
+    assert!(sanitized.get("password").is_none());
+    assert_eq!(Some(REDACTED_VALUE.to_string()), sanitized.as_string("email"));
+    assert_eq!(Some(42), sanitized.as_u64("value"));
+    assert!(sanitized.description.is_empty());
+    let hash = sanitized.as_string("api_token").unwrap();
+    assert_ne!("token", hash);
+    // Equal values result in equal hashes
+    assert_eq!(Some(hash.clone()), entity_instance.sanitized(&policy).as_string("api_token"));
+    // The salt changes the hashes
+    assert_ne!(Some(hash), entity_instance.sanitized(&policy.clone().with_salt(r_string())).as_string("api_token"));
+    // The original entity instance is unchanged
+    assert_eq!(Some("secret".to_string()), entity_instance.as_string("password"));
+    assert_eq!("Owned by user@example.com", entity_instance.description);
+}
+
+#[test]
+fn flow_instance_sanitized_test() {
+    let wrapper_entity_instance = entity_instance();
+    let entity_instance = entity_instance();
+    let mut properties = HashMap::new();
+    properties.insert("password".to_string(), json!("secret"));
+    let ty = RelationInstanceTypeId::new_from_type_unique_id(r_string(), r_string());
+    let relation_instance = RelationInstance::new(wrapper_entity_instance.id, ty, entity_instance.id, properties).with_description(r_string());
+    let mut flow_instance = FlowInstance::from(wrapper_entity_instance);
+    flow_instance.description = r_string();
+    flow_instance.entity_instances.push(entity_instance);
+    flow_instance.relation_instances.push(relation_instance);
+
+    let sanitized = flow_instance.sanitized(&SanitizePolicy::new().remove("password").strip_descriptions());
+    assert!(sanitized.description.is_empty());
+    assert_eq!(2, sanitized.entity_instances.len());
+    assert!(sanitized
+        .entity_instances
+        .iter()
+        .all(|entity_instance| entity_instance.get("password").is_none()));
+    assert!(sanitized.entity_instances.iter().all(|entity_instance| entity_instance.description.is_empty()));
+    let relation_instance = sanitized.relation_instances.first().unwrap();
+    assert!(relation_instance.get("password").is_none());
+    assert!(relation_instance.description.is_empty());
+    assert_eq!(flow_instance.relation_instances.first().unwrap().get_key(), relation_instance.get_key());
+}
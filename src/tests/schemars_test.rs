@@ -0,0 +1,45 @@
+use schemars::schema_for;
+use serde_json::json;
+
+use crate::Component;
+use crate::DataType;
+use crate::EntityInstance;
+use crate::EntityType;
+use crate::FlowType;
+use crate::RelationInstance;
+use crate::RelationType;
+
+#[test]
+fn data_type_json_schema_derive_test() {
+    let schema = serde_json::to_value(schema_for!(DataType)).unwrap();
+    let variants = schema.get("enum").unwrap().as_array().unwrap();
+    assert!(variants.contains(&json!("string")));
+    assert!(variants.contains(&json!("any")));
+}
+
+#[test]
+fn type_json_schema_derive_test() {
+    for schema in [
+        serde_json::to_value(schema_for!(Component)).unwrap(),
+        serde_json::to_value(schema_for!(EntityType)).unwrap(),
+        serde_json::to_value(schema_for!(RelationType)).unwrap(),
+        serde_json::to_value(schema_for!(FlowType)).unwrap(),
+    ] {
+        let properties = schema.get("properties").unwrap();
+        assert!(properties.get("namespace").is_some());
+        assert!(properties.get("type_name").is_some());
+    }
+}
+
+#[test]
+fn instance_json_schema_derive_test() {
+    let schema = serde_json::to_value(schema_for!(EntityInstance)).unwrap();
+    let properties = schema.get("properties").unwrap();
+    assert!(properties.get("id").is_some());
+    assert!(properties.get("properties").is_some());
+
+    let schema = serde_json::to_value(schema_for!(RelationInstance)).unwrap();
+    let properties = schema.get("properties").unwrap();
+    assert!(properties.get("outbound_id").is_some());
+    assert!(properties.get("inbound_id").is_some());
+}
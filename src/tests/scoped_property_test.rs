@@ -0,0 +1,86 @@
+use std::collections::HashMap;
+
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::test_utils::assert_dao_roundtrip;
+use crate::Component;
+use crate::ComponentTypeId;
+use crate::EntityInstance;
+use crate::EntityType;
+use crate::EntityTypeDao;
+use crate::EntityTypeId;
+use crate::PropertyError;
+use crate::PropertyProvenance;
+use crate::PropertyType;
+use crate::ValidationError;
+
+fn create_components() -> Vec<Component> {
+    vec![
+        Component::new_without_extensions(ComponentTypeId::new_from_type("base", "labeled"), "", vec![PropertyType::string("label")]),
+        Component::new_without_extensions(ComponentTypeId::new_from_type("base", "gauge"), "", vec![PropertyType::number("value")]),
+        Component::new_without_extensions(ComponentTypeId::new_from_type("base", "switch"), "", vec![PropertyType::bool("value")]),
+    ]
+}
+
+fn create_entity_type() -> EntityType {
+    let components = create_components().into_iter().map(|component| component.ty).collect();
+    EntityType::new(EntityTypeId::new_from_type("game", "panel"), "", components, vec![PropertyType::string("name")], Vec::new())
+}
+
+#[test]
+fn resolve_property_key_test() {
+    let components = create_components();
+    let entity_type = create_entity_type();
+    assert_eq!(Some("name".to_string()), entity_type.resolve_property_key("name", &components));
+    assert_eq!(Some("label".to_string()), entity_type.resolve_property_key("labeled.label", &components));
+    assert_eq!(Some("value".to_string()), entity_type.resolve_property_key("gauge.value", &components));
+    assert_eq!(None, entity_type.resolve_property_key("labeled.value", &components));
+
+    let entity_type = entity_type.with_scoped_properties();
+    assert_eq!(Some("name".to_string()), entity_type.resolve_property_key("name", &components));
+    assert_eq!(Some("labeled.label".to_string()), entity_type.resolve_property_key("label", &components));
+    assert_eq!(Some("gauge.value".to_string()), entity_type.resolve_property_key("gauge.value", &components));
+    // Declared by two components
+    assert_eq!(None, entity_type.resolve_property_key("value", &components));
+    assert_eq!(None, entity_type.resolve_property_key("unknown", &components));
+    assert_eq!("switch.value", entity_type.component_property_key(&ComponentTypeId::new_from_type("base", "switch"), "value"));
+}
+
+#[test]
+fn validate_scoped_properties_test() {
+    let components = create_components();
+    let entity_type = create_entity_type().with_scoped_properties();
+    let mut properties = HashMap::new();
+    properties.insert("name".to_string(), json!("Control panel"));
+    properties.insert("labeled.label".to_string(), json!("Panel"));
+    properties.insert("gauge.value".to_string(), json!(0.5));
+    let entity_instance = EntityInstance::new(entity_type.ty.clone(), Uuid::new_v4(), properties.clone());
+    assert_eq!(
+        Err(ValidationError::Property(PropertyError::MissingProperty("switch.value".to_string()))),
+        entity_type.validate_instance(&entity_instance, &components)
+    );
+    properties.insert("switch.value".to_string(), json!(true));
+    let entity_instance = EntityInstance::new(entity_type.ty.clone(), Uuid::new_v4(), properties);
+    assert_eq!(Ok(()), entity_type.validate_instance(&entity_instance, &components));
+
+    let provenance = entity_type.property_provenance(&components);
+    assert_eq!(Some(&PropertyProvenance::OwnType), provenance.get("name"));
+    assert_eq!(
+        Some(&PropertyProvenance::Component(ComponentTypeId::new_from_type("base", "switch"))),
+        provenance.get("switch.value")
+    );
+    assert!(!provenance.contains_key("value"));
+
+    let schema = entity_type.instance_json_schema(&components);
+    assert!(schema["properties"]["properties"]["properties"].get("gauge.value").is_some());
+}
+
+#[test]
+fn scoped_properties_dao_test() {
+    let entity_type = create_entity_type();
+    assert!(serde_json::to_value(&entity_type).unwrap().get("scoped_properties").is_none());
+    let entity_type = entity_type.with_scoped_properties();
+    let deserialized = assert_dao_roundtrip::<EntityType, EntityTypeDao>(&entity_type);
+    assert!(deserialized.scoped_properties);
+}
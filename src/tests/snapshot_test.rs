@@ -0,0 +1,59 @@
+use serde_json::json;
+
+use crate::assert_snapshot;
+use crate::canonical_pretty_json;
+use crate::embedded_component;
+use crate::to_snapshot;
+use crate::verify_snapshot;
+use crate::Component;
+use crate::ComponentDao;
+use crate::SnapshotError;
+
+const LABELED_COMPONENT_SNAPSHOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src/tests/snapshots/labeled_component.json");
+
+fn labeled_component_dao() -> ComponentDao {
+    let component: &Component = embedded_component!("types/labeled.json");
+    ComponentDao::from(component.clone())
+}
+
+#[test]
+fn canonical_pretty_json_test() {
+    let value = json!({ "b": [{ "d": 1, "c": 2 }], "a": null });
+    assert_eq!(
+        "{\n  \"a\": null,\n  \"b\": [\n    {\n      \"c\": 2,\n      \"d\": 1\n    }\n  ]\n}\n",
+        canonical_pretty_json(&value)
+    );
+}
+
+#[test]
+fn to_snapshot_is_stable_test() {
+    let dao = labeled_component_dao();
+    let snapshot = to_snapshot(&dao).unwrap();
+    let deserialized: ComponentDao = serde_json::from_str(&snapshot).unwrap();
+    assert_eq!(snapshot, to_snapshot(&deserialized).unwrap());
+}
+
+#[test]
+fn component_dao_snapshot_test() {
+    assert_snapshot(&labeled_component_dao(), LABELED_COMPONENT_SNAPSHOT);
+}
+
+#[test]
+fn snapshot_mismatch_test() {
+    let mut dao = labeled_component_dao();
+    dao.description = "Changed".to_string();
+    match verify_snapshot(&dao, LABELED_COMPONENT_SNAPSHOT) {
+        Err(SnapshotError::Mismatch { line, expected, actual, .. }) => {
+            assert_eq!(4, line);
+            assert!(expected.contains("hierarchical path"));
+            assert_eq!("  \"description\": \"Changed\",", actual);
+        }
+        result => panic!("Unexpected result {result:?}"),
+    }
+}
+
+#[test]
+fn missing_snapshot_test() {
+    let result = verify_snapshot(&labeled_component_dao(), concat!(env!("CARGO_MANIFEST_DIR"), "/src/tests/snapshots/missing.json"));
+    assert!(matches!(result, Err(SnapshotError::Io { .. })));
+}
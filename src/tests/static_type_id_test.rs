@@ -0,0 +1,34 @@
+use crate::ty;
+use crate::ComponentTypeId;
+use crate::EntityTypeId;
+use crate::NamespacedType;
+use crate::NamespacedTypeGetter;
+use crate::RelationTypeId;
+use crate::StaticTypeId;
+
+const LABELED: StaticTypeId = ty!("core__labeled");
+
+macro_rules! forward_ty {
+    ($type_id: literal) => {
+        ty!($type_id)
+    };
+}
+
+#[test]
+fn static_type_id_macro_test() {
+    assert_eq!(StaticTypeId::new("core", "labeled"), LABELED);
+    assert_eq!("core", LABELED.namespace());
+    assert_eq!("labeled", LABELED.type_name());
+    assert_eq!("core__labeled", LABELED.to_string());
+    assert_eq!(StaticTypeId::new("game", "player"), forward_ty!("game__player"));
+}
+
+#[test]
+fn static_type_id_into_type_id_test() {
+    let component_ty: ComponentTypeId = LABELED.into();
+    assert_eq!(ComponentTypeId::new_from_type("core", "labeled"), component_ty);
+    let entity_ty: EntityTypeId = ty!("game__player").into();
+    assert_eq!(EntityTypeId::new_from_type("game", "player"), entity_ty);
+    assert_eq!(RelationTypeId::new_from_type("game", "owns"), ty!("game__owns").into_type_id::<RelationTypeId>());
+    assert_eq!(NamespacedType::new("core", "labeled"), NamespacedType::from(LABELED));
+}
@@ -0,0 +1,187 @@
+use std::collections::HashMap;
+
+use serde_json::json;
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::flow_type_local_id;
+use crate::sub_flow_type_entity_id;
+use crate::test_utils::assert_json_roundtrip;
+use crate::EntityInstance;
+use crate::EntityTypeId;
+use crate::FlowType;
+use crate::FlowTypeDao;
+use crate::FlowTypeFlattenError;
+use crate::FlowTypeId;
+use crate::FlowTypePort;
+use crate::NamespacedTypeGetter;
+use crate::PropertyInstanceGetter;
+use crate::SubFlowTypeBinding;
+use crate::SubFlowTypeReference;
+use crate::TypeSystem;
+use crate::SUB_FLOW_CONNECTOR_NAMESPACE;
+
+fn properties(names: &[&str]) -> HashMap<String, Value> {
+    names.iter().map(|name| (name.to_string(), json!(false))).collect()
+}
+
+fn create_flow_type(type_name: &str, entity_names: &[&str]) -> FlowType {
+    let wrapper = EntityInstance::new(EntityTypeId::new_from_type("flow", "generic_flow"), flow_type_local_id(type_name), HashMap::new());
+    let entity_instances = entity_names
+        .iter()
+        .map(|name| {
+            EntityInstance::new(
+                EntityTypeId::new_from_type("logical", "and"),
+                flow_type_local_id(*name),
+                properties(&["lhs", "rhs", "result"]),
+            )
+        })
+        .collect();
+    FlowType::new(
+        FlowTypeId::new_from_type("logical", type_name),
+        "",
+        wrapper,
+        entity_instances,
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+    )
+}
+
+fn create_and_flow_type() -> FlowType {
+    let and_id = flow_type_local_id("and");
+    create_flow_type("and_flow", &["and"])
+        .with_inputs(vec![FlowTypePort::new("lhs", and_id, "lhs"), FlowTypePort::new("rhs", and_id, "rhs")])
+        .with_outputs(vec![FlowTypePort::new("result", and_id, "result")])
+}
+
+fn create_outer_flow_type() -> FlowType {
+    let source_id = flow_type_local_id("source");
+    create_flow_type("outer_flow", &["source"])
+        .with_sub_flow(SubFlowTypeReference::new(
+            "first",
+            FlowTypeId::new_from_type("logical", "and_flow"),
+            vec![
+                SubFlowTypeBinding::input(source_id, "result", "lhs"),
+                SubFlowTypeBinding::output("result", source_id, "rhs"),
+            ],
+        ))
+        .with_sub_flow(SubFlowTypeReference::new(
+            "second",
+            FlowTypeId::new_from_type("logical", "and_flow"),
+            vec![SubFlowTypeBinding::input(source_id, "result", "rhs")],
+        ))
+}
+
+#[test]
+fn flatten_flow_type_test() {
+    let outer_flow_type = create_outer_flow_type();
+    assert_eq!(vec![FlowTypeId::new_from_type("logical", "and_flow")], outer_flow_type.uses_flow_types());
+    // Inclusions of the same flow type which aren't adjacent are reported once, too
+    let interleaved = outer_flow_type
+        .clone()
+        .with_sub_flow(SubFlowTypeReference::new("third", FlowTypeId::new_from_type("logical", "or_flow"), Vec::new()))
+        .with_sub_flow(SubFlowTypeReference::new("fourth", FlowTypeId::new_from_type("logical", "and_flow"), Vec::new()));
+    assert_eq!(
+        vec![
+            FlowTypeId::new_from_type("logical", "and_flow"),
+            FlowTypeId::new_from_type("logical", "or_flow")
+        ],
+        interleaved.uses_flow_types()
+    );
+    let type_system = TypeSystem::new(Vec::new(), Vec::new(), Vec::new(), vec![create_and_flow_type(), outer_flow_type.clone()]);
+    let flattened = type_system.flatten_flow_type(&outer_flow_type).unwrap();
+    assert!(flattened.sub_flows.is_empty());
+
+    // The source, the two wrappers and the two logical ands of the included flow types.
+    assert_eq!(5, flattened.entity_instances.len());
+    let first_and_id = sub_flow_type_entity_id("first", flow_type_local_id("and"));
+    let second_and_id = sub_flow_type_entity_id("second", flow_type_local_id("and"));
+    assert_ne!(first_and_id, second_and_id);
+    assert!(flattened.has_entity_instance(first_and_id));
+    assert!(flattened.has_entity_instance(second_and_id));
+    assert!(flattened.has_entity_instance(sub_flow_type_entity_id("first", flow_type_local_id("and_flow"))));
+
+    // The port bindings are realized by connectors.
+    assert_eq!(3, flattened.relation_instances.len());
+    assert!(flattened.relation_instances.iter().all(|r| r.namespace() == SUB_FLOW_CONNECTOR_NAMESPACE));
+    let source_id = flow_type_local_id("source");
+    let connector = flattened
+        .relation_instances
+        .iter()
+        .find(|r| r.outbound_id == first_and_id && r.inbound_id == source_id)
+        .unwrap();
+    assert_eq!(Some("result".to_string()), connector.as_string("outbound_property_name"));
+    assert_eq!(Some("rhs".to_string()), connector.as_string("inbound_property_name"));
+    assert!(flattened
+        .relation_instances
+        .iter()
+        .any(|r| r.outbound_id == source_id && r.inbound_id == second_and_id));
+
+    let instantiation = outer_flow_type.instantiate_flattened(|ty| type_system.resolve_flow_type(ty).cloned()).unwrap();
+    assert_eq!(6, instantiation.flow_instance.entity_instances.len());
+    assert!(instantiation.resolve(first_and_id).is_some());
+}
+
+#[test]
+fn flatten_nested_flow_type_test() {
+    let middle_flow_type =
+        create_flow_type("middle_flow", &[]).with_sub_flow(SubFlowTypeReference::new("inner", FlowTypeId::new_from_type("logical", "and_flow"), Vec::new()));
+    let outer_flow_type =
+        create_flow_type("top_flow", &[]).with_sub_flow(SubFlowTypeReference::new("middle", FlowTypeId::new_from_type("logical", "middle_flow"), Vec::new()));
+    let type_system = TypeSystem::new(Vec::new(), Vec::new(), Vec::new(), vec![create_and_flow_type(), middle_flow_type]);
+    let flattened = type_system.flatten_flow_type(&outer_flow_type).unwrap();
+    let and_id = sub_flow_type_entity_id("middle", sub_flow_type_entity_id("inner", flow_type_local_id("and")));
+    assert!(flattened.has_entity_instance(and_id));
+    assert_eq!(3, flattened.entity_instances.len());
+}
+
+#[test]
+fn flatten_flow_type_errors_test() {
+    let outer_flow_type = create_outer_flow_type();
+    assert_eq!(
+        Err(FlowTypeFlattenError::MissingSubFlowType(FlowTypeId::new_from_type("logical", "and_flow"))),
+        outer_flow_type.flatten(|_| None)
+    );
+
+    let cyclic_flow_type =
+        create_flow_type("cyclic_flow", &[]).with_sub_flow(SubFlowTypeReference::new("self", FlowTypeId::new_from_type("logical", "cyclic_flow"), Vec::new()));
+    let resolved = cyclic_flow_type.clone();
+    assert_eq!(
+        Err(FlowTypeFlattenError::CyclicSubFlowType(FlowTypeId::new_from_type("logical", "cyclic_flow"))),
+        cyclic_flow_type.flatten(|_| Some(resolved.clone()))
+    );
+
+    let unknown_port_flow_type = create_flow_type("unknown_port_flow", &["source"]).with_sub_flow(SubFlowTypeReference::new(
+        "and",
+        FlowTypeId::new_from_type("logical", "and_flow"),
+        vec![SubFlowTypeBinding::input(Uuid::new_v4(), "result", "result")],
+    ));
+    assert_eq!(
+        Err(FlowTypeFlattenError::MissingPort {
+            ty: FlowTypeId::new_from_type("logical", "and_flow"),
+            port: "result".to_string(),
+        }),
+        unknown_port_flow_type.flatten(|_| Some(create_and_flow_type()))
+    );
+}
+
+#[test]
+fn sub_flow_type_reference_serde_test() {
+    let outer_flow_type = create_outer_flow_type();
+    let value = serde_json::to_value(FlowTypeDao::from(outer_flow_type.clone())).unwrap();
+    assert_eq!(json!("first"), value["sub_flows"][0]["name"]);
+    assert_eq!(json!("and_flow"), value["sub_flows"][0]["type_name"]);
+    assert_eq!(json!("input"), value["sub_flows"][0]["bindings"][0]["direction"]);
+    let deserialized = FlowType::from(assert_json_roundtrip(&FlowTypeDao::from(outer_flow_type.clone())));
+    assert_eq!(outer_flow_type.sub_flows, deserialized.sub_flows);
+
+    let reference: SubFlowTypeReference = serde_json::from_value(json!({
+        "name": "first",
+        "namespace": "logical",
+        "type_name": "and_flow",
+        "bindings": [{ "direction": "output", "outer_entity_id": "source", "outer_property_name": "rhs", "port": "result" }]
+    }))
+    .unwrap();
+    assert_eq!(flow_type_local_id("source"), reference.bindings[0].outer_entity_id);
+}
@@ -0,0 +1,83 @@
+use serde_json::json;
+
+use crate::test_utils::create_entity_instance;
+use crate::test_utils::r_string;
+use crate::Component;
+use crate::ComponentDao;
+use crate::ComponentTypeId;
+use crate::EntityType;
+use crate::EntityTypeDao;
+use crate::EntityTypeId;
+use crate::FlowType;
+use crate::FlowTypeId;
+use crate::RelationType;
+use crate::RelationTypeId;
+use crate::TagContainer;
+use crate::TypeDefinitionGetter;
+use crate::TypeSystem;
+
+#[test]
+fn tag_container_test() {
+    let component = Component::new_without_properties(ComponentTypeId::new_from_type(r_string(), r_string()), r_string(), Vec::new())
+        .with_tag("logical")
+        .with_tag("gates");
+    assert!(component.has_tag("logical"));
+    assert!(component.has_tag("gates"));
+    assert!(!component.has_tag("arithmetic"));
+    assert_eq!(vec!["logical".to_string(), "gates".to_string()], component.get_tags());
+}
+
+#[test]
+fn tags_dao_test() {
+    let entity_type = EntityType::new(EntityTypeId::new_from_type("logical", "and"), "", Vec::new(), Vec::new(), Vec::new()).with_tag("gates");
+    let dao = EntityTypeDao::from(entity_type);
+    assert_eq!(json!(["gates"]), serde_json::to_value(&dao).unwrap()["tags"]);
+    assert!(EntityType::from(dao).has_tag("gates"));
+
+    let dao: ComponentDao = serde_json::from_value(json!({
+        "namespace": "base",
+        "type_name": "labeled"
+    }))
+    .unwrap();
+    assert!(dao.tags.is_empty());
+}
+
+#[test]
+fn type_system_find_by_tag_test() {
+    let component = Component::new_without_properties(ComponentTypeId::new_from_type(r_string(), r_string()), r_string(), Vec::new()).with_tag("logical");
+    let entity_type = EntityType::new(EntityTypeId::new_from_type(r_string(), r_string()), r_string(), Vec::new(), Vec::new(), Vec::new()).with_tag("logical");
+    let other_entity_type = EntityType::new(EntityTypeId::new_from_type(r_string(), r_string()), r_string(), Vec::new(), Vec::new(), Vec::new());
+    let relation_type = RelationType::new(
+        entity_type.ty.clone(),
+        RelationTypeId::new_from_type(r_string(), r_string()),
+        entity_type.ty.clone(),
+        r_string(),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+    )
+    .with_tag("connectors");
+    let flow_type = FlowType::new(
+        FlowTypeId::new_from_type(r_string(), r_string()),
+        r_string(),
+        create_entity_instance(r_string()),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+    )
+    .with_tag("logical");
+    let type_system = TypeSystem::new(
+        vec![component.clone()],
+        vec![entity_type.clone(), other_entity_type],
+        vec![relation_type.clone()],
+        vec![flow_type.clone()],
+    );
+
+    assert_eq!(
+        vec![component.type_definition(), entity_type.type_definition(), flow_type.type_definition()],
+        type_system.find_by_tag("logical")
+    );
+    assert_eq!(vec![relation_type.type_definition()], type_system.find_by_tag("connectors"));
+    assert!(type_system.find_by_tag(&r_string()).is_empty());
+}
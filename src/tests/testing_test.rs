@@ -0,0 +1,109 @@
+use uuid::Uuid;
+
+use crate::random_entity_instance;
+use crate::random_entity_instance_of_type;
+use crate::random_properties;
+use crate::random_properties_scoped;
+use crate::random_relation_instance;
+use crate::random_value;
+use crate::Component;
+use crate::ComponentTypeId;
+use crate::DataType;
+use crate::EntityType;
+use crate::EntityTypeId;
+use crate::PropertyType;
+use crate::RelationType;
+use crate::RelationTypeId;
+use crate::TypeSystem;
+use crate::ValidationError;
+
+#[test]
+fn random_value_test() {
+    for data_type in [
+        DataType::Null,
+        DataType::Bool,
+        DataType::Number,
+        DataType::String,
+        DataType::Array,
+        DataType::Object,
+        DataType::Any,
+    ] {
+        assert!(data_type.accepts(&random_value(data_type)));
+    }
+}
+
+#[test]
+fn random_entity_instance_test() {
+    let component = Component::new_without_extensions(ComponentTypeId::new_from_type("base", "labeled"), "", vec![PropertyType::string("label")]);
+    let entity_type = EntityType::new(
+        EntityTypeId::new_from_type("logical", "and"),
+        "",
+        vec![component.ty.clone()],
+        vec![
+            PropertyType::input("lhs", DataType::Bool),
+            PropertyType::object("config"),
+            PropertyType::output("result", DataType::Number),
+        ],
+        Vec::new(),
+    );
+    let components = vec![component];
+    let entity_instance = random_entity_instance(&entity_type, &components);
+    assert_eq!(entity_type.ty, entity_instance.ty);
+    assert_eq!(4, entity_instance.properties.len());
+    assert!(entity_type.validate_instance(&entity_instance, &components).is_ok());
+
+    let entity_type = entity_type.with_scoped_properties();
+    let entity_instance = random_entity_instance(&entity_type, &components);
+    assert!(entity_instance.properties.contains_key("labeled.label"));
+    assert!(!entity_instance.properties.contains_key("label"));
+    assert!(entity_type.validate_instance(&entity_instance, &components).is_ok());
+
+    assert!(random_properties(&[], &entity_type.components, &components).contains_key("label"));
+    assert!(random_properties_scoped(true, &[], &entity_type.components, &components).contains_key("labeled.label"));
+}
+
+#[test]
+fn random_entity_instance_of_type_test() {
+    let component = Component::new_without_extensions(ComponentTypeId::new_from_type("base", "labeled"), "", vec![PropertyType::string("label")]);
+    let item = EntityType::new(
+        EntityTypeId::new_from_type("game", "item"),
+        "",
+        vec![component.ty.clone()],
+        vec![PropertyType::number("weight")],
+        Vec::new(),
+    );
+    let sword = EntityType::new(EntityTypeId::new_from_type("game", "sword"), "", Vec::new(), vec![PropertyType::bool("sharp")], Vec::new())
+        .with_parent(item.ty.clone());
+    let type_system = TypeSystem::new(vec![component], vec![item, sword.clone()], Vec::new(), Vec::new());
+    let entity_instance = random_entity_instance_of_type(&type_system, &sword.ty).unwrap();
+    assert_eq!(sword.ty, entity_instance.ty);
+    assert_eq!(3, entity_instance.properties.len());
+    assert_eq!(Ok(()), type_system.validate_entity_instance(&entity_instance));
+
+    let unknown_ty = EntityTypeId::new_from_type("game", "shield");
+    assert_eq!(
+        Err(ValidationError::UnknownEntityType(unknown_ty.clone())),
+        random_entity_instance_of_type(&type_system, &unknown_ty).map(|_| ())
+    );
+}
+
+#[test]
+fn random_relation_instance_test() {
+    let entity_ty = EntityTypeId::new_from_type("logical", "and");
+    let relation_type = RelationType::new(
+        entity_ty.clone(),
+        RelationTypeId::new_from_type("connector", "default_connector"),
+        entity_ty,
+        "",
+        Vec::new(),
+        vec![PropertyType::string("outbound_property_name"), PropertyType::array("path")],
+        Vec::new(),
+    );
+    let outbound_id = Uuid::new_v4();
+    let inbound_id = Uuid::new_v4();
+    let relation_instance = random_relation_instance(outbound_id, &relation_type, inbound_id, &[]);
+    assert_eq!(outbound_id, relation_instance.outbound_id);
+    assert_eq!(inbound_id, relation_instance.inbound_id);
+    assert_eq!(relation_type.ty, relation_instance.relation_type_id());
+    assert!(relation_type.validate_instance(&relation_instance, &[]).is_ok());
+}
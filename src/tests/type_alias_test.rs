@@ -0,0 +1,64 @@
+use std::collections::HashMap;
+
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::Component;
+use crate::ComponentTypeId;
+use crate::EntityInstance;
+use crate::EntityType;
+use crate::EntityTypeDao;
+use crate::EntityTypeId;
+use crate::PropertyType;
+use crate::TypeSystem;
+
+fn create_type_system() -> TypeSystem {
+    let component = Component::new_without_extensions(ComponentTypeId::new_from_type("base", "named"), "", vec![PropertyType::string("name")])
+        .with_alias(ComponentTypeId::new_from_type("base", "labeled"));
+    let entity_type = EntityType::new(
+        EntityTypeId::new_from_type("logical", "and"),
+        "",
+        vec![ComponentTypeId::new_from_type("base", "labeled")],
+        vec![PropertyType::bool("result")],
+        Vec::new(),
+    )
+    .with_alias(EntityTypeId::new_from_type("logical", "and_gate"));
+    TypeSystem::new(vec![component], vec![entity_type], Vec::new(), Vec::new())
+}
+
+#[test]
+fn type_alias_resolution_test() {
+    let type_system = create_type_system();
+    let ty = EntityTypeId::new_from_type("logical", "and");
+    let alias = EntityTypeId::new_from_type("logical", "and_gate");
+    assert!(type_system.get_entity_type(&alias).is_none());
+    assert_eq!(ty, type_system.resolve_entity_type(&alias).unwrap().ty);
+    assert_eq!(ty, type_system.resolve_entity_type(&ty).unwrap().ty);
+    assert!(type_system.resolve_entity_type(&EntityTypeId::new_from_type("logical", "or")).is_none());
+    assert_eq!(
+        ComponentTypeId::new_from_type("base", "named"),
+        type_system.resolve_component(&ComponentTypeId::new_from_type("base", "labeled")).unwrap().ty
+    );
+}
+
+#[test]
+fn type_alias_validation_test() {
+    let type_system = create_type_system();
+    let mut properties = HashMap::new();
+    properties.insert("name".to_string(), json!("and"));
+    properties.insert("result".to_string(), json!(false));
+    // An instance of the previous type id whose entity type references the component by its previous type id
+    let entity_instance = EntityInstance::new(EntityTypeId::new_from_type("logical", "and_gate"), Uuid::new_v4(), properties);
+    assert!(type_system.validate_entity_instance(&entity_instance).is_ok());
+}
+
+#[test]
+fn type_alias_dao_test() {
+    let entity_type = EntityType::new(EntityTypeId::new_from_type("logical", "and"), "", Vec::new(), Vec::new(), Vec::new())
+        .with_alias(EntityTypeId::new_from_type("logical", "and_gate"));
+    let dao = EntityTypeDao::from(entity_type);
+    assert_eq!(json!([{ "namespace": "logical", "type_name": "and_gate" }]), serde_json::to_value(&dao).unwrap()["aliases"]);
+    let entity_type = EntityType::from(dao);
+    assert!(entity_type.is_known_as(&EntityTypeId::new_from_type("logical", "and_gate")));
+    assert!(!entity_type.is_known_as(&EntityTypeId::new_from_type("logical", "or")));
+}
@@ -0,0 +1,42 @@
+use serde_json::json;
+
+use crate::test_utils::assert_json_roundtrip;
+use crate::type_appearance_extension_type_id;
+use crate::Component;
+use crate::ComponentTypeId;
+use crate::EntityType;
+use crate::EntityTypeId;
+use crate::ExtensionContainer;
+use crate::TypeAppearance;
+use crate::TypeShape;
+
+#[test]
+fn entity_type_appearance_test() {
+    let entity_type = EntityType::new(EntityTypeId::new_from_type("logical", "and"), "", Vec::new(), Vec::new(), Vec::new());
+    assert_eq!(None, entity_type.appearance().unwrap());
+
+    let appearance = TypeAppearance::default()
+        .with_color("#3366ff")
+        .with_icon("gate-and")
+        .with_shape(TypeShape::RoundedRectangle);
+    let entity_type = entity_type.with_appearance(appearance.clone()).unwrap();
+    assert_eq!(Some(appearance), entity_type.appearance().unwrap());
+    assert!(entity_type.has_own_extension(&type_appearance_extension_type_id()));
+    assert_eq!(
+        json!({ "color": "#3366ff", "icon": "gate-and", "shape": "rounded_rectangle" }),
+        entity_type.get_own_extension(&type_appearance_extension_type_id()).unwrap().extension
+    );
+
+    let entity_type = entity_type.with_appearance(TypeAppearance::default().with_shape(TypeShape::Circle)).unwrap();
+    assert_eq!(1, entity_type.extensions.len());
+    let deserialized = assert_json_roundtrip(&entity_type);
+    assert_eq!(Some(TypeAppearance::default().with_shape(TypeShape::Circle)), deserialized.appearance().unwrap());
+}
+
+#[test]
+fn component_appearance_test() {
+    let component = Component::new_without_extensions(ComponentTypeId::new_from_type("base", "labeled"), "", Vec::new());
+    assert_eq!(None, component.appearance().unwrap());
+    let component = component.with_appearance(TypeAppearance::default().with_icon("label")).unwrap();
+    assert_eq!(Some("label".to_string()), component.appearance().unwrap().and_then(|appearance| appearance.icon));
+}
@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::flow_type_local_id;
+use crate::test_utils::assert_dao_roundtrip;
+use crate::test_utils::create_entity_instance;
+use crate::test_utils::r_string;
+use crate::ComponentOrEntityTypeId;
+use crate::ComponentTypeId;
+use crate::EntityType;
+use crate::EntityTypeDao;
+use crate::EntityTypeId;
+use crate::FlowType;
+use crate::FlowTypeDao;
+use crate::FlowTypeId;
+use crate::FlowTypePort;
+use crate::NamespacedTypeGetter;
+use crate::PropertyType;
+use crate::RelationInstance;
+use crate::RelationInstanceTypeId;
+use crate::RelationType;
+use crate::RelationTypeDao;
+use crate::RelationTypeId;
+
+#[test]
+fn entity_type_dao_conversion_test() {
+    let component_ty = ComponentTypeId::new_from_type(r_string(), r_string());
+    let entity_type = EntityType::new(
+        EntityTypeId::new_from_type(r_string(), r_string()),
+        r_string(),
+        vec![component_ty.clone()],
+        vec![PropertyType::string(r_string())],
+        Vec::new(),
+    );
+    assert_dao_roundtrip::<EntityType, EntityTypeDao>(&entity_type);
+    let dao = EntityTypeDao::from(entity_type.clone());
+    assert_eq!(entity_type.namespace(), dao.namespace);
+    assert_eq!(entity_type.type_name(), dao.type_name);
+    assert_eq!(vec![component_ty], dao.components);
+    assert!(dao.extra.is_empty());
+
+    let converted = EntityType::from(dao);
+    assert_eq!(entity_type.ty, converted.ty);
+    assert_eq!(entity_type.description, converted.description);
+    assert_eq!(entity_type.components, converted.components);
+    assert_eq!(entity_type.properties.first().unwrap().name, converted.properties.first().unwrap().name);
+}
+
+#[test]
+fn entity_type_dao_deserialize_with_aliases_and_defaults_test() {
+    let dao: EntityTypeDao = serde_json::from_value(json!({ "name": "and", "future_field": 1 })).unwrap();
+    assert_eq!(String::new(), dao.namespace);
+    assert_eq!("and", dao.type_name);
+    assert_eq!(String::new(), dao.description);
+    assert!(dao.components.is_empty());
+    assert!(dao.properties.is_empty());
+    assert!(dao.extensions.is_empty());
+    assert_eq!(Some(&json!(1)), dao.extra.get("future_field"));
+}
+
+#[test]
+fn relation_type_dao_conversion_test() {
+    let relation_type = RelationType::new(
+        ComponentTypeId::new_from_type(r_string(), r_string()),
+        RelationTypeId::new_from_type(r_string(), r_string()),
+        EntityTypeId::new_from_type(r_string(), r_string()),
+        r_string(),
+        Vec::new(),
+        vec![PropertyType::bool(r_string())],
+        Vec::new(),
+    );
+    assert_dao_roundtrip::<RelationType, RelationTypeDao>(&relation_type);
+    let dao = RelationTypeDao::from(relation_type.clone());
+    assert_eq!(relation_type.namespace(), dao.namespace);
+    assert_eq!(relation_type.type_name(), dao.type_name);
+    assert_eq!(relation_type.outbound_type, dao.outbound_type);
+    assert_eq!(relation_type.inbound_type, dao.inbound_type);
+
+    let converted = RelationType::from(dao);
+    assert_eq!(relation_type.ty, converted.ty);
+    assert_eq!(relation_type.outbound_type, converted.outbound_type);
+    assert_eq!(relation_type.inbound_type, converted.inbound_type);
+    assert_eq!(relation_type.properties.first().unwrap().name, converted.properties.first().unwrap().name);
+}
+
+#[test]
+fn relation_type_dao_deserialize_with_aliases_and_defaults_test() {
+    let dao: RelationTypeDao = serde_json::from_value(json!({
+        "outbound": { "component": { "namespace": "base", "type_name": "labeled" } },
+        "name": "connector",
+        "inbound_type": { "entity_type": { "namespace": "logical", "type_name": "and" } },
+    }))
+    .unwrap();
+    assert_eq!(ComponentOrEntityTypeId::Component(ComponentTypeId::new_from_type("base", "labeled")), dao.outbound_type);
+    assert_eq!(String::new(), dao.namespace);
+    assert_eq!("connector", dao.type_name);
+    assert_eq!(ComponentOrEntityTypeId::EntityType(EntityTypeId::new_from_type("logical", "and")), dao.inbound_type);
+    assert!(dao.components.is_empty());
+    assert!(dao.properties.is_empty());
+}
+
+#[test]
+fn flow_type_dao_conversion_test() {
+    let wrapper_entity_instance = create_entity_instance(r_string());
+    let entity_instance = create_entity_instance(r_string());
+    let ty = RelationInstanceTypeId::new_from_type_unique_for_instance_id(r_string(), r_string(), r_string());
+    let relation_instance = RelationInstance::new(wrapper_entity_instance.id, ty, entity_instance.id, HashMap::new());
+    let mut flow_type = FlowType::new(
+        FlowTypeId::new_from_type(r_string(), r_string()),
+        r_string(),
+        wrapper_entity_instance.clone(),
+        vec![entity_instance.clone()],
+        vec![relation_instance.clone()],
+        vec![PropertyType::number(r_string())],
+        Vec::new(),
+    );
+    flow_type.add_input(FlowTypePort::new(r_string(), entity_instance.id, r_string()));
+
+    assert_dao_roundtrip::<FlowType, FlowTypeDao>(&flow_type);
+    let dao = FlowTypeDao::from(flow_type.clone());
+    assert_eq!(flow_type.namespace(), dao.namespace);
+    assert_eq!(flow_type.type_name(), dao.type_name);
+    assert_eq!(wrapper_entity_instance.id, dao.wrapper_entity_instance.id);
+    assert_eq!(1, dao.entity_instances.len());
+    assert_eq!(1, dao.relation_instances.len());
+    assert_eq!(1, dao.inputs.len());
+
+    let converted = FlowType::from(dao);
+    assert_eq!(flow_type.ty, converted.ty);
+    assert_eq!(flow_type.wrapper_entity_instance.id, converted.wrapper_entity_instance.id);
+    assert_eq!(entity_instance.properties, converted.entity_instances.first().unwrap().properties);
+    assert_eq!(relation_instance.get_key(), converted.relation_instances.first().unwrap().get_key());
+    assert_eq!(flow_type.inputs, converted.inputs);
+    assert_eq!(1, converted.variables.len());
+}
+
+#[test]
+fn flow_type_dao_deserialize_with_local_ids_test() {
+    let wrapper_id = Uuid::new_v4();
+    let dao: FlowTypeDao = serde_json::from_value(json!({
+        "name": "flow",
+        "wrapper_entity_instance": { "type_name": "wrapper", "id": wrapper_id },
+        "entities": [ { "type_name": "and", "id": "and1" } ],
+        "relations": [ { "outbound": wrapper_id, "name": "connector", "inbound": "and1" } ],
+    }))
+    .unwrap();
+    assert_eq!("flow", dao.type_name);
+    assert_eq!(wrapper_id, dao.wrapper_entity_instance.id);
+    assert_eq!(flow_type_local_id("and1"), dao.entity_instances.first().unwrap().id);
+    let relation_instance = dao.relation_instances.first().unwrap();
+    assert_eq!(wrapper_id, relation_instance.outbound_id);
+    assert_eq!(flow_type_local_id("and1"), relation_instance.inbound_id);
+    assert!(dao.variables.is_empty());
+    assert!(dao.inputs.is_empty());
+    assert!(dao.outputs.is_empty());
+}
@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::test_utils::assert_json_roundtrip;
+use crate::DataType;
+use crate::EntityInstance;
+use crate::EntityType;
+use crate::EntityTypeDao;
+use crate::EntityTypeId;
+use crate::FlowInstance;
+use crate::FlowType;
+use crate::FlowTypeDao;
+use crate::FlowTypeId;
+use crate::PropertyError;
+use crate::PropertyType;
+use crate::ValidationError;
+
+fn create_entity_type() -> EntityType {
+    EntityType::new(
+        EntityTypeId::new_from_type("logical", "and"),
+        "",
+        Vec::new(),
+        vec![PropertyType::input("lhs", DataType::Bool).with_example(true).with_example(false)],
+        Vec::new(),
+    )
+}
+
+fn create_example(lhs: serde_json::Value) -> EntityInstance {
+    EntityInstance::new(EntityTypeId::new_from_type("logical", "and"), Uuid::new_v4(), HashMap::from([("lhs".to_string(), lhs)]))
+}
+
+#[test]
+fn property_type_examples_test() {
+    let property_type = PropertyType::number("temperature").with_example(21.5).with_example(-3);
+    assert_eq!(vec![json!(21.5), json!(-3)], property_type.examples);
+    assert_eq!(Ok(()), property_type.validate_examples());
+    assert_eq!(json!([21.5, -3]), serde_json::to_value(&property_type).unwrap()["examples"]);
+    assert!(serde_json::to_value(PropertyType::number("temperature")).unwrap().get("examples").is_none());
+
+    let property_type = property_type.with_example("warm");
+    assert_eq!(
+        Err(PropertyError::InvalidDataType {
+            name: "temperature".to_string(),
+            expected: DataType::Number,
+            actual: DataType::String,
+        }),
+        property_type.validate_examples()
+    );
+}
+
+#[test]
+fn entity_type_examples_test() {
+    let entity_type = create_entity_type().with_example(create_example(json!(true)));
+    assert_eq!(1, entity_type.examples.len());
+    assert!(entity_type.validate_examples(&[]).is_ok());
+
+    let dao = EntityTypeDao::from(entity_type.clone());
+    assert_eq!(1, dao.examples.len());
+    let deserialized = EntityType::from(assert_json_roundtrip(&dao));
+    assert_eq!(entity_type.examples[0].id, deserialized.examples[0].id);
+    assert_eq!(json!(true), deserialized.examples[0].properties["lhs"]);
+
+    let entity_type = entity_type.with_example(create_example(json!(1)));
+    assert!(matches!(
+        entity_type.validate_examples(&[]),
+        Err(ValidationError::Property(PropertyError::InvalidDataType { .. }))
+    ));
+}
+
+#[test]
+fn flow_type_examples_test() {
+    let wrapper = EntityInstance::new_without_properties(EntityTypeId::new_from_type("flow", "generic_flow"), Uuid::new_v4());
+    let flow_type = FlowType::new(
+        FlowTypeId::new_from_type("logical", "and_flow"),
+        "",
+        wrapper.clone(),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+    );
+    assert!(serde_json::to_value(FlowTypeDao::from(flow_type.clone())).unwrap().get("examples").is_none());
+
+    let flow_type = flow_type.with_example(FlowInstance::from(wrapper.clone()));
+    let deserialized = FlowType::from(assert_json_roundtrip(&FlowTypeDao::from(flow_type)));
+    assert_eq!(1, deserialized.examples.len());
+    assert_eq!(wrapper.id, deserialized.examples[0].id);
+}
@@ -0,0 +1,67 @@
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::json;
+
+use crate::compact_type_id;
+use crate::compact_type_ids;
+use crate::parse_compact_type_id;
+use crate::to_compact_type_id;
+use crate::ComponentTypeId;
+use crate::EntityTypeId;
+use crate::NamespacedType;
+use crate::RelationTypeId;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct CompactTypeIds {
+    #[serde(with = "compact_type_id")]
+    component: ComponentTypeId,
+    #[serde(with = "compact_type_id")]
+    relation_type: RelationTypeId,
+    #[serde(with = "compact_type_ids")]
+    entity_types: Vec<EntityTypeId>,
+}
+
+fn create_compact_type_ids() -> CompactTypeIds {
+    CompactTypeIds {
+        component: ComponentTypeId::new_from_type("base", "labeled"),
+        relation_type: RelationTypeId::new_from_type("game", "owns"),
+        entity_types: vec![EntityTypeId::new_from_type("game", "player"), EntityTypeId::new_from_type("game", "unit")],
+    }
+}
+
+#[test]
+fn compact_type_id_serialize_test() {
+    assert_eq!(
+        json!({
+            "component": "base__labeled",
+            "relation_type": "game__owns",
+            "entity_types": ["game__player", "game__unit"]
+        }),
+        serde_json::to_value(create_compact_type_ids()).unwrap()
+    );
+}
+
+#[test]
+fn compact_type_id_deserialize_test() {
+    let compact: CompactTypeIds = serde_json::from_value(json!({
+        "component": "base__labeled",
+        "relation_type": { "namespace": "game", "type_name": "owns" },
+        "entity_types": ["game__player", { "namespace": "game", "type_name": "unit" }]
+    }))
+    .unwrap();
+    assert_eq!(create_compact_type_ids(), compact);
+
+    let invalid = json!({ "component": "base", "relation_type": "game__owns", "entity_types": [] });
+    assert!(serde_json::from_value::<CompactTypeIds>(invalid).is_err());
+    let invalid = json!({ "component": "base__labeled", "relation_type": "game__owns", "entity_types": ["a__b__c"] });
+    assert!(serde_json::from_value::<CompactTypeIds>(invalid).is_err());
+}
+
+#[test]
+fn parse_compact_type_id_test() {
+    assert_eq!(Some(NamespacedType::new("base", "labeled")), parse_compact_type_id("base__labeled"));
+    assert_eq!(None, parse_compact_type_id("base__"));
+    assert_eq!(None, parse_compact_type_id("__labeled"));
+    assert_eq!(None, parse_compact_type_id("labeled"));
+    assert_eq!("base__labeled", to_compact_type_id(&ComponentTypeId::new_from_type("base", "labeled")));
+}
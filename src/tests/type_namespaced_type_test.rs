@@ -1,4 +1,4 @@
-use crate::tests::utils::r_string;
+use crate::test_utils::r_string;
 use crate::NamespacedType;
 use crate::NamespacedTypeGetter;
 
@@ -11,3 +11,14 @@ fn namespaced_type_from_str_test() {
     assert_eq!(type_name, nt.type_name());
     assert_eq!(format!("{namespace}__{type_name}"), format!("{}", nt));
 }
+
+#[test]
+fn namespaced_type_json_test() {
+    let nt: NamespacedType = serde_json::from_str(r#"{ "name": "and" }"#).unwrap();
+    assert_eq!("", nt.namespace);
+    assert_eq!("and", nt.type_name);
+    let nt = NamespacedType::new("logical", "and");
+    let json = serde_json::to_value(&nt).unwrap();
+    assert_eq!(serde_json::json!({ "namespace": "logical", "type_name": "and" }), json);
+    assert_eq!(nt, serde_json::from_value(json).unwrap());
+}
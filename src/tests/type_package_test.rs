@@ -0,0 +1,66 @@
+use std::str::FromStr;
+
+use serde_json::json;
+
+use crate::TypeDefinition;
+use crate::TypePackage;
+use crate::TypePackageDependency;
+use crate::TypePackageDependencyError;
+use crate::TypePackageVersion;
+use crate::TypePackageVersionParseError;
+
+#[test]
+fn type_package_version_test() {
+    let version = TypePackageVersion::from_str("1.2.3").unwrap();
+    assert_eq!(TypePackageVersion::new(1, 2, 3), version);
+    assert_eq!("1.2.3", version.to_string());
+    assert_eq!(json!("1.2.3"), serde_json::to_value(version).unwrap());
+    assert_eq!(version, serde_json::from_value(json!("1.2.3")).unwrap());
+    assert_eq!(Err(TypePackageVersionParseError("1.2".to_string())), TypePackageVersion::from_str("1.2"));
+    assert!(TypePackageVersion::from_str("1.x.3").is_err());
+    assert!(serde_json::from_value::<TypePackageVersion>(json!("a.b.c")).is_err());
+
+    assert!(TypePackageVersion::new(1, 3, 0).is_compatible_with(&TypePackageVersion::new(1, 2, 3)));
+    assert!(TypePackageVersion::new(1, 2, 3).is_compatible_with(&TypePackageVersion::new(1, 2, 3)));
+    assert!(!TypePackageVersion::new(1, 2, 2).is_compatible_with(&TypePackageVersion::new(1, 2, 3)));
+    assert!(!TypePackageVersion::new(2, 0, 0).is_compatible_with(&TypePackageVersion::new(1, 2, 3)));
+    assert!(TypePackageVersion::new(0, 2, 5).is_compatible_with(&TypePackageVersion::new(0, 2, 1)));
+    assert!(!TypePackageVersion::new(0, 3, 0).is_compatible_with(&TypePackageVersion::new(0, 2, 1)));
+}
+
+#[test]
+fn type_package_test() {
+    let package: TypePackage = serde_json::from_value(json!({
+        "name": "logical",
+        "version": "1.0.0",
+        "namespaces": ["logical"],
+        "dependencies": [{ "name": "base", "version": "1.1.0" }],
+        "types": [
+            { "type_id_type": "EntityType", "namespace": "logical", "type_name": "and" },
+            { "type_id_type": "Component", "namespace": "base", "type_name": "labeled" },
+        ],
+    }))
+    .unwrap();
+    assert_eq!("logical", package.name);
+    assert!(package.has_namespace("logical"));
+    assert!(package.contains(&TypeDefinition::entity_type("logical", "and")));
+    assert!(!package.contains(&TypeDefinition::entity_type("logical", "or")));
+    assert_eq!(vec![TypeDefinition::component("base", "labeled")], package.types_outside_of_namespaces());
+
+    assert_eq!(Err(vec![TypePackageDependencyError::MissingPackage("base".to_string())]), package.check_dependencies(&[]));
+    let base_1_0 = TypePackage::new("base", TypePackageVersion::new(1, 0, 0));
+    assert_eq!(
+        Err(vec![TypePackageDependencyError::IncompatibleVersion {
+            name: "base".to_string(),
+            required: TypePackageVersion::new(1, 1, 0),
+            available: TypePackageVersion::new(1, 0, 0),
+        }]),
+        package.check_dependencies(&[base_1_0.clone()])
+    );
+    let base_1_2 = TypePackage::new("base", TypePackageVersion::new(1, 2, 0));
+    assert_eq!(Ok(()), package.check_dependencies(&[base_1_0, base_1_2]));
+
+    let mut other = TypePackage::new("other", TypePackageVersion::new(0, 1, 0));
+    other.dependencies.push(TypePackageDependency::new("logical", TypePackageVersion::new(1, 0, 0)));
+    assert_eq!(Ok(()), other.check_dependencies(&[package]));
+}
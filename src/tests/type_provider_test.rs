@@ -0,0 +1,74 @@
+use crate::collect_types;
+use crate::Component;
+use crate::ComponentTypeId;
+use crate::EntityType;
+use crate::EntityTypeId;
+use crate::PropertyType;
+use crate::TypeImportStrategy;
+use crate::TypeProvider;
+use crate::TypeSystemImportError;
+
+struct BaseTypes;
+
+impl TypeProvider for BaseTypes {
+    fn components(&self) -> Vec<Component> {
+        vec![Component::new_without_extensions(
+            ComponentTypeId::new_from_type("base", "labeled"),
+            "",
+            vec![PropertyType::string("label")],
+        )]
+    }
+}
+
+struct LogicalTypes;
+
+impl TypeProvider for LogicalTypes {
+    fn components(&self) -> Vec<Component> {
+        BaseTypes.components()
+    }
+
+    fn entity_types(&self) -> Vec<EntityType> {
+        vec![EntityType::new_from_type(
+            "logical",
+            "and",
+            "",
+            vec![ComponentTypeId::new_from_type("base", "labeled")],
+            vec![PropertyType::bool("result")],
+            Vec::new(),
+        )]
+    }
+}
+
+#[cfg(feature = "inventory")]
+static REGISTERED_LOGICAL_TYPES: LogicalTypes = LogicalTypes;
+
+#[cfg(feature = "inventory")]
+crate::register_type_provider!(REGISTERED_LOGICAL_TYPES);
+
+#[test]
+fn type_provider_test() {
+    let type_system = LogicalTypes.type_system();
+    assert_eq!(1, type_system.components.len());
+    assert_eq!(1, type_system.entity_types.len());
+    assert!(type_system.relation_types.is_empty());
+    assert!(type_system.flow_types.is_empty());
+}
+
+#[test]
+fn collect_types_test() {
+    let type_system = collect_types(&[&BaseTypes, &LogicalTypes], TypeImportStrategy::Skip).unwrap();
+    assert_eq!(1, type_system.components.len());
+    assert!(type_system.get_entity_type(&EntityTypeId::new_from_type("logical", "and")).is_some());
+
+    assert!(matches!(
+        collect_types(&[&BaseTypes, &LogicalTypes], TypeImportStrategy::Fail),
+        Err(TypeSystemImportError::Conflicts(_))
+    ));
+}
+
+#[test]
+#[cfg(feature = "inventory")]
+fn collect_registered_types_test() {
+    let type_system = crate::collect_registered_types(TypeImportStrategy::Skip).unwrap();
+    assert!(type_system.get_entity_type(&EntityTypeId::new_from_type("logical", "and")).is_some());
+}
@@ -0,0 +1,121 @@
+use crate::test_utils::create_entity_instance;
+use crate::Component;
+use crate::ComponentTypeId;
+use crate::DataType;
+use crate::EntityType;
+use crate::EntityTypeId;
+use crate::FlowType;
+use crate::FlowTypeId;
+use crate::PropertyType;
+use crate::RelationType;
+use crate::RelationTypeId;
+use crate::TypeDefinition;
+use crate::TypeIdType;
+use crate::TypeQuery;
+use crate::TypeSystem;
+
+fn create_type_system() -> TypeSystem {
+    let labeled = Component::new_without_extensions(ComponentTypeId::new_from_type("base", "labeled"), "", vec![PropertyType::string("label")]);
+    let and = EntityType::new(
+        EntityTypeId::new_from_type("logical", "and"),
+        "",
+        vec![labeled.ty.clone()],
+        vec![PropertyType::input("lhs", DataType::Bool), PropertyType::output("result", DataType::Bool)],
+        Vec::new(),
+    );
+    let or = EntityType::new(
+        EntityTypeId::new_from_type("logical", "or"),
+        "",
+        Vec::new(),
+        vec![PropertyType::input("lhs", DataType::Bool), PropertyType::output("result", DataType::Bool)],
+        Vec::new(),
+    );
+    let add = EntityType::new(
+        EntityTypeId::new_from_type("arithmetic_f64", "add"),
+        "",
+        Vec::new(),
+        vec![PropertyType::input("lhs", DataType::Number), PropertyType::output("result", DataType::Number)],
+        Vec::new(),
+    );
+    let connector = RelationType::new(
+        and.ty.clone(),
+        RelationTypeId::new_from_type("connector", "default_connector"),
+        or.ty.clone(),
+        "",
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+    );
+    let flow_type = FlowType::new(
+        FlowTypeId::new_from_type("logical", "and_or"),
+        "",
+        create_entity_instance("and_or"),
+        Vec::new(),
+        Vec::new(),
+        vec![PropertyType::number("threshold")],
+        Vec::new(),
+    );
+    TypeSystem::new(vec![labeled], vec![and, or, add], vec![connector], vec![flow_type])
+}
+
+#[test]
+fn type_search_namespace_and_name_test() {
+    let type_system = create_type_system();
+    assert_eq!(6, type_system.search(&TypeQuery::new()).len());
+    assert_eq!(
+        vec![
+            TypeDefinition::entity_type("logical", "and"),
+            TypeDefinition::entity_type("logical", "or"),
+            TypeDefinition::flow_type("logical", "and_or"),
+        ],
+        type_system.search(&TypeQuery::new().namespace("logical"))
+    );
+    assert_eq!(
+        vec![TypeDefinition::entity_type("arithmetic_f64", "add")],
+        type_system.search(&TypeQuery::new().namespace("arithmetic_*"))
+    );
+    assert_eq!(
+        vec![TypeDefinition::entity_type("logical", "and"), TypeDefinition::flow_type("logical", "and_or")],
+        type_system.search(&TypeQuery::new().name("and"))
+    );
+    assert_eq!(
+        vec![
+            TypeDefinition::entity_type("logical", "and"),
+            TypeDefinition::entity_type("arithmetic_f64", "add")
+        ],
+        type_system.search(&TypeQuery::new().name("a?d"))
+    );
+    assert_eq!(
+        vec![TypeDefinition::flow_type("logical", "and_or")],
+        type_system.search(&TypeQuery::new().name("*_or").type_id_type(TypeIdType::FlowType))
+    );
+}
+
+#[test]
+fn type_search_component_and_data_type_test() {
+    let type_system = create_type_system();
+    assert_eq!(
+        vec![TypeDefinition::entity_type("logical", "and")],
+        type_system.search(&TypeQuery::new().has_component(ComponentTypeId::new_from_type("base", "labeled")))
+    );
+    assert_eq!(
+        vec![TypeDefinition::entity_type("logical", "and"), TypeDefinition::entity_type("logical", "or")],
+        type_system.search(&TypeQuery::new().has_property_of_data_type(DataType::Bool))
+    );
+    // The properties of the components are respected
+    assert_eq!(
+        vec![TypeDefinition::component("base", "labeled"), TypeDefinition::entity_type("logical", "and")],
+        type_system.search(&TypeQuery::new().has_property_of_data_type(DataType::String))
+    );
+    // The variables of flow types are respected
+    assert_eq!(
+        vec![
+            TypeDefinition::entity_type("arithmetic_f64", "add"),
+            TypeDefinition::flow_type("logical", "and_or")
+        ],
+        type_system.search(&TypeQuery::new().has_property_of_data_type(DataType::Number))
+    );
+    assert!(type_system
+        .search(&TypeQuery::new().namespace("arithmetic_f64").has_property_of_data_type(DataType::Bool))
+        .is_empty());
+}
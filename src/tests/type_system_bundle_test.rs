@@ -0,0 +1,149 @@
+use serde_json::json;
+
+use crate::export_type_system_bundle;
+use crate::import_type_system_bundle;
+use crate::test_utils::create_entity_instance;
+use crate::test_utils::r_string;
+use crate::Component;
+use crate::ComponentTypeId;
+use crate::EntityType;
+use crate::EntityTypeId;
+use crate::FlowType;
+use crate::FlowTypeId;
+use crate::PropertyType;
+use crate::RelationType;
+use crate::RelationTypeId;
+use crate::TypeDefinitionGetter;
+use crate::TypeImportStrategy;
+use crate::TypeSystem;
+use crate::TypeSystemBundle;
+use crate::TypeSystemBundleError;
+use crate::TypeSystemImportError;
+use crate::TYPE_SYSTEM_BUNDLE_FORMAT_VERSION;
+
+fn create_type_system() -> TypeSystem {
+    let component_ty = ComponentTypeId::new_from_type(r_string(), r_string());
+    let component = Component::new_without_extensions(component_ty.clone(), r_string(), vec![PropertyType::string(r_string())]);
+    let entity_ty = EntityTypeId::new_from_type(r_string(), r_string());
+    let entity_type = EntityType::new(entity_ty.clone(), r_string(), vec![component_ty.clone()], Vec::new(), Vec::new());
+    let relation_type = RelationType::new(
+        entity_ty.clone(),
+        RelationTypeId::new_from_type(r_string(), r_string()),
+        component_ty,
+        r_string(),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+    );
+    let flow_type = FlowType::new(
+        FlowTypeId::new_from_type(r_string(), r_string()),
+        r_string(),
+        create_entity_instance(r_string()),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+    );
+    TypeSystem::new(vec![component], vec![entity_type], vec![relation_type], vec![flow_type])
+}
+
+#[test]
+fn type_system_bundle_export_import_test() {
+    let type_system = create_type_system();
+    let document = export_type_system_bundle(&type_system).unwrap();
+    let bundle: TypeSystemBundle = serde_json::from_str(&document).unwrap();
+    assert_eq!(TYPE_SYSTEM_BUNDLE_FORMAT_VERSION, bundle.format_version);
+    assert_eq!(1, bundle.components.len());
+    assert_eq!(1, bundle.entity_types.len());
+    assert_eq!(1, bundle.relation_types.len());
+    assert_eq!(1, bundle.flow_types.len());
+
+    let imported = import_type_system_bundle(&document).unwrap();
+    assert_eq!(type_system.components.first().unwrap().ty, imported.components.first().unwrap().ty);
+    assert_eq!(type_system.entity_types.first().unwrap().ty, imported.entity_types.first().unwrap().ty);
+    assert_eq!(type_system.entity_types.first().unwrap().components, imported.entity_types.first().unwrap().components);
+    assert_eq!(type_system.relation_types.first().unwrap().ty, imported.relation_types.first().unwrap().ty);
+    assert_eq!(type_system.flow_types.first().unwrap().ty, imported.flow_types.first().unwrap().ty);
+}
+
+#[test]
+fn type_system_bundle_import_errors_test() {
+    let document = json!({ "version": TYPE_SYSTEM_BUNDLE_FORMAT_VERSION + 1 }).to_string();
+    assert!(matches!(
+        import_type_system_bundle(&document),
+        Err(TypeSystemBundleError::UnsupportedFormatVersion(format_version)) if format_version == TYPE_SYSTEM_BUNDLE_FORMAT_VERSION + 1
+    ));
+    assert!(matches!(import_type_system_bundle("{}"), Err(TypeSystemBundleError::InvalidDocument(_))));
+
+    let type_system = import_type_system_bundle(&json!({ "format_version": 1 }).to_string()).unwrap();
+    assert!(type_system.is_empty());
+}
+
+#[test]
+fn type_system_bundle_preserves_unknown_fields_test() {
+    let document = json!({
+        "format_version": 1,
+        "future_field": [1, 2],
+        "components": [{ "namespace": "base", "type_name": "labeled", "future_component_field": true }],
+        "entity_types": [{ "namespace": "logical", "type_name": "and", "future_entity_type_field": "value" }],
+    });
+    let type_system = import_type_system_bundle(&document.to_string()).unwrap();
+    let exported: serde_json::Value = serde_json::from_str(&export_type_system_bundle(&type_system).unwrap()).unwrap();
+    assert_eq!(json!([1, 2]), exported["future_field"]);
+    assert_eq!(json!(true), exported["components"][0]["future_component_field"]);
+    assert_eq!(json!("value"), exported["entity_types"][0]["future_entity_type_field"]);
+}
+
+#[test]
+fn type_system_import_bundle_strategies_test() {
+    let type_system = create_type_system();
+    let component = type_system.components.first().unwrap().clone();
+    let mut imported_component = component.clone();
+    imported_component.description = r_string();
+    imported_component.properties.push(PropertyType::bool(r_string()));
+    let new_component = Component::new_without_extensions(ComponentTypeId::new_from_type(r_string(), r_string()), r_string(), Vec::new());
+    let imported = TypeSystem::new(vec![imported_component.clone(), new_component.clone()], Vec::new(), Vec::new(), Vec::new());
+
+    let mut skipped = type_system.clone();
+    let report = skipped
+        .import_bundle(TypeSystemBundle::from(imported.clone()), TypeImportStrategy::Skip)
+        .unwrap();
+    assert_eq!(vec![new_component.type_definition()], report.added);
+    assert_eq!(vec![component.type_definition()], report.skipped);
+    assert!(report.replaced.is_empty());
+    assert!(report.merged.is_empty());
+    assert_eq!(2, skipped.components.len());
+    assert_eq!(component.description, skipped.get_component(&component.ty).unwrap().description);
+
+    let mut replaced = type_system.clone();
+    let report = replaced
+        .import_bundle(TypeSystemBundle::from(imported.clone()), TypeImportStrategy::Replace)
+        .unwrap();
+    assert_eq!(vec![component.type_definition()], report.replaced);
+    let replaced_component = replaced.get_component(&component.ty).unwrap();
+    assert_eq!(imported_component.description, replaced_component.description);
+    assert_eq!(2, replaced_component.properties.len());
+
+    let mut merged = type_system.clone();
+    let report = merged
+        .import_bundle(TypeSystemBundle::from(imported.clone()), TypeImportStrategy::Merge)
+        .unwrap();
+    assert_eq!(vec![component.type_definition()], report.merged);
+    let merged_component = merged.get_component(&component.ty).unwrap();
+    assert_eq!(component.description, merged_component.description);
+    assert_eq!(2, merged_component.properties.len());
+
+    let mut failed = type_system.clone();
+    match failed.import_bundle(TypeSystemBundle::from(imported), TypeImportStrategy::Fail) {
+        Err(TypeSystemImportError::Conflicts(conflicts)) => assert_eq!(vec![component.type_definition()], conflicts),
+        _ => panic!("The import should fail"),
+    }
+    assert_eq!(1, failed.components.len());
+
+    let mut bundle = TypeSystemBundle::from(TypeSystem::default());
+    bundle.format_version = 0;
+    assert!(matches!(
+        failed.import_bundle(bundle, TypeImportStrategy::Replace),
+        Err(TypeSystemImportError::InvalidBundle(TypeSystemBundleError::UnsupportedFormatVersion(0)))
+    ));
+}
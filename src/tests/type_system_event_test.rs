@@ -0,0 +1,30 @@
+use serde_json::json;
+
+use crate::test_utils::assert_json_roundtrip_eq;
+use crate::ComponentTypeId;
+use crate::TypeDefinition;
+use crate::TypeSystemEvent;
+
+#[test]
+fn type_system_event_test() {
+    let ty = TypeDefinition::entity_type("logical", "and");
+    let event = TypeSystemEvent::ComponentAdded {
+        ty: ty.clone(),
+        component: ComponentTypeId::new_from_type("base", "labeled"),
+    };
+    assert_eq!(&ty, event.ty());
+    assert_eq!(&ty, TypeSystemEvent::TypeDeleted { ty: ty.clone() }.ty());
+    assert_eq!(
+        json!({
+            "event": "component_added",
+            "ty": { "type_id_type": "EntityType", "namespace": "logical", "type_name": "and" },
+            "component": { "namespace": "base", "type_name": "labeled" }
+        }),
+        serde_json::to_value(&event).unwrap()
+    );
+    assert_json_roundtrip_eq(&event);
+    assert_json_roundtrip_eq(&TypeSystemEvent::PropertyRemoved {
+        ty,
+        property_name: "result".to_string(),
+    });
+}
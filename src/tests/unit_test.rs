@@ -0,0 +1,73 @@
+use std::collections::HashMap;
+
+use serde_json::json;
+use serde_json::Value;
+
+use crate::convert_unit;
+use crate::Dimension;
+use crate::PropertyInstanceRefGetter;
+use crate::PropertyType;
+use crate::Unit;
+use crate::UnitError;
+
+#[test]
+fn parse_unit_test() {
+    let unit = Unit::parse("ms").unwrap();
+    assert_eq!("ms", unit.symbol);
+    assert_eq!(Dimension::TIME, unit.dimension);
+    assert_eq!(0.001, unit.scale);
+
+    assert_eq!(Dimension::TIME, Unit::parse("min").unwrap().dimension);
+    assert_eq!(Dimension::LENGTH, Unit::parse("mm").unwrap().dimension);
+    assert_eq!(Dimension::MASS, Unit::parse("kg").unwrap().dimension);
+    assert_eq!(1.0, Unit::parse("kg").unwrap().scale);
+    assert_eq!(Dimension::AMOUNT_OF_SUBSTANCE, Unit::parse("kmol").unwrap().dimension);
+    assert_eq!(Unit::parse("µs").unwrap().scale, Unit::parse("us").unwrap().scale);
+    assert_eq!(Dimension::LENGTH / Dimension::TIME, Unit::parse("km/h").unwrap().dimension);
+    let acceleration = Unit::parse("km/h/s").unwrap();
+    assert_eq!(Dimension::LENGTH / Dimension::TIME / Dimension::TIME, acceleration.dimension);
+    assert!((acceleration.scale - 1000.0 / 3600.0).abs() < 1e-12);
+    assert_eq!(Unit::parse("m/s/s").unwrap().dimension, Unit::parse("m / s / s").unwrap().dimension);
+    assert_eq!(Dimension::FREQUENCY, Dimension::DIMENSIONLESS / Dimension::TIME);
+    assert_eq!(Dimension::FORCE, Dimension::MASS * Dimension::LENGTH / Dimension::TIME / Dimension::TIME);
+
+    assert_eq!(Err(UnitError::UnknownUnit("furlong".to_string())), Unit::parse("furlong"));
+    assert_eq!(Err(UnitError::UnknownUnit("kh".to_string())), Unit::parse("kh"));
+}
+
+#[test]
+fn convert_unit_test() {
+    assert_eq!(Ok(1500.0), convert_unit(1.5, "s", "ms"));
+    assert_eq!(Ok(0.25), convert_unit(250.0, "ms", "s"));
+    assert_eq!(Ok(120.0), convert_unit(2.0, "h", "min"));
+    assert!((convert_unit(36.0, "km/h", "m/s").unwrap() - 10.0).abs() < 1e-9);
+    assert_eq!(
+        Err(UnitError::IncompatibleUnits {
+            from: "s".to_string(),
+            to: "m".to_string(),
+        }),
+        convert_unit(1.0, "s", "m")
+    );
+}
+
+#[test]
+fn property_type_unit_test() {
+    let property_type = PropertyType::number("timeout").with_unit("ms");
+    assert_eq!(Some("ms".to_string()), property_type.unit);
+    assert_eq!(Ok(Some(Dimension::TIME)), property_type.resolve_unit().map(|unit| unit.map(|unit| unit.dimension)));
+    assert_eq!(Ok(2.5), property_type.convert_to(2500.0, "s"));
+    assert_eq!(Ok(2500.0), property_type.convert_from(2.5, "s"));
+    assert_eq!(json!("ms"), serde_json::to_value(&property_type).unwrap()["unit"]);
+    assert!(serde_json::to_value(PropertyType::number("timeout")).unwrap().get("unit").is_none());
+    assert_eq!(Err(UnitError::MissingUnit("count".to_string())), PropertyType::number("count").convert_to(1.0, "s"));
+}
+
+#[test]
+fn as_f64_in_test() {
+    let timeout = PropertyType::number("timeout").with_unit("ms");
+    let properties: HashMap<String, Value> = HashMap::from([("timeout".to_string(), json!(1500))]);
+    assert_eq!(Ok(Some(1.5)), properties.as_f64_in(&timeout, "s"));
+    assert_eq!(Ok(Some(1500.0)), properties.as_f64_in(&timeout, "ms"));
+    assert!(properties.as_f64_in(&timeout, "m").is_err());
+    assert_eq!(Ok(None), properties.as_f64_in(&PropertyType::number("delay").with_unit("s"), "ms"));
+}
@@ -0,0 +1,159 @@
+use std::collections::HashMap;
+
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::test_utils::create_random_entity_instance;
+use crate::test_utils::r_string;
+use crate::test_utils::r_string_1000;
+use crate::try_property_identifier;
+use crate::Component;
+use crate::ComponentTypeId;
+use crate::DataType;
+use crate::EntityInstance;
+use crate::EntityType;
+use crate::EntityTypeId;
+use crate::IdentifierError;
+use crate::ModelError;
+use crate::Mutability;
+use crate::MutablePropertyInstanceSetter;
+use crate::NamespacedType;
+use crate::PropertyError;
+use crate::PropertyInstanceGetter;
+use crate::PropertyInstanceSetter;
+use crate::PropertyType;
+use crate::RelationInstance;
+use crate::RelationInstanceTypeId;
+use crate::RelationType;
+use crate::RelationTypeId;
+use crate::TypeDefinition;
+use crate::TypeSystem;
+use crate::ValidationError;
+
+fn type_system() -> TypeSystem {
+    let component_ty = ComponentTypeId::new_from_type("base", "labeled");
+    let component = Component::new_without_extensions(component_ty.clone(), "", vec![PropertyType::string("label")]);
+    let entity_type = EntityType::new_from_type("logical", "and", "", vec![component_ty], vec![PropertyType::bool("result")], Vec::new());
+    let relation_type = RelationType::new(
+        EntityTypeId::new_from_type("logical", "and"),
+        RelationTypeId::new_from_type("logical", "connector"),
+        EntityTypeId::new_from_type("logical", "and"),
+        "",
+        Vec::new(),
+        vec![PropertyType::string("outbound_property_name")],
+        Vec::new(),
+    );
+    TypeSystem::new(vec![component], vec![entity_type], vec![relation_type], Vec::new())
+}
+
+#[test]
+fn identifier_error_test() {
+    assert_eq!(Ok(()), NamespacedType::new("logical", "and").validate());
+    assert_eq!(Err(IdentifierError::Empty), NamespacedType::new("", "and").validate());
+    assert_eq!(Err(IdentifierError::ContainsSeparator("a__b".to_string())), NamespacedType::new("logical", "a__b").validate());
+    assert_eq!(Err(IdentifierError::TooLong(1000)), NamespacedType::new("logical", r_string_1000().as_str()).validate());
+
+    assert!(TypeDefinition::entity_type("logical", "and").try_type_id().is_ok());
+    assert_eq!(Err(IdentifierError::Empty), TypeDefinition::entity_type("logical", "").try_type_id());
+    assert_eq!(
+        Err(IdentifierError::TooLong(1000)),
+        TypeDefinition::entity_type("logical", r_string_1000().as_str()).try_type_id()
+    );
+
+    assert!(try_property_identifier("label").is_ok());
+    assert_eq!(Err(IdentifierError::Empty), try_property_identifier(""));
+    assert_eq!(Err(IdentifierError::TooLong(1000)), try_property_identifier(r_string_1000()));
+    assert_eq!("a__b must not contain the separator __", IdentifierError::ContainsSeparator("a__b".to_string()).to_string());
+}
+
+#[test]
+fn property_error_test() {
+    let properties = HashMap::from([("result".to_string(), json!(false))]);
+    let mut entity_instance = EntityInstance::new(EntityTypeId::new_from_type("logical", "and"), Uuid::new_v4(), properties);
+    assert_eq!(Ok(()), entity_instance.try_set("result", json!(true)));
+    assert_eq!(Some(true), entity_instance.as_bool("result"));
+    assert_eq!(Err(PropertyError::MissingProperty("unknown".to_string())), entity_instance.try_set("unknown", json!(true)));
+    assert!(entity_instance.get("unknown").is_none());
+
+    let property_name = r_string();
+    let reactive_entity_instance = create_random_entity_instance(property_name.clone());
+    assert_eq!(Ok(()), reactive_entity_instance.try_set_checked(property_name.clone(), json!("value")));
+    assert_eq!(Some("value".to_string()), reactive_entity_instance.as_string(property_name.clone()));
+    reactive_entity_instance.set_mutability(property_name.clone(), Mutability::Immutable);
+    assert_eq!(
+        Err(PropertyError::ImmutableProperty(property_name.clone())),
+        reactive_entity_instance.try_set_checked(property_name.clone(), json!("other"))
+    );
+    assert_eq!(Some("value".to_string()), reactive_entity_instance.as_string(property_name));
+    assert_eq!(
+        Err(PropertyError::MissingProperty("unknown".to_string())),
+        reactive_entity_instance.try_set_checked("unknown", json!(1))
+    );
+
+    let property_type = PropertyType::number("value");
+    assert_eq!(Ok(()), property_type.validate_value(&json!(1)));
+    assert_eq!(
+        Err(PropertyError::InvalidDataType {
+            name: "value".to_string(),
+            expected: DataType::Number,
+            actual: DataType::String,
+        }),
+        property_type.validate_value(&json!("1"))
+    );
+    assert_eq!(Ok(()), PropertyType::new("value", DataType::Any).validate_value(&json!("1")));
+}
+
+#[test]
+fn validation_error_test() {
+    let type_system = type_system();
+    let mut entity_instance = EntityInstance::new(
+        EntityTypeId::new_from_type("logical", "and"),
+        Uuid::new_v4(),
+        HashMap::from([("result".to_string(), json!(false)), ("label".to_string(), json!("and"))]),
+    );
+    assert_eq!(Ok(()), type_system.validate_entity_instance(&entity_instance));
+
+    entity_instance.properties.insert("label".to_string(), json!(1));
+    assert_eq!(
+        Err(ValidationError::Property(PropertyError::InvalidDataType {
+            name: "label".to_string(),
+            expected: DataType::String,
+            actual: DataType::Number,
+        })),
+        type_system.validate_entity_instance(&entity_instance)
+    );
+
+    entity_instance.properties.remove("result");
+    assert_eq!(
+        Err(ValidationError::Property(PropertyError::MissingProperty("result".to_string()))),
+        type_system.validate_entity_instance(&entity_instance)
+    );
+
+    let entity_type = type_system.get_entity_type(&EntityTypeId::new_from_type("logical", "and")).unwrap();
+    assert_eq!(
+        Err(ValidationError::UnknownComponent(ComponentTypeId::new_from_type("base", "labeled"))),
+        entity_type.validate_instance(&entity_instance, &[])
+    );
+
+    let other = EntityInstance::new(EntityTypeId::new_from_type("logical", "or"), Uuid::new_v4(), HashMap::new());
+    assert_eq!(
+        Err(ValidationError::UnexpectedType {
+            expected: "e__logical__and".to_string(),
+            actual: "e__logical__or".to_string(),
+        }),
+        entity_type.validate_instance(&other, &type_system.components)
+    );
+    assert_eq!(Err(ValidationError::UnknownEntityType(other.ty.clone())), type_system.validate_entity_instance(&other));
+
+    let ty = RelationInstanceTypeId::new_unique_for_instance_id(RelationTypeId::new_from_type("logical", "connector"), "result");
+    let mut relation_instance = RelationInstance::new_without_properties(Uuid::new_v4(), ty, Uuid::new_v4());
+    assert_eq!(
+        Err(ValidationError::Property(PropertyError::MissingProperty("outbound_property_name".to_string()))),
+        type_system.validate_relation_instance(&relation_instance)
+    );
+    relation_instance.properties.insert("outbound_property_name".to_string(), json!("result"));
+    assert_eq!(Ok(()), type_system.validate_relation_instance(&relation_instance));
+
+    let error: ModelError = ValidationError::UnknownComponent(ComponentTypeId::new_from_type("base", "labeled")).into();
+    assert_eq!("The component c__base__labeled is unknown", error.to_string());
+}
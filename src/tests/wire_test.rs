@@ -0,0 +1,257 @@
+use std::collections::HashMap;
+
+use chrono::DateTime;
+use prost::Message;
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::test_utils::create_entity_instance;
+use crate::test_utils::r_string;
+use crate::Component;
+use crate::ComponentOrEntityTypeId;
+use crate::ComponentTypeId;
+use crate::DataType;
+use crate::Deprecation;
+use crate::Documentation;
+use crate::EntityInstance;
+use crate::EntityType;
+use crate::EntityTypeId;
+use crate::Extension;
+use crate::ExtensionTypeId;
+use crate::FlowInstance;
+use crate::FlowType;
+use crate::FlowTypeId;
+use crate::FlowTypeParameter;
+use crate::FlowTypePort;
+use crate::Mutability;
+use crate::PropertyType;
+use crate::RelationCardinality;
+use crate::RelationInstance;
+use crate::RelationInstanceTypeId;
+use crate::RelationType;
+use crate::RelationTypeId;
+use crate::SocketType;
+use crate::SubFlowBinding;
+use crate::SubFlowReference;
+use crate::SubFlowTypeBinding;
+use crate::SubFlowTypeReference;
+use crate::WireComponent;
+use crate::WireEntityInstance;
+use crate::WireEntityType;
+use crate::WireFlowInstance;
+use crate::WireFlowType;
+use crate::WireFormatError;
+use crate::WirePropertyType;
+use crate::WireRelationType;
+use crate::WireTimestamp;
+
+#[test]
+fn component_wire_round_trip_test() {
+    let extension = Extension::new(ExtensionTypeId::new_from_type(r_string(), r_string()), r_string(), json!({ "x": [1, 2] }));
+    let property = PropertyType::new_with_all(r_string(), r_string(), DataType::Number, SocketType::Output, Mutability::Immutable, vec![extension.clone()]);
+    let component = Component::new(ComponentTypeId::new_from_type(r_string(), r_string()), r_string(), vec![property], vec![extension]);
+    let bytes = WireComponent::from(&component).encode_to_vec();
+    let decoded = Component::try_from(WireComponent::decode(bytes.as_slice()).unwrap()).unwrap();
+    assert_eq!(component.ty, decoded.ty);
+    assert_eq!(component.description, decoded.description);
+    let property = decoded.properties.first().unwrap();
+    assert_eq!(DataType::Number, property.data_type);
+    assert_eq!(SocketType::Output, property.socket_type);
+    assert_eq!(Mutability::Immutable, property.mutability);
+    assert_eq!(json!({ "x": [1, 2] }), property.extensions.first().unwrap().extension);
+    assert_eq!(component.extensions.first().unwrap().ty, decoded.extensions.first().unwrap().ty);
+}
+
+#[test]
+fn entity_type_and_relation_type_wire_round_trip_test() {
+    let component_ty = ComponentTypeId::new_from_type(r_string(), r_string());
+    let entity_type = EntityType::new(
+        EntityTypeId::new_from_type(r_string(), r_string()),
+        r_string(),
+        vec![component_ty.clone()],
+        vec![PropertyType::bool(r_string())],
+        Vec::new(),
+    );
+    let decoded = EntityType::try_from(WireEntityType::decode(WireEntityType::from(&entity_type).encode_to_vec().as_slice()).unwrap()).unwrap();
+    assert_eq!(entity_type.ty, decoded.ty);
+    assert_eq!(entity_type.components, decoded.components);
+    assert_eq!(entity_type.properties.first().unwrap().name, decoded.properties.first().unwrap().name);
+
+    let relation_type = RelationType::new(
+        ComponentOrEntityTypeId::Component(component_ty),
+        RelationTypeId::new_from_type(r_string(), r_string()),
+        ComponentOrEntityTypeId::EntityType(entity_type.ty.clone()),
+        r_string(),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+    )
+    .with_cardinality(RelationCardinality::one_to_many().with_max_outbound(3))
+    .with_inbound_type(ComponentOrEntityTypeId::any_entity_type());
+    let decoded = RelationType::try_from(WireRelationType::decode(WireRelationType::from(&relation_type).encode_to_vec().as_slice()).unwrap()).unwrap();
+    assert_eq!(relation_type.outbound_type, decoded.outbound_type);
+    assert_eq!(relation_type.ty, decoded.ty);
+    assert_eq!(relation_type.inbound_type, decoded.inbound_type);
+    assert_eq!(relation_type.cardinality, decoded.cardinality);
+    assert_eq!(relation_type.inbound_types, decoded.inbound_types);
+}
+
+#[test]
+fn flow_instance_wire_round_trip_test() {
+    let wrapper_entity_instance = create_entity_instance(r_string());
+    let mut entity_instance = create_entity_instance(r_string()).with_created_by(r_string());
+    entity_instance.touch_at(DateTime::from_timestamp(1_700_000_000, 123_456_789).unwrap());
+    let ty = RelationInstanceTypeId::new_from_type_unique_for_instance_id(r_string(), r_string(), r_string());
+    let mut relation_instance = RelationInstance::new(wrapper_entity_instance.id, ty, entity_instance.id, entity_instance.properties.clone().into());
+    relation_instance.touch_at(DateTime::from_timestamp(-1_700_000_000, 456).unwrap());
+    relation_instance.increment_revision();
+    let mut flow_instance = FlowInstance::from_instance_with_name(wrapper_entity_instance.clone(), r_string());
+    flow_instance.entity_instances.push(entity_instance.clone());
+    flow_instance.relation_instances.push(relation_instance.clone());
+    flow_instance.external_entity_ids.push(Uuid::new_v4());
+    flow_instance.sub_flows.push(SubFlowReference::new(
+        Uuid::new_v4(),
+        vec![SubFlowBinding::input(entity_instance.id, r_string(), Uuid::new_v4(), r_string())],
+    ));
+
+    let bytes = WireFlowInstance::from(&flow_instance).encode_to_vec();
+    let decoded = FlowInstance::try_from(WireFlowInstance::decode(bytes.as_slice()).unwrap()).unwrap();
+    assert_eq!(flow_instance.id, decoded.id);
+    assert_eq!(flow_instance.ty, decoded.ty);
+    assert_eq!(flow_instance.name, decoded.name);
+    assert_eq!(entity_instance.properties, decoded.entity_instances.get(1).unwrap().properties);
+    assert_eq!(entity_instance.created_at, decoded.entity_instances.get(1).unwrap().created_at);
+    assert_eq!(entity_instance.updated_at, decoded.entity_instances.get(1).unwrap().updated_at);
+    assert_eq!(entity_instance.created_by, decoded.entity_instances.get(1).unwrap().created_by);
+    assert!(decoded.entity_instances.first().unwrap().created_at.is_none());
+    assert_eq!(relation_instance.get_key(), decoded.relation_instances.first().unwrap().get_key());
+    assert_eq!(relation_instance.properties, decoded.relation_instances.first().unwrap().properties);
+    assert_eq!(relation_instance.updated_at, decoded.relation_instances.first().unwrap().updated_at);
+    assert_eq!(1, decoded.relation_instances.first().unwrap().revision);
+    assert_eq!(flow_instance.external_entity_ids, decoded.external_entity_ids);
+    assert_eq!(flow_instance.sub_flows, decoded.sub_flows);
+}
+
+#[test]
+fn flow_type_wire_round_trip_test() {
+    let wrapper_entity_instance = create_entity_instance(r_string());
+    let entity_instance = create_entity_instance(r_string());
+    let ty = RelationInstanceTypeId::new_from_type_unique_for_instance_id(r_string(), r_string(), r_string());
+    let relation_instance = RelationInstance::new(wrapper_entity_instance.id, ty, entity_instance.id, HashMap::new());
+    let mut flow_type = FlowType::new(
+        FlowTypeId::new_from_type(r_string(), r_string()),
+        r_string(),
+        wrapper_entity_instance.clone(),
+        vec![entity_instance.clone()],
+        vec![relation_instance.clone()],
+        vec![PropertyType::number(r_string())],
+        vec![Extension::new(ExtensionTypeId::new_from_type(r_string(), r_string()), r_string(), json!(true))],
+    )
+    .with_tag(r_string())
+    .with_alias(FlowTypeId::new_from_type(r_string(), r_string()))
+    .with_deprecation(Deprecation::new(r_string()).since(r_string()))
+    .with_localized_description("de-AT", r_string())
+    .with_documentation(Documentation::new(r_string()).with_body(r_string()))
+    .with_example(FlowInstance::from_instance_with_name(create_entity_instance(r_string()), r_string()))
+    .with_sub_flow(SubFlowTypeReference::new(
+        r_string(),
+        FlowTypeId::new_from_type(r_string(), r_string()),
+        vec![SubFlowTypeBinding::input(entity_instance.id, r_string(), r_string())],
+    ))
+    .with_type_parameter(
+        FlowTypeParameter::new(r_string())
+            .with_description(r_string())
+            .with_constraint(ComponentTypeId::new_from_type(r_string(), r_string()))
+            .with_default(EntityTypeId::new_from_type(r_string(), r_string())),
+    );
+    flow_type.add_input(FlowTypePort::new(r_string(), entity_instance.id, r_string()));
+    flow_type.add_output(FlowTypePort::new(r_string(), entity_instance.id, r_string()));
+
+    let bytes = WireFlowType::from(&flow_type).encode_to_vec();
+    let decoded = FlowType::try_from(WireFlowType::decode(bytes.as_slice()).unwrap()).unwrap();
+    assert_eq!(flow_type.ty, decoded.ty);
+    assert_eq!(flow_type.description, decoded.description);
+    assert_eq!(wrapper_entity_instance.id, decoded.wrapper_entity_instance.id);
+    assert_eq!(entity_instance.properties, decoded.entity_instances.first().unwrap().properties);
+    assert_eq!(relation_instance.get_key(), decoded.relation_instances.first().unwrap().get_key());
+    assert_eq!(flow_type.variables.first().unwrap().name, decoded.variables.first().unwrap().name);
+    assert_eq!(flow_type.inputs, decoded.inputs);
+    assert_eq!(flow_type.outputs, decoded.outputs);
+    assert_eq!(flow_type.tags, decoded.tags);
+    assert_eq!(flow_type.aliases, decoded.aliases);
+    assert_eq!(flow_type.deprecation, decoded.deprecation);
+    assert_eq!(flow_type.descriptions, decoded.descriptions);
+    assert_eq!(flow_type.documentation, decoded.documentation);
+    assert_eq!(flow_type.examples.first().unwrap().id, decoded.examples.first().unwrap().id);
+    assert_eq!(flow_type.sub_flows, decoded.sub_flows);
+    assert_eq!(flow_type.type_parameters, decoded.type_parameters);
+    assert_eq!(json!(true), decoded.extensions.first().unwrap().extension);
+
+    let mut wire_flow_type = WireFlowType::from(&flow_type);
+    wire_flow_type.wrapper_entity_instance = None;
+    assert_eq!(WireFormatError::MissingField("wrapper_entity_instance"), FlowType::try_from(wire_flow_type).unwrap_err());
+
+    let mut wire_flow_type = WireFlowType::from(&flow_type);
+    wire_flow_type.sub_flows[0].bindings[0].direction = 42;
+    assert_eq!(
+        WireFormatError::InvalidEnumValue("SubFlowBindingDirection", 42),
+        FlowType::try_from(wire_flow_type).unwrap_err()
+    );
+}
+
+#[test]
+fn invalid_wire_format_test() {
+    let mut wire_entity_instance = WireEntityInstance::from(&create_entity_instance(r_string()));
+    wire_entity_instance.id = vec![1, 2, 3];
+    assert_eq!(WireFormatError::InvalidUuid, EntityInstance::try_from(wire_entity_instance.clone()).unwrap_err());
+
+    wire_entity_instance.id = Uuid::new_v4().as_bytes().to_vec();
+    wire_entity_instance.created_at = Some(WireTimestamp { seconds: i64::MAX, nanos: 0 });
+    assert_eq!(
+        WireFormatError::InvalidTimestamp(i64::MAX, 0),
+        EntityInstance::try_from(wire_entity_instance.clone()).unwrap_err()
+    );
+    wire_entity_instance.created_at = Some(WireTimestamp { seconds: 0, nanos: -1 });
+    assert_eq!(WireFormatError::InvalidTimestamp(0, -1), EntityInstance::try_from(wire_entity_instance.clone()).unwrap_err());
+
+    wire_entity_instance.created_at = None;
+    wire_entity_instance.properties.insert(r_string(), String::from("{"));
+    assert!(matches!(EntityInstance::try_from(wire_entity_instance).unwrap_err(), WireFormatError::InvalidJson(_)));
+
+    let mut wire_property_type = WirePropertyType::from(&PropertyType::string(r_string()));
+    wire_property_type.data_type = 42;
+    assert_eq!(WireFormatError::InvalidEnumValue("DataType", 42), PropertyType::try_from(wire_property_type).unwrap_err());
+
+    let mut wire_relation_type = WireRelationType::from(&RelationType::new(
+        ComponentTypeId::new_from_type(r_string(), r_string()),
+        RelationTypeId::new_from_type(r_string(), r_string()),
+        ComponentTypeId::new_from_type(r_string(), r_string()),
+        r_string(),
+        Vec::new(),
+        Vec::new(),
+        Vec::new(),
+    ));
+    wire_relation_type.inbound_type = None;
+    assert_eq!(WireFormatError::MissingField("inbound_type"), RelationType::try_from(wire_relation_type).unwrap_err());
+}
+
+#[test]
+fn documentation_wire_round_trip_test() {
+    let documentation = Documentation::new(r_string()).with_body(r_string()).with_link(r_string(), r_string());
+    let entity_type = EntityType::new(EntityTypeId::new_from_type(r_string(), r_string()), r_string(), Vec::new(), Vec::new(), Vec::new())
+        .with_documentation(documentation.clone());
+    let bytes = WireEntityType::from(&entity_type).encode_to_vec();
+    let decoded = EntityType::try_from(WireEntityType::decode(bytes.as_slice()).unwrap()).unwrap();
+    assert_eq!(Some(documentation), decoded.documentation);
+}
+
+#[test]
+fn examples_wire_round_trip_test() {
+    let property_type = PropertyType::number(r_string()).with_example(21.5).with_example(-3);
+    let entity_type = EntityType::new(EntityTypeId::new_from_type(r_string(), r_string()), r_string(), Vec::new(), vec![property_type], Vec::new())
+        .with_example(create_entity_instance(r_string()));
+    let bytes = WireEntityType::from(&entity_type).encode_to_vec();
+    let decoded = EntityType::try_from(WireEntityType::decode(bytes.as_slice()).unwrap()).unwrap();
+    assert_eq!(vec![json!(21.5), json!(-3)], decoded.properties.first().unwrap().examples);
+    assert_eq!(entity_type.examples.first().unwrap().id, decoded.examples.first().unwrap().id);
+}
@@ -0,0 +1,101 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::set_typed_extension;
+use crate::typed_extension;
+use crate::Component;
+use crate::EntityType;
+use crate::ExtensionTypeId;
+use crate::FlowType;
+use crate::RelationType;
+use crate::WellKnownExtension;
+
+/// The namespace of the extension which contains the appearance of a type.
+pub const TYPE_APPEARANCE_EXTENSION_NAMESPACE: &str = "core";
+
+/// The type name of the extension which contains the appearance of a type.
+pub const TYPE_APPEARANCE_EXTENSION_TYPE_NAME: &str = "appearance";
+
+/// The shape in which user interfaces render instances of a type.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum TypeShape {
+    Rectangle,
+    RoundedRectangle,
+    Circle,
+    Ellipse,
+    Diamond,
+    Hexagon,
+}
+
+/// How user interfaces render a type and its instances.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TypeAppearance {
+    /// The color as CSS color, for example `#3366ff`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+
+    /// The name or the URL of the icon.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+
+    /// The shape of the nodes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shape: Option<TypeShape>,
+}
+
+impl TypeAppearance {
+    /// Sets the color as CSS color.
+    pub fn with_color<S: Into<String>>(mut self, color: S) -> TypeAppearance {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the name or the URL of the icon.
+    pub fn with_icon<S: Into<String>>(mut self, icon: S) -> TypeAppearance {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Sets the shape of the nodes.
+    pub fn with_shape(mut self, shape: TypeShape) -> TypeAppearance {
+        self.shape = Some(shape);
+        self
+    }
+}
+
+impl WellKnownExtension for TypeAppearance {
+    const NAMESPACE: &'static str = TYPE_APPEARANCE_EXTENSION_NAMESPACE;
+    const TYPE_NAME: &'static str = TYPE_APPEARANCE_EXTENSION_TYPE_NAME;
+    const DESCRIPTION: &'static str = "The appearance of the type";
+}
+
+/// Returns the type id of the extension which contains the appearance of a type.
+pub fn type_appearance_extension_type_id() -> ExtensionTypeId {
+    TypeAppearance::extension_type_id()
+}
+
+macro_rules! type_appearance {
+    ($($ty: ident),*) => {
+        $(
+            impl $ty {
+                /// Sets the appearance of the type. Replaces the previous appearance.
+                pub fn with_appearance(mut self, appearance: TypeAppearance) -> Result<$ty, serde_json::Error> {
+                    set_typed_extension(&mut self.extensions, &appearance)?;
+                    Ok(self)
+                }
+
+                /// Returns the appearance of the type.
+                ///
+                /// Fails if the extension doesn't contain a valid appearance.
+                pub fn appearance(&self) -> Result<Option<TypeAppearance>, serde_json::Error> {
+                    typed_extension(&self.extensions)
+                }
+            }
+        )*
+    };
+}
+
+type_appearance!(Component, EntityType, RelationType, FlowType);
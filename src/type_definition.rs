@@ -3,13 +3,17 @@ use serde::Deserialize;
 use serde::Serialize;
 use uuid::Uuid;
 
+use crate::IdentifierError;
+use crate::ModelError;
 use crate::NamespacedType;
 use crate::NamespacedTypeGetter;
 use crate::TypeIdType;
+use crate::MAX_IDENTIFIER_LENGTH;
 use crate::TYPE_ID_TYPE_SEPARATOR;
 
 /// Definition of a type with the type of the type, the namespace and the name of the type.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct TypeDefinition {
     pub type_id_type: TypeIdType,
     pub namespace: String,
@@ -21,8 +25,8 @@ impl TypeDefinition {
     pub fn new(type_type: TypeIdType, nt: NamespacedType) -> TypeDefinition {
         TypeDefinition {
             type_id_type: type_type,
-            namespace: nt.namespace,
-            type_name: nt.type_name,
+            namespace: nt.namespace(),
+            type_name: nt.type_name(),
         }
     }
 
@@ -54,6 +58,19 @@ impl TypeDefinition {
     pub fn flow_type<S: Into<String>>(namespace: S, type_name: S) -> TypeDefinition {
         TypeDefinition::new(TypeIdType::FlowType, NamespacedType::new(namespace, type_name))
     }
+
+    /// Constructs the type identifier of the type definition without fallback.
+    ///
+    /// Returns an error if the namespace or the type name is invalid or if the fully qualified
+    /// type name is too long to be an identifier.
+    pub fn try_type_id(&self) -> Result<Identifier, IdentifierError> {
+        NamespacedType::from(self).validate()?;
+        let fully_qualified_name = self.to_string();
+        if fully_qualified_name.len() > MAX_IDENTIFIER_LENGTH {
+            return Err(IdentifierError::TooLong(fully_qualified_name.len()));
+        }
+        Identifier::new(&fully_qualified_name).map_err(|_| IdentifierError::TooLong(fully_qualified_name.len()))
+    }
 }
 
 impl NamespacedTypeGetter for TypeDefinition {
@@ -90,10 +107,7 @@ impl From<&TypeDefinition> for TypeIdType {
 /// Returns the type of the type.
 impl From<&TypeDefinition> for NamespacedType {
     fn from(type_definition: &TypeDefinition) -> Self {
-        NamespacedType {
-            namespace: type_definition.namespace.clone(),
-            type_name: type_definition.type_name.clone(),
-        }
+        NamespacedType::new(type_definition.namespace.clone(), type_definition.type_name.clone())
     }
 }
 
@@ -110,14 +124,15 @@ impl From<&TypeDefinition> for Identifier {
 }
 
 impl TryFrom<&Identifier> for TypeDefinition {
-    type Error = ();
+    type Error = ModelError;
 
     fn try_from(t: &Identifier) -> Result<Self, Self::Error> {
         let s = t.to_string();
+        let invalid = || ModelError::InvalidTypeId(t.to_string());
         let mut s = s.split(&TYPE_ID_TYPE_SEPARATOR);
-        let type_type = s.next().ok_or(())?.try_into()?;
-        let namespace = s.next().ok_or(())?;
-        let type_name = s.next().ok_or(())?;
+        let type_type = s.next().ok_or_else(invalid)?.try_into()?;
+        let namespace = s.next().ok_or_else(invalid)?;
+        let type_name = s.next().ok_or_else(invalid)?;
         let nt = NamespacedType::new(namespace, type_name);
         Ok(TypeDefinition::new(type_type, nt))
     }
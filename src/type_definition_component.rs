@@ -10,6 +10,7 @@ use crate::TypeDefinition;
 
 /// References a component of a type.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct TypeDefinitionComponent {
     /// The type definition.
     #[serde(alias = "type")]
@@ -9,6 +9,7 @@ use crate::TypeDefinition;
 
 /// References a property of a type.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct TypeDefinitionProperty {
     /// The type definition.
     #[serde(alias = "type")]
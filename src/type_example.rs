@@ -0,0 +1,48 @@
+use serde_json::Value;
+
+use crate::Component;
+use crate::EntityInstance;
+use crate::EntityType;
+use crate::FlowInstance;
+use crate::FlowType;
+use crate::PropertyError;
+use crate::PropertyType;
+use crate::ValidationError;
+
+impl PropertyType {
+    /// Adds an example value of the property.
+    pub fn with_example<V: Into<Value>>(mut self, example: V) -> PropertyType {
+        self.examples.push(example.into());
+        self
+    }
+
+    /// Validates the example values against the data type of the property.
+    pub fn validate_examples(&self) -> Result<(), PropertyError> {
+        self.examples.iter().try_for_each(|example| self.validate_value(example))
+    }
+}
+
+impl EntityType {
+    /// Adds an example instance of the entity type.
+    pub fn with_example(mut self, example: EntityInstance) -> EntityType {
+        self.examples.push(example);
+        self
+    }
+
+    /// Validates the example values of the properties and the example instances of the entity
+    /// type.
+    pub fn validate_examples(&self, components: &[Component]) -> Result<(), ValidationError> {
+        for property_type in self.properties.iter() {
+            property_type.validate_examples()?;
+        }
+        self.examples.iter().try_for_each(|example| self.validate_instance(example, components))
+    }
+}
+
+impl FlowType {
+    /// Adds an example flow instance of the flow type.
+    pub fn with_example(mut self, example: FlowInstance) -> FlowType {
+        self.examples.push(example);
+        self
+    }
+}
@@ -0,0 +1,80 @@
+use serde::de::Error;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serializer;
+
+use crate::NamespacedType;
+use crate::NamespacedTypeGetter;
+use crate::TYPE_ID_TYPE_SEPARATOR;
+
+/// Serde helpers which serialize a type id as a compact string of the form `namespace__type_name`
+/// instead of an object with the namespace and the type name. Both forms are accepted on input.
+///
+/// ```ignore
+/// #[derive(Serialize, Deserialize)]
+/// struct Binding {
+///     #[serde(with = "compact_type_id")]
+///     component: ComponentTypeId,
+/// }
+/// ```
+pub mod compact_type_id {
+    use super::*;
+
+    pub fn serialize<T: NamespacedTypeGetter, S: Serializer>(ty: &T, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&to_compact_type_id(ty))
+    }
+
+    pub fn deserialize<'de, T: From<NamespacedType>, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+        CompactOrNamespacedType::deserialize(deserializer)?.into_namespaced_type().map(T::from)
+    }
+}
+
+/// Serde helpers which serialize a list of type ids as a list of compact strings of the form
+/// `namespace__type_name`. Both forms are accepted on input, also mixed in the same list.
+pub mod compact_type_ids {
+    use super::*;
+
+    pub fn serialize<T: NamespacedTypeGetter, S: Serializer>(tys: &[T], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(tys.iter().map(to_compact_type_id))
+    }
+
+    pub fn deserialize<'de, T: From<NamespacedType>, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<T>, D::Error> {
+        Vec::<CompactOrNamespacedType>::deserialize(deserializer)?
+            .into_iter()
+            .map(|ty| ty.into_namespaced_type().map(T::from))
+            .collect()
+    }
+}
+
+/// Returns the compact string of the given type id, which consists of the namespace and the type
+/// name.
+pub fn to_compact_type_id<T: NamespacedTypeGetter>(ty: &T) -> String {
+    format!("{}{}{}", ty.namespace(), TYPE_ID_TYPE_SEPARATOR, ty.type_name())
+}
+
+/// Parses the compact string of a type id, which consists of the namespace and the type name.
+pub fn parse_compact_type_id(s: &str) -> Option<NamespacedType> {
+    let mut parts = s.split(TYPE_ID_TYPE_SEPARATOR);
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(namespace), Some(type_name), None) if !namespace.is_empty() && !type_name.is_empty() => Some(NamespacedType::new(namespace, type_name)),
+        _ => None,
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum CompactOrNamespacedType {
+    Compact(String),
+    Namespaced(NamespacedType),
+}
+
+impl CompactOrNamespacedType {
+    fn into_namespaced_type<E: Error>(self) -> Result<NamespacedType, E> {
+        match self {
+            CompactOrNamespacedType::Compact(s) => {
+                parse_compact_type_id(&s).ok_or_else(|| E::custom(format!("{} is not a valid type id of the form namespace__type_name", s)))
+            }
+            CompactOrNamespacedType::Namespaced(ty) => Ok(ty),
+        }
+    }
+}
@@ -2,6 +2,8 @@ use serde::Deserialize;
 use serde::Serialize;
 use uuid::Uuid;
 
+use crate::ModelError;
+
 /// Separator for the string representation of a type definition.
 pub static TYPE_ID_TYPE_SEPARATOR: &str = "__";
 
@@ -32,6 +34,8 @@ pub static TYPE_ID_TYPE_NAMESPACE_FLOW_TYPE: Uuid = Uuid::from_u128(0x62b7c5106d
 
 /// The type of a type.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Copy, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub enum TypeIdType {
     Behaviour,
     Component,
@@ -84,7 +88,7 @@ impl ToString for TypeIdType {
 }
 
 impl TryFrom<&str> for TypeIdType {
-    type Error = ();
+    type Error = ModelError;
 
     fn try_from(s: &str) -> Result<Self, Self::Error> {
         match s {
@@ -94,7 +98,7 @@ impl TryFrom<&str> for TypeIdType {
             TYPE_ID_TYPE_EXTENSION => Ok(TypeIdType::Extension),
             TYPE_ID_TYPE_RELATION_TYPE => Ok(TypeIdType::RelationType),
             TYPE_ID_TYPE_FLOW_TYPE => Ok(TypeIdType::FlowType),
-            _ => Err(()),
+            _ => Err(ModelError::InvalidTypeIdType(s.to_string())),
         }
     }
 }
@@ -1,8 +1,22 @@
+use std::cmp::Ordering;
+use std::fmt::Debug;
 use std::fmt::Display;
 use std::fmt::Formatter;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::ops::Deref;
+use std::sync::OnceLock;
 
+use indradb::Identifier;
 use serde::Deserialize;
+use serde::Deserializer;
 use serde::Serialize;
+use serde::Serializer;
+
+use crate::IdentifierError;
+use crate::Namespace;
+use crate::MAX_IDENTIFIER_LENGTH;
+use crate::TYPE_ID_TYPE_SEPARATOR;
 
 /// Grants access to the namespace and the type name of a type of types.
 pub trait NamespacedTypeGetter {
@@ -11,44 +25,194 @@ pub trait NamespacedTypeGetter {
 
     /// Returns the name of the type.
     fn type_name(&self) -> String;
+
+    /// Returns the hierarchical namespace of the type.
+    fn hierarchical_namespace(&self) -> Namespace {
+        Namespace::from(self.namespace())
+    }
 }
 
+/// The string type of namespaces, type names and property names.
+///
+/// With the feature `smol_str` namespaces, type names and the names of property types are stored
+/// in a `SmolStr` which keeps short strings inline instead of allocating them on the heap.
+#[cfg(not(feature = "smol_str"))]
+pub type IdentifierString = String;
+
+/// The string type of namespaces, type names and property names.
+///
+/// With the feature `smol_str` namespaces, type names and the names of property types are stored
+/// in a `SmolStr` which keeps short strings inline instead of allocating them on the heap.
+#[cfg(feature = "smol_str")]
+pub type IdentifierString = smol_str::SmolStr;
+
 /// Defines the namespace and the name of a type.
-#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
+///
+/// Namespaced types are ordered by namespace, then by type name.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct NamespacedType {
     /// The namespace the component belongs to.
-    #[serde(default = "String::new")]
-    pub namespace: String,
+    #[serde(default)]
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
+    pub namespace: IdentifierString,
 
     /// The name of the type.
     #[serde(alias = "name")]
-    pub type_name: String,
+    #[cfg_attr(feature = "schemars", schemars(with = "String"))]
+    pub type_name: IdentifierString,
 }
 
 impl NamespacedType {
     /// Constructs a new namespaced type.
     pub fn new<S: Into<String>>(namespace: S, type_name: S) -> NamespacedType {
         NamespacedType {
-            namespace: namespace.into(),
-            type_name: type_name.into(),
+            namespace: identifier_string(namespace),
+            type_name: identifier_string(type_name),
         }
     }
+
+    /// Validates that neither the namespace nor the type name is empty, too long or contains the
+    /// separator of type ids. Otherwise the type id couldn't be parsed back.
+    pub fn validate(&self) -> Result<(), IdentifierError> {
+        validate_identifier_part(&self.namespace)?;
+        validate_identifier_part(&self.type_name)
+    }
+}
+
+fn validate_identifier_part(s: &str) -> Result<(), IdentifierError> {
+    if s.is_empty() {
+        return Err(IdentifierError::Empty);
+    }
+    if s.len() > MAX_IDENTIFIER_LENGTH {
+        return Err(IdentifierError::TooLong(s.len()));
+    }
+    if s.contains(TYPE_ID_TYPE_SEPARATOR) {
+        return Err(IdentifierError::ContainsSeparator(s.to_string()));
+    }
+    Ok(())
 }
 
 impl NamespacedTypeGetter for NamespacedType {
     /// Returns the namespace of the namespaced type.
     fn namespace(&self) -> String {
-        self.namespace.clone()
+        self.namespace.to_string()
     }
 
     /// Returns the name of the namespaced type.
     fn type_name(&self) -> String {
-        self.type_name.clone()
+        self.type_name.to_string()
     }
 }
 
+/// Converts the given string into the string type of identifiers.
+#[cfg(not(feature = "smol_str"))]
+pub(crate) fn identifier_string<S: Into<String>>(s: S) -> IdentifierString {
+    s.into()
+}
+
+/// Converts the given string into the string type of identifiers.
+#[cfg(feature = "smol_str")]
+pub(crate) fn identifier_string<S: Into<String>>(s: S) -> IdentifierString {
+    smol_str::SmolStr::new(s.into())
+}
+
 impl Display for NamespacedType {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}__{}", &self.namespace, self.type_name)
     }
 }
+
+/// The namespaced type of a type id together with the identifier of the type id, which is
+/// computed once on first access.
+///
+/// Serializes, compares and hashes like the namespaced type.
+pub(crate) struct CachedNamespacedType {
+    nt: NamespacedType,
+    identifier: OnceLock<Identifier>,
+}
+
+impl CachedNamespacedType {
+    /// Returns the cached identifier or computes it.
+    pub(crate) fn identifier<F: FnOnce() -> Identifier>(&self, f: F) -> Identifier {
+        self.identifier.get_or_init(f).clone()
+    }
+}
+
+impl From<NamespacedType> for CachedNamespacedType {
+    fn from(nt: NamespacedType) -> Self {
+        CachedNamespacedType {
+            nt,
+            identifier: OnceLock::new(),
+        }
+    }
+}
+
+impl Default for CachedNamespacedType {
+    fn default() -> Self {
+        NamespacedType::default().into()
+    }
+}
+
+impl Deref for CachedNamespacedType {
+    type Target = NamespacedType;
+
+    fn deref(&self) -> &Self::Target {
+        &self.nt
+    }
+}
+
+impl PartialEq for CachedNamespacedType {
+    fn eq(&self, other: &Self) -> bool {
+        self.nt == other.nt
+    }
+}
+
+impl Eq for CachedNamespacedType {}
+
+impl PartialOrd for CachedNamespacedType {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CachedNamespacedType {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.nt.cmp(&other.nt)
+    }
+}
+
+impl Hash for CachedNamespacedType {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.nt.hash(state)
+    }
+}
+
+impl Debug for CachedNamespacedType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&self.nt, f)
+    }
+}
+
+impl Serialize for CachedNamespacedType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.nt.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for CachedNamespacedType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        NamespacedType::deserialize(deserializer).map(CachedNamespacedType::from)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for CachedNamespacedType {
+    fn schema_name() -> String {
+        NamespacedType::schema_name()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        NamespacedType::json_schema(gen)
+    }
+}
@@ -0,0 +1,223 @@
+use std::fmt;
+use std::str::FromStr;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::NamespacedTypeGetter;
+use crate::TypeDefinition;
+
+/// The version of a type package in the form `major.minor.patch`.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[serde(try_from = "String", into = "String")]
+pub struct TypePackageVersion {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+}
+
+impl TypePackageVersion {
+    pub fn new(major: u64, minor: u64, patch: u64) -> TypePackageVersion {
+        TypePackageVersion { major, minor, patch }
+    }
+
+    /// Returns true, if this version satisfies the required version.
+    ///
+    /// A version is compatible, if the major version is equal and the version is not lower than
+    /// the required version. Before 1.0.0 the minor version has to be equal as well.
+    pub fn is_compatible_with(&self, required: &TypePackageVersion) -> bool {
+        if self.major != required.major || self < required {
+            return false;
+        }
+        self.major > 0 || self.minor == required.minor
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct TypePackageVersionParseError(pub String);
+
+impl fmt::Display for TypePackageVersionParseError {
+    #[cfg(not(tarpaulin_include))]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid package version {}", self.0)
+    }
+}
+
+impl FromStr for TypePackageVersion {
+    type Err = TypePackageVersionParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.split('.').collect();
+        if parts.len() != 3 {
+            return Err(TypePackageVersionParseError(s.to_string()));
+        }
+        let mut numbers = parts
+            .iter()
+            .map(|part| part.parse::<u64>().map_err(|_| TypePackageVersionParseError(s.to_string())));
+        Ok(TypePackageVersion {
+            major: numbers.next().unwrap()?,
+            minor: numbers.next().unwrap()?,
+            patch: numbers.next().unwrap()?,
+        })
+    }
+}
+
+impl TryFrom<String> for TypePackageVersion {
+    type Error = TypePackageVersionParseError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        TypePackageVersion::from_str(&s)
+    }
+}
+
+impl From<TypePackageVersion> for String {
+    fn from(version: TypePackageVersion) -> Self {
+        version.to_string()
+    }
+}
+
+impl fmt::Display for TypePackageVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+#[cfg(feature = "schemars")]
+impl schemars::JsonSchema for TypePackageVersion {
+    fn schema_name() -> String {
+        String::from("TypePackageVersion")
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        String::json_schema(gen)
+    }
+}
+
+/// A dependency of a type package on another type package.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TypePackageDependency {
+    /// The name of the required package.
+    pub name: String,
+
+    /// The minimal compatible version of the required package.
+    pub version: TypePackageVersion,
+}
+
+impl TypePackageDependency {
+    pub fn new<S: Into<String>>(name: S, version: TypePackageVersion) -> TypePackageDependency {
+        TypePackageDependency { name: name.into(), version }
+    }
+}
+
+/// The manifest of a package of types.
+///
+/// Groups of types can be distributed, versioned and dependency-checked as units.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TypePackage {
+    /// The unique name of the package.
+    pub name: String,
+
+    /// The version of the package.
+    pub version: TypePackageVersion,
+
+    /// Textual description of the package.
+    #[serde(default = "String::new")]
+    pub description: String,
+
+    /// The namespaces which are provided by the package.
+    #[serde(default = "Vec::new")]
+    pub namespaces: Vec<String>,
+
+    /// The packages which are required by this package.
+    #[serde(default = "Vec::new")]
+    pub dependencies: Vec<TypePackageDependency>,
+
+    /// The types which are contained in the package.
+    #[serde(default = "Vec::new")]
+    pub types: Vec<TypeDefinition>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum TypePackageDependencyError {
+    /// The required package with the given name is missing.
+    MissingPackage(String),
+
+    /// The required package is available, but the version is incompatible.
+    IncompatibleVersion {
+        name: String,
+        required: TypePackageVersion,
+        available: TypePackageVersion,
+    },
+}
+
+impl fmt::Display for TypePackageDependencyError {
+    #[cfg(not(tarpaulin_include))]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TypePackageDependencyError::MissingPackage(name) => write!(f, "The required package {} is missing", name),
+            TypePackageDependencyError::IncompatibleVersion { name, required, available } => {
+                write!(f, "The package {} is required in version {} but version {} is available", name, required, available)
+            }
+        }
+    }
+}
+
+impl TypePackage {
+    /// Constructs an empty package with the given name and version.
+    pub fn new<S: Into<String>>(name: S, version: TypePackageVersion) -> TypePackage {
+        TypePackage {
+            name: name.into(),
+            version,
+            description: String::new(),
+            namespaces: Vec::new(),
+            dependencies: Vec::new(),
+            types: Vec::new(),
+        }
+    }
+
+    /// Returns true, if the package provides the given namespace.
+    pub fn has_namespace<S: Into<String>>(&self, namespace: S) -> bool {
+        let namespace = namespace.into();
+        self.namespaces.contains(&namespace)
+    }
+
+    /// Returns true, if the package contains the given type.
+    pub fn contains(&self, ty: &TypeDefinition) -> bool {
+        self.types.contains(ty)
+    }
+
+    /// Returns the contained types which are not in one of the namespaces of the package.
+    pub fn types_outside_of_namespaces(&self) -> Vec<TypeDefinition> {
+        self.types.iter().filter(|ty| !self.has_namespace(ty.namespace())).cloned().collect()
+    }
+
+    /// Checks if the dependencies of the package are satisfied by the given available packages.
+    pub fn check_dependencies(&self, available: &[TypePackage]) -> Result<(), Vec<TypePackageDependencyError>> {
+        let errors: Vec<TypePackageDependencyError> = self
+            .dependencies
+            .iter()
+            .filter_map(|dependency| {
+                let candidates: Vec<&TypePackage> = available.iter().filter(|package| package.name == dependency.name).collect();
+                if candidates.is_empty() {
+                    return Some(TypePackageDependencyError::MissingPackage(dependency.name.clone()));
+                }
+                if candidates.iter().any(|package| package.version.is_compatible_with(&dependency.version)) {
+                    return None;
+                }
+                let available = candidates.iter().map(|package| package.version).max().unwrap();
+                Some(TypePackageDependencyError::IncompatibleVersion {
+                    name: dependency.name.clone(),
+                    required: dependency.version,
+                    available,
+                })
+            })
+            .collect();
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
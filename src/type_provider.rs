@@ -0,0 +1,102 @@
+use crate::Component;
+use crate::EntityType;
+use crate::FlowType;
+use crate::RelationType;
+use crate::TypeImportStrategy;
+use crate::TypeSystem;
+use crate::TypeSystemImportError;
+
+/// Provides types, for example the types of a plugin.
+pub trait TypeProvider: Send + Sync {
+    /// Returns the provided components.
+    fn components(&self) -> Vec<Component> {
+        Vec::new()
+    }
+
+    /// Returns the provided entity types.
+    fn entity_types(&self) -> Vec<EntityType> {
+        Vec::new()
+    }
+
+    /// Returns the provided relation types.
+    fn relation_types(&self) -> Vec<RelationType> {
+        Vec::new()
+    }
+
+    /// Returns the provided flow types.
+    fn flow_types(&self) -> Vec<FlowType> {
+        Vec::new()
+    }
+
+    /// Returns the provided types as type system.
+    fn type_system(&self) -> TypeSystem {
+        TypeSystem::new(self.components(), self.entity_types(), self.relation_types(), self.flow_types())
+    }
+}
+
+/// Collects the types of the given type providers into a single type system.
+///
+/// Types which are provided by multiple type providers are resolved using the given strategy.
+pub fn collect_types(providers: &[&dyn TypeProvider], strategy: TypeImportStrategy) -> Result<TypeSystem, TypeSystemImportError> {
+    let mut type_system = TypeSystem::default();
+    for provider in providers {
+        type_system.import(provider.type_system(), strategy)?;
+    }
+    Ok(type_system)
+}
+
+/// A statically registered type provider.
+///
+/// Use the macro `register_type_provider!` to register a type provider.
+#[cfg(feature = "inventory")]
+pub struct TypeProviderRegistration {
+    pub provider: &'static dyn TypeProvider,
+}
+
+#[cfg(feature = "inventory")]
+impl TypeProviderRegistration {
+    pub const fn new(provider: &'static dyn TypeProvider) -> TypeProviderRegistration {
+        TypeProviderRegistration { provider }
+    }
+}
+
+#[cfg(feature = "inventory")]
+inventory::collect!(TypeProviderRegistration);
+
+/// Returns all statically registered type providers.
+#[cfg(feature = "inventory")]
+pub fn registered_type_providers() -> Vec<&'static dyn TypeProvider> {
+    inventory::iter::<TypeProviderRegistration>
+        .into_iter()
+        .map(|registration| registration.provider)
+        .collect()
+}
+
+/// Collects the types of all statically registered type providers into a single type system.
+#[cfg(feature = "inventory")]
+pub fn collect_registered_types(strategy: TypeImportStrategy) -> Result<TypeSystem, TypeSystemImportError> {
+    collect_types(&registered_type_providers(), strategy)
+}
+
+/// Registers the given static type provider.
+///
+/// ```ignore
+/// struct LogicalTypes;
+///
+/// impl TypeProvider for LogicalTypes {
+///     fn entity_types(&self) -> Vec<EntityType> {
+///         vec![/* ... */]
+///     }
+/// }
+///
+/// register_type_provider!(LogicalTypes);
+/// ```
+#[cfg(feature = "inventory")]
+#[macro_export]
+macro_rules! register_type_provider {
+    ($provider: expr) => {
+        $crate::inventory::submit! {
+            $crate::TypeProviderRegistration::new(&$provider)
+        }
+    };
+}
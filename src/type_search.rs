@@ -0,0 +1,187 @@
+use crate::ComponentTypeId;
+use crate::DataType;
+use crate::Namespace;
+use crate::NamespacedTypeGetter;
+use crate::PropertyType;
+use crate::TypeDefinition;
+use crate::TypeDefinitionGetter;
+use crate::TypeIdType;
+use crate::TypeSystem;
+
+/// A query of the types of a type system.
+///
+/// All predicates of the query have to match. A query without predicates matches every type.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TypeQuery {
+    /// Only matches types of the given types of types.
+    pub type_id_types: Vec<TypeIdType>,
+
+    /// Only matches types of the namespaces which match the given glob pattern.
+    pub namespace: Option<String>,
+
+    /// Only matches types of the given hierarchical namespace or of its descendant namespaces.
+    pub parent_namespace: Option<Namespace>,
+
+    /// Only matches types whose name contains the given string or, if the string contains the
+    /// wildcards `*` or `?`, whose name matches the given glob pattern.
+    pub name: Option<String>,
+
+    /// Only matches entity types and relation types which are composed with the given component.
+    pub component: Option<ComponentTypeId>,
+
+    /// Only matches types which have a property of the given data type.
+    ///
+    /// The properties of the components of entity types and relation types and the variables of
+    /// flow types are respected.
+    pub property_data_type: Option<DataType>,
+}
+
+impl TypeQuery {
+    pub fn new() -> TypeQuery {
+        TypeQuery::default()
+    }
+
+    /// Only matches types of the given type of types.
+    pub fn type_id_type(mut self, type_id_type: TypeIdType) -> TypeQuery {
+        self.type_id_types.push(type_id_type);
+        self
+    }
+
+    /// Only matches types of the namespaces which match the given glob pattern.
+    pub fn namespace<S: Into<String>>(mut self, namespace: S) -> TypeQuery {
+        self.namespace = Some(namespace.into());
+        self
+    }
+
+    /// Only matches types of the given hierarchical namespace or of its descendant namespaces.
+    pub fn in_namespace<N: Into<Namespace>>(mut self, namespace: N) -> TypeQuery {
+        self.parent_namespace = Some(namespace.into());
+        self
+    }
+
+    /// Only matches types whose name contains the given string or matches the given glob pattern.
+    pub fn name<S: Into<String>>(mut self, name: S) -> TypeQuery {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Only matches types which are composed with the given component.
+    pub fn has_component(mut self, component: ComponentTypeId) -> TypeQuery {
+        self.component = Some(component);
+        self
+    }
+
+    /// Only matches types which have a property of the given data type.
+    pub fn has_property_of_data_type(mut self, data_type: DataType) -> TypeQuery {
+        self.property_data_type = Some(data_type);
+        self
+    }
+
+    fn matches_type_definition(&self, ty: &TypeDefinition) -> bool {
+        if !self.type_id_types.is_empty() && !self.type_id_types.contains(&ty.type_id_type) {
+            return false;
+        }
+        if let Some(namespace) = &self.namespace {
+            if !glob_matches(namespace, &ty.namespace) {
+                return false;
+            }
+        }
+        if let Some(parent_namespace) = &self.parent_namespace {
+            if !ty.hierarchical_namespace().starts_with(parent_namespace) {
+                return false;
+            }
+        }
+        if let Some(name) = &self.name {
+            let matches = if is_glob_pattern(name) {
+                glob_matches(name, &ty.type_name)
+            } else {
+                ty.type_name.contains(name.as_str())
+            };
+            if !matches {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn matches_components(&self, components: &[ComponentTypeId]) -> bool {
+        self.component.as_ref().map(|component| components.contains(component)).unwrap_or(true)
+    }
+
+    fn matches_properties(&self, properties: &[PropertyType], components: &[ComponentTypeId], type_system: &TypeSystem) -> bool {
+        let Some(data_type) = self.property_data_type else {
+            return true;
+        };
+        properties.iter().any(|property| property.data_type == data_type)
+            || components
+                .iter()
+                .filter_map(|ty| type_system.get_component(ty))
+                .any(|component| component.properties.iter().any(|property| property.data_type == data_type))
+    }
+}
+
+impl TypeSystem {
+    /// Returns the type definitions of the components, entity types, relation types and flow types
+    /// which match the given query.
+    pub fn search(&self, query: &TypeQuery) -> Vec<TypeDefinition> {
+        let components = self
+            .components
+            .iter()
+            .filter(|component| query.component.is_none() && query.matches_properties(&component.properties, &[], self))
+            .map(|component| component.type_definition());
+        let entity_types = self
+            .entity_types
+            .iter()
+            .filter(|entity_type| {
+                query.matches_components(&entity_type.components) && query.matches_properties(&entity_type.properties, &entity_type.components, self)
+            })
+            .map(|entity_type| entity_type.type_definition());
+        let relation_types = self
+            .relation_types
+            .iter()
+            .filter(|relation_type| {
+                query.matches_components(&relation_type.components) && query.matches_properties(&relation_type.properties, &relation_type.components, self)
+            })
+            .map(|relation_type| relation_type.type_definition());
+        let flow_types = self
+            .flow_types
+            .iter()
+            .filter(|flow_type| query.component.is_none() && query.matches_properties(&flow_type.variables, &[], self))
+            .map(|flow_type| flow_type.type_definition());
+        components
+            .chain(entity_types)
+            .chain(relation_types)
+            .chain(flow_types)
+            .filter(|ty| query.matches_type_definition(ty))
+            .collect()
+    }
+}
+
+fn is_glob_pattern(pattern: &str) -> bool {
+    pattern.contains(['*', '?'])
+}
+
+/// Matches the given string against the given glob pattern. `*` matches any sequence of
+/// characters and `?` matches a single character.
+pub(crate) fn glob_matches(pattern: &str, s: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let s: Vec<char> = s.chars().collect();
+    let (mut p, mut i) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+    while i < s.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == s[i]) {
+            p += 1;
+            i += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, i));
+            p += 1;
+        } else if let Some((star_p, star_i)) = backtrack {
+            p = star_p + 1;
+            i = star_i + 1;
+            backtrack = Some((star_p, star_i + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '*')
+}
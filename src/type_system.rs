@@ -0,0 +1,208 @@
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::openapi_components;
+use crate::type_system_to_graphql_sdl;
+use crate::BehaviourTypeId;
+use crate::Component;
+use crate::ComponentBehaviourTypeId;
+use crate::ComponentTypeId;
+use crate::EntityBehaviourTypeId;
+use crate::EntityInstance;
+use crate::EntityType;
+use crate::EntityTypeId;
+use crate::FlowType;
+use crate::FlowTypeId;
+use crate::RelationBehaviourTypeId;
+use crate::RelationInstance;
+use crate::RelationType;
+use crate::RelationTypeId;
+use crate::TagContainer;
+use crate::TypeDefinition;
+use crate::TypeDefinitionGetter;
+use crate::ValidationError;
+
+/// The type system contains the components, the entity types, the relation types and the flow types
+/// as well as the bindings of behaviours to these types.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TypeSystem {
+    /// The components.
+    #[serde(default = "Vec::new")]
+    pub components: Vec<Component>,
+
+    /// The entity types.
+    #[serde(default = "Vec::new")]
+    pub entity_types: Vec<EntityType>,
+
+    /// The relation types.
+    #[serde(default = "Vec::new")]
+    pub relation_types: Vec<RelationType>,
+
+    /// The flow types.
+    #[serde(default = "Vec::new")]
+    pub flow_types: Vec<FlowType>,
+
+    /// The bindings of behaviours to components.
+    #[serde(default = "Vec::new")]
+    pub component_behaviours: Vec<ComponentBehaviourTypeId>,
+
+    /// The bindings of behaviours to entity types.
+    #[serde(default = "Vec::new")]
+    pub entity_behaviours: Vec<EntityBehaviourTypeId>,
+
+    /// The bindings of behaviours to relation types.
+    #[serde(default = "Vec::new")]
+    pub relation_behaviours: Vec<RelationBehaviourTypeId>,
+
+    /// Unknown fields of the data access object, which are re-emitted when converting back into
+    /// a data access object.
+    #[serde(skip)]
+    pub extra: Map<String, Value>,
+}
+
+impl TypeSystem {
+    /// Constructs a type system from the given components, entity types, relation types and flow types.
+    pub fn new(components: Vec<Component>, entity_types: Vec<EntityType>, relation_types: Vec<RelationType>, flow_types: Vec<FlowType>) -> TypeSystem {
+        TypeSystem {
+            components,
+            entity_types,
+            relation_types,
+            flow_types,
+            component_behaviours: Vec::new(),
+            entity_behaviours: Vec::new(),
+            relation_behaviours: Vec::new(),
+            extra: Map::new(),
+        }
+    }
+
+    /// Returns the component with the given type id.
+    pub fn get_component(&self, ty: &ComponentTypeId) -> Option<&Component> {
+        self.components.iter().find(|component| &component.ty == ty)
+    }
+
+    /// Returns the entity type with the given type id.
+    pub fn get_entity_type(&self, ty: &EntityTypeId) -> Option<&EntityType> {
+        self.entity_types.iter().find(|entity_type| &entity_type.ty == ty)
+    }
+
+    /// Returns the relation type with the given type id.
+    pub fn get_relation_type(&self, ty: &RelationTypeId) -> Option<&RelationType> {
+        self.relation_types.iter().find(|relation_type| &relation_type.ty == ty)
+    }
+
+    /// Returns the flow type with the given type id.
+    pub fn get_flow_type(&self, ty: &FlowTypeId) -> Option<&FlowType> {
+        self.flow_types.iter().find(|flow_type| &flow_type.ty == ty)
+    }
+
+    /// Returns the component with the given type id or with the given previous type id.
+    pub fn resolve_component(&self, ty: &ComponentTypeId) -> Option<&Component> {
+        self.get_component(ty)
+            .or_else(|| self.components.iter().find(|component| component.is_known_as(ty)))
+    }
+
+    /// Returns the entity type with the given type id or with the given previous type id.
+    pub fn resolve_entity_type(&self, ty: &EntityTypeId) -> Option<&EntityType> {
+        self.get_entity_type(ty)
+            .or_else(|| self.entity_types.iter().find(|entity_type| entity_type.is_known_as(ty)))
+    }
+
+    /// Returns the relation type with the given type id or with the given previous type id.
+    pub fn resolve_relation_type(&self, ty: &RelationTypeId) -> Option<&RelationType> {
+        self.get_relation_type(ty)
+            .or_else(|| self.relation_types.iter().find(|relation_type| relation_type.is_known_as(ty)))
+    }
+
+    /// Returns the flow type with the given type id or with the given previous type id.
+    pub fn resolve_flow_type(&self, ty: &FlowTypeId) -> Option<&FlowType> {
+        self.get_flow_type(ty)
+            .or_else(|| self.flow_types.iter().find(|flow_type| flow_type.is_known_as(ty)))
+    }
+
+    /// Returns the behaviours which are bound to the given component.
+    pub fn get_component_behaviours(&self, ty: &ComponentTypeId) -> Vec<&BehaviourTypeId> {
+        self.component_behaviours.iter().filter(|b| b.applies_to(ty)).map(|b| &b.behaviour_ty).collect()
+    }
+
+    /// Returns the behaviours which are bound to the given entity type.
+    pub fn get_entity_behaviours(&self, ty: &EntityTypeId) -> Vec<&BehaviourTypeId> {
+        self.entity_behaviours.iter().filter(|b| b.applies_to(ty)).map(|b| &b.behaviour_ty).collect()
+    }
+
+    /// Returns the behaviours which are bound to the given relation type.
+    pub fn get_relation_behaviours(&self, ty: &RelationTypeId) -> Vec<&BehaviourTypeId> {
+        self.relation_behaviours.iter().filter(|b| b.applies_to(ty)).map(|b| &b.behaviour_ty).collect()
+    }
+
+    /// Returns the type definitions of the components, entity types, relation types and flow types.
+    pub fn type_definitions(&self) -> Vec<TypeDefinition> {
+        let components = self.components.iter().map(|t| t.type_definition());
+        let entity_types = self.entity_types.iter().map(|t| t.type_definition());
+        let relation_types = self.relation_types.iter().map(|t| t.type_definition());
+        let flow_types = self.flow_types.iter().map(|t| t.type_definition());
+        components.chain(entity_types).chain(relation_types).chain(flow_types).collect()
+    }
+
+    /// Returns the type definitions of the deprecated components, entity types, relation types and
+    /// flow types.
+    pub fn deprecated_types(&self) -> Vec<TypeDefinition> {
+        let components = self.components.iter().filter(|t| t.is_deprecated()).map(|t| t.type_definition());
+        let entity_types = self.entity_types.iter().filter(|t| t.is_deprecated()).map(|t| t.type_definition());
+        let relation_types = self.relation_types.iter().filter(|t| t.is_deprecated()).map(|t| t.type_definition());
+        let flow_types = self.flow_types.iter().filter(|t| t.is_deprecated()).map(|t| t.type_definition());
+        components.chain(entity_types).chain(relation_types).chain(flow_types).collect()
+    }
+
+    /// Returns the type definitions of the components, entity types, relation types and flow types
+    /// which are tagged with the given tag.
+    pub fn find_by_tag(&self, tag: &str) -> Vec<TypeDefinition> {
+        let components = self.components.iter().filter(|t| t.has_tag(tag)).map(|t| t.type_definition());
+        let entity_types = self.entity_types.iter().filter(|t| t.has_tag(tag)).map(|t| t.type_definition());
+        let relation_types = self.relation_types.iter().filter(|t| t.has_tag(tag)).map(|t| t.type_definition());
+        let flow_types = self.flow_types.iter().filter(|t| t.has_tag(tag)).map(|t| t.type_definition());
+        components.chain(entity_types).chain(relation_types).chain(flow_types).collect()
+    }
+
+    /// Returns true, if the type system doesn't contain any type.
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty() && self.entity_types.is_empty() && self.relation_types.is_empty() && self.flow_types.is_empty()
+    }
+
+    /// Renders the type system as GraphQL SDL.
+    pub fn to_graphql_sdl(&self) -> String {
+        type_system_to_graphql_sdl(self)
+    }
+
+    /// Returns the OpenAPI `components` object which contains the schemas of entity instance
+    /// documents of the selected entity types.
+    pub fn to_openapi_components(&self, entity_types: &[EntityTypeId]) -> Value {
+        openapi_components(self, entity_types)
+    }
+
+    /// Validates the given entity instance against its entity type and the components of its
+    /// entity type, including the components and properties which are inherited from the
+    /// ancestors of its entity type.
+    pub fn validate_entity_instance(&self, entity_instance: &EntityInstance) -> Result<(), ValidationError> {
+        let entity_type = self
+            .resolve_entity_type(&entity_instance.ty)
+            .ok_or_else(|| ValidationError::UnknownEntityType(entity_instance.ty.clone()))?;
+        if entity_type.extends.is_some() {
+            return self
+                .resolve_effective_entity_type(&entity_type.ty)?
+                .validate_instance(entity_instance, &self.components);
+        }
+        entity_type.validate_instance(entity_instance, &self.components)
+    }
+
+    /// Validates the given relation instance against its relation type and the components of its
+    /// relation type.
+    pub fn validate_relation_instance(&self, relation_instance: &RelationInstance) -> Result<(), ValidationError> {
+        let relation_ty = relation_instance.relation_type_id();
+        self.resolve_relation_type(&relation_ty)
+            .ok_or(ValidationError::UnknownRelationType(relation_ty))?
+            .validate_instance(relation_instance, &self.components)
+    }
+}
@@ -0,0 +1,142 @@
+use std::fmt;
+
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Map;
+use serde_json::Value;
+
+use crate::Component;
+use crate::ComponentBehaviourTypeId;
+use crate::ComponentBehaviourTypeIdDao;
+use crate::ComponentDao;
+use crate::EntityBehaviourTypeId;
+use crate::EntityBehaviourTypeIdDao;
+use crate::EntityType;
+use crate::EntityTypeDao;
+use crate::FlowType;
+use crate::FlowTypeDao;
+use crate::RelationBehaviourTypeId;
+use crate::RelationBehaviourTypeIdDao;
+use crate::RelationType;
+use crate::RelationTypeDao;
+use crate::TypeSystem;
+
+/// The current version of the bundle format.
+pub const TYPE_SYSTEM_BUNDLE_FORMAT_VERSION: u32 = 1;
+
+/// Data access object of a whole type system.
+///
+/// A bundle contains the components, entity types, relation types and flow types in a single
+/// document, for example to ship all types of a plugin in one file.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct TypeSystemBundle {
+    /// The version of the bundle format.
+    #[serde(alias = "version")]
+    pub format_version: u32,
+
+    /// The components.
+    #[serde(default = "Vec::new")]
+    pub components: Vec<ComponentDao>,
+
+    /// The entity types.
+    #[serde(default = "Vec::new")]
+    pub entity_types: Vec<EntityTypeDao>,
+
+    /// The relation types.
+    #[serde(default = "Vec::new")]
+    pub relation_types: Vec<RelationTypeDao>,
+
+    /// The flow types.
+    #[serde(default = "Vec::new")]
+    pub flow_types: Vec<FlowTypeDao>,
+
+    /// The bindings of behaviours to components.
+    #[serde(default = "Vec::new")]
+    pub component_behaviours: Vec<ComponentBehaviourTypeIdDao>,
+
+    /// The bindings of behaviours to entity types.
+    #[serde(default = "Vec::new")]
+    pub entity_behaviours: Vec<EntityBehaviourTypeIdDao>,
+
+    /// The bindings of behaviours to relation types.
+    #[serde(default = "Vec::new")]
+    pub relation_behaviours: Vec<RelationBehaviourTypeIdDao>,
+
+    /// Unknown fields, for example written by a newer version, which are re-emitted on save.
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl TypeSystemBundle {
+    /// Returns true, if the format version of the bundle is supported.
+    pub fn is_supported(&self) -> bool {
+        self.format_version > 0 && self.format_version <= TYPE_SYSTEM_BUNDLE_FORMAT_VERSION
+    }
+}
+
+#[derive(Debug)]
+pub enum TypeSystemBundleError {
+    /// The format version of the bundle is not supported.
+    UnsupportedFormatVersion(u32),
+
+    /// The bundle is not a valid document.
+    InvalidDocument(serde_json::Error),
+}
+
+impl fmt::Display for TypeSystemBundleError {
+    #[cfg(not(tarpaulin_include))]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TypeSystemBundleError::UnsupportedFormatVersion(format_version) => write!(f, "The bundle format version {} is not supported", format_version),
+            TypeSystemBundleError::InvalidDocument(e) => write!(f, "The bundle is not a valid document: {}", e),
+        }
+    }
+}
+
+impl From<TypeSystem> for TypeSystemBundle {
+    fn from(type_system: TypeSystem) -> Self {
+        TypeSystemBundle {
+            format_version: TYPE_SYSTEM_BUNDLE_FORMAT_VERSION,
+            components: type_system.components.into_iter().map(ComponentDao::from).collect(),
+            entity_types: type_system.entity_types.into_iter().map(EntityTypeDao::from).collect(),
+            relation_types: type_system.relation_types.into_iter().map(RelationTypeDao::from).collect(),
+            flow_types: type_system.flow_types.into_iter().map(FlowTypeDao::from).collect(),
+            component_behaviours: type_system.component_behaviours.into_iter().map(ComponentBehaviourTypeIdDao::from).collect(),
+            entity_behaviours: type_system.entity_behaviours.into_iter().map(EntityBehaviourTypeIdDao::from).collect(),
+            relation_behaviours: type_system.relation_behaviours.into_iter().map(RelationBehaviourTypeIdDao::from).collect(),
+            extra: type_system.extra,
+        }
+    }
+}
+
+impl TryFrom<TypeSystemBundle> for TypeSystem {
+    type Error = TypeSystemBundleError;
+
+    fn try_from(bundle: TypeSystemBundle) -> Result<Self, Self::Error> {
+        if !bundle.is_supported() {
+            return Err(TypeSystemBundleError::UnsupportedFormatVersion(bundle.format_version));
+        }
+        Ok(TypeSystem {
+            components: bundle.components.into_iter().map(Component::from).collect(),
+            entity_types: bundle.entity_types.into_iter().map(EntityType::from).collect(),
+            relation_types: bundle.relation_types.into_iter().map(RelationType::from).collect(),
+            flow_types: bundle.flow_types.into_iter().map(FlowType::from).collect(),
+            component_behaviours: bundle.component_behaviours.into_iter().map(ComponentBehaviourTypeId::from).collect(),
+            entity_behaviours: bundle.entity_behaviours.into_iter().map(EntityBehaviourTypeId::from).collect(),
+            relation_behaviours: bundle.relation_behaviours.into_iter().map(RelationBehaviourTypeId::from).collect(),
+            extra: bundle.extra,
+        })
+    }
+}
+
+/// Exports the given type system as JSON bundle document.
+pub fn export_type_system_bundle(type_system: &TypeSystem) -> Result<String, TypeSystemBundleError> {
+    serde_json::to_string_pretty(&TypeSystemBundle::from(type_system.clone())).map_err(TypeSystemBundleError::InvalidDocument)
+}
+
+/// Imports a type system from the given JSON bundle document.
+pub fn import_type_system_bundle(s: &str) -> Result<TypeSystem, TypeSystemBundleError> {
+    let bundle: TypeSystemBundle = serde_json::from_str(s).map_err(TypeSystemBundleError::InvalidDocument)?;
+    TypeSystem::try_from(bundle)
+}
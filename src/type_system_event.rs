@@ -0,0 +1,49 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::ComponentTypeId;
+use crate::TypeDefinition;
+
+/// An event of a change of the type system.
+///
+/// The events are exchanged by the event buses of the runtime and of the plugins.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum TypeSystemEvent {
+    /// The type has been created.
+    TypeCreated { ty: TypeDefinition },
+
+    /// The type has been updated.
+    TypeUpdated { ty: TypeDefinition },
+
+    /// The type has been deleted.
+    TypeDeleted { ty: TypeDefinition },
+
+    /// The component has been added to the entity type or relation type.
+    ComponentAdded { ty: TypeDefinition, component: ComponentTypeId },
+
+    /// The component has been removed from the entity type or relation type.
+    ComponentRemoved { ty: TypeDefinition, component: ComponentTypeId },
+
+    /// The property has been added to the type.
+    PropertyAdded { ty: TypeDefinition, property_name: String },
+
+    /// The property has been removed from the type.
+    PropertyRemoved { ty: TypeDefinition, property_name: String },
+}
+
+impl TypeSystemEvent {
+    /// Returns the type which has been changed.
+    pub fn ty(&self) -> &TypeDefinition {
+        match self {
+            TypeSystemEvent::TypeCreated { ty }
+            | TypeSystemEvent::TypeUpdated { ty }
+            | TypeSystemEvent::TypeDeleted { ty }
+            | TypeSystemEvent::ComponentAdded { ty, .. }
+            | TypeSystemEvent::ComponentRemoved { ty, .. }
+            | TypeSystemEvent::PropertyAdded { ty, .. }
+            | TypeSystemEvent::PropertyRemoved { ty, .. } => ty,
+        }
+    }
+}
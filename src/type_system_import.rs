@@ -0,0 +1,272 @@
+use std::fmt;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::Component;
+use crate::EntityType;
+use crate::Extension;
+use crate::FlowType;
+use crate::PropertyType;
+use crate::RelationType;
+use crate::ReservedNamespaces;
+use crate::TypeDefinition;
+use crate::TypeDefinitionGetter;
+use crate::TypeSystem;
+use crate::TypeSystemBundle;
+use crate::TypeSystemBundleError;
+
+/// Defines how conflicts are resolved when importing a type which already exists in the type system.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Copy, Eq)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "lowercase")]
+pub enum TypeImportStrategy {
+    /// Keeps the existing type and skips the imported type.
+    Skip,
+
+    /// Replaces the existing type with the imported type.
+    Replace,
+
+    /// Adds the properties, components, extensions (and for flow types the variables and ports)
+    /// of the imported type which are missing in the existing type.
+    Merge,
+
+    /// Fails if at least one conflict exists. The type system remains unchanged.
+    Fail,
+}
+
+/// Reports what has been done during the import of a bundle.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TypeSystemImportReport {
+    /// The types which have been added.
+    pub added: Vec<TypeDefinition>,
+
+    /// The existing types which have been replaced.
+    pub replaced: Vec<TypeDefinition>,
+
+    /// The existing types which have been merged with the imported types.
+    pub merged: Vec<TypeDefinition>,
+
+    /// The imported types which have been skipped.
+    pub skipped: Vec<TypeDefinition>,
+}
+
+#[derive(Debug)]
+pub enum TypeSystemImportError {
+    /// The bundle cannot be converted into a type system.
+    InvalidBundle(TypeSystemBundleError),
+
+    /// The import has been aborted because the types already exists.
+    Conflicts(Vec<TypeDefinition>),
+
+    /// The import has been aborted because the types are defined in reserved namespaces.
+    ReservedNamespaces(Vec<TypeDefinition>),
+}
+
+impl fmt::Display for TypeSystemImportError {
+    #[cfg(not(tarpaulin_include))]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TypeSystemImportError::InvalidBundle(e) => write!(f, "{}", e),
+            TypeSystemImportError::Conflicts(conflicts) => write!(f, "The import has been aborted because of {} conflicts", conflicts.len()),
+            TypeSystemImportError::ReservedNamespaces(types) => {
+                write!(f, "The import has been aborted because {} types are defined in reserved namespaces", types.len())
+            }
+        }
+    }
+}
+
+impl TypeSystem {
+    /// Imports the types of the given bundle into the type system.
+    ///
+    /// Types which already exists in the type system are resolved using the given strategy.
+    pub fn import_bundle(&mut self, bundle: TypeSystemBundle, strategy: TypeImportStrategy) -> Result<TypeSystemImportReport, TypeSystemImportError> {
+        let imported = TypeSystem::try_from(bundle).map_err(TypeSystemImportError::InvalidBundle)?;
+        self.import(imported, strategy)
+    }
+
+    /// Imports the types of the given type system into the type system.
+    ///
+    /// Types which already exists in the type system are resolved using the given strategy.
+    pub fn import(&mut self, imported: TypeSystem, strategy: TypeImportStrategy) -> Result<TypeSystemImportReport, TypeSystemImportError> {
+        if strategy == TypeImportStrategy::Fail {
+            let conflicts = self.conflicts(&imported);
+            if !conflicts.is_empty() {
+                return Err(TypeSystemImportError::Conflicts(conflicts));
+            }
+        }
+        let mut report = TypeSystemImportReport::default();
+        import_types(&mut self.components, imported.components, strategy, &mut report, merge_component);
+        import_types(&mut self.entity_types, imported.entity_types, strategy, &mut report, merge_entity_type);
+        import_types(&mut self.relation_types, imported.relation_types, strategy, &mut report, merge_relation_type);
+        import_types(&mut self.flow_types, imported.flow_types, strategy, &mut report, merge_flow_type);
+        merge_vec(&mut self.component_behaviours, imported.component_behaviours);
+        merge_vec(&mut self.entity_behaviours, imported.entity_behaviours);
+        merge_vec(&mut self.relation_behaviours, imported.relation_behaviours);
+        Ok(report)
+    }
+
+    /// Imports the user defined types of the given type system into the type system.
+    ///
+    /// In contrast to `import`, the import is aborted if at least one of the imported types is
+    /// defined in one of the given reserved namespaces. The type system remains unchanged.
+    pub fn import_user_types(
+        &mut self,
+        imported: TypeSystem,
+        strategy: TypeImportStrategy,
+        reserved_namespaces: &ReservedNamespaces,
+    ) -> Result<TypeSystemImportReport, TypeSystemImportError> {
+        let violations = reserved_namespaces.violations(&imported);
+        if !violations.is_empty() {
+            return Err(TypeSystemImportError::ReservedNamespaces(violations));
+        }
+        self.import(imported, strategy)
+    }
+
+    /// Returns the types of the given type system which already exists in this type system.
+    pub fn conflicts(&self, other: &TypeSystem) -> Vec<TypeDefinition> {
+        let mut conflicts = Vec::new();
+        conflicts.extend(
+            other
+                .components
+                .iter()
+                .filter(|c| self.get_component(&c.ty).is_some())
+                .map(|c| c.type_definition()),
+        );
+        conflicts.extend(
+            other
+                .entity_types
+                .iter()
+                .filter(|e| self.get_entity_type(&e.ty).is_some())
+                .map(|e| e.type_definition()),
+        );
+        conflicts.extend(
+            other
+                .relation_types
+                .iter()
+                .filter(|r| self.get_relation_type(&r.ty).is_some())
+                .map(|r| r.type_definition()),
+        );
+        conflicts.extend(
+            other
+                .flow_types
+                .iter()
+                .filter(|f| self.get_flow_type(&f.ty).is_some())
+                .map(|f| f.type_definition()),
+        );
+        conflicts
+    }
+}
+
+fn import_types<T: TypeDefinitionGetter>(
+    existing: &mut Vec<T>,
+    imported: Vec<T>,
+    strategy: TypeImportStrategy,
+    report: &mut TypeSystemImportReport,
+    merge: fn(&mut T, T),
+) {
+    for imported in imported {
+        let ty = imported.type_definition();
+        match existing.iter_mut().find(|existing| existing.type_definition() == ty) {
+            None => {
+                existing.push(imported);
+                report.added.push(ty);
+            }
+            Some(existing) => match strategy {
+                TypeImportStrategy::Replace => {
+                    *existing = imported;
+                    report.replaced.push(ty);
+                }
+                TypeImportStrategy::Merge => {
+                    merge(existing, imported);
+                    report.merged.push(ty);
+                }
+                TypeImportStrategy::Skip | TypeImportStrategy::Fail => report.skipped.push(ty),
+            },
+        }
+    }
+}
+
+fn merge_property_types(existing: &mut Vec<PropertyType>, imported: Vec<PropertyType>) {
+    for property_type in imported {
+        if !existing.iter().any(|p| p.name == property_type.name) {
+            existing.push(property_type);
+        }
+    }
+}
+
+fn merge_vec<T: PartialEq>(existing: &mut Vec<T>, imported: Vec<T>) {
+    for item in imported {
+        if !existing.contains(&item) {
+            existing.push(item);
+        }
+    }
+}
+
+fn merge_extensions(existing: &mut Vec<Extension>, imported: Vec<Extension>) {
+    for extension in imported {
+        if !existing.iter().any(|e| e.ty == extension.ty) {
+            existing.push(extension);
+        }
+    }
+}
+
+fn merge_option<T>(existing: &mut Option<T>, imported: Option<T>) {
+    if existing.is_none() {
+        *existing = imported;
+    }
+}
+
+fn merge_description(existing: &mut String, imported: String) {
+    if existing.is_empty() {
+        *existing = imported;
+    }
+}
+
+fn merge_component(existing: &mut Component, imported: Component) {
+    merge_description(&mut existing.description, imported.description);
+    merge_vec(&mut existing.tags, imported.tags);
+    merge_vec(&mut existing.aliases, imported.aliases);
+    merge_option(&mut existing.deprecation, imported.deprecation);
+    merge_property_types(&mut existing.properties, imported.properties);
+    merge_extensions(&mut existing.extensions, imported.extensions);
+}
+
+fn merge_entity_type(existing: &mut EntityType, imported: EntityType) {
+    merge_description(&mut existing.description, imported.description);
+    merge_vec(&mut existing.tags, imported.tags);
+    merge_vec(&mut existing.aliases, imported.aliases);
+    merge_option(&mut existing.deprecation, imported.deprecation);
+    merge_vec(&mut existing.components, imported.components);
+    merge_property_types(&mut existing.properties, imported.properties);
+    merge_extensions(&mut existing.extensions, imported.extensions);
+}
+
+fn merge_relation_type(existing: &mut RelationType, imported: RelationType) {
+    merge_description(&mut existing.description, imported.description);
+    merge_vec(&mut existing.tags, imported.tags);
+    merge_vec(&mut existing.aliases, imported.aliases);
+    merge_option(&mut existing.deprecation, imported.deprecation);
+    merge_vec(&mut existing.components, imported.components);
+    merge_property_types(&mut existing.properties, imported.properties);
+    merge_extensions(&mut existing.extensions, imported.extensions);
+}
+
+fn merge_flow_type(existing: &mut FlowType, imported: FlowType) {
+    merge_description(&mut existing.description, imported.description);
+    merge_vec(&mut existing.tags, imported.tags);
+    merge_vec(&mut existing.aliases, imported.aliases);
+    merge_option(&mut existing.deprecation, imported.deprecation);
+    merge_property_types(&mut existing.variables, imported.variables);
+    for port in imported.inputs {
+        if !existing.has_input(&port.name) {
+            existing.inputs.push(port);
+        }
+    }
+    for port in imported.outputs {
+        if !existing.has_output(&port.name) {
+            existing.outputs.push(port);
+        }
+    }
+    merge_extensions(&mut existing.extensions, imported.extensions);
+}
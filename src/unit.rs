@@ -0,0 +1,189 @@
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::ops::Div;
+use std::ops::Mul;
+
+use crate::PropertyType;
+use crate::UnitError;
+
+/// The separator of the numerator and the denominator of a compound unit, for example `m/s`.
+pub const UNIT_DIVISION_SEPARATOR: char = '/';
+
+/// The dimension of a unit as exponents of the SI base quantities in the order time, length,
+/// mass, electric current, temperature, amount of substance and luminous intensity.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Dimension(pub [i8; 7]);
+
+impl Dimension {
+    pub const DIMENSIONLESS: Dimension = Dimension([0, 0, 0, 0, 0, 0, 0]);
+    pub const TIME: Dimension = Dimension([1, 0, 0, 0, 0, 0, 0]);
+    pub const LENGTH: Dimension = Dimension([0, 1, 0, 0, 0, 0, 0]);
+    pub const MASS: Dimension = Dimension([0, 0, 1, 0, 0, 0, 0]);
+    pub const ELECTRIC_CURRENT: Dimension = Dimension([0, 0, 0, 1, 0, 0, 0]);
+    pub const TEMPERATURE: Dimension = Dimension([0, 0, 0, 0, 1, 0, 0]);
+    pub const AMOUNT_OF_SUBSTANCE: Dimension = Dimension([0, 0, 0, 0, 0, 1, 0]);
+    pub const LUMINOUS_INTENSITY: Dimension = Dimension([0, 0, 0, 0, 0, 0, 1]);
+    pub const FREQUENCY: Dimension = Dimension([-1, 0, 0, 0, 0, 0, 0]);
+    pub const FORCE: Dimension = Dimension([-2, 1, 1, 0, 0, 0, 0]);
+    pub const PRESSURE: Dimension = Dimension([-2, -1, 1, 0, 0, 0, 0]);
+    pub const ENERGY: Dimension = Dimension([-2, 2, 1, 0, 0, 0, 0]);
+    pub const POWER: Dimension = Dimension([-3, 2, 1, 0, 0, 0, 0]);
+    pub const VOLTAGE: Dimension = Dimension([-3, 2, 1, -1, 0, 0, 0]);
+}
+
+impl Mul for Dimension {
+    type Output = Dimension;
+
+    fn mul(self, rhs: Dimension) -> Dimension {
+        Dimension(std::array::from_fn(|i| self.0[i] + rhs.0[i]))
+    }
+}
+
+impl Div for Dimension {
+    type Output = Dimension;
+
+    fn div(self, rhs: Dimension) -> Dimension {
+        Dimension(std::array::from_fn(|i| self.0[i] - rhs.0[i]))
+    }
+}
+
+/// A unit of a numeric property, which is defined by its dimension and by its scale relative to
+/// the coherent SI unit of the dimension. For example, the unit `ms` has the dimension time and
+/// the scale `0.001`.
+///
+/// Units with an offset like degree Celsius are not supported.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Unit {
+    /// The symbol of the unit.
+    pub symbol: String,
+
+    /// The dimension of the unit.
+    pub dimension: Dimension,
+
+    /// The scale of the unit relative to the coherent SI unit.
+    pub scale: f64,
+}
+
+/// The known units: the symbol, the dimension, the scale and whether SI prefixes are applicable.
+const UNITS: &[(&str, Dimension, f64, bool)] = &[
+    ("", Dimension::DIMENSIONLESS, 1.0, false),
+    ("%", Dimension::DIMENSIONLESS, 0.01, false),
+    ("s", Dimension::TIME, 1.0, true),
+    ("min", Dimension::TIME, 60.0, false),
+    ("h", Dimension::TIME, 3600.0, false),
+    ("d", Dimension::TIME, 86400.0, false),
+    ("m", Dimension::LENGTH, 1.0, true),
+    ("g", Dimension::MASS, 0.001, true),
+    ("t", Dimension::MASS, 1000.0, false),
+    ("A", Dimension::ELECTRIC_CURRENT, 1.0, true),
+    ("K", Dimension::TEMPERATURE, 1.0, true),
+    ("mol", Dimension::AMOUNT_OF_SUBSTANCE, 1.0, true),
+    ("cd", Dimension::LUMINOUS_INTENSITY, 1.0, true),
+    ("Hz", Dimension::FREQUENCY, 1.0, true),
+    ("N", Dimension::FORCE, 1.0, true),
+    ("Pa", Dimension::PRESSURE, 1.0, true),
+    ("J", Dimension::ENERGY, 1.0, true),
+    ("W", Dimension::POWER, 1.0, true),
+    ("V", Dimension::VOLTAGE, 1.0, true),
+];
+
+/// The SI prefixes and their factors.
+const PREFIXES: &[(&str, f64)] = &[
+    ("n", 1e-9),
+    ("µ", 1e-6),
+    ("u", 1e-6),
+    ("m", 1e-3),
+    ("c", 1e-2),
+    ("k", 1e3),
+    ("M", 1e6),
+    ("G", 1e9),
+];
+
+impl Unit {
+    pub fn new<S: Into<String>>(symbol: S, dimension: Dimension, scale: f64) -> Unit {
+        Unit {
+            symbol: symbol.into(),
+            dimension,
+            scale,
+        }
+    }
+
+    /// Parses the given unit symbol.
+    ///
+    /// Known are the SI base units, some derived SI units and common units of time, optionally
+    /// with an SI prefix like `k` or `m`. A numerator and a denominator can be combined like
+    /// `km/h`. Divisions are left-associative, so `m/s/s` is parsed as `(m/s)/s`.
+    pub fn parse(symbol: &str) -> Result<Unit, UnitError> {
+        if let Some((numerator, denominator)) = symbol.rsplit_once(UNIT_DIVISION_SEPARATOR) {
+            let numerator = Unit::parse(numerator.trim())?;
+            let denominator = Unit::parse(denominator.trim())?;
+            return Ok(Unit::new(symbol, numerator.dimension / denominator.dimension, numerator.scale / denominator.scale));
+        }
+        if let Some((_, dimension, scale, _)) = UNITS.iter().find(|(s, ..)| *s == symbol) {
+            return Ok(Unit::new(symbol, *dimension, *scale));
+        }
+        PREFIXES
+            .iter()
+            .filter_map(|(prefix, factor)| symbol.strip_prefix(prefix).map(|unit| (unit, factor)))
+            .find_map(|(unit, factor)| {
+                UNITS
+                    .iter()
+                    .find(|(s, _, _, prefixable)| *prefixable && *s == unit)
+                    .map(|(_, dimension, scale, _)| Unit::new(symbol, *dimension, factor * scale))
+            })
+            .ok_or_else(|| UnitError::UnknownUnit(symbol.to_string()))
+    }
+
+    /// Returns true, if values can be converted between this unit and the other unit.
+    pub fn is_compatible(&self, other: &Unit) -> bool {
+        self.dimension == other.dimension
+    }
+
+    /// Converts the given value from this unit into the other unit.
+    pub fn convert(&self, value: f64, to: &Unit) -> Result<f64, UnitError> {
+        if !self.is_compatible(to) {
+            return Err(UnitError::IncompatibleUnits {
+                from: self.symbol.clone(),
+                to: to.symbol.clone(),
+            });
+        }
+        Ok(value * self.scale / to.scale)
+    }
+}
+
+impl Display for Unit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.symbol)
+    }
+}
+
+/// Converts the given value between the given unit symbols.
+pub fn convert_unit(value: f64, from: &str, to: &str) -> Result<f64, UnitError> {
+    Unit::parse(from)?.convert(value, &Unit::parse(to)?)
+}
+
+impl PropertyType {
+    /// Sets the unit of the values of the property.
+    pub fn with_unit<S: Into<String>>(mut self, unit: S) -> PropertyType {
+        self.unit = Some(unit.into());
+        self
+    }
+
+    /// Returns the parsed unit of the property, if the property has a unit.
+    pub fn resolve_unit(&self) -> Result<Option<Unit>, UnitError> {
+        self.unit.as_deref().map(Unit::parse).transpose()
+    }
+
+    /// Converts the given value of the property into the given unit.
+    pub fn convert_to(&self, value: f64, unit: &str) -> Result<f64, UnitError> {
+        let from = self.unit.as_deref().ok_or_else(|| UnitError::MissingUnit(self.name.to_string()))?;
+        convert_unit(value, from, unit)
+    }
+
+    /// Converts the given value from the given unit into the unit of the property.
+    pub fn convert_from(&self, value: f64, unit: &str) -> Result<f64, UnitError> {
+        let to = self.unit.as_deref().ok_or_else(|| UnitError::MissingUnit(self.name.to_string()))?;
+        convert_unit(value, unit, to)
+    }
+}
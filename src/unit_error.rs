@@ -0,0 +1,17 @@
+use thiserror::Error;
+
+/// Error of the parsing or the conversion of units.
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum UnitError {
+    /// The unit symbol is unknown.
+    #[error("The unit {0} is unknown")]
+    UnknownUnit(String),
+
+    /// The units have different dimensions, for example seconds and meters.
+    #[error("The unit {from} can't be converted into the unit {to}")]
+    IncompatibleUnits { from: String, to: String },
+
+    /// The property has no unit.
+    #[error("The property {0} has no unit")]
+    MissingUnit(String),
+}
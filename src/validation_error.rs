@@ -0,0 +1,138 @@
+use std::collections::HashMap;
+
+use serde_json::Value;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::identifier_string;
+use crate::qualified_property_name;
+use crate::Component;
+use crate::ComponentOrEntityTypeId;
+use crate::ComponentTypeId;
+use crate::EntityTypeId;
+use crate::FlowTypeId;
+use crate::IdentifierError;
+use crate::PropertyError;
+use crate::PropertyType;
+use crate::RelationEndpoint;
+use crate::RelationTypeId;
+
+/// Error of the validation of an instance against its type.
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum ValidationError {
+    /// The instance is of another type than the type it is validated against.
+    #[error("Expected an instance of {expected} but got an instance of {actual}")]
+    UnexpectedType { expected: String, actual: String },
+
+    /// The component is not known.
+    #[error("The component {0} is unknown")]
+    UnknownComponent(ComponentTypeId),
+
+    /// The entity type is not known.
+    #[error("The entity type {0} is unknown")]
+    UnknownEntityType(EntityTypeId),
+
+    /// The entity type inherits from itself.
+    #[error("The entity type {0} is part of an inheritance cycle")]
+    InheritanceCycle(EntityTypeId),
+
+    /// The entity type is abstract and can't be instantiated.
+    #[error("The entity type {0} is abstract and can't be instantiated")]
+    AbstractEntityType(EntityTypeId),
+
+    /// The entity type extends a final entity type.
+    #[error("The entity type {ty} must not extend the final entity type {parent}")]
+    FinalEntityTypeExtended { ty: EntityTypeId, parent: EntityTypeId },
+
+    /// The instances of the final entity type can't be composed with the component.
+    #[error("The instances of the final entity type {ty} can't be composed with the component {component}")]
+    FinalEntityTypeComposed { ty: EntityTypeId, component: ComponentTypeId },
+
+    /// The relation type is not known.
+    #[error("The relation type {0} is unknown")]
+    UnknownRelationType(RelationTypeId),
+
+    /// The entity instance at the endpoint is related to more entity instances by relation
+    /// instances of the relation type than the cardinality of the relation type allows.
+    #[error("The {endpoint} entity instance {id} is related to more than {max} entity instances by relation instances of the relation type {ty}")]
+    CardinalityExceeded {
+        ty: RelationTypeId,
+        id: Uuid,
+        endpoint: RelationEndpoint,
+        max: usize,
+    },
+
+    /// The entity type of the entity instance at the endpoint doesn't satisfy the endpoint
+    /// constraint of the relation type.
+    #[error("The entity type {actual} doesn't satisfy the {endpoint} constraint {expected} of the relation type {ty}")]
+    UnsatisfiedEndpoint {
+        ty: RelationTypeId,
+        endpoint: RelationEndpoint,
+        expected: ComponentOrEntityTypeId,
+        actual: EntityTypeId,
+    },
+
+    /// An entity type argument is given for a type parameter which the flow type doesn't have.
+    #[error("The flow type {ty} has no type parameter {parameter}")]
+    UnknownTypeParameter { ty: FlowTypeId, parameter: String },
+
+    /// Neither an entity type argument nor a default is given for a type parameter of the flow
+    /// type.
+    #[error("The type parameter {parameter} of the flow type {ty} requires an entity type argument")]
+    MissingTypeArgument { ty: FlowTypeId, parameter: String },
+
+    /// The entity type argument doesn't satisfy the constraint of the type parameter.
+    #[error("The entity type {argument} doesn't satisfy the constraint {constraint} of the type parameter {parameter} of the flow type {ty}")]
+    UnsatisfiedTypeParameterConstraint {
+        ty: FlowTypeId,
+        parameter: String,
+        constraint: ComponentOrEntityTypeId,
+        argument: EntityTypeId,
+    },
+
+    /// The user defined type is defined in a reserved namespace.
+    #[error("The type {ty} must not be defined in the reserved namespace {namespace}")]
+    ReservedNamespace { ty: String, namespace: String },
+
+    /// A property of the instance is missing or invalid.
+    #[error(transparent)]
+    Property(#[from] PropertyError),
+
+    /// The type id is invalid.
+    #[error(transparent)]
+    Identifier(#[from] IdentifierError),
+}
+
+/// Validates the properties of an instance against the property types of its type and against
+/// the property types of the components of its type.
+///
+/// If the properties of the components are scoped, they are validated by their qualified names.
+pub(crate) fn validate_instance_properties(
+    scoped: bool,
+    property_types: &[PropertyType],
+    component_tys: &[ComponentTypeId],
+    components: &[Component],
+    properties: &HashMap<String, Value>,
+) -> Result<(), ValidationError> {
+    for property_type in property_types.iter() {
+        property_type.validate_property(properties)?;
+    }
+    for component_ty in component_tys.iter() {
+        let component = components
+            .iter()
+            .find(|component| component.is_known_as(component_ty))
+            .ok_or_else(|| ValidationError::UnknownComponent(component_ty.clone()))?;
+        if scoped {
+            for property_type in component.properties.iter() {
+                let mut property_type = property_type.clone();
+                property_type.name = identifier_string(qualified_property_name(component_ty, &property_type.name));
+                property_type.validate_property(properties)?;
+            }
+            continue;
+        }
+        for property_type in component.properties.iter().filter(|p| !property_types.iter().any(|o| o.name == p.name)) {
+            property_type.validate_property(properties)?;
+        }
+    }
+    Ok(())
+}
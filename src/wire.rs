@@ -0,0 +1,1360 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use chrono::DateTime;
+use chrono::Utc;
+use serde_json::Map;
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::identifier_string;
+use crate::Cardinality;
+use crate::Component;
+use crate::ComponentOrEntityTypeId;
+use crate::ComponentTypeId;
+use crate::DataType;
+use crate::Deprecation;
+use crate::Documentation;
+use crate::DocumentationLink;
+use crate::EntityInstance;
+use crate::EntityType;
+use crate::EntityTypeId;
+use crate::Extension;
+use crate::ExtensionTypeId;
+use crate::FlowInstance;
+use crate::FlowType;
+use crate::FlowTypeId;
+use crate::FlowTypeParameter;
+use crate::FlowTypePort;
+use crate::LanguageTag;
+use crate::Mutability;
+use crate::NamespacedType;
+use crate::NamespacedTypeGetter;
+use crate::PropertyType;
+use crate::RelationCardinality;
+use crate::RelationInstance;
+use crate::RelationInstanceTypeId;
+use crate::RelationType;
+use crate::RelationTypeId;
+use crate::SocketType;
+use crate::SubFlowBinding;
+use crate::SubFlowBindingDirection;
+use crate::SubFlowReference;
+use crate::SubFlowTypeBinding;
+use crate::SubFlowTypeReference;
+
+// The protobuf messages of the wire format. The schema is defined in proto/model.proto.
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, prost::Enumeration)]
+#[repr(i32)]
+pub enum WireDataType {
+    Null = 0,
+    Bool = 1,
+    Number = 2,
+    String = 3,
+    Array = 4,
+    Object = 5,
+    Any = 6,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, prost::Enumeration)]
+#[repr(i32)]
+pub enum WireSocketType {
+    None = 0,
+    Input = 1,
+    Output = 2,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, prost::Enumeration)]
+#[repr(i32)]
+pub enum WireMutability {
+    Mutable = 0,
+    Immutable = 1,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, prost::Enumeration)]
+#[repr(i32)]
+pub enum WireComponentOrEntityTypeKind {
+    Component = 0,
+    EntityType = 1,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, prost::Enumeration)]
+#[repr(i32)]
+pub enum WireSubFlowBindingDirection {
+    Input = 0,
+    Output = 1,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, prost::Enumeration)]
+#[repr(i32)]
+pub enum WireCardinality {
+    ManyToMany = 0,
+    OneToOne = 1,
+    OneToMany = 2,
+    ManyToOne = 3,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct WireNamespacedType {
+    #[prost(string, tag = "1")]
+    pub namespace: String,
+    #[prost(string, tag = "2")]
+    pub type_name: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct WireComponentOrEntityTypeId {
+    #[prost(enumeration = "WireComponentOrEntityTypeKind", tag = "1")]
+    pub kind: i32,
+    #[prost(string, tag = "2")]
+    pub namespace: String,
+    #[prost(string, tag = "3")]
+    pub type_name: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct WireExtension {
+    #[prost(string, tag = "1")]
+    pub namespace: String,
+    #[prost(string, tag = "2")]
+    pub type_name: String,
+    #[prost(string, tag = "3")]
+    pub description: String,
+    #[prost(string, tag = "4")]
+    pub extension: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct WireTimestamp {
+    #[prost(int64, tag = "1")]
+    pub seconds: i64,
+    #[prost(int32, tag = "2")]
+    pub nanos: i32,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct WirePropertyType {
+    #[prost(string, tag = "1")]
+    pub name: String,
+    #[prost(string, tag = "2")]
+    pub description: String,
+    #[prost(enumeration = "WireDataType", tag = "3")]
+    pub data_type: i32,
+    #[prost(enumeration = "WireSocketType", tag = "4")]
+    pub socket_type: i32,
+    #[prost(enumeration = "WireMutability", tag = "5")]
+    pub mutability: i32,
+    #[prost(message, repeated, tag = "6")]
+    pub extensions: Vec<WireExtension>,
+    #[prost(map = "string, string", tag = "7")]
+    pub descriptions: HashMap<String, String>,
+    #[prost(message, optional, tag = "8")]
+    pub documentation: Option<WireDocumentation>,
+    #[prost(string, repeated, tag = "9")]
+    pub examples: Vec<String>,
+    #[prost(string, optional, tag = "10")]
+    pub unit: Option<String>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct WireRelationCardinality {
+    #[prost(enumeration = "WireCardinality", tag = "1")]
+    pub cardinality: i32,
+    #[prost(uint64, optional, tag = "2")]
+    pub max_outbound: Option<u64>,
+    #[prost(uint64, optional, tag = "3")]
+    pub max_inbound: Option<u64>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct WireDeprecation {
+    #[prost(string, optional, tag = "1")]
+    pub since: Option<String>,
+    #[prost(message, optional, tag = "2")]
+    pub replacement: Option<WireNamespacedType>,
+    #[prost(string, tag = "3")]
+    pub message: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct WireDocumentation {
+    #[prost(string, tag = "1")]
+    pub summary: String,
+    #[prost(string, optional, tag = "2")]
+    pub body: Option<String>,
+    #[prost(message, repeated, tag = "3")]
+    pub links: Vec<WireDocumentationLink>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct WireDocumentationLink {
+    #[prost(string, tag = "1")]
+    pub title: String,
+    #[prost(string, tag = "2")]
+    pub url: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct WireComponent {
+    #[prost(string, tag = "1")]
+    pub namespace: String,
+    #[prost(string, tag = "2")]
+    pub type_name: String,
+    #[prost(string, tag = "3")]
+    pub description: String,
+    #[prost(message, repeated, tag = "4")]
+    pub properties: Vec<WirePropertyType>,
+    #[prost(message, repeated, tag = "5")]
+    pub extensions: Vec<WireExtension>,
+    #[prost(string, repeated, tag = "6")]
+    pub tags: Vec<String>,
+    #[prost(message, repeated, tag = "7")]
+    pub aliases: Vec<WireNamespacedType>,
+    #[prost(message, optional, tag = "8")]
+    pub deprecation: Option<WireDeprecation>,
+    #[prost(map = "string, string", tag = "9")]
+    pub descriptions: HashMap<String, String>,
+    #[prost(message, optional, tag = "10")]
+    pub documentation: Option<WireDocumentation>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct WireEntityType {
+    #[prost(string, tag = "1")]
+    pub namespace: String,
+    #[prost(string, tag = "2")]
+    pub type_name: String,
+    #[prost(string, tag = "3")]
+    pub description: String,
+    #[prost(message, repeated, tag = "4")]
+    pub components: Vec<WireNamespacedType>,
+    #[prost(message, repeated, tag = "5")]
+    pub properties: Vec<WirePropertyType>,
+    #[prost(message, repeated, tag = "6")]
+    pub extensions: Vec<WireExtension>,
+    #[prost(string, repeated, tag = "7")]
+    pub tags: Vec<String>,
+    #[prost(message, repeated, tag = "8")]
+    pub aliases: Vec<WireNamespacedType>,
+    #[prost(message, optional, tag = "9")]
+    pub deprecation: Option<WireDeprecation>,
+    #[prost(message, optional, tag = "10")]
+    pub extends: Option<WireNamespacedType>,
+    #[prost(bool, tag = "11")]
+    pub is_abstract: bool,
+    #[prost(bool, tag = "12")]
+    pub is_final: bool,
+    #[prost(bool, tag = "13")]
+    pub scoped_properties: bool,
+    #[prost(map = "string, string", tag = "14")]
+    pub descriptions: HashMap<String, String>,
+    #[prost(message, optional, tag = "15")]
+    pub documentation: Option<WireDocumentation>,
+    #[prost(message, repeated, tag = "16")]
+    pub examples: Vec<WireEntityInstance>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct WireRelationType {
+    #[prost(message, optional, tag = "1")]
+    pub outbound_type: Option<WireComponentOrEntityTypeId>,
+    #[prost(string, tag = "2")]
+    pub namespace: String,
+    #[prost(string, tag = "3")]
+    pub type_name: String,
+    #[prost(message, optional, tag = "4")]
+    pub inbound_type: Option<WireComponentOrEntityTypeId>,
+    #[prost(string, tag = "5")]
+    pub description: String,
+    #[prost(message, repeated, tag = "6")]
+    pub components: Vec<WireNamespacedType>,
+    #[prost(message, repeated, tag = "7")]
+    pub properties: Vec<WirePropertyType>,
+    #[prost(message, repeated, tag = "8")]
+    pub extensions: Vec<WireExtension>,
+    #[prost(string, repeated, tag = "9")]
+    pub tags: Vec<String>,
+    #[prost(message, repeated, tag = "10")]
+    pub aliases: Vec<WireNamespacedType>,
+    #[prost(message, optional, tag = "11")]
+    pub deprecation: Option<WireDeprecation>,
+    #[prost(message, optional, tag = "12")]
+    pub cardinality: Option<WireRelationCardinality>,
+    #[prost(bool, tag = "13")]
+    pub bidirectional: bool,
+    #[prost(string, optional, tag = "14")]
+    pub inverse_name: Option<String>,
+    #[prost(message, repeated, tag = "15")]
+    pub outbound_types: Vec<WireComponentOrEntityTypeId>,
+    #[prost(message, repeated, tag = "16")]
+    pub inbound_types: Vec<WireComponentOrEntityTypeId>,
+    #[prost(bool, tag = "17")]
+    pub symmetric: bool,
+    #[prost(bool, tag = "18")]
+    pub transitive: bool,
+    #[prost(bool, tag = "19")]
+    pub reflexive: bool,
+    #[prost(bool, tag = "20")]
+    pub scoped_properties: bool,
+    #[prost(map = "string, string", tag = "21")]
+    pub descriptions: HashMap<String, String>,
+    #[prost(message, optional, tag = "22")]
+    pub documentation: Option<WireDocumentation>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct WireEntityInstance {
+    #[prost(string, tag = "1")]
+    pub namespace: String,
+    #[prost(string, tag = "2")]
+    pub type_name: String,
+    #[prost(bytes = "vec", tag = "3")]
+    pub id: Vec<u8>,
+    #[prost(string, tag = "4")]
+    pub description: String,
+    #[prost(map = "string, string", tag = "5")]
+    pub properties: HashMap<String, String>,
+    #[prost(message, repeated, tag = "6")]
+    pub extensions: Vec<WireExtension>,
+    #[prost(message, optional, tag = "7")]
+    pub created_at: Option<WireTimestamp>,
+    #[prost(message, optional, tag = "8")]
+    pub updated_at: Option<WireTimestamp>,
+    #[prost(string, optional, tag = "9")]
+    pub created_by: Option<String>,
+    #[prost(message, optional, tag = "10")]
+    pub deleted_at: Option<WireTimestamp>,
+    #[prost(uint64, tag = "11")]
+    pub revision: u64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct WireRelationInstance {
+    #[prost(bytes = "vec", tag = "1")]
+    pub outbound_id: Vec<u8>,
+    #[prost(string, tag = "2")]
+    pub namespace: String,
+    #[prost(string, tag = "3")]
+    pub type_name: String,
+    #[prost(string, tag = "4")]
+    pub instance_id: String,
+    #[prost(bytes = "vec", tag = "5")]
+    pub inbound_id: Vec<u8>,
+    #[prost(string, tag = "6")]
+    pub description: String,
+    #[prost(map = "string, string", tag = "7")]
+    pub properties: HashMap<String, String>,
+    #[prost(message, repeated, tag = "8")]
+    pub extensions: Vec<WireExtension>,
+    #[prost(message, optional, tag = "9")]
+    pub created_at: Option<WireTimestamp>,
+    #[prost(message, optional, tag = "10")]
+    pub updated_at: Option<WireTimestamp>,
+    #[prost(string, optional, tag = "11")]
+    pub created_by: Option<String>,
+    #[prost(message, optional, tag = "12")]
+    pub deleted_at: Option<WireTimestamp>,
+    #[prost(uint64, tag = "13")]
+    pub revision: u64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct WireSubFlowBinding {
+    #[prost(enumeration = "WireSubFlowBindingDirection", tag = "1")]
+    pub direction: i32,
+    #[prost(bytes = "vec", tag = "2")]
+    pub outer_entity_id: Vec<u8>,
+    #[prost(string, tag = "3")]
+    pub outer_property_name: String,
+    #[prost(bytes = "vec", tag = "4")]
+    pub inner_entity_id: Vec<u8>,
+    #[prost(string, tag = "5")]
+    pub inner_property_name: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct WireSubFlowReference {
+    #[prost(bytes = "vec", tag = "1")]
+    pub flow_id: Vec<u8>,
+    #[prost(message, repeated, tag = "2")]
+    pub bindings: Vec<WireSubFlowBinding>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct WireFlowInstance {
+    #[prost(bytes = "vec", tag = "1")]
+    pub id: Vec<u8>,
+    #[prost(string, tag = "2")]
+    pub namespace: String,
+    #[prost(string, tag = "3")]
+    pub type_name: String,
+    #[prost(string, tag = "4")]
+    pub name: String,
+    #[prost(string, tag = "5")]
+    pub description: String,
+    #[prost(message, repeated, tag = "6")]
+    pub entity_instances: Vec<WireEntityInstance>,
+    #[prost(message, repeated, tag = "7")]
+    pub relation_instances: Vec<WireRelationInstance>,
+    #[prost(bytes = "vec", repeated, tag = "8")]
+    pub external_entity_ids: Vec<Vec<u8>>,
+    #[prost(message, repeated, tag = "9")]
+    pub sub_flows: Vec<WireSubFlowReference>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct WireFlowTypePort {
+    #[prost(string, tag = "1")]
+    pub name: String,
+    #[prost(string, tag = "2")]
+    pub description: String,
+    #[prost(bytes = "vec", tag = "3")]
+    pub entity_id: Vec<u8>,
+    #[prost(string, tag = "4")]
+    pub property_name: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct WireSubFlowTypeBinding {
+    #[prost(enumeration = "WireSubFlowBindingDirection", tag = "1")]
+    pub direction: i32,
+    #[prost(bytes = "vec", tag = "2")]
+    pub outer_entity_id: Vec<u8>,
+    #[prost(string, tag = "3")]
+    pub outer_property_name: String,
+    #[prost(string, tag = "4")]
+    pub port: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct WireSubFlowTypeReference {
+    #[prost(string, tag = "1")]
+    pub name: String,
+    #[prost(string, tag = "2")]
+    pub namespace: String,
+    #[prost(string, tag = "3")]
+    pub type_name: String,
+    #[prost(message, repeated, tag = "4")]
+    pub bindings: Vec<WireSubFlowTypeBinding>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct WireFlowTypeParameter {
+    #[prost(string, tag = "1")]
+    pub name: String,
+    #[prost(string, tag = "2")]
+    pub description: String,
+    #[prost(message, optional, tag = "3")]
+    pub constraint: Option<WireComponentOrEntityTypeId>,
+    #[prost(message, optional, tag = "4")]
+    pub default: Option<WireNamespacedType>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct WireFlowType {
+    #[prost(string, tag = "1")]
+    pub namespace: String,
+    #[prost(string, tag = "2")]
+    pub type_name: String,
+    #[prost(string, tag = "3")]
+    pub description: String,
+    #[prost(message, optional, tag = "4")]
+    pub wrapper_entity_instance: Option<WireEntityInstance>,
+    #[prost(message, repeated, tag = "5")]
+    pub entity_instances: Vec<WireEntityInstance>,
+    #[prost(message, repeated, tag = "6")]
+    pub relation_instances: Vec<WireRelationInstance>,
+    #[prost(message, repeated, tag = "7")]
+    pub variables: Vec<WirePropertyType>,
+    #[prost(message, repeated, tag = "8")]
+    pub inputs: Vec<WireFlowTypePort>,
+    #[prost(message, repeated, tag = "9")]
+    pub outputs: Vec<WireFlowTypePort>,
+    #[prost(message, repeated, tag = "10")]
+    pub extensions: Vec<WireExtension>,
+    #[prost(string, repeated, tag = "11")]
+    pub tags: Vec<String>,
+    #[prost(message, repeated, tag = "12")]
+    pub aliases: Vec<WireNamespacedType>,
+    #[prost(message, optional, tag = "13")]
+    pub deprecation: Option<WireDeprecation>,
+    #[prost(map = "string, string", tag = "14")]
+    pub descriptions: HashMap<String, String>,
+    #[prost(message, optional, tag = "15")]
+    pub documentation: Option<WireDocumentation>,
+    #[prost(message, repeated, tag = "16")]
+    pub examples: Vec<WireFlowInstance>,
+    #[prost(message, repeated, tag = "17")]
+    pub sub_flows: Vec<WireSubFlowTypeReference>,
+    #[prost(message, repeated, tag = "18")]
+    pub type_parameters: Vec<WireFlowTypeParameter>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum WireFormatError {
+    /// The bytes are not a valid uuid.
+    InvalidUuid,
+
+    /// The string is not a valid JSON document.
+    InvalidJson(String),
+
+    /// The value of the enumeration with the given name is unknown.
+    InvalidEnumValue(&'static str, i32),
+
+    /// The required field with the given name is missing.
+    MissingField(&'static str),
+
+    /// The seconds and nanoseconds since the unix epoch are out of the range of timestamps.
+    InvalidTimestamp(i64, i32),
+}
+
+impl fmt::Display for WireFormatError {
+    #[cfg(not(tarpaulin_include))]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WireFormatError::InvalidUuid => write!(f, "Invalid uuid"),
+            WireFormatError::InvalidJson(message) => write!(f, "Invalid JSON: {}", message),
+            WireFormatError::InvalidEnumValue(name, value) => write!(f, "Invalid value {} of {}", value, name),
+            WireFormatError::MissingField(name) => write!(f, "Missing field {}", name),
+            WireFormatError::InvalidTimestamp(seconds, nanos) => write!(f, "Invalid timestamp {}s {}ns", seconds, nanos),
+        }
+    }
+}
+
+fn uuid_from_wire(bytes: &[u8]) -> Result<Uuid, WireFormatError> {
+    Uuid::from_slice(bytes).map_err(|_| WireFormatError::InvalidUuid)
+}
+
+fn timestamp_to_wire(timestamp: &DateTime<Utc>) -> WireTimestamp {
+    WireTimestamp {
+        seconds: timestamp.timestamp(),
+        nanos: timestamp.timestamp_subsec_nanos() as i32,
+    }
+}
+
+fn timestamp_from_wire(timestamp: Option<WireTimestamp>) -> Result<Option<DateTime<Utc>>, WireFormatError> {
+    timestamp
+        .map(|WireTimestamp { seconds, nanos }| {
+            u32::try_from(nanos)
+                .ok()
+                .and_then(|subsec_nanos| DateTime::from_timestamp(seconds, subsec_nanos))
+                .ok_or(WireFormatError::InvalidTimestamp(seconds, nanos))
+        })
+        .transpose()
+}
+
+fn json_from_wire(s: &str) -> Result<Value, WireFormatError> {
+    serde_json::from_str(s).map_err(|e| WireFormatError::InvalidJson(e.to_string()))
+}
+
+fn properties_to_wire(properties: &HashMap<String, Value>) -> HashMap<String, String> {
+    properties.iter().map(|(name, value)| (name.clone(), value.to_string())).collect()
+}
+
+fn properties_from_wire(properties: HashMap<String, String>) -> Result<HashMap<String, Value>, WireFormatError> {
+    properties.into_iter().map(|(name, value)| Ok((name, json_from_wire(&value)?))).collect()
+}
+
+fn extensions_from_wire(extensions: Vec<WireExtension>) -> Result<Vec<Extension>, WireFormatError> {
+    extensions.into_iter().map(Extension::try_from).collect()
+}
+
+fn property_types_from_wire(properties: Vec<WirePropertyType>) -> Result<Vec<PropertyType>, WireFormatError> {
+    properties.into_iter().map(PropertyType::try_from).collect()
+}
+
+fn type_ids_to_wire<T: NamespacedTypeGetter>(tys: &[T]) -> Vec<WireNamespacedType> {
+    tys.iter()
+        .map(|ty| WireNamespacedType {
+            namespace: ty.namespace(),
+            type_name: ty.type_name(),
+        })
+        .collect()
+}
+
+fn type_ids_from_wire<T: From<NamespacedType>>(tys: Vec<WireNamespacedType>) -> Vec<T> {
+    tys.into_iter().map(|ty| NamespacedType::new(ty.namespace, ty.type_name).into()).collect()
+}
+
+fn descriptions_to_wire(descriptions: &HashMap<LanguageTag, String>) -> HashMap<String, String> {
+    descriptions
+        .iter()
+        .map(|(language, description)| (language.to_string(), description.clone()))
+        .collect()
+}
+
+fn descriptions_from_wire(descriptions: HashMap<String, String>) -> HashMap<LanguageTag, String> {
+    descriptions
+        .into_iter()
+        .map(|(language, description)| (LanguageTag::new(language), description))
+        .collect()
+}
+
+impl From<&DataType> for WireDataType {
+    fn from(data_type: &DataType) -> Self {
+        match data_type {
+            DataType::Null => WireDataType::Null,
+            DataType::Bool => WireDataType::Bool,
+            DataType::Number => WireDataType::Number,
+            DataType::String => WireDataType::String,
+            DataType::Array => WireDataType::Array,
+            DataType::Object => WireDataType::Object,
+            DataType::Any => WireDataType::Any,
+        }
+    }
+}
+
+impl From<WireDataType> for DataType {
+    fn from(data_type: WireDataType) -> Self {
+        match data_type {
+            WireDataType::Null => DataType::Null,
+            WireDataType::Bool => DataType::Bool,
+            WireDataType::Number => DataType::Number,
+            WireDataType::String => DataType::String,
+            WireDataType::Array => DataType::Array,
+            WireDataType::Object => DataType::Object,
+            WireDataType::Any => DataType::Any,
+        }
+    }
+}
+
+impl From<&SocketType> for WireSocketType {
+    fn from(socket_type: &SocketType) -> Self {
+        match socket_type {
+            SocketType::None => WireSocketType::None,
+            SocketType::Input => WireSocketType::Input,
+            SocketType::Output => WireSocketType::Output,
+        }
+    }
+}
+
+impl From<WireSocketType> for SocketType {
+    fn from(socket_type: WireSocketType) -> Self {
+        match socket_type {
+            WireSocketType::None => SocketType::None,
+            WireSocketType::Input => SocketType::Input,
+            WireSocketType::Output => SocketType::Output,
+        }
+    }
+}
+
+impl From<&Mutability> for WireMutability {
+    fn from(mutability: &Mutability) -> Self {
+        match mutability {
+            Mutability::Mutable => WireMutability::Mutable,
+            Mutability::Immutable => WireMutability::Immutable,
+        }
+    }
+}
+
+impl From<WireMutability> for Mutability {
+    fn from(mutability: WireMutability) -> Self {
+        match mutability {
+            WireMutability::Mutable => Mutability::Mutable,
+            WireMutability::Immutable => Mutability::Immutable,
+        }
+    }
+}
+
+impl From<&ComponentOrEntityTypeId> for WireComponentOrEntityTypeId {
+    fn from(ty: &ComponentOrEntityTypeId) -> Self {
+        let kind = match ty {
+            ComponentOrEntityTypeId::Component(_) => WireComponentOrEntityTypeKind::Component,
+            ComponentOrEntityTypeId::EntityType(_) => WireComponentOrEntityTypeKind::EntityType,
+        };
+        WireComponentOrEntityTypeId {
+            kind: kind as i32,
+            namespace: ty.namespace(),
+            type_name: ty.type_name(),
+        }
+    }
+}
+
+impl TryFrom<WireComponentOrEntityTypeId> for ComponentOrEntityTypeId {
+    type Error = WireFormatError;
+
+    fn try_from(ty: WireComponentOrEntityTypeId) -> Result<Self, Self::Error> {
+        match WireComponentOrEntityTypeKind::from_i32(ty.kind) {
+            Some(WireComponentOrEntityTypeKind::Component) => Ok(ComponentTypeId::new_from_type(ty.namespace, ty.type_name).into()),
+            Some(WireComponentOrEntityTypeKind::EntityType) => Ok(EntityTypeId::new_from_type(ty.namespace, ty.type_name).into()),
+            None => Err(WireFormatError::InvalidEnumValue("ComponentOrEntityTypeKind", ty.kind)),
+        }
+    }
+}
+
+impl From<&Extension> for WireExtension {
+    fn from(extension: &Extension) -> Self {
+        WireExtension {
+            namespace: extension.ty.namespace(),
+            type_name: extension.ty.type_name(),
+            description: extension.description.clone(),
+            extension: extension.extension.to_string(),
+        }
+    }
+}
+
+impl TryFrom<WireExtension> for Extension {
+    type Error = WireFormatError;
+
+    fn try_from(extension: WireExtension) -> Result<Self, Self::Error> {
+        Ok(Extension {
+            ty: ExtensionTypeId::new_from_type(extension.namespace, extension.type_name),
+            description: extension.description,
+            extension: json_from_wire(&extension.extension)?,
+        })
+    }
+}
+
+impl From<&PropertyType> for WirePropertyType {
+    fn from(property_type: &PropertyType) -> Self {
+        WirePropertyType {
+            name: property_type.name.to_string(),
+            description: property_type.description.clone(),
+            descriptions: descriptions_to_wire(&property_type.descriptions),
+            documentation: property_type.documentation.as_ref().map(WireDocumentation::from),
+            data_type: WireDataType::from(&property_type.data_type) as i32,
+            socket_type: WireSocketType::from(&property_type.socket_type) as i32,
+            mutability: WireMutability::from(&property_type.mutability) as i32,
+            extensions: property_type.extensions.iter().map(WireExtension::from).collect(),
+            examples: property_type.examples.iter().map(Value::to_string).collect(),
+            unit: property_type.unit.clone(),
+        }
+    }
+}
+
+impl TryFrom<WirePropertyType> for PropertyType {
+    type Error = WireFormatError;
+
+    fn try_from(property_type: WirePropertyType) -> Result<Self, Self::Error> {
+        let data_type = WireDataType::from_i32(property_type.data_type).ok_or(WireFormatError::InvalidEnumValue("DataType", property_type.data_type))?;
+        let socket_type =
+            WireSocketType::from_i32(property_type.socket_type).ok_or(WireFormatError::InvalidEnumValue("SocketType", property_type.socket_type))?;
+        let mutability = WireMutability::from_i32(property_type.mutability).ok_or(WireFormatError::InvalidEnumValue("Mutability", property_type.mutability))?;
+        Ok(PropertyType {
+            name: identifier_string(property_type.name),
+            description: property_type.description,
+            descriptions: descriptions_from_wire(property_type.descriptions),
+            documentation: property_type.documentation.map(Documentation::from),
+            data_type: data_type.into(),
+            socket_type: socket_type.into(),
+            mutability: mutability.into(),
+            extensions: extensions_from_wire(property_type.extensions)?,
+            examples: property_type.examples.iter().map(|example| json_from_wire(example)).collect::<Result<_, _>>()?,
+            unit: property_type.unit,
+        })
+    }
+}
+
+impl From<&Deprecation> for WireDeprecation {
+    fn from(deprecation: &Deprecation) -> Self {
+        WireDeprecation {
+            since: deprecation.since.clone(),
+            replacement: deprecation.replacement.as_ref().map(|ty| WireNamespacedType {
+                namespace: ty.namespace(),
+                type_name: ty.type_name(),
+            }),
+            message: deprecation.message.clone(),
+        }
+    }
+}
+
+impl From<WireDeprecation> for Deprecation {
+    fn from(deprecation: WireDeprecation) -> Self {
+        Deprecation {
+            since: deprecation.since,
+            replacement: deprecation.replacement.map(|ty| NamespacedType::new(ty.namespace, ty.type_name)),
+            message: deprecation.message,
+        }
+    }
+}
+
+impl From<&Documentation> for WireDocumentation {
+    fn from(documentation: &Documentation) -> Self {
+        WireDocumentation {
+            summary: documentation.summary.clone(),
+            body: documentation.body.clone(),
+            links: documentation
+                .links
+                .iter()
+                .map(|link| WireDocumentationLink {
+                    title: link.title.clone(),
+                    url: link.url.clone(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<WireDocumentation> for Documentation {
+    fn from(documentation: WireDocumentation) -> Self {
+        Documentation {
+            summary: documentation.summary,
+            body: documentation.body,
+            links: documentation
+                .links
+                .into_iter()
+                .map(|link| DocumentationLink {
+                    title: link.title,
+                    url: link.url,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<&RelationCardinality> for WireRelationCardinality {
+    fn from(cardinality: &RelationCardinality) -> Self {
+        let wire_cardinality = match cardinality.cardinality {
+            Cardinality::ManyToMany => WireCardinality::ManyToMany,
+            Cardinality::OneToOne => WireCardinality::OneToOne,
+            Cardinality::OneToMany => WireCardinality::OneToMany,
+            Cardinality::ManyToOne => WireCardinality::ManyToOne,
+        };
+        WireRelationCardinality {
+            cardinality: wire_cardinality as i32,
+            max_outbound: cardinality.max_outbound.map(|max| max as u64),
+            max_inbound: cardinality.max_inbound.map(|max| max as u64),
+        }
+    }
+}
+
+impl TryFrom<WireRelationCardinality> for RelationCardinality {
+    type Error = WireFormatError;
+
+    fn try_from(cardinality: WireRelationCardinality) -> Result<Self, Self::Error> {
+        let relation_cardinality = match WireCardinality::from_i32(cardinality.cardinality) {
+            Some(WireCardinality::ManyToMany) => Cardinality::ManyToMany,
+            Some(WireCardinality::OneToOne) => Cardinality::OneToOne,
+            Some(WireCardinality::OneToMany) => Cardinality::OneToMany,
+            Some(WireCardinality::ManyToOne) => Cardinality::ManyToOne,
+            None => return Err(WireFormatError::InvalidEnumValue("Cardinality", cardinality.cardinality)),
+        };
+        Ok(RelationCardinality {
+            cardinality: relation_cardinality,
+            max_outbound: cardinality.max_outbound.map(|max| max as usize),
+            max_inbound: cardinality.max_inbound.map(|max| max as usize),
+        })
+    }
+}
+
+impl From<&Component> for WireComponent {
+    fn from(component: &Component) -> Self {
+        WireComponent {
+            namespace: component.namespace(),
+            type_name: component.type_name(),
+            description: component.description.clone(),
+            descriptions: descriptions_to_wire(&component.descriptions),
+            documentation: component.documentation.as_ref().map(WireDocumentation::from),
+            properties: component.properties.iter().map(WirePropertyType::from).collect(),
+            extensions: component.extensions.iter().map(WireExtension::from).collect(),
+            tags: component.tags.clone(),
+            aliases: type_ids_to_wire(&component.aliases),
+            deprecation: component.deprecation.as_ref().map(WireDeprecation::from),
+        }
+    }
+}
+
+impl TryFrom<WireComponent> for Component {
+    type Error = WireFormatError;
+
+    fn try_from(component: WireComponent) -> Result<Self, Self::Error> {
+        Ok(Component {
+            ty: ComponentTypeId::new_from_type(component.namespace, component.type_name),
+            description: component.description,
+            descriptions: descriptions_from_wire(component.descriptions),
+            documentation: component.documentation.map(Documentation::from),
+            properties: property_types_from_wire(component.properties)?,
+            extensions: extensions_from_wire(component.extensions)?,
+            tags: component.tags,
+            aliases: type_ids_from_wire(component.aliases),
+            deprecation: component.deprecation.map(Deprecation::from),
+            extra: Map::new(),
+        })
+    }
+}
+
+impl From<&EntityType> for WireEntityType {
+    fn from(entity_type: &EntityType) -> Self {
+        WireEntityType {
+            namespace: entity_type.namespace(),
+            type_name: entity_type.type_name(),
+            description: entity_type.description.clone(),
+            descriptions: descriptions_to_wire(&entity_type.descriptions),
+            documentation: entity_type.documentation.as_ref().map(WireDocumentation::from),
+            components: type_ids_to_wire(&entity_type.components),
+            properties: entity_type.properties.iter().map(WirePropertyType::from).collect(),
+            extensions: entity_type.extensions.iter().map(WireExtension::from).collect(),
+            examples: entity_type.examples.iter().map(WireEntityInstance::from).collect(),
+            tags: entity_type.tags.clone(),
+            aliases: type_ids_to_wire(&entity_type.aliases),
+            deprecation: entity_type.deprecation.as_ref().map(WireDeprecation::from),
+            extends: entity_type.extends.as_ref().map(|extends| WireNamespacedType {
+                namespace: extends.namespace(),
+                type_name: extends.type_name(),
+            }),
+            is_abstract: entity_type.is_abstract,
+            is_final: entity_type.is_final,
+            scoped_properties: entity_type.scoped_properties,
+        }
+    }
+}
+
+impl TryFrom<WireEntityType> for EntityType {
+    type Error = WireFormatError;
+
+    fn try_from(entity_type: WireEntityType) -> Result<Self, Self::Error> {
+        Ok(EntityType {
+            ty: EntityTypeId::new_from_type(entity_type.namespace, entity_type.type_name),
+            description: entity_type.description,
+            descriptions: descriptions_from_wire(entity_type.descriptions),
+            documentation: entity_type.documentation.map(Documentation::from),
+            components: type_ids_from_wire(entity_type.components),
+            properties: property_types_from_wire(entity_type.properties)?,
+            extensions: extensions_from_wire(entity_type.extensions)?,
+            examples: entity_type.examples.into_iter().map(EntityInstance::try_from).collect::<Result<_, _>>()?,
+            tags: entity_type.tags,
+            aliases: type_ids_from_wire(entity_type.aliases),
+            deprecation: entity_type.deprecation.map(Deprecation::from),
+            extends: entity_type
+                .extends
+                .map(|extends| EntityTypeId::new_from_type(extends.namespace, extends.type_name)),
+            is_abstract: entity_type.is_abstract,
+            is_final: entity_type.is_final,
+            scoped_properties: entity_type.scoped_properties,
+            extra: Map::new(),
+        })
+    }
+}
+
+impl From<&RelationType> for WireRelationType {
+    fn from(relation_type: &RelationType) -> Self {
+        WireRelationType {
+            outbound_type: Some(WireComponentOrEntityTypeId::from(&relation_type.outbound_type)),
+            namespace: relation_type.namespace(),
+            type_name: relation_type.type_name(),
+            inbound_type: Some(WireComponentOrEntityTypeId::from(&relation_type.inbound_type)),
+            description: relation_type.description.clone(),
+            descriptions: descriptions_to_wire(&relation_type.descriptions),
+            documentation: relation_type.documentation.as_ref().map(WireDocumentation::from),
+            components: type_ids_to_wire(&relation_type.components),
+            properties: relation_type.properties.iter().map(WirePropertyType::from).collect(),
+            extensions: relation_type.extensions.iter().map(WireExtension::from).collect(),
+            tags: relation_type.tags.clone(),
+            aliases: type_ids_to_wire(&relation_type.aliases),
+            deprecation: relation_type.deprecation.as_ref().map(WireDeprecation::from),
+            cardinality: relation_type.cardinality.as_ref().map(WireRelationCardinality::from),
+            bidirectional: relation_type.bidirectional,
+            inverse_name: relation_type.inverse_name.clone(),
+            outbound_types: relation_type.outbound_types.iter().map(WireComponentOrEntityTypeId::from).collect(),
+            inbound_types: relation_type.inbound_types.iter().map(WireComponentOrEntityTypeId::from).collect(),
+            symmetric: relation_type.symmetric,
+            transitive: relation_type.transitive,
+            reflexive: relation_type.reflexive,
+            scoped_properties: relation_type.scoped_properties,
+        }
+    }
+}
+
+impl TryFrom<WireRelationType> for RelationType {
+    type Error = WireFormatError;
+
+    fn try_from(relation_type: WireRelationType) -> Result<Self, Self::Error> {
+        let outbound_type = relation_type.outbound_type.ok_or(WireFormatError::MissingField("outbound_type"))?;
+        let inbound_type = relation_type.inbound_type.ok_or(WireFormatError::MissingField("inbound_type"))?;
+        Ok(RelationType {
+            outbound_type: outbound_type.try_into()?,
+            ty: RelationTypeId::new_from_type(relation_type.namespace, relation_type.type_name),
+            inbound_type: inbound_type.try_into()?,
+            description: relation_type.description,
+            descriptions: descriptions_from_wire(relation_type.descriptions),
+            documentation: relation_type.documentation.map(Documentation::from),
+            components: type_ids_from_wire(relation_type.components),
+            properties: property_types_from_wire(relation_type.properties)?,
+            extensions: extensions_from_wire(relation_type.extensions)?,
+            tags: relation_type.tags,
+            aliases: type_ids_from_wire(relation_type.aliases),
+            deprecation: relation_type.deprecation.map(Deprecation::from),
+            cardinality: relation_type.cardinality.map(RelationCardinality::try_from).transpose()?,
+            bidirectional: relation_type.bidirectional,
+            inverse_name: relation_type.inverse_name,
+            outbound_types: relation_type
+                .outbound_types
+                .into_iter()
+                .map(ComponentOrEntityTypeId::try_from)
+                .collect::<Result<_, _>>()?,
+            inbound_types: relation_type
+                .inbound_types
+                .into_iter()
+                .map(ComponentOrEntityTypeId::try_from)
+                .collect::<Result<_, _>>()?,
+            symmetric: relation_type.symmetric,
+            transitive: relation_type.transitive,
+            reflexive: relation_type.reflexive,
+            scoped_properties: relation_type.scoped_properties,
+            extra: Map::new(),
+        })
+    }
+}
+
+impl From<&EntityInstance> for WireEntityInstance {
+    fn from(entity_instance: &EntityInstance) -> Self {
+        WireEntityInstance {
+            namespace: entity_instance.namespace(),
+            type_name: entity_instance.type_name(),
+            id: entity_instance.id.as_bytes().to_vec(),
+            description: entity_instance.description.clone(),
+            properties: properties_to_wire(&entity_instance.properties),
+            extensions: entity_instance.extensions.iter().map(WireExtension::from).collect(),
+            created_at: entity_instance.created_at.as_ref().map(timestamp_to_wire),
+            updated_at: entity_instance.updated_at.as_ref().map(timestamp_to_wire),
+            created_by: entity_instance.created_by.clone(),
+            deleted_at: entity_instance.deleted_at.as_ref().map(timestamp_to_wire),
+            revision: entity_instance.revision,
+        }
+    }
+}
+
+impl TryFrom<WireEntityInstance> for EntityInstance {
+    type Error = WireFormatError;
+
+    fn try_from(entity_instance: WireEntityInstance) -> Result<Self, Self::Error> {
+        Ok(EntityInstance {
+            ty: EntityTypeId::new_from_type(entity_instance.namespace, entity_instance.type_name),
+            id: uuid_from_wire(&entity_instance.id)?,
+            description: entity_instance.description,
+            properties: properties_from_wire(entity_instance.properties)?.into(),
+            extensions: extensions_from_wire(entity_instance.extensions)?,
+            created_at: timestamp_from_wire(entity_instance.created_at)?,
+            updated_at: timestamp_from_wire(entity_instance.updated_at)?,
+            created_by: entity_instance.created_by,
+            deleted_at: timestamp_from_wire(entity_instance.deleted_at)?,
+            revision: entity_instance.revision,
+            extra: Map::new(),
+        })
+    }
+}
+
+impl From<&RelationInstance> for WireRelationInstance {
+    fn from(relation_instance: &RelationInstance) -> Self {
+        let ty = relation_instance.relation_type_id();
+        WireRelationInstance {
+            outbound_id: relation_instance.outbound_id.as_bytes().to_vec(),
+            namespace: ty.namespace(),
+            type_name: ty.type_name(),
+            instance_id: relation_instance.instance_id(),
+            inbound_id: relation_instance.inbound_id.as_bytes().to_vec(),
+            description: relation_instance.description.clone(),
+            properties: properties_to_wire(&relation_instance.properties),
+            extensions: relation_instance.extensions.iter().map(WireExtension::from).collect(),
+            created_at: relation_instance.created_at.as_ref().map(timestamp_to_wire),
+            updated_at: relation_instance.updated_at.as_ref().map(timestamp_to_wire),
+            created_by: relation_instance.created_by.clone(),
+            deleted_at: relation_instance.deleted_at.as_ref().map(timestamp_to_wire),
+            revision: relation_instance.revision,
+        }
+    }
+}
+
+impl TryFrom<WireRelationInstance> for RelationInstance {
+    type Error = WireFormatError;
+
+    fn try_from(relation_instance: WireRelationInstance) -> Result<Self, Self::Error> {
+        let ty = RelationTypeId::new_from_type(relation_instance.namespace, relation_instance.type_name);
+        Ok(RelationInstance {
+            outbound_id: uuid_from_wire(&relation_instance.outbound_id)?,
+            ty: RelationInstanceTypeId::new_unique_for_instance_id(ty, relation_instance.instance_id),
+            inbound_id: uuid_from_wire(&relation_instance.inbound_id)?,
+            description: relation_instance.description,
+            properties: properties_from_wire(relation_instance.properties)?.into(),
+            extensions: extensions_from_wire(relation_instance.extensions)?,
+            created_at: timestamp_from_wire(relation_instance.created_at)?,
+            updated_at: timestamp_from_wire(relation_instance.updated_at)?,
+            created_by: relation_instance.created_by,
+            deleted_at: timestamp_from_wire(relation_instance.deleted_at)?,
+            revision: relation_instance.revision,
+            extra: Map::new(),
+        })
+    }
+}
+
+fn direction_to_wire(direction: &SubFlowBindingDirection) -> i32 {
+    let direction = match direction {
+        SubFlowBindingDirection::Input => WireSubFlowBindingDirection::Input,
+        SubFlowBindingDirection::Output => WireSubFlowBindingDirection::Output,
+    };
+    direction as i32
+}
+
+fn direction_from_wire(direction: i32) -> Result<SubFlowBindingDirection, WireFormatError> {
+    match WireSubFlowBindingDirection::from_i32(direction) {
+        Some(WireSubFlowBindingDirection::Input) => Ok(SubFlowBindingDirection::Input),
+        Some(WireSubFlowBindingDirection::Output) => Ok(SubFlowBindingDirection::Output),
+        None => Err(WireFormatError::InvalidEnumValue("SubFlowBindingDirection", direction)),
+    }
+}
+
+impl From<&SubFlowBinding> for WireSubFlowBinding {
+    fn from(binding: &SubFlowBinding) -> Self {
+        WireSubFlowBinding {
+            direction: direction_to_wire(&binding.direction),
+            outer_entity_id: binding.outer_entity_id.as_bytes().to_vec(),
+            outer_property_name: binding.outer_property_name.clone(),
+            inner_entity_id: binding.inner_entity_id.as_bytes().to_vec(),
+            inner_property_name: binding.inner_property_name.clone(),
+        }
+    }
+}
+
+impl TryFrom<WireSubFlowBinding> for SubFlowBinding {
+    type Error = WireFormatError;
+
+    fn try_from(binding: WireSubFlowBinding) -> Result<Self, Self::Error> {
+        Ok(SubFlowBinding {
+            direction: direction_from_wire(binding.direction)?,
+            outer_entity_id: uuid_from_wire(&binding.outer_entity_id)?,
+            outer_property_name: binding.outer_property_name,
+            inner_entity_id: uuid_from_wire(&binding.inner_entity_id)?,
+            inner_property_name: binding.inner_property_name,
+        })
+    }
+}
+
+impl From<&SubFlowReference> for WireSubFlowReference {
+    fn from(sub_flow: &SubFlowReference) -> Self {
+        WireSubFlowReference {
+            flow_id: sub_flow.flow_id.as_bytes().to_vec(),
+            bindings: sub_flow.bindings.iter().map(WireSubFlowBinding::from).collect(),
+        }
+    }
+}
+
+impl TryFrom<WireSubFlowReference> for SubFlowReference {
+    type Error = WireFormatError;
+
+    fn try_from(sub_flow: WireSubFlowReference) -> Result<Self, Self::Error> {
+        Ok(SubFlowReference {
+            flow_id: uuid_from_wire(&sub_flow.flow_id)?,
+            bindings: sub_flow.bindings.into_iter().map(SubFlowBinding::try_from).collect::<Result<Vec<_>, _>>()?,
+        })
+    }
+}
+
+impl From<&FlowInstance> for WireFlowInstance {
+    fn from(flow_instance: &FlowInstance) -> Self {
+        WireFlowInstance {
+            id: flow_instance.id.as_bytes().to_vec(),
+            namespace: flow_instance.namespace(),
+            type_name: flow_instance.type_name(),
+            name: flow_instance.name.clone(),
+            description: flow_instance.description.clone(),
+            entity_instances: flow_instance.entity_instances.iter().map(WireEntityInstance::from).collect(),
+            relation_instances: flow_instance.relation_instances.iter().map(WireRelationInstance::from).collect(),
+            external_entity_ids: flow_instance.external_entity_ids.iter().map(|id| id.as_bytes().to_vec()).collect(),
+            sub_flows: flow_instance.sub_flows.iter().map(WireSubFlowReference::from).collect(),
+        }
+    }
+}
+
+impl TryFrom<WireFlowInstance> for FlowInstance {
+    type Error = WireFormatError;
+
+    fn try_from(flow_instance: WireFlowInstance) -> Result<Self, Self::Error> {
+        Ok(FlowInstance {
+            id: uuid_from_wire(&flow_instance.id)?,
+            ty: EntityTypeId::new_from_type(flow_instance.namespace, flow_instance.type_name),
+            name: flow_instance.name,
+            description: flow_instance.description,
+            entity_instances: flow_instance
+                .entity_instances
+                .into_iter()
+                .map(EntityInstance::try_from)
+                .collect::<Result<Vec<_>, _>>()?,
+            relation_instances: flow_instance
+                .relation_instances
+                .into_iter()
+                .map(RelationInstance::try_from)
+                .collect::<Result<Vec<_>, _>>()?,
+            external_entity_ids: flow_instance
+                .external_entity_ids
+                .iter()
+                .map(|id| uuid_from_wire(id))
+                .collect::<Result<Vec<_>, _>>()?,
+            sub_flows: flow_instance
+                .sub_flows
+                .into_iter()
+                .map(SubFlowReference::try_from)
+                .collect::<Result<Vec<_>, _>>()?,
+            extra: Map::new(),
+        })
+    }
+}
+
+impl From<&FlowTypePort> for WireFlowTypePort {
+    fn from(port: &FlowTypePort) -> Self {
+        WireFlowTypePort {
+            name: port.name.clone(),
+            description: port.description.clone(),
+            entity_id: port.entity_id.as_bytes().to_vec(),
+            property_name: port.property_name.clone(),
+        }
+    }
+}
+
+impl TryFrom<WireFlowTypePort> for FlowTypePort {
+    type Error = WireFormatError;
+
+    fn try_from(port: WireFlowTypePort) -> Result<Self, Self::Error> {
+        Ok(FlowTypePort {
+            name: port.name,
+            description: port.description,
+            entity_id: uuid_from_wire(&port.entity_id)?,
+            property_name: port.property_name,
+        })
+    }
+}
+
+impl From<&SubFlowTypeBinding> for WireSubFlowTypeBinding {
+    fn from(binding: &SubFlowTypeBinding) -> Self {
+        WireSubFlowTypeBinding {
+            direction: direction_to_wire(&binding.direction),
+            outer_entity_id: binding.outer_entity_id.as_bytes().to_vec(),
+            outer_property_name: binding.outer_property_name.clone(),
+            port: binding.port.clone(),
+        }
+    }
+}
+
+impl TryFrom<WireSubFlowTypeBinding> for SubFlowTypeBinding {
+    type Error = WireFormatError;
+
+    fn try_from(binding: WireSubFlowTypeBinding) -> Result<Self, Self::Error> {
+        Ok(SubFlowTypeBinding {
+            direction: direction_from_wire(binding.direction)?,
+            outer_entity_id: uuid_from_wire(&binding.outer_entity_id)?,
+            outer_property_name: binding.outer_property_name,
+            port: binding.port,
+        })
+    }
+}
+
+impl From<&SubFlowTypeReference> for WireSubFlowTypeReference {
+    fn from(sub_flow: &SubFlowTypeReference) -> Self {
+        WireSubFlowTypeReference {
+            name: sub_flow.name.clone(),
+            namespace: sub_flow.ty.namespace(),
+            type_name: sub_flow.ty.type_name(),
+            bindings: sub_flow.bindings.iter().map(WireSubFlowTypeBinding::from).collect(),
+        }
+    }
+}
+
+impl TryFrom<WireSubFlowTypeReference> for SubFlowTypeReference {
+    type Error = WireFormatError;
+
+    fn try_from(sub_flow: WireSubFlowTypeReference) -> Result<Self, Self::Error> {
+        Ok(SubFlowTypeReference {
+            name: sub_flow.name,
+            ty: FlowTypeId::new_from_type(sub_flow.namespace, sub_flow.type_name),
+            bindings: sub_flow.bindings.into_iter().map(SubFlowTypeBinding::try_from).collect::<Result<Vec<_>, _>>()?,
+        })
+    }
+}
+
+impl From<&FlowTypeParameter> for WireFlowTypeParameter {
+    fn from(type_parameter: &FlowTypeParameter) -> Self {
+        WireFlowTypeParameter {
+            name: type_parameter.name.clone(),
+            description: type_parameter.description.clone(),
+            constraint: type_parameter.constraint.as_ref().map(WireComponentOrEntityTypeId::from),
+            default: type_parameter.default.as_ref().map(|ty| WireNamespacedType {
+                namespace: ty.namespace(),
+                type_name: ty.type_name(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<WireFlowTypeParameter> for FlowTypeParameter {
+    type Error = WireFormatError;
+
+    fn try_from(type_parameter: WireFlowTypeParameter) -> Result<Self, Self::Error> {
+        Ok(FlowTypeParameter {
+            name: type_parameter.name,
+            description: type_parameter.description,
+            constraint: type_parameter.constraint.map(ComponentOrEntityTypeId::try_from).transpose()?,
+            default: type_parameter.default.map(|ty| EntityTypeId::new_from_type(ty.namespace, ty.type_name)),
+        })
+    }
+}
+
+impl From<&FlowType> for WireFlowType {
+    fn from(flow_type: &FlowType) -> Self {
+        WireFlowType {
+            namespace: flow_type.namespace(),
+            type_name: flow_type.type_name(),
+            description: flow_type.description.clone(),
+            wrapper_entity_instance: Some(WireEntityInstance::from(&flow_type.wrapper_entity_instance)),
+            entity_instances: flow_type.entity_instances.iter().map(WireEntityInstance::from).collect(),
+            relation_instances: flow_type.relation_instances.iter().map(WireRelationInstance::from).collect(),
+            variables: flow_type.variables.iter().map(WirePropertyType::from).collect(),
+            inputs: flow_type.inputs.iter().map(WireFlowTypePort::from).collect(),
+            outputs: flow_type.outputs.iter().map(WireFlowTypePort::from).collect(),
+            extensions: flow_type.extensions.iter().map(WireExtension::from).collect(),
+            tags: flow_type.tags.clone(),
+            aliases: type_ids_to_wire(&flow_type.aliases),
+            deprecation: flow_type.deprecation.as_ref().map(WireDeprecation::from),
+            descriptions: descriptions_to_wire(&flow_type.descriptions),
+            documentation: flow_type.documentation.as_ref().map(WireDocumentation::from),
+            examples: flow_type.examples.iter().map(WireFlowInstance::from).collect(),
+            sub_flows: flow_type.sub_flows.iter().map(WireSubFlowTypeReference::from).collect(),
+            type_parameters: flow_type.type_parameters.iter().map(WireFlowTypeParameter::from).collect(),
+        }
+    }
+}
+
+impl TryFrom<WireFlowType> for FlowType {
+    type Error = WireFormatError;
+
+    fn try_from(flow_type: WireFlowType) -> Result<Self, Self::Error> {
+        let wrapper_entity_instance = flow_type
+            .wrapper_entity_instance
+            .ok_or(WireFormatError::MissingField("wrapper_entity_instance"))?;
+        Ok(FlowType {
+            ty: FlowTypeId::new_from_type(flow_type.namespace, flow_type.type_name),
+            description: flow_type.description,
+            wrapper_entity_instance: wrapper_entity_instance.try_into()?,
+            entity_instances: flow_type
+                .entity_instances
+                .into_iter()
+                .map(EntityInstance::try_from)
+                .collect::<Result<Vec<_>, _>>()?,
+            relation_instances: flow_type
+                .relation_instances
+                .into_iter()
+                .map(RelationInstance::try_from)
+                .collect::<Result<Vec<_>, _>>()?,
+            variables: property_types_from_wire(flow_type.variables)?,
+            inputs: flow_type.inputs.into_iter().map(FlowTypePort::try_from).collect::<Result<Vec<_>, _>>()?,
+            outputs: flow_type.outputs.into_iter().map(FlowTypePort::try_from).collect::<Result<Vec<_>, _>>()?,
+            extensions: extensions_from_wire(flow_type.extensions)?,
+            tags: flow_type.tags,
+            aliases: type_ids_from_wire(flow_type.aliases),
+            deprecation: flow_type.deprecation.map(Deprecation::from),
+            descriptions: descriptions_from_wire(flow_type.descriptions),
+            documentation: flow_type.documentation.map(Documentation::from),
+            examples: flow_type.examples.into_iter().map(FlowInstance::try_from).collect::<Result<_, _>>()?,
+            sub_flows: flow_type
+                .sub_flows
+                .into_iter()
+                .map(SubFlowTypeReference::try_from)
+                .collect::<Result<Vec<_>, _>>()?,
+            type_parameters: flow_type
+                .type_parameters
+                .into_iter()
+                .map(FlowTypeParameter::try_from)
+                .collect::<Result<Vec<_>, _>>()?,
+            extra: Map::new(),
+        })
+    }
+}